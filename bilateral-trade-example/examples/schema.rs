@@ -3,9 +3,12 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use bilateral_trade_example::contract_info::ContractInfo;
-use bilateral_trade_example::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use bilateral_trade_example::state::{AskOrder, BidOrder};
+use bilateral_trade_example::contract_info::{ContractInfo, VersionInfo};
+use bilateral_trade_example::msg::{
+    ExecuteMsg, FindMatchingBidsResponse, GetAsksResponse, GetBidsResponse, GetConfigResponse,
+    GetEscrowSummaryResponse, GetOrderPairResponse, InstantiateMsg, MatchPreviewResponse, QueryMsg,
+};
+use bilateral_trade_example::state::{AskOrder, BidOrder, TradeStats};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -17,6 +20,15 @@ fn main() {
     export_schema(&schema_for!(BidOrder), &out_dir);
     export_schema(&schema_for!(ContractInfo), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
+    export_schema(&schema_for!(FindMatchingBidsResponse), &out_dir);
+    export_schema(&schema_for!(GetAsksResponse), &out_dir);
+    export_schema(&schema_for!(GetBidsResponse), &out_dir);
+    export_schema(&schema_for!(GetConfigResponse), &out_dir);
+    export_schema(&schema_for!(GetEscrowSummaryResponse), &out_dir);
+    export_schema(&schema_for!(GetOrderPairResponse), &out_dir);
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
+    export_schema(&schema_for!(MatchPreviewResponse), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(TradeStats), &out_dir);
+    export_schema(&schema_for!(VersionInfo), &out_dir);
 }
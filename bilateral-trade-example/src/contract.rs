@@ -1,28 +1,53 @@
 use cosmwasm_std::{
-    attr, coin, entry_point, to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, Response, StdResult, Timestamp, Uint128,
+    attr, coin, entry_point, to_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps,
+    DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult, Storage, Timestamp, Uint128,
 };
 use provwasm_std::{
-    assess_custom_fee, bind_name, write_scope, NameBinding, Party, PartyType, ProvenanceMsg,
-    ProvenanceQuerier, ProvenanceQuery, Scope,
+    assess_custom_fee, bind_name, grant_marker_access, revoke_marker_access, write_scope, Marker,
+    MarkerAccess, NameBinding, Party, PartyType, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery,
+    Scope,
 };
-use thiserror::private::DisplayAsDisplay;
+use semver::Version;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::any::type_name;
 
-use crate::contract_info::{get_contract_info, set_contract_info, ContractInfo, CONTRACT_VERSION};
+use crate::contract_info::{
+    get_contract_info, set_contract_info, ContractInfo, FeeMode, VersionInfo, CONTRACT_VERSION,
+};
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{
+    AskOrBid, ExecuteMsg, FindMatchingBidsResponse, GetAsksResponse, GetBidsResponse,
+    GetConfigResponse, GetEscrowSummaryResponse, GetOrderPairResponse, InstantiateMsg, MatchPair,
+    MatchPreviewResponse, MigrateMsg, QueryMsg,
+};
 use crate::state::{
-    get_ask_storage_read_v2, get_ask_storage_v2, get_bid_storage_read_v2, get_bid_storage_v2,
-    AskOrderV2, BaseType, BidOrderV2,
+    get_ask_storage, get_ask_storage_read, get_ask_storage_read_v2, get_ask_storage_v2,
+    get_bid_storage, get_bid_storage_read, get_bid_storage_read_v2, get_bid_storage_v2,
+    get_open_order_count_storage, get_open_order_count_storage_read, get_trade_stats_storage,
+    get_trade_stats_storage_read, AskOrderV2, BaseType, BidOrderV2,
 };
 
+// the amount of orders returned by a listing query when no limit is provided
+const DEFAULT_QUERY_LIMIT: u32 = 25;
+// the maximum amount of orders a listing query can return, regardless of the limit provided
+const MAX_QUERY_LIMIT: u32 = 100;
+// the base denom filter value that matches every scope base in a listing query
+const SCOPE_BASE_FILTER: &str = "scope";
+// the bech32 human-readable part and separator that begin every scope address
+const SCOPE_ADDRESS_PREFIX: &str = "scope1";
+// the basis points that make up an entire quote, and therefore the largest fee that can be charged in basis points
+const TOTAL_BASIS_POINTS: u128 = 10000;
+// creation fees are charged in nhash, so basis points fees can only be computed from quotes in this denom
+const FEE_DENOM: &str = "nhash";
+
 // smart contract initialization entrypoint
 // This will set up a specific instance of this contract on the blockchain that has a unique address (generated upon instantiation)
 // the storage containing ask/bid info will be unique to this instance of the smart contract, so only asks/bid_storage
 // within the same instance can be matched
 #[entry_point]
 pub fn instantiate(
-    deps: DepsMut<ProvenanceQuery>,
+    mut deps: DepsMut<ProvenanceQuery>,
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
@@ -57,34 +82,86 @@ pub fn instantiate(
         });
     }
 
+    // A match fee of zero is invalid, but omitting the match fee (None) is valid because it indicates
+    // no fee is to be charged
+    if msg.match_fee.is_some() && msg.match_fee.unwrap().is_zero() {
+        return Err(ContractError::InvalidFee {
+            fee_type: "match".to_string(),
+        });
+    }
+
+    // Ask and bid fees charged in basis points cannot exceed the order's entire quote
+    let fee_mode = msg.fee_mode.unwrap_or_default();
+    check_fee_basis_points(&fee_mode, "ask", &msg.ask_fee)?;
+    check_fee_basis_points(&fee_mode, "bid", &msg.bid_fee)?;
+
+    // An empty list of allowed quote denoms would prevent any order from being created, but omitting
+    // the list (None) is valid because it indicates that any denom is accepted
+    if matches!(&msg.allowed_quote_denoms, Some(denoms) if denoms.is_empty()) {
+        return Err(ContractError::MissingField {
+            field: "allowed_quote_denoms".into(),
+        });
+    }
+
     // set contract info
-    let contract_info = ContractInfo::new(
-        info.sender,
+    let mut contract_info = ContractInfo::new(
+        info.sender.to_owned(),
         msg.bind_name,
         msg.contract_name,
         msg.ask_fee,
         msg.bid_fee,
     );
+    contract_info.allowed_quote_denoms = msg.allowed_quote_denoms;
+    contract_info.match_fee = msg.match_fee;
+    contract_info.allow_self_match = msg.allow_self_match.unwrap_or(false);
+    contract_info.min_base_amount = msg.min_base_amount;
+    contract_info.min_quote_amount = msg.min_quote_amount;
+    contract_info.fee_mode = fee_mode;
+    contract_info.max_orders_per_owner = msg.max_orders_per_owner;
     set_contract_info(deps.storage, &contract_info)?;
 
     // create name binding provenance message
     let bind_name_msg = bind_name(
         contract_info.bind_name,
-        env.contract.address,
+        env.contract.address.to_owned(),
         NameBinding::Restricted,
     )?;
 
     // build response
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_messages(vec![bind_name_msg]) // this message will be executed in the same transaction once this function returns
         .add_attributes(vec![
             // these are attributes that will be included in the event resulting from this contract instantiation
             attr(
                 "contract_info",
-                format!("{:?}", get_contract_info(deps.storage)?),
+                to_json_string(&get_contract_info(deps.storage)?)?,
             ),
             attr("action", "init"),
-        ]))
+        ]);
+
+    // a seeded ask is created exactly as the CreateScopeAsk route would create it for the instantiator, so its
+    // scope transfer (and any ask fee) is executed after the name is bound
+    if let Some(initial_ask) = msg.initial_ask {
+        let ask_response = create_scope_ask(
+            deps.branch(),
+            env,
+            info,
+            initial_ask.id,
+            initial_ask.quote,
+            initial_ask.scope_address,
+            None,
+        )?;
+        response = response
+            .add_submessages(ask_response.messages)
+            .add_attributes(
+                ask_response
+                    .attributes
+                    .into_iter()
+                    .filter(|attribute| attribute.key != "action"),
+            );
+    }
+
+    Ok(response)
 }
 
 // smart contract execute entrypoint
@@ -101,29 +178,98 @@ pub fn execute(
             id,
             quote,
             scope_address,
-        } => create_ask(deps, env, info, id, quote, scope_address),
+            marker_denom,
+            expiration,
+            exact_base,
+            expected_previous_owner,
+        } => create_ask(
+            deps,
+            env,
+            info,
+            id,
+            quote,
+            scope_address,
+            marker_denom,
+            expiration,
+            exact_base,
+            expected_previous_owner,
+        ),
+        ExecuteMsg::CreateScopeAsk {
+            id,
+            quote,
+            scope_address,
+            expiration,
+        } => create_scope_ask(deps, env, info, id, quote, scope_address, expiration),
         ExecuteMsg::CreateBid {
             id,
             base,
             effective_time,
-        } => create_bid(deps, env, info, id, base, effective_time),
+            expiration,
+            accepted_ask_quote,
+            requested_data_access,
+            value_owner_override,
+        } => create_bid(
+            deps,
+            env,
+            info,
+            id,
+            base,
+            effective_time,
+            expiration,
+            accepted_ask_quote,
+            requested_data_access,
+            value_owner_override,
+        ),
         ExecuteMsg::CancelAsk { id } => cancel_ask(deps, env, info, id),
+        ExecuteMsg::ReduceAsk { id, remove } => reduce_ask(deps, info, id, remove),
         ExecuteMsg::CancelBid { id } => cancel_bid(deps, env, info, id),
-        ExecuteMsg::UpdateFees { ask_fee, bid_fee } => update_fees(deps, info, ask_fee, bid_fee),
+        ExecuteMsg::IncreaseBid { id } => increase_bid(deps, env, info, id),
+        ExecuteMsg::CancelAllForOwner { owner } => cancel_all_for_owner(deps, env, info, owner),
+        ExecuteMsg::AdminCancel {
+            order_type,
+            id,
+            reason,
+        } => admin_cancel(deps, env, info, order_type, id, reason),
+        ExecuteMsg::DrainAll {} => drain_all(deps, env, info),
+        ExecuteMsg::UpdateFees {
+            ask_fee,
+            bid_fee,
+            match_fee,
+            fee_mode,
+        } => update_fees(deps, info, ask_fee, bid_fee, match_fee, fee_mode),
+        ExecuteMsg::UpdateAdmin { new_admin } => update_admin(deps, info, new_admin),
+        ExecuteMsg::UpdateBindName { bind_name } => update_bind_name(deps, env, info, bind_name),
+        ExecuteMsg::UpdateQuoteDenoms {
+            allowed_quote_denoms,
+        } => update_quote_denoms(deps, info, allowed_quote_denoms),
+        ExecuteMsg::UpdateMinOrderSizes {
+            min_base_amount,
+            min_quote_amount,
+        } => update_min_order_sizes(deps, info, min_base_amount, min_quote_amount),
+        ExecuteMsg::SetPaused { paused } => set_paused(deps, info, paused),
         ExecuteMsg::ExecuteMatch { ask_id, bid_id } => {
             execute_match(deps, env, info, ask_id, bid_id)
         }
+        ExecuteMsg::ExecuteMatchBatch { pairs } => execute_match_batch(deps, env, info, pairs),
+        ExecuteMsg::ReapExpired { ids } => reap_expired(deps, env, info, ids),
+        ExecuteMsg::ExecutePartialMatch {
+            ask_id,
+            bid_id,
+            fill_amount,
+        } => execute_partial_match(deps, env, info, ask_id, bid_id, fill_amount),
     }
 }
 
 // create ask entrypoint
-// This entrypoint will add an entry into ask storage indicating that the owner of some coin/a scope will accept
-// some amount of tokens (the quote Vec<Coin>) in exchange for the coin/scope. Upon executing this contract entrypoint,
+// This entrypoint will add an entry into ask storage indicating that the owner of some coin/a scope/a marker will accept
+// some amount of tokens (the quote Vec<Coin>) in exchange for the coin/scope/marker. Upon executing this contract entrypoint,
 // the coin up for sale is transferred to the smart contract's control (or in the case of a scope, the scope's ownership has
-// to have already been transferred to the contract before executing this method), but will not be transferred to a buyer
+// to have already been transferred to the contract before executing this method, and in the case of a marker, the contract
+// must have already been granted admin access to the marker), but will not be transferred to a buyer
 // until there is a bid created that matches the quote provided here, and the contract admin executes the match. In the case of
-// a base of Coin, the coins have to be provided to the contract via info.funds. The contract will hold onto the provided coins/scope
+// a base of Coin, the coins have to be provided to the contract via info.funds. The contract will hold onto the provided coins/scope/marker
 // until the ask is either matched with a bid by the admin or is cancelled by its owner.
+#[allow(clippy::too_many_arguments)]
 fn create_ask(
     deps: DepsMut<ProvenanceQuery>,
     env: Env,
@@ -131,19 +277,21 @@ fn create_ask(
     id: String,
     quote: Vec<Coin>,
     scope_address: Option<String>,
+    marker_denom: Option<String>,
+    expiration: Option<Timestamp>,
+    exact_base: Option<Vec<Coin>>,
+    expected_previous_owner: Option<String>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    // the id has to be provided in the message, not generated randomly in the contract as contracts have to be deterministic
-    if id.is_empty() {
-        return Err(ContractError::MissingField { field: "id".into() });
-    }
-    if quote.is_empty() {
-        return Err(ContractError::MissingField {
-            field: "quote".into(),
-        });
+    check_not_paused(deps.as_ref())?;
+    check_ask_fields(deps.as_ref(), &id, &quote)?;
+    // an ask can only ever list a single type of base
+    if scope_address.is_some() && marker_denom.is_some() {
+        return Err(ContractError::MultipleAskBases);
     }
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
     let base = if let Some(address) = scope_address {
-        // can't provide funds when putting in an ask for a scope
-        if !info.funds.is_empty() {
+        // can't provide funds (or declare an exact coin base) when putting in an ask for a scope
+        if !info.funds.is_empty() || exact_base.is_some() {
             return Err(ContractError::ScopeAskBaseWithFunds);
         }
         // verify that the scope is owned by the contract prior to consuming via the ask route
@@ -152,48 +300,234 @@ fn create_ask(
         // eliminate the risk of the scope being owned by the contract but not yet registered as an ask within the contract
         // (otherwise anyone could subsequently create an ask for someone else's scope and end up with the funds upon a match)
         // ... unfortunately from the perspective of the contract we have no way to enforce that behavior
+        // the CreateScopeAsk route avoids this entirely by transferring ownership to the contract within the same message
+        check_scope_address(&address)?;
         check_scope_owners(
             &ProvenanceQuerier::new(&deps.querier).get_scope(&address)?,
             Some(&env.contract.address),
             Some(&env.contract.address),
         )?;
         BaseType::scope(&address)
+    } else if let Some(denom) = marker_denom {
+        // can't provide funds (or declare an exact coin base) when putting in an ask for a marker
+        if !info.funds.is_empty() || exact_base.is_some() {
+            return Err(ContractError::MarkerAskBaseWithFunds);
+        }
+        // verify that the contract has been granted admin access to the marker prior to consuming via the ask route.
+        // much like with scopes, the grant should be done in a previous message in the same transaction. The asker's
+        // own access is revoked below, so only the contract may hold admin access outside of the asker
+        check_marker_admins(
+            &ProvenanceQuerier::new(&deps.querier).get_marker_by_denom(&denom)?,
+            &env.contract.address,
+            Some(&info.sender),
+        )?;
+        // remove the asker's access to the marker now that it is controlled by the contract
+        messages.push(revoke_marker_access(&denom, info.sender.to_owned())?);
+        BaseType::marker(&denom)
     } else {
         if info.funds.is_empty() {
             return Err(ContractError::MissingAskBase);
         }
-        BaseType::coins(info.funds)
+        check_no_zero_amounts(&info.funds)?;
+        match exact_base {
+            Some(exact_base) => {
+                check_no_zero_amounts(&exact_base)?;
+                check_exact_base(&exact_base, &info.funds)?;
+                // anything sent beyond the declared base is returned to the asker
+                let refund = subtract_coins(&info.funds, &exact_base);
+                if !refund.is_empty() {
+                    messages.push(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: info.sender.to_string(),
+                        amount: refund,
+                    }));
+                }
+                BaseType::coins(exact_base)
+            }
+            None => BaseType::coins(info.funds),
+        }
+    };
+    let expected_previous_owner = match expected_previous_owner {
+        Some(address) => Some(validate_expected_previous_owner(
+            deps.as_ref(),
+            &base,
+            address,
+        )?),
+        None => None,
     };
-
-    let mut ask_storage = get_ask_storage_v2(deps.storage);
 
     // create/store the ask order, mapping the provided base with the quote the seller is willing to accept
-    let ask_order = AskOrderV2 {
-        base,
-        id,
-        owner: info.sender,
-        quote,
-    };
+    save_ask_order(
+        deps,
+        env,
+        AskOrderV2 {
+            base,
+            expiration,
+            expected_previous_owner,
+            id,
+            owner: info.sender,
+            quote,
+        },
+        messages,
+    )
+}
+
+// create scope ask entrypoint
+// This entrypoint lists a scope owned by the sender for trade, transferring ownership of the scope to the contract in the
+// same message that creates the ask. Unlike creating an ask for a scope that was already transferred to the contract in a
+// previous message, there is never a moment where the contract owns the scope without a recorded ask, so no other account
+// can claim the scope by creating an ask for it first
+fn create_scope_ask(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    quote: Vec<Coin>,
+    scope_address: String,
+    expiration: Option<Timestamp>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_not_paused(deps.as_ref())?;
+    check_ask_fields(deps.as_ref(), &id, &quote)?;
+    // can't provide funds when putting in an ask for a scope
+    if !info.funds.is_empty() {
+        return Err(ContractError::ScopeAskBaseWithFunds);
+    }
+    // the sender must currently own the scope that they are listing
+    check_scope_address(&scope_address)?;
+    let scope = ProvenanceQuerier::new(&deps.querier).get_scope(&scope_address)?;
+    check_scope_owners(&scope, Some(&info.sender), Some(&info.sender))?;
+    // hand ownership of the scope to the contract. The sender's existing data access is kept until a match occurs, at
+    // which point execute_match removes it before the scope reaches the bidder. The contract signs the message, and the
+    // Provenance Blockchain metadata module accepts the account that invoked the contract as the current owner's signature
+    let write_scope_msg = write_scope(
+        replace_scope_owner(
+            scope,
+            env.contract.address.to_owned(),
+            DataAccessPolicy::Keep,
+        )?,
+        vec![env.contract.address.to_owned()],
+    )?;
+
+    save_ask_order(
+        deps,
+        env,
+        AskOrderV2 {
+            base: BaseType::scope(&scope_address),
+            expiration,
+            expected_previous_owner: None,
+            id,
+            owner: info.sender,
+            quote,
+        },
+        vec![write_scope_msg],
+    )
+}
+
+// verifies the fields that every ask requires, regardless of its base
+fn check_ask_fields(
+    deps: Deps<ProvenanceQuery>,
+    id: &str,
+    quote: &[Coin],
+) -> Result<(), ContractError> {
+    // the id has to be provided in the message, not generated randomly in the contract as contracts have to be deterministic
+    if id.is_empty() {
+        return Err(ContractError::MissingField { field: "id".into() });
+    }
+    // saving over an existing ask would orphan its escrowed base in the contract
+    if get_ask_storage_read_v2(deps.storage)
+        .may_load(id.as_bytes())?
+        .is_some()
+    {
+        return Err(ContractError::OrderIdAlreadyExists { id: id.into() });
+    }
+    if quote.is_empty() {
+        return Err(ContractError::MissingField {
+            field: "quote".into(),
+        });
+    }
+    check_no_zero_amounts(quote)?;
+    // only quotes in denoms approved by the contract admin can be accepted
+    check_quote_denoms(&get_contract_info(deps.storage)?, quote)
+}
+
+// ensures that an expected previous owner is only declared for a scope base, and that it is a valid address
+fn validate_expected_previous_owner(
+    deps: Deps<ProvenanceQuery>,
+    base: &BaseType,
+    address: String,
+) -> Result<Addr, ContractError> {
+    if !matches!(base, BaseType::Scope { .. }) {
+        return Err(ContractError::InvalidExpectedPreviousOwner {
+            explanation: format!(
+                "an expected previous owner can only be declared for a scope base, not a {} base",
+                base.type_name(),
+            ),
+        });
+    }
+    Ok(deps.api.addr_validate(&address)?)
+}
+
+// ensures that no order is created with a zero amount coin, which would otherwise linger in its base or quote and
+// confuse the coin comparisons made when determining if an ask and bid match
+fn check_no_zero_amounts(coins: &[Coin]) -> Result<(), ContractError> {
+    match coins.iter().find(|coin| coin.amount.is_zero()) {
+        Some(zero_coin) => Err(ContractError::ZeroAmountCoin {
+            denom: zero_coin.denom.to_owned(),
+        }),
+        None => Ok(()),
+    }
+}
+
+// ensures that the admin has not paused the creation of new orders
+fn check_not_paused(deps: Deps<ProvenanceQuery>) -> Result<(), ContractError> {
+    if get_contract_info(deps.storage)?.paused {
+        return Err(ContractError::ContractPaused);
+    }
+    Ok(())
+}
+
+// stores a new ask and produces the response for its creation, including the given messages and any configured ask fee
+fn save_ask_order(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    mut ask_order: AskOrderV2,
+    messages: Vec<CosmosMsg<ProvenanceMsg>>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // store the base and quote in their canonical form so that matching never depends on how the coins were listed
+    ask_order.base = merge_base_coins(ask_order.base);
+    ask_order.quote = merge_coins(ask_order.quote);
+    let contract_info = get_contract_info(deps.storage)?;
+    // dust orders are rejected once the base is known, regardless of the route that created the ask
+    check_min_order_sizes(&contract_info, &ask_order.base, &ask_order.quote)?;
+    // the match fee is paid out of the ask's quote, so an ask that could never cover it would never be matchable
+    check_match_fee_quote(&contract_info, &ask_order.quote)?;
+    open_order(deps.storage, &contract_info, &ask_order.owner)?;
+
     // key the ask by id to allow for lookup by id later
-    ask_storage.save(ask_order.id.as_bytes(), &ask_order)?;
+    get_ask_storage_v2(deps.storage).save(ask_order.id.as_bytes(), &ask_order)?;
 
     let mut response = Response::new()
+        .add_messages(messages)
         // anything watching the event stream could see an event from this contract with this attribute, and then act on it if desired
         .add_attribute("action", "create_ask")
+        .add_attribute("ask_order", to_json_string(&ask_order)?)
+        .add_attribute("order_id", &ask_order.id)
+        .add_attribute("order_type", "ask")
         .set_data(to_binary(&ask_order)?);
 
-    let contract_info = get_contract_info(deps.storage)?;
-
     // Only generate an ask fee message if it is configured within the contract info
     if let Some(ref ask_fee) = &contract_info.ask_fee {
-        response = response
-            .add_attribute("fee_charged", format!("{}nhash", ask_fee.as_display()))
-            .add_message(generate_creation_fee(
-                ask_fee.u128(),
-                "Ask",
-                env.contract.address,
-                contract_info.admin,
-            )?);
+        let fee_amount = creation_fee_amount(&contract_info.fee_mode, ask_fee, &ask_order.quote)?;
+        // a basis points fee can round down to nothing for a small enough quote
+        if !fee_amount.is_zero() {
+            response = response
+                .add_attribute("fee_charged", format!("{}{}", fee_amount, FEE_DENOM))
+                .add_message(generate_creation_fee(
+                    fee_amount.u128(),
+                    "Ask",
+                    env.contract.address,
+                    contract_info.admin,
+                )?);
+        }
     }
 
     Ok(response)
@@ -205,6 +539,7 @@ fn create_ask(
 // (some other set of coins/a scope). Note that in order to create a bid, the bidder has to send funds into the contract
 // that will be held/managed by the contract until either this bid is matched with an appropriate ask by the admin, or
 // this bid is cancelled.
+#[allow(clippy::too_many_arguments)]
 fn create_bid(
     deps: DepsMut<ProvenanceQuery>,
     env: Env,
@@ -212,7 +547,15 @@ fn create_bid(
     id: String,
     base: BaseType,
     effective_time: Option<Timestamp>,
+    expiration: Option<Timestamp>,
+    accepted_ask_quote: Option<Vec<Coin>>,
+    requested_data_access: Option<Vec<String>>,
+    value_owner_override: Option<String>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_not_paused(deps.as_ref())?;
+    // store the base and quote in their canonical form so that matching never depends on how the coins were listed
+    let base = merge_base_coins(base);
+    let quote = merge_coins(info.funds);
     // you have to provide information on what you are wanting to buy
     // the case of a scope base isn't checked, as the scope doesn't necessarily even have to exist yet,
     // it is just an address that could be created in the future (i.e. maybe there is some off-chain agreement in progress)
@@ -222,52 +565,317 @@ fn create_bid(
                 field: "base".into(),
             });
         }
+        check_no_zero_amounts(coins)?;
     }
 
     // the id has to be provided in the message, not generated randomly in the contract as contracts have to be deterministic
     if id.is_empty() {
         return Err(ContractError::MissingField { field: "id".into() });
     }
+    // saving over an existing bid would orphan its escrowed quote in the contract
+    if get_bid_storage_read_v2(deps.storage)
+        .may_load(id.as_bytes())?
+        .is_some()
+    {
+        return Err(ContractError::OrderIdAlreadyExists { id });
+    }
     // the bidder has to send funds into the contract in order to buy something/determine the quote amount
-    if info.funds.is_empty() {
+    if quote.is_empty() {
         return Err(ContractError::MissingBidQuote);
     }
+    check_no_zero_amounts(&quote)?;
+    // only quotes in denoms approved by the contract admin can be accepted
+    check_quote_denoms(&get_contract_info(deps.storage)?, &quote)?;
+    check_min_order_sizes(&get_contract_info(deps.storage)?, &base, &quote)?;
+    if let Some(accepted_ask_quote) = &accepted_ask_quote {
+        check_accepted_ask_quote(accepted_ask_quote, &quote)?;
+    }
+    let requested_data_access = match requested_data_access {
+        Some(addresses) => validate_requested_data_access(deps.as_ref(), &base, addresses)?,
+        None => vec![],
+    };
+    let value_owner_override = match value_owner_override {
+        Some(address) => Some(validate_value_owner_override(
+            deps.as_ref(),
+            &base,
+            address,
+        )?),
+        None => None,
+    };
 
+    let contract_info = get_contract_info(deps.storage)?;
+    open_order(deps.storage, &contract_info, &info.sender)?;
     let mut bid_storage = get_bid_storage_v2(deps.storage);
 
     // create/store the bid details
     let bid_order = BidOrderV2 {
+        accepted_ask_quote,
         base,
         effective_time,
+        expiration,
         id,
         owner: info.sender,
-        quote: info.funds,
+        quote,
+        requested_data_access,
+        value_owner_override,
     };
     // key the bid by id so it can be retrieved as such later
     bid_storage.save(bid_order.id.as_bytes(), &bid_order)?;
 
     let mut response = Response::new()
         // anything watching the event stream could see an event from this contract with this attribute, and then act on it if desired
-        .add_attributes(vec![attr("action", "create_bid")])
+        .add_attributes(vec![
+            attr("action", "create_bid"),
+            attr("bid_order", to_json_string(&bid_order)?),
+            attr("order_id", &bid_order.id),
+            attr("order_type", "bid"),
+            attr("escrow_receipt", escrow_receipt(&bid_order)),
+        ])
         .set_data(to_binary(&bid_order)?);
 
     let contract_info = get_contract_info(deps.storage)?;
 
     // Only generate a bid fee message if it is configured within the contract info
     if let Some(ref bid_fee) = &contract_info.bid_fee {
-        response = response
-            .add_attribute("fee_charged", format!("{}nhash", bid_fee.as_display()))
-            .add_message(generate_creation_fee(
-                bid_fee.u128(),
-                "Bid",
-                env.contract.address,
-                contract_info.admin,
-            )?);
+        let fee_amount = creation_fee_amount(&contract_info.fee_mode, bid_fee, &bid_order.quote)?;
+        // a basis points fee can round down to nothing for a small enough quote
+        if !fee_amount.is_zero() {
+            response = response
+                .add_attribute("fee_charged", format!("{}{}", fee_amount, FEE_DENOM))
+                .add_message(generate_creation_fee(
+                    fee_amount.u128(),
+                    "Bid",
+                    env.contract.address,
+                    contract_info.admin,
+                )?);
+        }
     }
 
     Ok(response)
 }
 
+// derives a sha256 hex digest from the bid's id, owner, and quote that a bidder can present off-chain to prove that
+// they created an open bid. the receipt is not stored, so it is purely informational and can be recomputed by anyone
+// holding the bid details
+fn escrow_receipt(bid_order: &BidOrderV2) -> String {
+    let quote = bid_order
+        .quote
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    Sha256::digest(format!("{}:{}:{}", bid_order.id, bid_order.owner, quote).as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// ensures that data access is only requested for scope bases, and that every requested address is valid
+fn validate_requested_data_access(
+    deps: Deps<ProvenanceQuery>,
+    base: &BaseType,
+    addresses: Vec<String>,
+) -> Result<Vec<Addr>, ContractError> {
+    if !matches!(base, BaseType::Scope { .. }) {
+        return Err(ContractError::InvalidRequestedDataAccess {
+            explanation: format!(
+                "data access can only be requested for a scope base, not a {} base",
+                base.type_name(),
+            ),
+        });
+    }
+    let mut validated: Vec<Addr> = vec![];
+    for address in addresses {
+        let address = deps.api.addr_validate(&address)?;
+        if !validated.contains(&address) {
+            validated.push(address);
+        }
+    }
+    Ok(validated)
+}
+
+// ensures that a value owner override is only requested for a scope base, and that the override is a valid address
+fn validate_value_owner_override(
+    deps: Deps<ProvenanceQuery>,
+    base: &BaseType,
+    address: String,
+) -> Result<Addr, ContractError> {
+    if !matches!(base, BaseType::Scope { .. }) {
+        return Err(ContractError::InvalidValueOwnerOverride {
+            explanation: format!(
+                "a value owner can only be requested for a scope base, not a {} base",
+                base.type_name(),
+            ),
+        });
+    }
+    Ok(deps.api.addr_validate(&address)?)
+}
+
+// ensures that the funds escrowed by a bid are enough to pay the quote of any ask it will accept
+fn check_accepted_ask_quote(
+    accepted_ask_quote: &[Coin],
+    funds: &[Coin],
+) -> Result<(), ContractError> {
+    if accepted_ask_quote.is_empty() {
+        return Err(ContractError::InvalidAcceptedAskQuote {
+            explanation: "at least one coin must be provided".into(),
+        });
+    }
+    for quote_coin in accepted_ask_quote {
+        let escrowed = funds
+            .iter()
+            .filter(|fund| fund.denom == quote_coin.denom)
+            .fold(Uint128::zero(), |total, fund| total + fund.amount);
+        if quote_coin.amount.is_zero() || quote_coin.amount > escrowed {
+            return Err(ContractError::InvalidAcceptedAskQuote {
+                explanation: format!(
+                    "[{}{}] must be above zero and covered by the [{}{}] provided",
+                    quote_coin.amount, quote_coin.denom, escrowed, quote_coin.denom,
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+// verifies that a declared exact ask base is non-empty and fully covered by the funds sent with the ask
+fn check_exact_base(exact_base: &[Coin], funds: &[Coin]) -> Result<(), ContractError> {
+    if exact_base.is_empty() || exact_base.iter().any(|base| base.amount.is_zero()) {
+        return Err(ContractError::MissingAskBase);
+    }
+    // sum each denom on both sides so that repeated denoms in either list are compared as a whole
+    let covered = exact_base.iter().all(|base| {
+        let sent = funds
+            .iter()
+            .filter(|fund| fund.denom == base.denom)
+            .fold(Uint128::zero(), |total, fund| total + fund.amount);
+        let declared = exact_base
+            .iter()
+            .filter(|other| other.denom == base.denom)
+            .fold(Uint128::zero(), |total, other| total + other.amount);
+        sent >= declared
+    });
+    if !covered {
+        return Err(ContractError::InsufficientBaseFunds {
+            exact_base: exact_base
+                .iter()
+                .map(|base| base.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        });
+    }
+    Ok(())
+}
+
+// serializes a value to a deterministic JSON string, allowing event attributes to be reliably deserialized off-chain
+fn to_json_string<T: Serialize>(value: &T) -> Result<String, ContractError> {
+    serde_json_wasm::to_string(value)
+        .map_err(|error| ContractError::Std(StdError::serialize_err(type_name::<T>(), error)))
+}
+
+// verifies that the address is formatted as a scope address before it is used to query the metadata module, which
+// would otherwise reject a malformed address with an opaque querier error
+fn check_scope_address(address: &str) -> Result<(), ContractError> {
+    if address.len() <= SCOPE_ADDRESS_PREFIX.len() || !address.starts_with(SCOPE_ADDRESS_PREFIX) {
+        return Err(ContractError::InvalidScopeAddress {
+            address: address.into(),
+        });
+    }
+    Ok(())
+}
+
+// verifies that every coin in the quote uses a denom in the contract's allowed quote denoms, if they are configured
+fn check_quote_denoms(contract_info: &ContractInfo, quote: &[Coin]) -> Result<(), ContractError> {
+    if let Some(allowed_quote_denoms) = &contract_info.allowed_quote_denoms {
+        if let Some(disallowed) = quote
+            .iter()
+            .find(|coin| !allowed_quote_denoms.contains(&coin.denom))
+        {
+            return Err(ContractError::DisallowedQuoteDenom {
+                denom: disallowed.denom.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ensures that an order is not too small to be worth keeping in the contract.  Only coin bases are checked, because
+// scopes and markers have no amount to compare
+fn check_min_order_sizes(
+    contract_info: &ContractInfo,
+    base: &BaseType,
+    quote: &[Coin],
+) -> Result<(), ContractError> {
+    if let (Some(min_base_amount), BaseType::Coin { coins }) =
+        (&contract_info.min_base_amount, base)
+    {
+        check_min_amounts(min_base_amount, coins)?;
+    }
+    if let Some(min_quote_amount) = &contract_info.min_quote_amount {
+        check_min_amounts(min_quote_amount, quote)?;
+    }
+    Ok(())
+}
+
+fn check_min_amounts(minimums: &[Coin], coins: &[Coin]) -> Result<(), ContractError> {
+    for checked in coins {
+        if let Some(minimum) = minimums
+            .iter()
+            .find(|minimum| minimum.denom == checked.denom && checked.amount < minimum.amount)
+        {
+            return Err(ContractError::OrderTooSmall {
+                denom: checked.denom.clone(),
+                amount: checked.amount,
+                minimum: minimum.amount,
+            });
+        }
+    }
+    Ok(())
+}
+
+// ensures that an ask or bid fee charged in basis points does not exceed the entire quote of the order
+fn check_fee_basis_points(
+    fee_mode: &FeeMode,
+    fee_type: &str,
+    fee: &Option<Uint128>,
+) -> Result<(), ContractError> {
+    match (fee_mode, fee) {
+        (FeeMode::BasisPoints, Some(basis_points)) if basis_points.u128() > TOTAL_BASIS_POINTS => {
+            Err(ContractError::InvalidFeeBasisPoints {
+                fee_type: fee_type.into(),
+                basis_points: *basis_points,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+// computes the amount of nhash charged for creating an order with the given quote.  A flat fee is charged as-is, while
+// a basis points fee is taken as a share of the quote, which must consist of nhash alone for its value to be known
+fn creation_fee_amount(
+    fee_mode: &FeeMode,
+    fee: &Uint128,
+    quote: &[Coin],
+) -> Result<Uint128, ContractError> {
+    match fee_mode {
+        FeeMode::Flat => Ok(*fee),
+        FeeMode::BasisPoints => {
+            if let Some(coin) = quote.iter().find(|coin| coin.denom != FEE_DENOM) {
+                return Err(ContractError::InvalidQuoteForFee {
+                    explanation: format!(
+                        "basis points fees require a quote entirely in {}, but found [{}]",
+                        FEE_DENOM, coin.denom,
+                    ),
+                });
+            }
+            let quote_total = quote
+                .iter()
+                .fold(Uint128::zero(), |total, coin| total + coin.amount);
+            Ok(quote_total.multiply_ratio(fee.u128(), TOTAL_BASIS_POINTS))
+        }
+    }
+}
+
 fn generate_creation_fee<S: Into<String>>(
     fee_amount: u128,
     fee_type: S,
@@ -296,6 +904,21 @@ fn generate_creation_fee<S: Into<String>>(
     )?)
 }
 
+fn generate_match_fee(
+    fee_amount: u128,
+    contract_address: Addr,
+    admin_address: Addr,
+) -> Result<CosmosMsg<ProvenanceMsg>, ContractError> {
+    // The match fee follows the same rules as the creation fees: it must use nhash, must be sent
+    // from the contract address, and names the admin as the recipient of its cut of the fee
+    Ok(assess_custom_fee(
+        coin(fee_amount, "nhash"),
+        Some("match fee".to_string()),
+        contract_address,
+        Some(admin_address),
+    )?)
+}
+
 // cancel ask entrypoint
 // this entrypoint allows the account that created an ask to cancel the ask, transferring the base back to them and
 // effectively taking it off the market and preventing any match from happening in the future
@@ -329,93 +952,478 @@ fn cancel_ask(
             // remove the ask order from storage
             let mut ask_storage = get_ask_storage_v2(deps.storage);
             ask_storage.remove(id.as_bytes());
-
-            let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
-
-            // determine which type of base this ask was for (a scope or coins) and return as appropriate by
-            // either transferring the coin to the ask owner's account, or setting the ask owner as the scope's owner
-            match stored_ask_order.base {
-                BaseType::Coin { coins } => {
-                    messages.push(cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
-                        to_address: stored_ask_order.owner.to_string(),
-                        amount: coins,
-                    }));
-                }
-                BaseType::Scope { scope_address } => {
-                    // fetch scope
-                    let scope = ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?;
-
-                    // Set the original asker's address back to being the owner and value owner address
-                    messages.push(write_scope(
-                        replace_scope_owner(scope, stored_ask_order.owner)?,
-                        vec![env.contract.address],
-                    )?);
-                }
-            };
+            close_order(deps.storage, &stored_ask_order.owner)?;
 
             // 'send base back to owner' message
             Ok(Response::new()
                 // whatever messages were produced (in order to return the base to the owner) have to be added to the
                 // response so they can be executed after this function returns in the same transaction
-                .add_messages(messages)
+                .add_messages(return_ask_base(
+                    deps.as_ref(),
+                    &env.contract.address,
+                    stored_ask_order,
+                )?)
                 // anything watching the event stream could see an event from this contract with this attribute, and then act on it if desired
                 .add_attributes(vec![attr("action", "cancel_ask")]))
         }
     }
 }
 
-// cancel bid entrypoint
-// this entrypoint allows the account that created an bid to cancel the bid, transferring the quote (provided funds) back to them and
-// preventing any match from happening in the future using those funds
-fn cancel_bid(
+// reduce ask entrypoint
+// this entrypoint allows the account that created an ask with a coin base to withdraw some of the base's coins, leaving
+// the rest listed.  Scopes and markers are non-divisible, so their asks can only be cancelled
+fn reduce_ask(
     deps: DepsMut<ProvenanceQuery>,
-    _env: Env,
     info: MessageInfo,
     id: String,
+    remove: Vec<Coin>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    // return error if id is empty, we need to know which bid to cancel
+    // return error if id is empty, we need to know which ask to reduce
     if id.is_empty() {
         return Err(ContractError::Unauthorized {});
     }
 
-    // return error if funds sent
+    // return error if funds sent (this entrypoint is only to return funds to the owner, not accept new funds)
     if !info.funds.is_empty() {
         return Err(ContractError::CancelWithFunds {});
     }
 
-    // try and find the bid being cancelled
-    let bid_storage = get_bid_storage_read_v2(deps.storage);
-    let stored_bid_order = bid_storage.load(id.as_bytes());
-    match stored_bid_order {
-        Ok(stored_bid_order) => {
-            if !info.sender.eq(&stored_bid_order.owner) {
-                return Err(ContractError::Unauthorized {});
-            }
-
-            // remove the ask order from storage
-            let mut bid_storage = get_bid_storage_v2(deps.storage);
-            bid_storage.remove(id.as_bytes());
+    let mut ask_order = get_ask_storage_read_v2(deps.storage)
+        .load(id.as_bytes())
+        .map_err(|_| ContractError::Unauthorized {})?;
+    if !info.sender.eq(&ask_order.owner) {
+        return Err(ContractError::Unauthorized {});
+    }
 
-            // 'send quote back to owner' message
-            Ok(Response::new()
-                // whatever messages were produced (in order to return the base to the owner) have to be added to the
-                // response so they can be executed after this function returns in the same transaction
-                .add_message(BankMsg::Send {
-                    to_address: stored_bid_order.owner.to_string(),
-                    amount: stored_bid_order.quote,
-                })
-                // anything watching the event stream could see an event from this contract with this attribute, and then act on it if desired
-                .add_attributes(vec![attr("action", "cancel_bid")]))
+    let base_coins = match &ask_order.base {
+        BaseType::Coin { coins } => coins.to_owned(),
+        base => {
+            return Err(ContractError::InvalidAskReduction {
+                explanation: format!(
+                    "only coin bases can be reduced, not a {} base",
+                    base.type_name(),
+                ),
+            })
         }
-        Err(_) => Err(ContractError::Unauthorized {}),
+    };
+    check_ask_reduction(&base_coins, &remove)?;
+
+    // write back the reduced ask, removing it if the entire base has been withdrawn
+    let remaining = subtract_coins(&base_coins, &remove);
+    let ask_removed = remaining.is_empty();
+    if ask_removed {
+        get_ask_storage_v2(deps.storage).remove(id.as_bytes());
+        close_order(deps.storage, &ask_order.owner)?;
+    } else {
+        ask_order.base = BaseType::Coin { coins: remaining };
+        get_ask_storage_v2(deps.storage).save(id.as_bytes(), &ask_order)?;
     }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: ask_order.owner.to_string(),
+            amount: remove,
+        })
+        .add_attributes(vec![
+            attr("action", "reduce_ask"),
+            attr("order_id", &id),
+            attr("ask_removed", ask_removed.to_string()),
+        ]))
 }
 
-fn update_fees(
+// ensures that the coins being withdrawn from an ask are a non-empty subset of its base
+fn check_ask_reduction(base: &[Coin], remove: &[Coin]) -> Result<(), ContractError> {
+    if remove.is_empty() {
+        return Err(ContractError::InvalidAskReduction {
+            explanation: "at least one coin must be removed".to_string(),
+        });
+    }
+    for removed in remove {
+        if removed.amount.is_zero() {
+            return Err(ContractError::InvalidAskReduction {
+                explanation: format!("cannot remove a zero amount of [{}]", removed.denom),
+            });
+        }
+        // the removed coins are refunded in a single bank send, which does not allow duplicate denoms
+        if remove
+            .iter()
+            .filter(|coin| coin.denom == removed.denom)
+            .count()
+            > 1
+        {
+            return Err(ContractError::InvalidAskReduction {
+                explanation: format!("[{}] can only be removed once", removed.denom),
+            });
+        }
+        let available = base
+            .iter()
+            .find(|coin| coin.denom == removed.denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default();
+        if removed.amount > available {
+            return Err(ContractError::InvalidAskReduction {
+                explanation: format!(
+                    "cannot remove [{}] from a base holding [{}{}]",
+                    removed, available, removed.denom,
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+// determine which type of base an ask was for (a scope, a marker or coins) and produce the messages to return it as
+// appropriate by either transferring the coin to the ask owner's account, setting the ask owner as the scope's owner, or
+// handing control of the marker back to the ask owner
+fn return_ask_base(
+    deps: Deps<ProvenanceQuery>,
+    contract_address: &Addr,
+    ask_order: AskOrderV2,
+) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    match ask_order.base {
+        BaseType::Coin { coins } => Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: ask_order.owner.to_string(),
+            amount: coins,
+        })]),
+        BaseType::Scope { scope_address } => {
+            // fetch scope
+            let scope = ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?;
+
+            // if the scope's ownership was changed outside of the contract, the contract can no longer sign a write
+            // for it.  Failing here explains why, instead of surfacing an opaque error from the metadata module
+            check_scope_owners(&scope, Some(contract_address), Some(contract_address)).map_err(
+                |error| match error {
+                    ContractError::InvalidScopeOwner {
+                        scope_address,
+                        explanation,
+                    } => ContractError::ScopeNotHeldByContract {
+                        scope_address,
+                        explanation,
+                    },
+                    error => error,
+                },
+            )?;
+
+            // Set the original asker's address back to being the owner and value owner address, unless the asker
+            // declared that the scope was transferred to the contract by another account, which takes precedence
+            let previous_owner = ask_order.expected_previous_owner.unwrap_or(ask_order.owner);
+            Ok(vec![write_scope(
+                replace_scope_owner(scope, previous_owner, DataAccessPolicy::Keep)?,
+                vec![contract_address.to_owned()],
+            )?])
+        }
+        BaseType::Marker { denom } => transfer_marker(denom, ask_order.owner, contract_address),
+    }
+}
+
+// cancel bid entrypoint
+// this entrypoint allows the account that created an bid to cancel the bid, transferring the quote (provided funds) back to them and
+// preventing any match from happening in the future using those funds
+fn cancel_bid(
+    deps: DepsMut<ProvenanceQuery>,
+    _env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // return error if id is empty, we need to know which bid to cancel
+    if id.is_empty() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::CancelWithFunds {});
+    }
+
+    // try and find the bid being cancelled
+    let bid_storage = get_bid_storage_read_v2(deps.storage);
+    let stored_bid_order = bid_storage.load(id.as_bytes());
+    match stored_bid_order {
+        Ok(stored_bid_order) => {
+            if !info.sender.eq(&stored_bid_order.owner) {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            // remove the ask order from storage
+            let mut bid_storage = get_bid_storage_v2(deps.storage);
+            bid_storage.remove(id.as_bytes());
+            close_order(deps.storage, &stored_bid_order.owner)?;
+
+            // 'send quote back to owner' message
+            Ok(Response::new()
+                // whatever messages were produced (in order to return the base to the owner) have to be added to the
+                // response so they can be executed after this function returns in the same transaction
+                .add_message(BankMsg::Send {
+                    to_address: stored_bid_order.owner.to_string(),
+                    amount: stored_bid_order.quote,
+                })
+                // anything watching the event stream could see an event from this contract with this attribute, and then act on it if desired
+                .add_attributes(vec![
+                    attr("action", "cancel_bid"),
+                    attr("base_type", stored_bid_order.base.type_name()),
+                ]))
+        }
+        Err(_) => Err(ContractError::Unauthorized {}),
+    }
+}
+
+// increase bid entrypoint
+// this entrypoint allows the account that created a bid to add funds to its quote, raising the offer while keeping the
+// bid in place.  The added funds are held by the contract alongside the bid's existing quote
+fn increase_bid(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_not_paused(deps.as_ref())?;
+    // return error if id is empty, we need to know which bid to increase
+    if id.is_empty() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut bid_order = get_bid_storage_read_v2(deps.storage)
+        .load(id.as_bytes())
+        .map_err(|_| ContractError::Unauthorized {})?;
+    if !info.sender.eq(&bid_order.owner) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // an expired bid can no longer be matched, so any funds added to it would sit idle until the bid is reaped
+    if bid_order.is_expired(&env.block.time) {
+        return Err(ContractError::OrderExpired { id });
+    }
+
+    // the funds sent are the amount to add to the quote
+    if info.funds.is_empty() {
+        return Err(ContractError::MissingBidQuote);
+    }
+    check_no_zero_amounts(&info.funds)?;
+    // new denoms added to the quote must be approved by the contract admin, just as when the bid was created
+    check_quote_denoms(&get_contract_info(deps.storage)?, &info.funds)?;
+
+    bid_order.quote = merge_coins([bid_order.quote, info.funds].concat());
+    get_bid_storage_v2(deps.storage).save(id.as_bytes(), &bid_order)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "increase_bid"),
+        attr("order_id", &id),
+        attr(
+            "quote",
+            bid_order
+                .quote
+                .iter()
+                .map(|coin| coin.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        ),
+    ]))
+}
+
+// cancel all for owner entrypoint
+// this entrypoint allows an account to cancel every ask and bid it owns at once, returning each base and quote exactly
+// as if each order were cancelled individually. The admin may specify a different owner to clean up after an account
+// that has withdrawn from trading
+fn cancel_all_for_owner(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    owner: Option<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // return error if funds sent (this entrypoint is only to return funds to the owner, not accept new funds)
+    if !info.funds.is_empty() {
+        return Err(ContractError::CancelWithFunds {});
+    }
+
+    // only the admin may cancel the orders of another account
+    let owner = match owner {
+        Some(owner) => {
+            let owner = deps.api.addr_validate(&owner)?;
+            if owner != info.sender && info.sender != get_contract_info(deps.storage)?.admin {
+                return Err(ContractError::Unauthorized {});
+            }
+            owner
+        }
+        None => info.sender,
+    };
+
+    let cancelled = cancel_orders(deps, &env.contract.address, Some(&owner))?;
+
+    Ok(Response::new()
+        .add_messages(cancelled.messages)
+        .add_attributes(vec![
+            attr("action", "cancel_all_for_owner"),
+            attr("owner", owner.as_str()),
+            attr("cancelled_asks", cancelled.ask_ids.join(",")),
+            attr("cancelled_bids", cancelled.bid_ids.join(",")),
+        ]))
+}
+
+// admin cancel entrypoint
+// allows the admin to remove a single abusive or erroneous order owned by any account, returning its escrow to the owner
+// exactly as if the owner had cancelled it. The admin's reason is recorded in the event stream for auditability
+fn admin_cancel(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    order_type: AskOrBid,
+    id: String,
+    reason: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Prevent any users beside the admin from executing this route
+    if info.sender != get_contract_info(deps.storage)?.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    // return error if funds sent (this entrypoint is only to return funds to the owner, not accept new funds)
+    if !info.funds.is_empty() {
+        return Err(ContractError::CancelWithFunds {});
+    }
+    if id.is_empty() {
+        return Err(ContractError::MissingField { field: "id".into() });
+    }
+    if reason.is_empty() {
+        return Err(ContractError::MissingField {
+            field: "reason".into(),
+        });
+    }
+
+    let (messages, owner) = match order_type {
+        AskOrBid::Ask => {
+            let ask_order = get_ask_storage_read_v2(deps.storage).load(id.as_bytes())?;
+            get_ask_storage_v2(deps.storage).remove(id.as_bytes());
+            close_order(deps.storage, &ask_order.owner)?;
+            let owner = ask_order.owner.to_owned();
+            (
+                return_ask_base(deps.as_ref(), &env.contract.address, ask_order)?,
+                owner,
+            )
+        }
+        AskOrBid::Bid => {
+            let bid_order = get_bid_storage_read_v2(deps.storage).load(id.as_bytes())?;
+            get_bid_storage_v2(deps.storage).remove(id.as_bytes());
+            close_order(deps.storage, &bid_order.owner)?;
+            (
+                vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: bid_order.owner.to_string(),
+                    amount: bid_order.quote,
+                })],
+                bid_order.owner,
+            )
+        }
+    };
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "admin_cancel"),
+        attr(
+            "order_type",
+            match order_type {
+                AskOrBid::Ask => "ask",
+                AskOrBid::Bid => "bid",
+            },
+        ),
+        attr("order_id", &id),
+        attr("owner", owner.as_str()),
+        attr("reason", reason),
+    ]))
+}
+
+// drain all entrypoint
+// winds down the contract by cancelling every open ask and bid, returning each escrowed base and quote to its owner,
+// and then pausing the contract so that no new orders can be created. Only the admin may drain the contract
+fn drain_all(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent any users beside the admin from executing this route
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Prevent funds from accidentally being escrowed in the contract
+    if !info.funds.is_empty() {
+        return Err(ContractError::DrainAllWithFunds {});
+    }
+    contract_info.paused = true;
+    set_contract_info(deps.storage, &contract_info)?;
+
+    let cancelled = cancel_orders(deps, &env.contract.address, None)?;
+
+    Ok(Response::new()
+        .add_messages(cancelled.messages)
+        .add_attributes(vec![
+            attr("action", "drain_all"),
+            attr("cancelled_asks", cancelled.ask_ids.join(",")),
+            attr("cancelled_bids", cancelled.bid_ids.join(",")),
+            attr("paused", "true"),
+        ]))
+}
+
+// the result of cancelling many orders at once: the messages returning each order's escrow, and the cancelled order ids
+struct CancelledOrders {
+    messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    ask_ids: Vec<String>,
+    bid_ids: Vec<String>,
+}
+
+// removes every order owned by the given owner (or every order at all, when no owner is given) from storage, producing
+// the messages that return each ask's base and each bid's quote
+fn cancel_orders(
+    deps: DepsMut<ProvenanceQuery>,
+    contract_address: &Addr,
+    owner: Option<&Addr>,
+) -> Result<CancelledOrders, ContractError> {
+    let is_cancelled = |order_owner: &Addr| owner.is_none() || owner == Some(order_owner);
+    let ask_orders = get_ask_storage_read_v2(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|record| record.map(|(_, ask_order)| ask_order))
+        .filter(|record| !matches!(record, Ok(ask_order) if !is_cancelled(&ask_order.owner)))
+        .collect::<StdResult<Vec<AskOrderV2>>>()?;
+    let bid_orders = get_bid_storage_read_v2(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|record| record.map(|(_, bid_order)| bid_order))
+        .filter(|record| !matches!(record, Ok(bid_order) if !is_cancelled(&bid_order.owner)))
+        .collect::<StdResult<Vec<BidOrderV2>>>()?;
+
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let mut ask_ids: Vec<String> = vec![];
+    let mut bid_ids: Vec<String> = vec![];
+
+    for ask_order in ask_orders {
+        get_ask_storage_v2(deps.storage).remove(ask_order.id.as_bytes());
+        close_order(deps.storage, &ask_order.owner)?;
+        ask_ids.push(ask_order.id.clone());
+        messages.append(&mut return_ask_base(
+            deps.as_ref(),
+            contract_address,
+            ask_order,
+        )?);
+    }
+
+    for bid_order in bid_orders {
+        get_bid_storage_v2(deps.storage).remove(bid_order.id.as_bytes());
+        close_order(deps.storage, &bid_order.owner)?;
+        bid_ids.push(bid_order.id);
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid_order.owner.to_string(),
+            amount: bid_order.quote,
+        }));
+    }
+
+    Ok(CancelledOrders {
+        messages,
+        ask_ids,
+        bid_ids,
+    })
+}
+
+fn update_fees(
     deps: DepsMut<ProvenanceQuery>,
     info: MessageInfo,
     ask_fee: Option<Uint128>,
     bid_fee: Option<Uint128>,
+    match_fee: Option<Uint128>,
+    fee_mode: Option<FeeMode>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     let mut contract_info = get_contract_info(deps.storage)?;
     // Prevent any users beside the admin from executing this route
@@ -440,525 +1448,6329 @@ fn update_fees(
             fee_type: "bid".to_string(),
         });
     }
+    // A match fee of zero is invalid, but omitting the match fee (None) is valid because it indicates
+    // no fee is to be charged
+    if match_fee.is_some() && match_fee.unwrap().is_zero() {
+        return Err(ContractError::InvalidFee {
+            fee_type: "match".to_string(),
+        });
+    }
+    // Ask and bid fees charged in basis points cannot exceed the order's entire quote
+    let fee_mode = fee_mode.unwrap_or_default();
+    check_fee_basis_points(&fee_mode, "ask", &ask_fee)?;
+    check_fee_basis_points(&fee_mode, "bid", &bid_fee)?;
     // Format the ask and bid fee output messages up front before declaring the response, allowing
     // for the ask fee and bid fee to be moved into the contract_info and avoiding errors
+    let creation_fee_unit = match fee_mode {
+        FeeMode::Flat => FEE_DENOM,
+        FeeMode::BasisPoints => "bps",
+    };
     let ask_fee_message = if let Some(ref ask_fee) = &ask_fee {
-        format!("{}nhash", ask_fee.u128())
+        format!("{}{}", ask_fee.u128(), creation_fee_unit)
     } else {
         "cleared".to_string()
     };
     let bid_fee_message = if let Some(ref bid_fee) = &bid_fee {
-        format!("{}nhash", bid_fee.u128())
+        format!("{}{}", bid_fee.u128(), creation_fee_unit)
     } else {
         "cleared".to_string()
     };
-    // Overwrite the ask and bid fees in the contract info and save the new result
+    let match_fee_message = if let Some(ref match_fee) = &match_fee {
+        format!("{}nhash", match_fee.u128())
+    } else {
+        "cleared".to_string()
+    };
+    // Overwrite the ask, bid and match fees in the contract info and save the new result
     contract_info.ask_fee = ask_fee;
     contract_info.bid_fee = bid_fee;
+    contract_info.match_fee = match_fee;
+    contract_info.fee_mode = fee_mode;
     set_contract_info(deps.storage, &contract_info)?;
     Ok(Response::new()
         .add_attribute("action", "update_fees")
         .add_attribute("new_ask_fee", ask_fee_message)
-        .add_attribute("new_bid_fee", bid_fee_message))
+        .add_attribute("new_bid_fee", bid_fee_message)
+        .add_attribute("new_match_fee", match_fee_message))
 }
 
-// match and execute an ask and bid order
-// this allows for the atomic transfer of the bid funds to the seller and the quote asset (coin/scope) to the bidder,
-// ensuring neither party has chance to back out of the deal after a partial transfer
-fn execute_match(
+fn update_admin(
     deps: DepsMut<ProvenanceQuery>,
-    env: Env,
     info: MessageInfo,
-    ask_id: String,
-    bid_id: String,
+    new_admin: String,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    // only the admin may execute matches
-    if info.sender != get_contract_info(deps.storage)?.admin {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent any users beside the admin from executing this route
+    if info.sender != contract_info.admin {
         return Err(ContractError::Unauthorized {});
     }
+    // Prevent funds from accidentally being escrowed in the contract
+    if !info.funds.is_empty() {
+        return Err(ContractError::UpdateAdminWithFunds {});
+    }
+    // Ensure the new admin is a valid address before handing over control of the contract
+    let new_admin = deps.api.addr_validate(&new_admin)?;
+    let previous_admin = contract_info.admin;
+    contract_info.admin = new_admin;
+    set_contract_info(deps.storage, &contract_info)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_admin")
+        .add_attribute("previous_admin", previous_admin)
+        .add_attribute("new_admin", contract_info.admin))
+}
 
-    // return error if id is empty
-    if ask_id.is_empty() | bid_id.is_empty() {
+// store a new bind name in the contract info and bind that name to the contract address.  Unlike the NewBindName
+// migration, the bind message is dispatched with this transaction.  The previously bound name is not unbound
+fn update_bind_name(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    new_bind_name: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent any users beside the admin from executing this route
+    if info.sender != contract_info.admin {
         return Err(ContractError::Unauthorized {});
     }
-
-    // return error if funds sent
+    // Prevent funds from accidentally being escrowed in the contract
     if !info.funds.is_empty() {
-        return Err(ContractError::ExecuteWithFunds {});
+        return Err(ContractError::UpdateBindNameWithFunds {});
     }
-
-    let ask_storage_read = get_ask_storage_read_v2(deps.storage);
-    let ask_order_result = ask_storage_read.load(ask_id.as_bytes());
-    if ask_order_result.is_err() {
-        return Err(ContractError::AskBidMismatch {});
+    if new_bind_name.is_empty() {
+        return Err(ContractError::MissingField {
+            field: "bind_name".into(),
+        });
     }
+    let previous_bind_name = contract_info.bind_name;
+    contract_info.bind_name = new_bind_name;
+    set_contract_info(deps.storage, &contract_info)?;
 
-    let bid_storage_read = get_bid_storage_read_v2(deps.storage);
-    let bid_order_result = bid_storage_read.load(bid_id.as_bytes());
-    if bid_order_result.is_err() {
-        return Err(ContractError::AskBidMismatch {});
-    }
+    let bind_name_msg = bind_name(
+        &contract_info.bind_name,
+        env.contract.address,
+        NameBinding::Restricted,
+    )?;
 
-    let ask_order = ask_order_result.unwrap();
-    let bid_order = bid_order_result.unwrap();
+    Ok(Response::new()
+        .add_message(bind_name_msg)
+        .add_attribute("action", "update_bind_name")
+        .add_attribute("previous_bind_name", previous_bind_name)
+        .add_attribute("new_bind_name", contract_info.bind_name))
+}
 
-    // this is possibly the most critical piece of this entrypoint, in that it ensures the price the bidder is paying is
-    // the same as what the seller listed their asset for sale at
-    if !is_executable(&ask_order, &bid_order) {
-        return Err(ContractError::AskBidMismatch {});
+fn update_quote_denoms(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    allowed_quote_denoms: Option<Vec<String>>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent any users beside the admin from executing this route
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
     }
+    // Prevent funds from accidentally being escrowed in the contract
+    if !info.funds.is_empty() {
+        return Err(ContractError::UpdateQuoteDenomsWithFunds {});
+    }
+    // An empty list of allowed quote denoms would prevent any order from being created, but omitting
+    // the list (None) is valid because it indicates that any denom is accepted
+    if matches!(&allowed_quote_denoms, Some(denoms) if denoms.is_empty()) {
+        return Err(ContractError::MissingField {
+            field: "allowed_quote_denoms".into(),
+        });
+    }
+    let allowed_quote_denoms_message = if let Some(ref denoms) = &allowed_quote_denoms {
+        denoms.join(",")
+    } else {
+        "cleared".to_string()
+    };
+    // Overwrite the allowed quote denoms in the contract info and save the new result.  Existing
+    // orders are unaffected by this change
+    contract_info.allowed_quote_denoms = allowed_quote_denoms;
+    set_contract_info(deps.storage, &contract_info)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_quote_denoms")
+        .add_attribute("new_allowed_quote_denoms", allowed_quote_denoms_message))
+}
 
-    // 'send quote to asker' and 'send base to bidder' messages
-    let response = Response::new().add_message(BankMsg::Send {
-        to_address: ask_order.owner.to_string(),
-        amount: ask_order.quote,
-    });
-    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
-
-    match bid_order.base {
-        BaseType::Coin { coins } => messages.push(cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
-            to_address: bid_order.owner.to_string(),
-            amount: coins,
-        })),
-        BaseType::Scope { scope_address } => {
-            // fetch scope
-            let scope = ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?;
-
-            messages.push(write_scope(
-                replace_scope_owner(scope, bid_order.owner)?,
-                vec![env.contract.address],
-            )?)
-        }
+fn update_min_order_sizes(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    min_base_amount: Option<Vec<Coin>>,
+    min_quote_amount: Option<Vec<Coin>>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent any users beside the admin from executing this route
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Prevent funds from accidentally being escrowed in the contract
+    if !info.funds.is_empty() {
+        return Err(ContractError::UpdateMinOrderSizesWithFunds {});
+    }
+    let describe = |minimums: &Option<Vec<Coin>>| match minimums {
+        Some(minimums) => minimums
+            .iter()
+            .map(|minimum| minimum.to_string())
+            .collect::<Vec<String>>()
+            .join(","),
+        None => "cleared".to_string(),
     };
+    let response = Response::new()
+        .add_attribute("action", "update_min_order_sizes")
+        .add_attribute("new_min_base_amount", describe(&min_base_amount))
+        .add_attribute("new_min_quote_amount", describe(&min_quote_amount));
+    // Existing orders are unaffected by this change
+    contract_info.min_base_amount = min_base_amount;
+    contract_info.min_quote_amount = min_quote_amount;
+    set_contract_info(deps.storage, &contract_info)?;
+    Ok(response)
+}
+
+fn set_paused(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent any users beside the admin from executing this route
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Prevent funds from accidentally being escrowed in the contract
+    if !info.funds.is_empty() {
+        return Err(ContractError::SetPausedWithFunds {});
+    }
+    contract_info.paused = paused;
+    set_contract_info(deps.storage, &contract_info)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_paused")
+        .add_attribute("paused", paused.to_string()))
+}
+
+// match and execute an ask and bid order
+// this allows for the atomic transfer of the bid funds to the seller and the quote asset (coin/scope) to the bidder,
+// ensuring neither party has chance to back out of the deal after a partial transfer
+fn execute_match(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    ask_id: String,
+    bid_id: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // only the admin may execute matches
+    let contract_info = get_contract_info(deps.storage)?;
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::ExecuteWithFunds {});
+    }
+
+    let (ask_order, bid_order) =
+        load_matchable_orders(deps.as_ref(), &env, &contract_info, &ask_id, &bid_id)?;
+    record_trade(deps.storage, &ask_order.quote)?;
+    let trade_attributes =
+        trade_attributes(&ask_order, &bid_order, &ask_order.quote, &bid_order.base);
+    let (asker, bidder) = (ask_order.owner.to_owned(), bid_order.owner.to_owned());
+    let messages =
+        generate_match_messages(deps.as_ref(), &env, contract_info, ask_order, bid_order)?;
 
     // finally remove the orders from storage
     get_ask_storage_v2(deps.storage).remove(ask_id.as_bytes());
     get_bid_storage_v2(deps.storage).remove(bid_id.as_bytes());
+    close_order(deps.storage, &asker)?;
+    close_order(deps.storage, &bidder)?;
 
-    Ok(response
+    Ok(Response::new()
         // whatever messages were produced (in order to return the base to the owner) have to be added to the
         // response so they can be executed after this function returns in the same transaction
         .add_messages(messages)
         // anything watching the event stream could see an event from this contract with this attribute, and then act on it if desired
-        .add_attributes(vec![attr("action", "execute")]))
+        .add_attributes(vec![attr("action", "execute")])
+        .add_attributes(trade_attributes))
 }
 
-// the logic determining if an ask/bid are actually a legitinate match
-fn is_executable(ask_order: &AskOrderV2, bid_order: &BidOrderV2) -> bool {
-    // sort the base and quote vectors by the order chain: denom, amount
-    // this ensures that the ask/bid can be repeatably compared with the same result
-    let coin_sorter =
-        |a: &Coin, b: &Coin| a.denom.cmp(&b.denom).then_with(|| a.amount.cmp(&b.amount));
-
-    let ask_base = ask_order.base.to_owned().sorted();
-    let bid_base = bid_order.base.to_owned().sorted();
+// describes an executed match for the event stream: the matched orders and their owners, the quote paid for the base,
+// and the base delivered to the bidder, which is its coins, scope address or marker denom.  A full match pays the ask's
+// entire quote for the bid's entire base, while a partial match only pays for the portion of the base that was filled
+fn trade_attributes(
+    ask_order: &AskOrderV2,
+    bid_order: &BidOrderV2,
+    quote_paid: &[Coin],
+    base_delivered: &BaseType,
+) -> Vec<Attribute> {
+    let format_coins = |coins: &[Coin]| {
+        coins
+            .iter()
+            .map(|coin| coin.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+    let base_type = base_delivered.type_name();
+    let base_delivered = match base_delivered {
+        BaseType::Coin { coins } => format_coins(coins),
+        BaseType::Scope { scope_address } => scope_address.to_owned(),
+        BaseType::Marker { denom } => denom.to_owned(),
+    };
+    vec![
+        attr("ask_id", &ask_order.id),
+        attr("bid_id", &bid_order.id),
+        attr("asker", ask_order.owner.as_str()),
+        attr("bidder", bid_order.owner.as_str()),
+        attr("quote_paid", format_coins(quote_paid)),
+        attr("base_type", base_type),
+        attr("base_delivered", base_delivered),
+    ]
+}
 
-    let mut ask_quote = ask_order.quote.to_owned();
-    ask_quote.sort_by(coin_sorter);
-    let mut bid_quote = bid_order.quote.to_owned();
-    bid_quote.sort_by(coin_sorter);
+// match and execute multiple ask and bid orders
+// every pair is validated before any messages are produced or any order is removed from storage, so a single invalid
+// pair rejects the entire batch and the book is left exactly as it was
+fn execute_match_batch(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    pairs: Vec<MatchPair>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // only the admin may execute matches
+    let contract_info = get_contract_info(deps.storage)?;
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    ask_base == bid_base && ask_quote == bid_quote
-}
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::ExecuteWithFunds {});
+    }
 
-/// Verifies that the scope is properly owned.  At minimum, checks that the scope has only a singular owner.
-/// If expected_owner is provided, the single owner with party type Owner must match this address.
-/// If expected_value_owner is provided, the value_owner_address value must match this.
-fn check_scope_owners(
-    scope: &Scope,
-    expected_owner: Option<&Addr>,
-    expected_value_owner: Option<&Addr>,
-) -> Result<(), ContractError> {
-    let owners = scope
-        .owners
-        .iter()
-        .filter(|owner| owner.role == PartyType::Owner)
-        .collect::<Vec<&Party>>();
-    // if more than one owner is specified, removing all of them can potentially cause data loss
-    if owners.len() != 1 {
-        return Err(ContractError::InvalidScopeOwner {
-            scope_address: scope.scope_id.clone(),
-            explanation: format!(
-                "the scope should only include a single owner, but found: {}",
-                owners.len(),
-            ),
+    if pairs.is_empty() {
+        return Err(ContractError::MissingField {
+            field: "pairs".into(),
         });
     }
-    if let Some(expected) = expected_owner {
-        let owner = owners.first().unwrap();
-        if &owner.address != expected {
-            return Err(ContractError::InvalidScopeOwner {
-                scope_address: scope.scope_id.clone(),
-                explanation: format!(
-                    "the scope owner was expected to be [{}], not [{}]",
-                    expected, owner.address,
-                ),
-            });
+
+    let mut matched_orders: Vec<(AskOrderV2, BidOrderV2)> = vec![];
+    for pair in pairs.iter() {
+        // an order can only be matched once, so an id repeated within the batch is treated the same as a missing order
+        if matched_orders.iter().any(|(ask_order, bid_order)| {
+            ask_order.id == pair.ask_id || bid_order.id == pair.bid_id
+        }) {
+            return Err(ContractError::AskBidMismatch {});
         }
+        matched_orders.push(load_matchable_orders(
+            deps.as_ref(),
+            &env,
+            &contract_info,
+            &pair.ask_id,
+            &pair.bid_id,
+        )?);
     }
-    if let Some(expected) = expected_value_owner {
-        if &scope.value_owner_address != expected {
-            return Err(ContractError::InvalidScopeOwner {
-                scope_address: scope.scope_id.clone(),
-                explanation: format!(
-                    "the scope's value owner was expected to be [{}], not [{}]",
-                    expected, scope.value_owner_address,
-                ),
-            });
-        }
+
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    // every match in the batch is reported on the trade tape in the same way as an individual match, in batch order
+    let mut batch_trade_attributes: Vec<Attribute> = vec![];
+    for (ask_order, bid_order) in matched_orders {
+        batch_trade_attributes.append(&mut trade_attributes(
+            &ask_order,
+            &bid_order,
+            &ask_order.quote,
+            &bid_order.base,
+        ));
+        get_ask_storage_v2(deps.storage).remove(ask_order.id.as_bytes());
+        get_bid_storage_v2(deps.storage).remove(bid_order.id.as_bytes());
+        close_order(deps.storage, &ask_order.owner)?;
+        close_order(deps.storage, &bid_order.owner)?;
+        record_trade(deps.storage, &ask_order.quote)?;
+        messages.append(&mut generate_match_messages(
+            deps.as_ref(),
+            &env,
+            contract_info.clone(),
+            ask_order,
+            bid_order,
+        )?);
     }
-    Ok(())
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(vec![
+            attr("action", "execute_batch"),
+            attr(
+                "matched_asks",
+                pairs
+                    .iter()
+                    .map(|pair| pair.ask_id.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            ),
+            attr(
+                "matched_bids",
+                pairs
+                    .iter()
+                    .map(|pair| pair.bid_id.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            ),
+        ])
+        .add_attributes(batch_trade_attributes))
 }
 
-/// Switches the scope's current owner value to the given owner value.
-fn replace_scope_owner(mut scope: Scope, new_owner: Addr) -> Result<Scope, ContractError> {
-    // Empty out all owners from the scope now that it's verified safe to do
-    scope.owners = scope
-        .owners
-        .into_iter()
-        .filter(|owner| owner.role != PartyType::Owner)
-        .collect();
-    // Append the target value as the new sole owner
-    scope.owners.push(Party {
-        address: new_owner.clone(),
-        role: PartyType::Owner,
-    });
-    // Swap over the value owner, ensuring that the target owner not only is listed as an owner,
-    // but has full access control over the scope
-    scope.value_owner_address = new_owner;
-    Ok(scope)
+// adds an executed match to the contract's running trade stats
+fn record_trade(storage: &mut dyn Storage, quote: &[Coin]) -> Result<(), ContractError> {
+    let mut trade_stats_storage = get_trade_stats_storage(storage);
+    let mut trade_stats = trade_stats_storage.may_load()?.unwrap_or_default();
+    trade_stats.record_match(quote)?;
+    trade_stats_storage.save(&trade_stats)?;
+    Ok(())
 }
 
-// smart contract query entrypoint
-// this allows anyone to inspect the details of the contract or specific asks/bids
-// note that the raw underlying contract storage is also available to anyone who so desires to look
-// this can be queried via code off-chain or other smart contracts. Potentially the details of an ask/bid
-// may be of interest to an application/individual that wants to buy/sell something listed as a base
-#[entry_point]
-pub fn query(deps: Deps<ProvenanceQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::GetAsk { id } => {
-            let ask_storage_read = get_ask_storage_read_v2(deps.storage);
-            return to_binary(&ask_storage_read.load(id.as_bytes())?);
-        }
-        QueryMsg::GetBid { id } => {
-            let bid_storage_read = get_bid_storage_read_v2(deps.storage);
-            return to_binary(&bid_storage_read.load(id.as_bytes())?);
+// counts a newly created order against its owner's open orders, rejecting it if the owner is already at the limit
+fn open_order(
+    storage: &mut dyn Storage,
+    contract_info: &ContractInfo,
+    owner: &Addr,
+) -> Result<(), ContractError> {
+    let open_orders = get_open_order_count_storage_read(storage)
+        .may_load(owner.as_bytes())?
+        .unwrap_or(0);
+    if let Some(max_orders) = contract_info.max_orders_per_owner {
+        if open_orders >= max_orders {
+            return Err(ContractError::TooManyOpenOrders {
+                owner: owner.to_string(),
+                max_orders,
+            });
         }
-        QueryMsg::GetContractInfo {} => to_binary(&get_contract_info(deps.storage)?),
     }
+    get_open_order_count_storage(storage).save(owner.as_bytes(), &(open_orders + 1))?;
+    Ok(())
 }
 
-// the router for handling the raw migrate message. In this case there is only one type of migration
-#[entry_point]
-pub fn migrate(
-    deps: DepsMut<ProvenanceQuery>,
-    _env: Env,
-    msg: MigrateMsg,
-) -> Result<Response, ContractError> {
-    match msg {
-        MigrateMsg::NewVersion {} => migrate_new_version(deps),
+// frees one of an owner's open order slots once an order is removed by a cancel, match or any other route.  Orders
+// stored before counts were introduced were never counted, so the count never falls below zero
+fn close_order(storage: &mut dyn Storage, owner: &Addr) -> StdResult<()> {
+    let open_orders = get_open_order_count_storage_read(storage)
+        .may_load(owner.as_bytes())?
+        .unwrap_or(0);
+    if open_orders <= 1 {
+        get_open_order_count_storage(storage).remove(owner.as_bytes());
+    } else {
+        get_open_order_count_storage(storage).save(owner.as_bytes(), &(open_orders - 1))?;
     }
+    Ok(())
 }
 
-// just set the new version in the contract storage.
-// If the structure of ask/bid storage were to change between versions, you might need to iterate through all entries
-// and modify each from the old format to the new
-fn migrate_new_version(deps: DepsMut<ProvenanceQuery>) -> Result<Response, ContractError> {
-    let mut contract_info = get_contract_info(deps.storage)?;
-    // Bump version in contract info the version stored in the wasm
-    contract_info.contract_version = CONTRACT_VERSION.into();
-    set_contract_info(deps.storage, &contract_info)?;
-    Ok(Response::new().add_attribute("action", "migrate"))
+// loads an ask and bid from storage, ensuring that they exist and can be matched with each other
+fn load_matchable_orders(
+    deps: Deps<ProvenanceQuery>,
+    env: &Env,
+    contract_info: &ContractInfo,
+    ask_id: &str,
+    bid_id: &str,
+) -> Result<(AskOrderV2, BidOrderV2), ContractError> {
+    // return error if id is empty
+    if ask_id.is_empty() | bid_id.is_empty() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let ask_order = get_ask_storage_read_v2(deps.storage)
+        .load(ask_id.as_bytes())
+        .map_err(|_| ContractError::AskBidMismatch {})?;
+    let bid_order = get_bid_storage_read_v2(deps.storage)
+        .load(bid_id.as_bytes())
+        .map_err(|_| ContractError::AskBidMismatch {})?;
+
+    // expired orders can no longer be matched, and are only eligible to be reaped
+    if ask_order.is_expired(&env.block.time) {
+        return Err(ContractError::OrderExpired { id: ask_order.id });
+    }
+    if bid_order.is_expired(&env.block.time) {
+        return Err(ContractError::OrderExpired { id: bid_order.id });
+    }
+
+    // matching an account's ask with its own bid is only permitted when explicitly enabled at instantiation
+    if is_self_match(contract_info, &ask_order, &bid_order) {
+        return Err(ContractError::SelfMatch);
+    }
+
+    // this is possibly the most critical piece of matching, in that it ensures the price the bidder is paying is
+    // the same as what the seller listed their asset for sale at
+    if !is_executable(&ask_order, &bid_order) {
+        return Err(ContractError::AskBidMismatch {});
+    }
+
+    Ok((ask_order, bid_order))
 }
 
-// unit tests
-#[cfg(test)]
-mod tests {
-    use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coin, coins, Addr, BankMsg, StdError};
-    use cosmwasm_std::{CosmosMsg, Uint128};
-    use provwasm_mocks::mock_dependencies;
-    use provwasm_std::{
-        MetadataMsgParams, MsgFeesMsgParams, NameMsgParams, ProvenanceMsg, ProvenanceMsgParams,
-        ProvenanceRoute,
+// produces the messages that send the quote to the asker, the base to the bidder and charge the match fee for an ask
+// and bid that have already been verified as matchable
+fn generate_match_messages(
+    deps: Deps<ProvenanceQuery>,
+    env: &Env,
+    contract_info: ContractInfo,
+    ask_order: AskOrderV2,
+    bid_order: BidOrderV2,
+) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+
+    // a bid may escrow more than the quote it accepted, and the difference is returned to the bidder
+    let bidder_refund = subtract_coins(&bid_order.quote, &ask_order.quote);
+    let bidder_refund_message = if bidder_refund.is_empty() {
+        None
+    } else {
+        Some(CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid_order.owner.to_string(),
+            amount: bidder_refund,
+        }))
     };
 
-    use crate::contract_info::{ContractInfo, CONTRACT_TYPE, CONTRACT_VERSION};
-    use crate::state::{get_bid_storage_read_v2, BaseType};
+    // the match fee is deducted from the quote before it reaches the asker
+    let asker_quote = deduct_match_fee(env, &contract_info, ask_order.quote, &mut messages)?;
+
+    // 'send quote to asker' and 'send base to bidder' messages.  A quote consisting entirely of the match fee leaves
+    // nothing to send to the asker
+    if !asker_quote.is_empty() {
+        messages.insert(
+            0,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ask_order.owner.to_string(),
+                amount: asker_quote,
+            }),
+        );
+    }
 
-    use super::*;
-    use crate::msg::ExecuteMsg;
+    match bid_order.base {
+        BaseType::Coin { coins } => messages.push(cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid_order.owner.to_string(),
+            amount: coins,
+        })),
+        BaseType::Scope { scope_address } => {
+            // fetch scope
+            let scope = ProvenanceQuerier::new(&deps.querier).get_scope(scope_address)?;
 
-    #[test]
-    fn test_is_executable() {
-        assert!(is_executable(
-            &AskOrderV2 {
-                base: BaseType::coin(100, "base_1"),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::coin(100, "base_1"),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_1"),
+            // the bidder should not inherit any stale data access grants belonging to the previous owner of the scope
+            let mut data_access = scope
+                .data_access
+                .iter()
+                .filter(|address| **address != ask_order.owner)
+                .cloned()
+                .collect::<Vec<Addr>>();
+            // grant data access to any addresses the bidder requested that don't already have it
+            for address in bid_order.requested_data_access {
+                if !data_access.contains(&address) {
+                    data_access.push(address);
+                }
             }
-        ));
-        assert!(is_executable(
-            &AskOrderV2 {
-                base: BaseType::coins(vec![coin(100, "base_1"), coin(200, "base_2")]),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::coins(vec![coin(200, "base_2"), coin(100, "base_1")]),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_1"),
+            let specification_id = scope.specification_id.to_owned();
+            let mut new_scope = replace_scope_owner(
+                scope,
+                bid_order.owner,
+                DataAccessPolicy::ReplaceWith(data_access.to_owned()),
+            )?;
+            // the bidder becomes the scope's owner, but may have requested that the value owner be held elsewhere,
+            // like with a custodian
+            if let Some(value_owner) = bid_order.value_owner_override {
+                new_scope.value_owner_address = value_owner;
             }
-        ));
-        assert!(is_executable(
-            &AskOrderV2 {
-                base: BaseType::scope("scope1234"),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::scope("scope1234"),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_1"),
+            // writing the scope replaces it entirely, so anything other than the owners that changes while rebuilding it
+            // would sever the scope's link to its specification or grant data access to the wrong accounts
+            if new_scope.specification_id != specification_id {
+                return Err(ContractError::InvalidScopeRewrite {
+                    scope_address: new_scope.scope_id,
+                    explanation: format!(
+                        "expected specification id [{}], but found [{}]",
+                        specification_id, new_scope.specification_id,
+                    ),
+                });
             }
-        ));
-        assert!(!is_executable(
-            &AskOrderV2 {
-                base: BaseType::coin(100, "base_1"),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::coin(100, "base_2"),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_1"),
+            if new_scope.data_access != data_access {
+                return Err(ContractError::InvalidScopeRewrite {
+                    scope_address: new_scope.scope_id,
+                    explanation: "the data access of the rebuilt scope was modified".into(),
+                });
             }
-        ));
-        assert!(!is_executable(
-            &AskOrderV2 {
-                base: BaseType::coin(100, "base_1"),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::coin(100, "base_1"),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_2"),
+            messages.push(write_scope(
+                new_scope,
+                vec![env.contract.address.to_owned()],
+            )?)
+        }
+        BaseType::Marker { denom } => messages.append(&mut transfer_marker(
+            denom,
+            bid_order.owner,
+            &env.contract.address,
+        )?),
+    };
+
+    if let Some(bidder_refund_message) = bidder_refund_message {
+        messages.push(bidder_refund_message);
+    }
+
+    Ok(messages)
+}
+
+// ensures that a quote includes enough nhash to pay the contract's match fee.  The match fee is only ever charged in
+// nhash, so while one is configured, asks quoted in any other denom alone cannot be created or matched
+fn check_match_fee_quote(
+    contract_info: &ContractInfo,
+    quote: &[Coin],
+) -> Result<(), ContractError> {
+    if let Some(match_fee) = contract_info.match_fee {
+        if !quote
+            .iter()
+            .any(|quote_coin| quote_coin.denom == FEE_DENOM && quote_coin.amount >= match_fee)
+        {
+            return Err(ContractError::InsufficientQuoteForMatchFee { match_fee });
+        }
+    }
+    Ok(())
+}
+
+// deducts the contract's match fee, if one is configured, from the quote paid for a match, appending the messages that
+// charge the fee and returning the remainder of the quote that is owed to the asker
+fn deduct_match_fee(
+    env: &Env,
+    contract_info: &ContractInfo,
+    quote: Vec<Coin>,
+    messages: &mut Vec<CosmosMsg<ProvenanceMsg>>,
+) -> Result<Vec<Coin>, ContractError> {
+    let match_fee = match contract_info.match_fee {
+        Some(match_fee) => match_fee,
+        None => return Ok(quote),
+    };
+    // asks are checked for the fee when they are created, but an ask created before the fee was configured, or a
+    // partial fill of an ask, may still be short of it
+    check_match_fee_quote(contract_info, &quote)?;
+    let fee_coin = coin(match_fee.u128(), FEE_DENOM);
+    // The custom fee is always charged to the signer of the execute message, which is the admin.  Sending the
+    // deducted quote to the admin covers that charge, so the asker ultimately pays the fee, which is split between
+    // the Provenance Blockchain fee module and the admin in the same way as the creation fees
+    messages.push(CosmosMsg::Bank(BankMsg::Send {
+        to_address: contract_info.admin.to_string(),
+        amount: vec![fee_coin.clone()],
+    }));
+    messages.push(generate_match_fee(
+        match_fee.u128(),
+        env.contract.address.to_owned(),
+        contract_info.admin.to_owned(),
+    )?);
+    Ok(subtract_coins(&quote, &[fee_coin]))
+}
+
+// reap expired orders entrypoint
+// this entrypoint allows any account to remove expired asks and bids from storage, returning the base of each ask and
+// the quote of each bid to their owners exactly as if the owners had cancelled them
+fn reap_expired(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    ids: Vec<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // return error if funds sent (this entrypoint is only to return funds to the owners, not accept new funds)
+    if !info.funds.is_empty() {
+        return Err(ContractError::CancelWithFunds {});
+    }
+
+    if ids.is_empty() {
+        return Err(ContractError::MissingField {
+            field: "ids".into(),
+        });
+    }
+
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let mut reaped_asks: Vec<String> = vec![];
+    let mut reaped_bids: Vec<String> = vec![];
+
+    for id in ids {
+        let mut reaped = false;
+
+        if let Ok(ask_order) = get_ask_storage_read_v2(deps.storage).load(id.as_bytes()) {
+            if ask_order.is_expired(&env.block.time) {
+                get_ask_storage_v2(deps.storage).remove(id.as_bytes());
+                close_order(deps.storage, &ask_order.owner)?;
+                messages.append(&mut return_ask_base(
+                    deps.as_ref(),
+                    &env.contract.address,
+                    ask_order,
+                )?);
+                reaped_asks.push(id.clone());
+                reaped = true;
             }
-        ));
-        assert!(!is_executable(
-            &AskOrderV2 {
-                base: BaseType::scope("scope1234"),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::coin(100, "base_1"),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_1"),
+        }
+
+        if let Ok(bid_order) = get_bid_storage_read_v2(deps.storage).load(id.as_bytes()) {
+            if bid_order.is_expired(&env.block.time) {
+                get_bid_storage_v2(deps.storage).remove(id.as_bytes());
+                close_order(deps.storage, &bid_order.owner)?;
+                messages.push(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: bid_order.owner.to_string(),
+                    amount: bid_order.quote,
+                }));
+                reaped_bids.push(id.clone());
+                reaped = true;
             }
-        ));
-        assert!(!is_executable(
-            &AskOrderV2 {
-                base: BaseType::scope("scope1234"),
-                id: "ask_id".to_string(),
-                owner: Addr::unchecked("asker"),
-                quote: coins(100, "quote_1"),
-            },
-            &BidOrderV2 {
-                base: BaseType::scope("scope4321"),
-                effective_time: Some(Timestamp::default()),
-                id: "bid_id".to_string(),
-                owner: Addr::unchecked("bidder"),
-                quote: coins(100, "quote_1"),
+        }
+
+        if !reaped {
+            return Err(ContractError::OrderNotExpired { id });
+        }
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "reap_expired"),
+        attr("reaped_asks", reaped_asks.join(",")),
+        attr("reaped_bids", reaped_bids.join(",")),
+    ]))
+}
+
+// partially match and execute an ask and bid order with coin bases
+// this allows a bidder to buy a portion of a larger ask (or an asker to sell a portion of a larger bid), as long as
+// both orders are offering the same price per unit of base. The filled portion of the base is sent to the bidder, the
+// proportional amount of the quote is sent to the asker, and both orders are written back to storage with their remaining
+// amounts. Any order that is completely filled by this match is removed from storage instead
+fn execute_partial_match(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    ask_id: String,
+    bid_id: String,
+    fill_amount: Uint128,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // only the admin may execute matches
+    let contract_info = get_contract_info(deps.storage)?;
+    if info.sender != contract_info.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // return error if id is empty
+    if ask_id.is_empty() | bid_id.is_empty() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::ExecuteWithFunds {});
+    }
+
+    let mut ask_order = get_ask_storage_read_v2(deps.storage)
+        .load(ask_id.as_bytes())
+        .map_err(|_| ContractError::AskBidMismatch {})?;
+    let mut bid_order = get_bid_storage_read_v2(deps.storage)
+        .load(bid_id.as_bytes())
+        .map_err(|_| ContractError::AskBidMismatch {})?;
+
+    // expired orders can no longer be matched, even partially, and are only eligible to be reaped
+    if ask_order.is_expired(&env.block.time) {
+        return Err(ContractError::OrderExpired { id: ask_order.id });
+    }
+    if bid_order.is_expired(&env.block.time) {
+        return Err(ContractError::OrderExpired { id: bid_order.id });
+    }
+
+    // partial matches are subject to the same self match restrictions as full matches
+    if is_self_match(&contract_info, &ask_order, &bid_order) {
+        return Err(ContractError::SelfMatch);
+    }
+
+    // the price of a partial fill is derived from the escrowed quote, which is only the price the bidder will pay when
+    // no other quote was accepted
+    if bid_order.accepted_ask_quote.is_some() {
+        return Err(ContractError::AskBidMismatch {});
+    }
+
+    // scopes and markers are non-divisible, so only a single coin base can ever be partially filled
+    let (ask_base, bid_base) = match (&ask_order.base, &bid_order.base) {
+        (BaseType::Coin { coins: ask_coins }, BaseType::Coin { coins: bid_coins }) => {
+            match (ask_coins.as_slice(), bid_coins.as_slice()) {
+                ([ask_coin], [bid_coin]) if ask_coin.denom == bid_coin.denom => {
+                    (ask_coin.to_owned(), bid_coin.to_owned())
+                }
+                _ => return Err(ContractError::AskBidMismatch {}),
             }
-        ));
+        }
+        _ => return Err(ContractError::PartialMatchNonCoinBase),
+    };
+
+    if fill_amount.is_zero() || fill_amount > ask_base.amount || fill_amount > bid_base.amount {
+        return Err(ContractError::InvalidFillAmount {
+            explanation: format!(
+                "fill amount must be above zero and at most the smaller base amount of [{}], but was [{}]",
+                ask_base.amount.min(bid_base.amount),
+                fill_amount,
+            ),
+        });
+    }
+
+    // the price per unit of base must be identical for both orders: ask_quote / ask_base == bid_quote / bid_base
+    if !is_same_price(
+        &ask_order.quote,
+        ask_base.amount,
+        &bid_order.quote,
+        bid_base.amount,
+    ) {
+        return Err(ContractError::AskBidMismatch {});
+    }
+
+    // determine the portion of the quote owed for the filled base.  Only exact divisions are allowed, otherwise
+    // fractional amounts of the quote would be stranded in the contract
+    let mut quote_paid: Vec<Coin> = vec![];
+    for quote in ask_order.quote.iter() {
+        let amount = quote.amount.multiply_ratio(fill_amount, ask_base.amount);
+        if amount.full_mul(ask_base.amount) != quote.amount.full_mul(fill_amount) {
+            return Err(ContractError::InvalidFillAmount {
+                explanation: format!(
+                    "fill amount [{}] does not produce a whole amount of quote [{}]",
+                    fill_amount, quote.denom,
+                ),
+            });
+        }
+        quote_paid.push(coin(amount.u128(), &quote.denom));
+    }
+
+    // the trade tape reports only the filled portion of the base and the quote paid for it
+    let trade_attributes = trade_attributes(
+        &ask_order,
+        &bid_order,
+        &quote_paid,
+        &BaseType::coin(fill_amount.u128(), &ask_base.denom),
+    );
+
+    // a partial fill is a trade like any other, so the match fee is deducted from the portion of the quote it pays
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let asker_quote = deduct_match_fee(&env, &contract_info, quote_paid.clone(), &mut messages)?;
+    // 'send quote to asker' and 'send base to bidder' messages.  A quote consisting entirely of the match fee leaves
+    // nothing to send to the asker
+    if !asker_quote.is_empty() {
+        messages.insert(
+            0,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: ask_order.owner.to_string(),
+                amount: asker_quote,
+            }),
+        );
+    }
+    messages.push(CosmosMsg::Bank(BankMsg::Send {
+        to_address: bid_order.owner.to_string(),
+        amount: vec![coin(fill_amount.u128(), &ask_base.denom)],
+    }));
+
+    // reduce both orders by the filled amounts
+    let ask_remaining = ask_base.amount - fill_amount;
+    let bid_remaining = bid_base.amount - fill_amount;
+    ask_order.base = BaseType::coin(ask_remaining.u128(), &ask_base.denom);
+    bid_order.base = BaseType::coin(bid_remaining.u128(), &bid_base.denom);
+    ask_order.quote = subtract_coins(&ask_order.quote, &quote_paid);
+    bid_order.quote = subtract_coins(&bid_order.quote, &quote_paid);
+    record_trade(deps.storage, &quote_paid)?;
+
+    // write back the reduced orders, removing any that have been completely filled
+    if ask_remaining.is_zero() {
+        get_ask_storage_v2(deps.storage).remove(ask_id.as_bytes());
+        close_order(deps.storage, &ask_order.owner)?;
+    } else {
+        get_ask_storage_v2(deps.storage).save(ask_id.as_bytes(), &ask_order)?;
+    }
+    if bid_remaining.is_zero() {
+        get_bid_storage_v2(deps.storage).remove(bid_id.as_bytes());
+        close_order(deps.storage, &bid_order.owner)?;
+    } else {
+        get_bid_storage_v2(deps.storage).save(bid_id.as_bytes(), &bid_order)?;
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        // anything watching the event stream can use the remaining amounts to keep track of the book
+        .add_attributes(vec![
+            attr("action", "execute_partial"),
+            attr(
+                "ask_remaining_base",
+                format!("{}{}", ask_remaining, ask_base.denom),
+            ),
+            attr(
+                "bid_remaining_base",
+                format!("{}{}", bid_remaining, bid_base.denom),
+            ),
+        ])
+        .add_attributes(trade_attributes))
+}
+
+// determines if two quotes represent the same price for their respective base amounts by cross multiplying each quote
+// coin with the other order's base amount, avoiding any rounding that division would introduce
+fn is_same_price(
+    ask_quote: &[Coin],
+    ask_base_amount: Uint128,
+    bid_quote: &[Coin],
+    bid_base_amount: Uint128,
+) -> bool {
+    ask_quote.len() == bid_quote.len()
+        && ask_quote.iter().all(|ask_coin| {
+            bid_quote.iter().any(|bid_coin| {
+                ask_coin.denom == bid_coin.denom
+                    && ask_coin.amount.full_mul(bid_base_amount)
+                        == bid_coin.amount.full_mul(ask_base_amount)
+            })
+        })
+}
+
+// combines coins sharing a denom into a single coin holding their summed amount, keeping the order in which each denom
+// first appears
+fn merge_coins(coins: Vec<Coin>) -> Vec<Coin> {
+    let mut merged: Vec<Coin> = vec![];
+    for coin in coins {
+        match merged
+            .iter_mut()
+            .find(|existing| existing.denom == coin.denom)
+        {
+            Some(existing) => existing.amount += coin.amount,
+            None => merged.push(coin),
+        }
+    }
+    merged
+}
+
+// merges duplicate denoms within a coin base.  Scope and marker bases are returned unchanged
+fn merge_base_coins(base: BaseType) -> BaseType {
+    match base {
+        BaseType::Coin { coins } => BaseType::coins(merge_coins(coins)),
+        base => base,
+    }
+}
+
+// removes the given amounts from the source coins, dropping any denoms that reach zero
+fn subtract_coins(source: &[Coin], amounts: &[Coin]) -> Vec<Coin> {
+    source
+        .iter()
+        .map(|source_coin| {
+            let subtracted = amounts
+                .iter()
+                .filter(|amount| amount.denom == source_coin.denom)
+                .fold(source_coin.amount, |total, amount| {
+                    total.saturating_sub(amount.amount)
+                });
+            coin(subtracted.u128(), &source_coin.denom)
+        })
+        .filter(|remaining| !remaining.amount.is_zero())
+        .collect()
+}
+
+// determines whether or not an ask and bid belong to the same owner in a contract that does not allow self matches
+fn is_self_match(
+    contract_info: &ContractInfo,
+    ask_order: &AskOrderV2,
+    bid_order: &BidOrderV2,
+) -> bool {
+    !contract_info.allow_self_match && ask_order.owner == bid_order.owner
+}
+
+/// The logic determining if an ask/bid are actually a legitimate match.
+///
+/// The bases must be equal after sorting coin bases by denom, then amount.  Scope and marker bases
+/// must reference the same scope address or marker denom.  The ask's quote must equal the bid's
+/// effective quote (the accepted ask quote when one is set, otherwise the escrowed quote), compared
+/// after the same sorting.  Amounts must match exactly: a larger or smaller coin is not a match.
+/// Expiration, effective time and ownership are not considered.
+///
+/// ```
+/// use bilateral_trade_example::contract::is_executable;
+/// use bilateral_trade_example::state::{AskOrderV2, BaseType, BidOrderV2};
+/// use cosmwasm_std::{coin, Addr};
+///
+/// let ask = AskOrderV2 {
+///     base: BaseType::coins(vec![coin(100, "base_1"), coin(200, "base_2")]),
+///     expiration: None,
+///     expected_previous_owner: None,
+///     id: "ask_id".into(),
+///     owner: Addr::unchecked("asker"),
+///     quote: vec![coin(100, "quote_1")],
+/// };
+/// let mut bid = BidOrderV2 {
+///     accepted_ask_quote: None,
+///     // coin order does not matter
+///     base: BaseType::coins(vec![coin(200, "base_2"), coin(100, "base_1")]),
+///     effective_time: None,
+///     expiration: None,
+///     id: "bid_id".into(),
+///     owner: Addr::unchecked("bidder"),
+///     quote: vec![coin(100, "quote_1")],
+///     requested_data_access: vec![],
+///     value_owner_override: None,
+/// };
+/// assert!(is_executable(&ask, &bid));
+///
+/// // a differing quote amount is not a match
+/// bid.quote = vec![coin(101, "quote_1")];
+/// assert!(!is_executable(&ask, &bid));
+/// ```
+pub fn is_executable(ask_order: &AskOrderV2, bid_order: &BidOrderV2) -> bool {
+    // sort the base and quote vectors by the order chain: denom, amount
+    // this ensures that the ask/bid can be repeatably compared with the same result
+    let coin_sorter =
+        |a: &Coin, b: &Coin| a.denom.cmp(&b.denom).then_with(|| a.amount.cmp(&b.amount));
+
+    let ask_base = ask_order.base.to_owned().sorted();
+    let bid_base = bid_order.base.to_owned().sorted();
+
+    let mut ask_quote = ask_order.quote.to_owned();
+    ask_quote.sort_by(coin_sorter);
+    // a bid that accepts a quote other than its escrow is compared using the accepted quote
+    let mut bid_quote = bid_order.effective_quote().to_owned();
+    bid_quote.sort_by(coin_sorter);
+
+    ask_base == bid_base && ask_quote == bid_quote
+}
+
+/// Verifies that the scope is properly owned.  At minimum, checks that the scope has only a singular owner.
+/// If expected_owner is provided, the single owner with party type Owner must match this address.
+/// If expected_value_owner is provided, the value_owner_address value must match this.
+fn check_scope_owners(
+    scope: &Scope,
+    expected_owner: Option<&Addr>,
+    expected_value_owner: Option<&Addr>,
+) -> Result<(), ContractError> {
+    let owners = scope
+        .owners
+        .iter()
+        .filter(|owner| owner.role == PartyType::Owner)
+        .collect::<Vec<&Party>>();
+    // if more than one owner is specified, removing all of them can potentially cause data loss
+    if owners.len() != 1 {
+        return Err(ContractError::InvalidScopeOwner {
+            scope_address: scope.scope_id.clone(),
+            explanation: format!(
+                "the scope should only include a single owner, but found: {}",
+                owners.len(),
+            ),
+        });
+    }
+    if let Some(expected) = expected_owner {
+        let owner = owners.first().unwrap();
+        if &owner.address != expected {
+            return Err(ContractError::InvalidScopeOwner {
+                scope_address: scope.scope_id.clone(),
+                explanation: format!(
+                    "the scope owner was expected to be [{}], not [{}]",
+                    expected, owner.address,
+                ),
+            });
+        }
+    }
+    if let Some(expected) = expected_value_owner {
+        if &scope.value_owner_address != expected {
+            return Err(ContractError::InvalidScopeOwner {
+                scope_address: scope.scope_id.clone(),
+                explanation: format!(
+                    "the scope's value owner was expected to be [{}], not [{}]",
+                    expected, scope.value_owner_address,
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Verifies that the contract has been granted admin access to the marker, and that no other account
+/// besides the optionally provided previous owner also holds admin access.
+fn check_marker_admins(
+    marker: &Marker,
+    contract_address: &Addr,
+    previous_owner: Option<&Addr>,
+) -> Result<(), ContractError> {
+    let admins = marker
+        .permissions
+        .iter()
+        .filter(|grant| grant.permissions.contains(&MarkerAccess::Admin))
+        .map(|grant| &grant.address)
+        .collect::<Vec<&Addr>>();
+    if !admins.contains(&contract_address) {
+        return Err(ContractError::InvalidMarkerPermissions {
+            denom: marker.denom.clone(),
+            explanation: format!(
+                "the contract [{}] must be granted admin access",
+                contract_address,
+            ),
+        });
+    }
+    if let Some(other_admin) = admins
+        .iter()
+        .find(|admin| **admin != contract_address && Some(**admin) != previous_owner)
+    {
+        return Err(ContractError::InvalidMarkerPermissions {
+            denom: marker.denom.clone(),
+            explanation: format!(
+                "only the contract may hold admin access, but [{}] also has admin access",
+                other_admin,
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Hands full control of the marker to the new owner and removes the contract's access to it.
+fn transfer_marker(
+    denom: String,
+    new_owner: Addr,
+    contract_address: &Addr,
+) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    Ok(vec![
+        grant_marker_access(&denom, new_owner, MarkerAccess::all())?,
+        revoke_marker_access(&denom, contract_address.to_owned())?,
+    ])
+}
+
+/// Determines how a scope's data_access parties are handled when its owner is replaced.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataAccessPolicy {
+    /// Leaves the existing data_access parties untouched.
+    Keep,
+    /// Removes all existing data_access parties.
+    Clear,
+    /// Overwrites the existing data_access parties with the given addresses.
+    ReplaceWith(Vec<Addr>),
+}
+
+/// Switches the scope's current owner value to the given owner value, handling the scope's
+/// data_access parties in the manner described by the given policy.
+pub fn replace_scope_owner(
+    mut scope: Scope,
+    new_owner: Addr,
+    data_access_policy: DataAccessPolicy,
+) -> Result<Scope, ContractError> {
+    // Empty out all owners from the scope now that it's verified safe to do
+    scope.owners = scope
+        .owners
+        .into_iter()
+        .filter(|owner| owner.role != PartyType::Owner)
+        .collect();
+    // Append the target value as the new sole owner
+    scope.owners.push(Party {
+        address: new_owner.clone(),
+        role: PartyType::Owner,
+    });
+    // Swap over the value owner, ensuring that the target owner not only is listed as an owner,
+    // but has full access control over the scope
+    scope.value_owner_address = new_owner;
+    match data_access_policy {
+        DataAccessPolicy::Keep => {}
+        DataAccessPolicy::Clear => scope.data_access.clear(),
+        DataAccessPolicy::ReplaceWith(data_access) => scope.data_access = data_access,
+    }
+    Ok(scope)
+}
+
+// smart contract query entrypoint
+// this allows anyone to inspect the details of the contract or specific asks/bids
+// note that the raw underlying contract storage is also available to anyone who so desires to look
+// this can be queried via code off-chain or other smart contracts. Potentially the details of an ask/bid
+// may be of interest to an application/individual that wants to buy/sell something listed as a base
+#[entry_point]
+pub fn query(deps: Deps<ProvenanceQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetAsk { id } => {
+            let ask_storage_read = get_ask_storage_read_v2(deps.storage);
+            return to_binary(&ask_storage_read.load(id.as_bytes())?);
+        }
+        QueryMsg::GetBid { id } => {
+            let bid_storage_read = get_bid_storage_read_v2(deps.storage);
+            return to_binary(&bid_storage_read.load(id.as_bytes())?);
+        }
+        QueryMsg::GetAsks {
+            start_after,
+            limit,
+            filter_base_denom,
+            filter_quote_denom,
+            filter_scope_address,
+        } => to_binary(&get_asks(
+            deps,
+            start_after,
+            limit,
+            OrderFilters {
+                base_denom: filter_base_denom,
+                quote_denom: filter_quote_denom,
+                scope_address: filter_scope_address,
+            },
+        )?),
+        QueryMsg::GetBids {
+            start_after,
+            limit,
+            filter_base_denom,
+            filter_quote_denom,
+            filter_scope_address,
+        } => to_binary(&get_bids(
+            deps,
+            start_after,
+            limit,
+            OrderFilters {
+                base_denom: filter_base_denom,
+                quote_denom: filter_quote_denom,
+                scope_address: filter_scope_address,
+            },
+        )?),
+        QueryMsg::GetContractInfo {} => to_binary(&get_contract_info(deps.storage)?),
+        QueryMsg::MatchPreview { ask_id, bid_id } => {
+            to_binary(&match_preview(deps, env, ask_id, bid_id))
+        }
+        QueryMsg::GetOrderPair { ask_id, bid_id } => {
+            to_binary(&get_order_pair(deps, env, ask_id, bid_id)?)
+        }
+        QueryMsg::FindMatchingBids { ask_id } => to_binary(&find_matching_bids(deps, env, ask_id)?),
+        QueryMsg::GetTradeStats {} => to_binary(
+            &get_trade_stats_storage_read(deps.storage)
+                .may_load()?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::GetVersion {} => to_binary(&VersionInfo::current()),
+        QueryMsg::GetEscrowSummary {} => to_binary(&get_escrow_summary(deps)?),
+        QueryMsg::GetConfig {} => to_binary(&get_config(deps)?),
+    }
+}
+
+// find every bid that could be matched with an ask, ordered so that the earliest effective bid is first.  Bids without
+// an effective time are ordered last, and the sort is stable, so bids with the same effective time remain ordered by id
+fn find_matching_bids(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    ask_id: String,
+) -> StdResult<FindMatchingBidsResponse> {
+    let contract_info = get_contract_info(deps.storage)?;
+    let ask_order = get_ask_storage_read_v2(deps.storage).load(ask_id.as_bytes())?;
+    // an expired ask can only be reaped, so no bid can be matched with it
+    if ask_order.is_expired(&env.block.time) {
+        return Ok(FindMatchingBidsResponse { bids: vec![] });
+    }
+    let mut bids = get_bid_storage_read_v2(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|record| record.map(|(_, bid_order)| bid_order))
+        // bids are held to the same expiration and self match checks that execute_match applies
+        .filter(|record| match record {
+            Ok(bid_order) => {
+                !bid_order.is_expired(&env.block.time)
+                    && !is_self_match(&contract_info, &ask_order, bid_order)
+                    && is_executable(&ask_order, bid_order)
+            }
+            // errors are retained so that they are surfaced by the collect below
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<BidOrderV2>>>()?;
+    bids.sort_by(|a, b| match (&a.effective_time, &b.effective_time) {
+        (Some(a_time), Some(b_time)) => a_time.cmp(b_time),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    Ok(FindMatchingBidsResponse { bids })
+}
+
+// fetch an ask and bid together, omitting either when it does not exist so that callers can distinguish a missing order
+// from a pair that will not match
+fn get_order_pair(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    ask_id: String,
+    bid_id: String,
+) -> StdResult<GetOrderPairResponse> {
+    let contract_info = get_contract_info(deps.storage)?;
+    let ask = get_ask_storage_read_v2(deps.storage).may_load(ask_id.as_bytes())?;
+    let bid = get_bid_storage_read_v2(deps.storage).may_load(bid_id.as_bytes())?;
+    // the pair is held to the same expiration and self match checks that execute_match applies
+    let would_match = match (&ask, &bid) {
+        (Some(ask_order), Some(bid_order)) => {
+            !ask_order.is_expired(&env.block.time)
+                && !bid_order.is_expired(&env.block.time)
+                && !is_self_match(&contract_info, ask_order, bid_order)
+                && is_executable(ask_order, bid_order)
+        }
+        _ => false,
+    };
+    Ok(GetOrderPairResponse {
+        ask,
+        bid,
+        would_match,
+    })
+}
+
+// gather every admin-controlled parameter from the contract info so that clients can read them all at once
+fn get_config(deps: Deps<ProvenanceQuery>) -> StdResult<GetConfigResponse> {
+    let contract_info = get_contract_info(deps.storage)?;
+    Ok(GetConfigResponse {
+        admin: contract_info.admin,
+        ask_fee: contract_info.ask_fee,
+        bid_fee: contract_info.bid_fee,
+        match_fee: contract_info.match_fee,
+        fee_mode: contract_info.fee_mode,
+        allowed_quote_denoms: contract_info.allowed_quote_denoms,
+        min_base_amount: contract_info.min_base_amount,
+        min_quote_amount: contract_info.min_quote_amount,
+        allow_self_match: contract_info.allow_self_match,
+        paused: contract_info.paused,
+        max_orders_per_owner: contract_info.max_orders_per_owner,
+    })
+}
+
+// total every coin held by the contract on behalf of open orders.  Asks escrow their base and bids escrow their quote
+fn get_escrow_summary(deps: Deps<ProvenanceQuery>) -> StdResult<GetEscrowSummaryResponse> {
+    let mut summary = GetEscrowSummaryResponse {
+        coins: vec![],
+        escrowed_scopes: 0,
+        escrowed_markers: 0,
+    };
+    for record in get_ask_storage_read_v2(deps.storage).range(None, None, Order::Ascending) {
+        let (_, ask_order) = record?;
+        match ask_order.base {
+            BaseType::Coin { coins } => add_coins(&mut summary.coins, &coins)?,
+            BaseType::Scope { .. } => summary.escrowed_scopes += 1,
+            BaseType::Marker { .. } => summary.escrowed_markers += 1,
+        }
+    }
+    for record in get_bid_storage_read_v2(deps.storage).range(None, None, Order::Ascending) {
+        let (_, bid_order) = record?;
+        add_coins(&mut summary.coins, &bid_order.quote)?;
+    }
+    summary.coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+    Ok(summary)
+}
+
+// adds each coin to the running total of its denom
+fn add_coins(totals: &mut Vec<Coin>, coins: &[Coin]) -> StdResult<()> {
+    for added in coins {
+        match totals.iter_mut().find(|total| total.denom == added.denom) {
+            Some(total) => total.amount = total.amount.checked_add(added.amount)?,
+            None => totals.push(added.clone()),
+        }
+    }
+    Ok(())
+}
+
+// determine whether or not an ask and bid would be matched by execute_match, explaining why when they would not
+fn match_preview(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    ask_id: String,
+    bid_id: String,
+) -> MatchPreviewResponse {
+    let mismatch = |reason: String| MatchPreviewResponse {
+        matches: false,
+        reason: Some(reason),
+    };
+    let ask_order = match get_ask_storage_read_v2(deps.storage).load(ask_id.as_bytes()) {
+        Ok(ask_order) => ask_order,
+        Err(_) => return mismatch(format!("ask [{}] does not exist", ask_id)),
+    };
+    let bid_order = match get_bid_storage_read_v2(deps.storage).load(bid_id.as_bytes()) {
+        Ok(bid_order) => bid_order,
+        Err(_) => return mismatch(format!("bid [{}] does not exist", bid_id)),
+    };
+    if ask_order.is_expired(&env.block.time) {
+        return mismatch(format!("ask [{}] has expired", ask_id));
+    }
+    if bid_order.is_expired(&env.block.time) {
+        return mismatch(format!("bid [{}] has expired", bid_id));
+    }
+    if let Ok(contract_info) = get_contract_info(deps.storage) {
+        if is_self_match(&contract_info, &ask_order, &bid_order) {
+            return mismatch("the ask and bid are owned by the same account".to_string());
+        }
+    }
+    if !is_executable(&ask_order, &bid_order) {
+        // substituting the ask's quote into the bid isolates the base comparison, determining which half of the
+        // orders caused the mismatch without duplicating the comparison logic
+        let bases_match = is_executable(
+            &ask_order,
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                quote: ask_order.quote.to_owned(),
+                ..bid_order
+            },
+        );
+        return mismatch(if bases_match {
+            "the ask quote does not match the bid quote".to_string()
+        } else {
+            "the ask base does not match the bid base".to_string()
+        });
+    }
+    MatchPreviewResponse {
+        matches: true,
+        reason: None,
+    }
+}
+
+// fetch a page of asks from storage, ordered by their id.  The returned last_key can be provided as start_after in a
+// subsequent query to continue iterating over the asks
+fn get_asks(
+    deps: Deps<ProvenanceQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    filters: OrderFilters,
+) -> StdResult<GetAsksResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = exclusive_start(start_after);
+    let asks = get_ask_storage_read_v2(deps.storage)
+        .range(start.as_deref(), None, Order::Ascending)
+        .map(|record| record.map(|(_, ask_order)| ask_order))
+        .filter(|record| match record {
+            Ok(ask_order) => filters.matches(&ask_order.base, &ask_order.quote),
+            // errors are retained so that they are surfaced by the collect below
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<AskOrderV2>>>()?;
+    let last_key = asks.last().map(|ask_order| ask_order.id.clone());
+    Ok(GetAsksResponse { asks, last_key })
+}
+
+// fetch a page of bids from storage, ordered by their id.  The returned last_key can be provided as start_after in a
+// subsequent query to continue iterating over the bids
+fn get_bids(
+    deps: Deps<ProvenanceQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    filters: OrderFilters,
+) -> StdResult<GetBidsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = exclusive_start(start_after);
+    let bids = get_bid_storage_read_v2(deps.storage)
+        .range(start.as_deref(), None, Order::Ascending)
+        .map(|record| record.map(|(_, bid_order)| bid_order))
+        .filter(|record| match record {
+            Ok(bid_order) => filters.matches(&bid_order.base, &bid_order.quote),
+            // errors are retained so that they are surfaced by the collect below
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<BidOrderV2>>>()?;
+    let last_key = bids.last().map(|bid_order| bid_order.id.clone());
+    Ok(GetBidsResponse { bids, last_key })
+}
+
+// optional criteria used to narrow down the orders returned by a listing query
+struct OrderFilters {
+    base_denom: Option<String>,
+    quote_denom: Option<String>,
+    scope_address: Option<String>,
+}
+
+impl OrderFilters {
+    // determine whether or not an order's base and quote satisfy every provided filter
+    fn matches(&self, base: &BaseType, quote: &[Coin]) -> bool {
+        let base_denom_matches = match &self.base_denom {
+            None => true,
+            Some(denom) => match base {
+                BaseType::Coin { coins } => coins.iter().any(|coin| &coin.denom == denom),
+                BaseType::Marker {
+                    denom: marker_denom,
+                } => marker_denom == denom,
+                BaseType::Scope { .. } => denom == SCOPE_BASE_FILTER,
+            },
+        };
+        let quote_denom_matches = match &self.quote_denom {
+            None => true,
+            Some(denom) => quote.iter().any(|coin| &coin.denom == denom),
+        };
+        let scope_address_matches = match &self.scope_address {
+            None => true,
+            Some(address) => {
+                matches!(base, BaseType::Scope { scope_address } if scope_address == address)
+            }
+        };
+        base_denom_matches && quote_denom_matches && scope_address_matches
+    }
+}
+
+// storage ranges are inclusive of the start key, so appending a zero byte to the key ensures that iteration begins
+// with the first key after it
+fn exclusive_start(start_after: Option<String>) -> Option<Vec<u8>> {
+    start_after.map(|key| {
+        let mut bytes = key.into_bytes();
+        bytes.push(0);
+        bytes
+    })
+}
+
+// the router for handling the raw migrate message. Orders can optionally be migrated to the current storage format
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    msg: MigrateMsg,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    match msg {
+        MigrateMsg::NewVersion {} => migrate_new_version(deps),
+        MigrateMsg::MigrateOrders {} => migrate_orders(deps),
+        MigrateMsg::NewBindName { bind_name } => migrate_new_bind_name(deps, env, bind_name),
+    }
+}
+
+// set the new version in the contract storage, rejecting the migration if it would not move the contract to a newer
+// version.  Stored asks and bids are left untouched; migrate_orders handles converting them to the current format
+fn migrate_new_version(
+    deps: DepsMut<ProvenanceQuery>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+    // Prevent downgrades and redundant migrations by comparing the versions as semver values
+    let stored_version = contract_info.parse_contract_version()?;
+    let target_version = CONTRACT_VERSION.parse::<Version>()?;
+    if stored_version >= target_version {
+        return Err(ContractError::InvalidVersion {
+            explanation: format!(
+                "stored contract version {} is greater than or equal to the attempted migration version {}",
+                stored_version, target_version
+            ),
+        });
+    }
+    // Bump version in contract info the version stored in the wasm
+    contract_info.contract_version = CONTRACT_VERSION.into();
+    set_contract_info(deps.storage, &contract_info)?;
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+// set the new version in the contract storage, and convert every stored order to the current storage format.
+// V1 orders are moved into V2 storage, and V2 orders are re-saved so that any fields added after they were stored
+// (like expiration) are written with their default values
+fn migrate_orders(
+    mut deps: DepsMut<ProvenanceQuery>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let response = migrate_new_version(deps.branch())?;
+
+    let v1_asks = get_ask_storage_read(deps.storage)
+        .range(None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let v2_asks = get_ask_storage_read_v2(deps.storage)
+        .range(None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, ask_order) in v2_asks.iter() {
+        get_ask_storage_v2(deps.storage).save(key, ask_order)?;
+    }
+    for (key, ask_order) in v1_asks.iter() {
+        if get_ask_storage_read_v2(deps.storage)
+            .may_load(key)?
+            .is_some()
+        {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "cannot migrate v1 ask [{}]: an ask with the same id already exists",
+                ask_order.id
+            ))));
+        }
+        get_ask_storage_v2(deps.storage).save(key, &AskOrderV2::from_v1(ask_order.to_owned()))?;
+        get_ask_storage(deps.storage).remove(key);
+    }
+
+    let v1_bids = get_bid_storage_read(deps.storage)
+        .range(None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let v2_bids = get_bid_storage_read_v2(deps.storage)
+        .range(None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, bid_order) in v2_bids.iter() {
+        get_bid_storage_v2(deps.storage).save(key, bid_order)?;
+    }
+    for (key, bid_order) in v1_bids.iter() {
+        if get_bid_storage_read_v2(deps.storage)
+            .may_load(key)?
+            .is_some()
+        {
+            return Err(ContractError::Std(StdError::generic_err(format!(
+                "cannot migrate v1 bid [{}]: a bid with the same id already exists",
+                bid_order.id
+            ))));
+        }
+        get_bid_storage_v2(deps.storage).save(key, &BidOrderV2::from_v1(bid_order.to_owned()))?;
+        get_bid_storage(deps.storage).remove(key);
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("migrated_asks", (v1_asks.len() + v2_asks.len()).to_string()),
+        attr("migrated_bids", (v1_bids.len() + v2_bids.len()).to_string()),
+    ]))
+}
+
+// store a new bind name in the contract info.  The contract version and stored orders are left untouched.
+// Keep in mind: any messages added to a migration response are ignored, so the bind message below is never dispatched
+// and the new name is not bound by this migration.  The UpdateBindName execute route performs the binding
+fn migrate_new_bind_name(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    new_bind_name: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if new_bind_name.is_empty() {
+        return Err(ContractError::MissingField {
+            field: "bind_name".into(),
+        });
+    }
+    let mut contract_info = get_contract_info(deps.storage)?;
+    contract_info.bind_name = new_bind_name;
+    set_contract_info(deps.storage, &contract_info)?;
+
+    let bind_name_msg = bind_name(
+        &contract_info.bind_name,
+        env.contract.address,
+        NameBinding::Restricted,
+    )?;
+
+    Ok(Response::new()
+        .add_message(bind_name_msg)
+        .add_attribute("action", "migrate_bind_name")
+        .add_attribute("bind_name", contract_info.bind_name))
+}
+
+// unit tests
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, coins, from_binary, Addr, BankMsg, Decimal, StdError};
+    use cosmwasm_std::{CosmosMsg, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{
+        AccessGrant, MarkerStatus, MarkerType, MetadataMsgParams, MsgFeesMsgParams, NameMsgParams,
+        ProvenanceMsg, ProvenanceMsgParams, ProvenanceRoute,
+    };
+    use std::str::FromStr;
+
+    use crate::contract_info::{
+        ContractInfo, FeeMode, VersionInfo, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::state::{
+        get_bid_storage_read_v2, AskOrder, BaseType, BidOrder, TradeStats, NAMESPACE_ORDER_ASK_V2,
+    };
+    use cosmwasm_std::Storage;
+    use cosmwasm_storage::to_length_prefixed;
+
+    use super::*;
+    use crate::msg::{ExecuteMsg, InitialAsk};
+
+    #[test]
+    fn test_is_executable() {
+        assert!(is_executable(
+            &AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(is_executable(
+            &AskOrderV2 {
+                base: BaseType::coins(vec![coin(100, "base_1"), coin(200, "base_2")]),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coins(vec![coin(200, "base_2"), coin(100, "base_1")]),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(is_executable(
+            &AskOrderV2 {
+                base: BaseType::scope("scope1234"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::scope("scope1234"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(!is_executable(
+            &AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_2"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(!is_executable(
+            &AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_2"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(!is_executable(
+            &AskOrderV2 {
+                base: BaseType::scope("scope1234"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(!is_executable(
+            &AskOrderV2 {
+                base: BaseType::scope("scope1234"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::scope("scope4321"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(is_executable(
+            &AskOrderV2 {
+                base: BaseType::marker("marker1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::marker("marker1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(!is_executable(
+            &AskOrderV2 {
+                base: BaseType::marker("marker1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::marker("marker2"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+        assert!(!is_executable(
+            &AskOrderV2 {
+                base: BaseType::marker("marker1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            &BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "marker1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            }
+        ));
+    }
+
+    #[test]
+    fn instantiate_with_valid_data() {
+        // create valid init data
+        let mut deps = mock_dependencies(&[]);
+        let info = mock_info("contract_admin", &[]);
+        let init_msg = InstantiateMsg {
+            bind_name: "contract_bind_name".to_string(),
+            contract_name: "contract_name".to_string(),
+            ask_fee: None,
+            bid_fee: None,
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: None,
+            min_base_amount: None,
+            min_quote_amount: None,
+            initial_ask: None,
+            fee_mode: None,
+            max_orders_per_owner: None,
+        };
+
+        // initialize
+        let init_response = instantiate(deps.as_mut(), mock_env(), info, init_msg.clone());
+
+        // verify initialize response
+        match init_response {
+            Ok(init_response) => {
+                assert_eq!(init_response.messages.len(), 1);
+                assert_eq!(
+                    init_response.messages[0].msg,
+                    CosmosMsg::Custom(ProvenanceMsg {
+                        route: ProvenanceRoute::Name,
+                        params: ProvenanceMsgParams::Name(NameMsgParams::BindName {
+                            name: init_msg.bind_name,
+                            address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                            restrict: true
+                        }),
+                        version: "2.0.0".to_string(),
+                    })
+                );
+                let expected_contract_info = ContractInfo {
+                    admin: Addr::unchecked("contract_admin"),
+                    bind_name: "contract_bind_name".to_string(),
+                    contract_name: "contract_name".to_string(),
+                    contract_type: CONTRACT_TYPE.into(),
+                    contract_version: CONTRACT_VERSION.into(),
+                    ask_fee: None,
+                    bid_fee: None,
+                    allowed_quote_denoms: None,
+                    match_fee: None,
+                    allow_self_match: false,
+                    paused: false,
+                    min_base_amount: None,
+                    min_quote_amount: None,
+                    fee_mode: FeeMode::Flat,
+                    max_orders_per_owner: None,
+                };
+
+                assert_eq!(init_response.attributes.len(), 2);
+                assert_eq!(
+                    init_response.attributes[0],
+                    attr(
+                        "contract_info",
+                        serde_json_wasm::to_string(&expected_contract_info).unwrap()
+                    )
+                );
+                assert_eq!(
+                    serde_json_wasm::from_str::<ContractInfo>(&init_response.attributes[0].value)
+                        .unwrap(),
+                    expected_contract_info,
+                    "the contract_info attribute should round trip to the stored value",
+                );
+                assert_eq!(init_response.attributes[1], attr("action", "init"));
+            }
+            error => panic!("failed to initialize: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn instantiate_with_initial_ask() {
+        let init_msg = |initial_ask: Option<InitialAsk>| InstantiateMsg {
+            bind_name: "contract_bind_name".to_string(),
+            contract_name: "contract_name".to_string(),
+            ask_fee: None,
+            bid_fee: None,
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: None,
+            min_base_amount: None,
+            min_quote_amount: None,
+            initial_ask,
+            fee_mode: None,
+            max_orders_per_owner: None,
+        };
+        let scope_address = "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string();
+        let scope_input = Scope {
+            scope_id: scope_address.clone(),
+            specification_id: "scopespec1qs0lctxj49wprm9xwxt5wk0paswqzkdaax".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("contract_admin"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked("contract_admin"),
+        };
+        let initial_ask = InitialAsk {
+            id: "house_ask".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: scope_address.clone(),
+        };
+
+        // without a seed, the contract starts with no asks
+        let mut deps = mock_dependencies(&[]);
+        let response = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            init_msg(None),
+        )
+        .expect("instantiating without an initial ask should succeed");
+        assert_eq!(1, response.messages.len());
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .next()
+            .is_none());
+
+        // a scope that the instantiator does not own cannot be seeded
+        let mut deps = mock_dependencies(&[]);
+        deps.querier.with_scope(scope_input.clone());
+        let err = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not_the_owner", &[]),
+            init_msg(Some(initial_ask.clone())),
+        )
+        .expect_err("seeding an ask for a scope owned by another account should fail");
+        assert!(
+            matches!(err, ContractError::InvalidScopeOwner { .. }),
+            "expected an invalid scope owner error, but got: {:?}",
+            err,
+        );
+
+        // the seeded ask is owned by the instantiator, and the scope is transferred to the contract after binding its name
+        let mut deps = mock_dependencies(&[]);
+        deps.querier.with_scope(scope_input.clone());
+        let response = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            init_msg(Some(initial_ask)),
+        )
+        .expect("instantiating with an initial ask for an owned scope should succeed");
+        assert_eq!(2, response.messages.len());
+        assert_eq!(
+            CosmosMsg::Custom(ProvenanceMsg {
+                route: ProvenanceRoute::Metadata,
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
+                    scope: Scope {
+                        owners: vec![Party {
+                            address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                            role: PartyType::Owner,
+                        }],
+                        value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        ..scope_input
+                    },
+                    signers: vec![Addr::unchecked(MOCK_CONTRACT_ADDR)],
+                }),
+                version: "2.0.0".to_string(),
+            }),
+            response.messages[1].msg,
+        );
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "order_id" && attribute.value == "house_ask"));
+        assert_eq!(
+            1,
+            response
+                .attributes
+                .iter()
+                .filter(|attribute| attribute.key == "action")
+                .count(),
+            "only the instantiation action should be emitted",
+        );
+        assert_eq!(
+            AskOrderV2 {
+                base: BaseType::scope(scope_address),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "house_ask".into(),
+                owner: Addr::unchecked("contract_admin"),
+                quote: coins(100, "quote_1"),
+            },
+            get_ask_storage_read_v2(&deps.storage)
+                .load(b"house_ask")
+                .expect("the initial ask should be stored"),
+        );
+    }
+
+    #[test]
+    fn instantiate_with_invalid_data() {
+        // create invalid init data
+        let mut deps = mock_dependencies(&[]);
+        let info = mock_info("contract_owner", &[]);
+        let init_msg = InstantiateMsg {
+            bind_name: "".to_string(),
+            contract_name: "contract_name".to_string(),
+            ask_fee: None,
+            bid_fee: None,
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: None,
+            min_base_amount: None,
+            min_quote_amount: None,
+            initial_ask: None,
+            fee_mode: None,
+            max_orders_per_owner: None,
+        };
+
+        // initialize
+        let init_response = instantiate(deps.as_mut(), mock_env(), info.to_owned(), init_msg);
+
+        // verify initialize response
+        match init_response {
+            Ok(_) => panic!("expected error, but init_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "bind_name")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        let init_msg = InstantiateMsg {
+            bind_name: "bind_name".to_string(),
+            contract_name: "".to_string(),
+            ask_fee: None,
+            bid_fee: None,
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: None,
+            min_base_amount: None,
+            min_quote_amount: None,
+            initial_ask: None,
+            fee_mode: None,
+            max_orders_per_owner: None,
+        };
+
+        // initialize
+        let init_response = instantiate(deps.as_mut(), mock_env(), info.to_owned(), init_msg);
+
+        // verify initialize response
+        match init_response {
+            Ok(_) => panic!("expected error, but init_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "contract_name")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        let init_msg = InstantiateMsg {
+            bind_name: "bind_name".to_string(),
+            contract_name: "contract_name".to_string(),
+            ask_fee: Some(Uint128::zero()),
+            bid_fee: None,
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: None,
+            min_base_amount: None,
+            min_quote_amount: None,
+            initial_ask: None,
+            fee_mode: None,
+            max_orders_per_owner: None,
+        };
+
+        let init_response = instantiate(deps.as_mut(), mock_env(), info.to_owned(), init_msg);
+
+        match init_response {
+            Ok(_) => panic!("expected error, but init_response ok"),
+            Err(error) => match error {
+                ContractError::InvalidFee { fee_type } => {
+                    assert_eq!(fee_type, "ask");
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        let init_msg = InstantiateMsg {
+            bind_name: "bind_name".to_string(),
+            contract_name: "contract_name".to_string(),
+            ask_fee: Some(Uint128::new(100)),
+            bid_fee: Some(Uint128::zero()),
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: None,
+            min_base_amount: None,
+            min_quote_amount: None,
+            initial_ask: None,
+            fee_mode: None,
+            max_orders_per_owner: None,
+        };
+
+        let init_response = instantiate(deps.as_mut(), mock_env(), info, init_msg);
+
+        match init_response {
+            Ok(_) => panic!("expected error, but init_response ok"),
+            Err(error) => match error {
+                ContractError::InvalidFee { fee_type } => {
+                    assert_eq!(fee_type, "bid");
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+    }
+
+    #[test]
+    fn create_ask_for_coin_with_valid_data_no_fee() {
+        test_valid_coin_ask(None);
+    }
+
+    #[test]
+    fn create_ask_for_coin_with_valid_data_and_fee() {
+        test_valid_coin_ask(Some(500));
+    }
+
+    #[test]
+    fn create_ask_for_coin_with_exact_base() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let create_exact_ask = |id: &str| ExecuteMsg::CreateAsk {
+            id: id.into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: Some(coins(100, "base_1")),
+            expected_previous_owner: None,
+        };
+
+        // funds matching the exact base are held without a refund
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_exact_ask("exact_ask"),
+        ) {
+            Ok(response) => assert_eq!(0, response.messages.len()),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // funds beyond the exact base are refunded to the asker
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[coin(150, "base_1"), coin(10, "other")]),
+            create_exact_ask("over_ask"),
+        ) {
+            Ok(response) => {
+                assert_eq!(1, response.messages.len());
+                assert_eq!(
+                    response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: vec![coin(50, "base_1"), coin(10, "other")],
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        for id in ["exact_ask", "over_ask"] {
+            assert_eq!(
+                BaseType::coins(coins(100, "base_1")),
+                ask_storage.load(id.as_bytes()).unwrap().base,
+            );
+        }
+
+        // funds short of the exact base are rejected
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(99, "base_1")),
+            create_exact_ask("under_ask"),
+        ) {
+            Err(ContractError::InsufficientBaseFunds { exact_base }) => {
+                assert_eq!("100base_1", exact_base)
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .may_load(b"under_ask")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn create_ask_with_match_fee_requires_nhash_quote() {
+        let mut deps = mock_dependencies(&[]);
+        let mut contract_info = ContractInfo::new(
+            Addr::unchecked("contract_admin"),
+            "contract_bind_name".into(),
+            "contract_name".into(),
+            None,
+            None,
+        );
+        contract_info.match_fee = Some(Uint128::new(50));
+        if let Err(error) = set_contract_info(&mut deps.storage, &contract_info) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let create_ask_msg = |id: &str, quote: Vec<Coin>| ExecuteMsg::CreateAsk {
+            id: id.into(),
+            quote,
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // a quote without nhash, or without enough nhash, could never pay the match fee
+        for (id, quote) in [
+            ("quote_ask", coins(200, "quote_1")),
+            ("short_ask", vec![coin(200, "quote_1"), coin(49, "nhash")]),
+        ] {
+            match execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(100, "base_1")),
+                create_ask_msg(id, quote),
+            ) {
+                Err(ContractError::InsufficientQuoteForMatchFee { match_fee }) => {
+                    assert_eq!(Uint128::new(50), match_fee)
+                }
+                result => panic!("unexpected result: {:?}", result),
+            }
+            assert!(get_ask_storage_read_v2(&deps.storage)
+                .may_load(id.as_bytes())
+                .unwrap()
+                .is_none());
+        }
+
+        // other quote denoms are accepted alongside enough nhash to pay the match fee
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_ask_msg("fee_ask", vec![coin(200, "quote_1"), coin(50, "nhash")]),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .may_load(b"fee_ask")
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_create_ask_for_scope_with_valid_data_no_fee() {
+        test_valid_scope_ask(None);
+    }
+
+    #[test]
+    fn test_create_ask_for_scope_with_valid_data_and_fee() {
+        test_valid_scope_ask(Some(1000000));
+    }
+
+    #[test]
+    fn test_create_scope_ask() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let scope_address = "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string();
+        let scope_input = Scope {
+            scope_id: scope_address.clone(),
+            specification_id: "scopespec1qs0lctxj49wprm9xwxt5wk0paswqzkdaax".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("asker"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![Addr::unchecked("asker")],
+            value_owner_address: Addr::unchecked("asker"),
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        // a scope owned by a different account cannot be listed
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not_the_owner", &[]),
+            ExecuteMsg::CreateScopeAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: scope_address.clone(),
+                expiration: None,
+            },
+        );
+        match create_ask_response {
+            Err(ContractError::InvalidScopeOwner { .. }) => {}
+            response => panic!(
+                "expected an invalid scope owner error, but got: {:?}",
+                response
+            ),
+        }
+
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CreateScopeAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: scope_address.clone(),
+                expiration: None,
+            },
+        )
+        .expect("creating a scope ask for a scope owned by the sender should succeed");
+
+        // the scope is transferred to the contract in the same message that creates the ask
+        assert_eq!(1, create_ask_response.messages.len());
+        assert_eq!(
+            create_ask_response.messages[0].msg,
+            CosmosMsg::Custom(ProvenanceMsg {
+                route: ProvenanceRoute::Metadata,
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
+                    scope: Scope {
+                        owners: vec![Party {
+                            address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                            role: PartyType::Owner,
+                        }],
+                        value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        ..scope_input
+                    },
+                    signers: vec![Addr::unchecked(MOCK_CONTRACT_ADDR)],
+                }),
+                version: "2.0.0".to_string(),
+            })
+        );
+        assert_eq!(
+            create_ask_response.attributes[0],
+            attr("action", "create_ask")
+        );
+        assert_eq!(
+            AskOrderV2 {
+                base: BaseType::scope(scope_address),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            get_ask_storage_read_v2(&deps.storage)
+                .load(b"ask_id")
+                .expect("the scope ask should be stored"),
+        );
+    }
+
+    #[test]
+    fn create_ask_with_invalid_scope_address() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // no scope is mocked, so any query to the metadata module would fail with a querier error
+        for invalid_address in ["not a scope address", "scope1", "marker1qraczfp249d3rm"] {
+            for msg in [
+                ExecuteMsg::CreateAsk {
+                    id: "ask_id".into(),
+                    quote: coins(100, "quote_1"),
+                    scope_address: Some(invalid_address.to_string()),
+                    marker_denom: None,
+                    expiration: None,
+                    exact_base: None,
+                    expected_previous_owner: None,
+                },
+                ExecuteMsg::CreateScopeAsk {
+                    id: "ask_id".into(),
+                    quote: coins(100, "quote_1"),
+                    scope_address: invalid_address.to_string(),
+                    expiration: None,
+                },
+            ] {
+                match execute(deps.as_mut(), mock_env(), mock_info("asker", &[]), msg) {
+                    Err(ContractError::InvalidScopeAddress { address }) => {
+                        assert_eq!(invalid_address, address)
+                    }
+                    response => panic!(
+                        "expected an invalid scope address error, but got: {:?}",
+                        response
+                    ),
+                }
+            }
+        }
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .is_err());
+    }
+
+    #[test]
+    fn create_ask_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // create ask invalid data
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "".into(),
+            quote: vec![],
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // handle create ask
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_ask_msg,
+        );
+
+        // verify handle create ask response returns ContractError::MissingField { id }
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but handle_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "id")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        // create ask missing id
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // handle create ask
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_ask_msg,
+        );
+
+        // verify execute create ask response returns ContractError::MissingField { id }
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "id")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        // create ask missing quote
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "id".into(),
+            quote: vec![],
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // execute create ask
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_ask_msg,
+        );
+
+        // verify execute create ask response returns ContractError::MissingField { quote }
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "quote")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        // create ask missing base
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // execute create ask
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_ask_msg,
+        );
+
+        // verify execute create ask response returns ContractError::AskMissingBase
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::MissingAskBase {} => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        // create ask with scope and funds provided
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: Some("scope-address".to_string()),
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(150, "fakecoin")),
+            create_ask_msg,
+        );
+
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::ScopeAskBaseWithFunds => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        // create ask with scope provided with incorrect value owner address
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: Some("scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string()),
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        deps.querier.with_scope(Scope {
+            scope_id: "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string(),
+            specification_id: "spec_address".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked("not_contract_address"),
+        });
+
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_ask_msg.clone(),
+        );
+
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::InvalidScopeOwner {
+                    scope_address,
+                    explanation,
+                } => {
+                    assert_eq!(
+                        "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk", scope_address,
+                        "the proper scope address should be found",
+                    );
+                    assert_eq!(
+                        "the scope's value owner was expected to be [cosmos2contract], not [not_contract_address]", explanation,
+                        "the proper explanation must be used in the InvalidScopeOwner error",
+                    );
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        // create ask with scope provided with multiple owners specified - re-using previous ask msg
+        deps.querier.with_scope(Scope {
+            scope_id: "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string(),
+            specification_id: "spec_address".to_string(),
+            owners: vec![
+                Party {
+                    address: Addr::unchecked("asker"),
+                    role: PartyType::Owner,
+                },
+                Party {
+                    address: Addr::unchecked("other-guy"),
+                    role: PartyType::Owner,
+                },
+            ],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        });
+
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_ask_msg.clone(),
+        );
+
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::InvalidScopeOwner {
+                    scope_address,
+                    explanation,
+                } => {
+                    assert_eq!(
+                        "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk", scope_address,
+                        "the proper scope address should be found",
+                    );
+                    assert_eq!(
+                        "the scope should only include a single owner, but found: 2", explanation,
+                        "the proper explanation must be used in the InvalidScopeOwner error",
+                    );
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+
+        // create ask with scope provided with incorrect contract owner specified - re-using previous ask msg
+        deps.querier.with_scope(Scope {
+            scope_id: "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string(),
+            specification_id: "spec_address".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("not-contract-address"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        });
+
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_ask_msg,
+        );
+
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
+            Err(error) => match error {
+                ContractError::InvalidScopeOwner {
+                    scope_address,
+                    explanation,
+                } => {
+                    assert_eq!(
+                        "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk", scope_address,
+                        "the proper scope address should be found",
+                    );
+                    assert_eq!(
+                        "the scope owner was expected to be [cosmos2contract], not [not-contract-address]", explanation,
+                        "the proper explanation must be used in the InvalidScopeOwner error",
+                    );
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        };
+    }
+
+    #[test]
+    fn test_create_coin_bid_with_valid_data_no_fee() {
+        test_valid_coin_bid(None);
+    }
+
+    #[test]
+    fn test_create_coin_bid_with_valid_data_and_fee() {
+        test_valid_coin_bid(Some(12345));
+    }
+
+    #[test]
+    fn create_ask_with_duplicate_id() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "ask_id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_ask_msg.clone(),
+        )
+        .expect("the first ask with the id should be created");
+
+        // attempting to reuse the id must not overwrite the stored ask
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_asker", &coins(50, "base_2")),
+            create_ask_msg,
+        );
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but create_ask_response ok"),
+            Err(ContractError::OrderIdAlreadyExists { id }) => assert_eq!("ask_id", id),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+        let stored_ask = get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .unwrap();
+        assert_eq!(Addr::unchecked("asker"), stored_ask.owner);
+        assert_eq!(BaseType::coin(100, "base_1"), stored_ask.base);
+    }
+
+    #[test]
+    fn create_bid_with_duplicate_id() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: "bid_id".into(),
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            create_bid_msg.clone(),
+        )
+        .expect("the first bid with the id should be created");
+
+        // attempting to reuse the id must not overwrite the stored bid
+        let create_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_bidder", &coins(50, "quote_1")),
+            create_bid_msg,
+        );
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but create_bid_response ok"),
+            Err(ContractError::OrderIdAlreadyExists { id }) => assert_eq!("bid_id", id),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+        let stored_bid = get_bid_storage_read_v2(&deps.storage)
+            .load(b"bid_id")
+            .unwrap();
+        assert_eq!(Addr::unchecked("bidder"), stored_bid.owner);
+        assert_eq!(coins(100, "quote_1"), stored_bid.quote);
+    }
+
+    #[test]
+    fn create_bid_emits_stable_escrow_receipt() {
+        let create_bid_receipt = |bidder: &str| -> String {
+            let mut deps = mock_dependencies(&[]);
+            if let Err(error) = set_contract_info(
+                &mut deps.storage,
+                &ContractInfo::new(
+                    Addr::unchecked("contract_admin"),
+                    "contract_bind_name".into(),
+                    "contract_name".into(),
+                    None,
+                    None,
+                ),
+            ) {
+                panic!("unexpected error: {:?}", error)
+            }
+            let create_bid_response = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(bidder, &coins(100, "quote_1")),
+                ExecuteMsg::CreateBid {
+                    id: "bid_id".into(),
+                    base: BaseType::coin(100, "base_1"),
+                    effective_time: Some(Timestamp::default()),
+                    expiration: None,
+                    accepted_ask_quote: None,
+                    requested_data_access: None,
+                    value_owner_override: None,
+                },
+            )
+            .expect("the bid should be created");
+            create_bid_response
+                .attributes
+                .into_iter()
+                .find(|attribute| attribute.key == "escrow_receipt")
+                .expect("the escrow_receipt attribute should be emitted")
+                .value
+        };
+
+        let receipt = create_bid_receipt("bidder");
+        assert_eq!(64, receipt.len());
+        assert!(receipt.chars().all(|c| c.is_ascii_hexdigit()));
+        // identical bid details always produce the same receipt
+        assert_eq!(receipt, create_bid_receipt("bidder"));
+        // the receipt is tied to the bid's owner
+        assert_ne!(receipt, create_bid_receipt("other_bidder"));
+    }
+
+    #[test]
+    fn create_bid_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // create bid missing id
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: "".into(),
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+
+        // execute create bid
+        let create_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            create_bid_msg,
+        );
+
+        // verify execute create bid response returns ContractError::MissingField { id }
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but create_bid_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "id")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        // create bid missing base
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: "id".into(),
+            base: BaseType::coins(vec![]),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+
+        // execute create bid
+        let create_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            create_bid_msg,
+        );
+
+        // verify execute create bid response returns ContractError::MissingField { base }
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but create_bid_response ok"),
+            Err(error) => match error {
+                ContractError::MissingField { field } => {
+                    assert_eq!(field, "base")
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        // create bid missing quote
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: "id".into(),
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+
+        // execute create bid
+        let create_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            create_bid_msg,
+        );
+
+        // verify execute create bid response returns ContractError::BidMissingQuote
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but create_bid_response ok"),
+            Err(error) => match error {
+                ContractError::MissingBidQuote {} => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn create_orders_with_zero_amount_coins() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let create_ask_msg =
+            |quote: Vec<Coin>, exact_base: Option<Vec<Coin>>| ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote,
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base,
+                expected_previous_owner: None,
+            };
+        let create_bid_msg = |base: Vec<Coin>| ExecuteMsg::CreateBid {
+            id: "bid_id".into(),
+            base: BaseType::coins(base),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+
+        let attempts = vec![
+            // a zero amount in the ask's quote
+            (
+                mock_info("asker", &coins(100, "base_1")),
+                create_ask_msg(vec![coin(100, "quote_1"), coin(0, "quote_2")], None),
+                "quote_2",
+            ),
+            // a zero amount in the funds that make up the ask's base
+            (
+                mock_info("asker", &[coin(100, "base_1"), coin(0, "base_2")]),
+                create_ask_msg(coins(100, "quote_1"), None),
+                "base_2",
+            ),
+            // a zero amount in the ask's declared exact base
+            (
+                mock_info("asker", &coins(100, "base_1")),
+                create_ask_msg(
+                    coins(100, "quote_1"),
+                    Some(vec![coin(100, "base_1"), coin(0, "base_2")]),
+                ),
+                "base_2",
+            ),
+            // a zero amount in the bid's base
+            (
+                mock_info("bidder", &coins(100, "quote_1")),
+                create_bid_msg(vec![coin(100, "base_1"), coin(0, "base_2")]),
+                "base_2",
+            ),
+            // a zero amount in the funds that make up the bid's quote
+            (
+                mock_info("bidder", &[coin(100, "quote_1"), coin(0, "quote_2")]),
+                create_bid_msg(coins(100, "base_1")),
+                "quote_2",
+            ),
+        ];
+        for (info, msg, expected_denom) in attempts {
+            match execute(deps.as_mut(), mock_env(), info, msg.clone()) {
+                Err(ContractError::ZeroAmountCoin { denom }) => assert_eq!(expected_denom, denom),
+                result => panic!("expected {:?} to be rejected, but got: {:?}", msg, result),
+            }
+        }
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .is_err());
+        assert!(get_bid_storage_read_v2(&deps.storage)
+            .load(b"bid_id")
+            .is_err());
+    }
+
+    #[test]
+    fn create_orders_with_duplicate_denoms() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // an ask quoting the same denom twice is stored with a single merged coin
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: vec![coin(60, "quote_1"), coin(40, "quote_1")],
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        )
+        .expect("the ask should be created");
+        let stored_ask = get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .unwrap();
+        assert_eq!(coins(100, "quote_1"), stored_ask.quote);
+
+        // a bid requesting the same denom twice is stored with a single merged coin
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coins(vec![coin(25, "base_1"), coin(75, "base_1")]),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        )
+        .expect("the bid should be created");
+        let stored_bid = get_bid_storage_read_v2(&deps.storage)
+            .load(b"bid_id")
+            .unwrap();
+        assert_eq!(BaseType::coin(100, "base_1"), stored_bid.base);
+
+        // the merged orders match their single coin counterparts
+        assert!(is_executable(&stored_ask, &stored_bid));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "bid_id".into(),
+            },
+        )
+        .expect("the merged orders should be matched");
+    }
+
+    #[test]
+    fn test_create_scope_bid_with_valid_data_no_fee() {
+        test_valid_scope_bid(None);
+    }
+
+    #[test]
+    fn test_create_scope_bid_with_valid_data_and_fee() {
+        test_valid_scope_bid(Some(3434234));
+    }
+
+    #[test]
+    fn cancel_coin_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // create ask data
+        let asker_info = mock_info("asker", &coins(200, "base_1"));
+
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "ask_id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // execute create ask
+        if let Err(error) = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // verify ask order stored
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_ok());
+
+        // cancel ask order
+        let asker_info = mock_info("asker", &[]);
+
+        let cancel_ask_msg = ExecuteMsg::CancelAsk {
+            id: "ask_id".to_string(),
+        };
+        let cancel_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            asker_info.clone(),
+            cancel_ask_msg,
+        );
+
+        match cancel_ask_response {
+            Ok(cancel_ask_response) => {
+                assert_eq!(cancel_ask_response.attributes.len(), 1);
+                assert_eq!(
+                    cancel_ask_response.attributes[0],
+                    attr("action", "cancel_ask")
+                );
+                assert_eq!(cancel_ask_response.messages.len(), 1);
+                assert_eq!(
+                    cancel_ask_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: asker_info.sender.to_string(),
+                        amount: coins(200, "base_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify ask order removed from storage
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_err());
+
+        // create bid data
+        let bidder_info = mock_info("bidder", &coins(100, "quote_1"));
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: "bid_id".into(),
+            base: BaseType::coins(vec![Coin {
+                denom: "base_1".into(),
+                amount: Uint128::new(200),
+            }]),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+
+        // execute create bid
+        if let Err(error) = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // verify bid order stored
+        let bid_storage = get_bid_storage_read_v2(&deps.storage);
+        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_ok(),);
+
+        // cancel bid order
+        let bidder_info = mock_info("bidder", &[]);
+
+        let cancel_bid_msg = ExecuteMsg::CancelBid {
+            id: "bid_id".to_string(),
+        };
+
+        let cancel_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            bidder_info.clone(),
+            cancel_bid_msg,
+        );
+
+        match cancel_bid_response {
+            Ok(cancel_bid_response) => {
+                assert_eq!(cancel_bid_response.attributes.len(), 2);
+                assert_eq!(
+                    cancel_bid_response.attributes[0],
+                    attr("action", "cancel_bid")
+                );
+                assert_eq!(cancel_bid_response.attributes[1], attr("base_type", "coin"));
+                assert_eq!(cancel_bid_response.messages.len(), 1);
+                assert_eq!(
+                    cancel_bid_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: bidder_info.sender.to_string(),
+                        amount: coins(100, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify bid order removed from storage
+        let bid_storage = get_bid_storage_read_v2(&deps.storage);
+        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_err());
+    }
+
+    #[test]
+    fn reduce_ask_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // create an ask with a two denom base
+        let asker_info = mock_info("asker", &[coin(200, "base_1"), coin(50, "base_2")]);
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "ask_id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+        if let Err(error) = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // withdraw one of the denoms, leaving the other listed
+        let reduce_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::ReduceAsk {
+                id: "ask_id".into(),
+                remove: coins(50, "base_2"),
+            },
+        );
+        match reduce_ask_response {
+            Ok(reduce_ask_response) => {
+                assert_eq!(
+                    reduce_ask_response.attributes,
+                    vec![
+                        attr("action", "reduce_ask"),
+                        attr("order_id", "ask_id"),
+                        attr("ask_removed", "false"),
+                    ]
+                );
+                assert_eq!(reduce_ask_response.messages.len(), 1);
+                assert_eq!(
+                    reduce_ask_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(50, "base_2"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+        let stored_ask = get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .expect("the reduced ask should remain in storage");
+        assert_eq!(BaseType::coin(200, "base_1"), stored_ask.base);
+        assert_eq!(coins(100, "quote_1"), stored_ask.quote);
+
+        // withdrawing the rest of the base removes the ask
+        let reduce_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::ReduceAsk {
+                id: "ask_id".into(),
+                remove: coins(200, "base_1"),
+            },
+        )
+        .expect("the remaining base should be withdrawn");
+        assert_eq!(
+            reduce_ask_response.attributes[2],
+            attr("ask_removed", "true")
+        );
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .is_err());
+    }
+
+    #[test]
+    fn reduce_ask_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let asker_info = mock_info("asker", &[coin(200, "base_1"), coin(50, "base_2")]);
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "ask_id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+        if let Err(error) = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // removals that are not a subset of the base, or that repeat or zero out a denom, are rejected
+        for remove in [
+            coins(51, "base_2"),
+            coins(1, "base_3"),
+            vec![coin(25, "base_2"), coin(25, "base_2")],
+            coins(0, "base_1"),
+            vec![],
+        ] {
+            match execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &[]),
+                ExecuteMsg::ReduceAsk {
+                    id: "ask_id".into(),
+                    remove: remove.clone(),
+                },
+            ) {
+                Err(ContractError::InvalidAskReduction { .. }) => {}
+                result => panic!(
+                    "expected removing {:?} to be rejected, but got: {:?}",
+                    remove, result
+                ),
+            }
+        }
+
+        // only the owner can reduce the ask
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not_asker", &[]),
+            ExecuteMsg::ReduceAsk {
+                id: "ask_id".into(),
+                remove: coins(50, "base_2"),
+            },
+        ) {
+            Err(ContractError::Unauthorized {}) => {}
+            result => panic!("expected an unauthorized error, but got: {:?}", result),
+        }
+
+        // the rejected reductions leave the base untouched
+        let stored_ask = get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .unwrap();
+        assert_eq!(
+            BaseType::coins(vec![coin(200, "base_1"), coin(50, "base_2")]),
+            stored_ask.base
+        );
+
+        // scope bases cannot be divided
+        get_ask_storage_v2(&mut deps.storage)
+            .save(
+                b"scope_ask_id",
+                &AskOrderV2 {
+                    base: BaseType::scope("scope1qzge0zaztu65tx5x5llv5xc9ztsqxlkwel"),
+                    expiration: None,
+                    expected_previous_owner: None,
+                    id: "scope_ask_id".into(),
+                    owner: Addr::unchecked("asker"),
+                    quote: coins(100, "quote_1"),
+                },
+            )
+            .unwrap();
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::ReduceAsk {
+                id: "scope_ask_id".into(),
+                remove: coins(100, "base_1"),
+            },
+        ) {
+            Err(ContractError::InvalidAskReduction { explanation }) => {
+                assert!(explanation.contains("scope"))
+            }
+            result => panic!(
+                "expected a scope base to be rejected, but got: {:?}",
+                result
+            ),
+        }
+    }
+
+    #[test]
+    fn increase_bid_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(200, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        )
+        .expect("the bid should be created");
+
+        // funds in a denom already in the quote are added to it
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(50, "quote_1")),
+            ExecuteMsg::IncreaseBid {
+                id: "bid_id".into(),
+            },
+        )
+        .expect("the bid should be increased");
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            response.attributes,
+            vec![
+                attr("action", "increase_bid"),
+                attr("order_id", "bid_id"),
+                attr("quote", "150quote_1"),
+            ]
+        );
+        assert_eq!(
+            coins(150, "quote_1"),
+            get_bid_storage_read_v2(&deps.storage)
+                .load(b"bid_id")
+                .expect("the bid should still be stored")
+                .quote,
+        );
+
+        // funds in a new denom are added to the quote alongside the existing coins
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(25, "quote_2")),
+            ExecuteMsg::IncreaseBid {
+                id: "bid_id".into(),
+            },
+        )
+        .expect("the bid should be increased with a new denom");
+        assert_eq!(
+            vec![coin(150, "quote_1"), coin(25, "quote_2")],
+            get_bid_storage_read_v2(&deps.storage)
+                .load(b"bid_id")
+                .expect("the bid should still be stored")
+                .quote,
+        );
+
+        // only the owner can increase the bid, and funds must be provided
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not_bidder", &coins(50, "quote_1")),
+            ExecuteMsg::IncreaseBid {
+                id: "bid_id".into(),
+            },
+        ) {
+            Err(ContractError::Unauthorized {}) => {}
+            result => panic!("expected an unauthorized error, but got: {:?}", result),
+        }
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            ExecuteMsg::IncreaseBid {
+                id: "bid_id".into(),
+            },
+        ) {
+            Err(ContractError::MissingBidQuote) => {}
+            result => panic!("expected a missing bid quote error, but got: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn increase_bid_with_expired_bid() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let env = mock_env();
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(200, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: Some(env.block.time.minus_seconds(1)),
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        if let Err(error) =
+            get_bid_storage_v2(&mut deps.storage).save(bid_order.id.as_bytes(), &bid_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        match execute(
+            deps.as_mut(),
+            env,
+            mock_info("bidder", &coins(50, "quote_1")),
+            ExecuteMsg::IncreaseBid {
+                id: "bid_id".into(),
+            },
+        ) {
+            Err(ContractError::OrderExpired { id }) => assert_eq!("bid_id", id),
+            result => panic!("expected an order expired error, but got: {:?}", result),
+        }
+        // the expired bid's quote is unchanged, so only its original funds are returned when it is reaped
+        assert_eq!(
+            bid_order,
+            get_bid_storage_read_v2(&deps.storage)
+                .load(b"bid_id")
+                .expect("the bid should still be stored"),
+        );
+    }
+
+    #[test]
+    fn cancel_scope_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // create ask data - omit funds because a scope is being provided
+        let asker_info = mock_info("asker", &[]);
+
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: "ask_id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: Some("scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string()),
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        deps.querier.with_scope(Scope {
+            scope_id: "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string(),
+            specification_id: "spec_address".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        });
+
+        // execute create ask
+        if let Err(error) = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // verify ask order stored
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_ok());
+
+        // cancel ask order
+        let asker_info = mock_info("asker", &[]);
+
+        let cancel_ask_msg = ExecuteMsg::CancelAsk {
+            id: "ask_id".to_string(),
+        };
+        let cancel_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            asker_info.clone(),
+            cancel_ask_msg,
+        );
+
+        match cancel_ask_response {
+            Ok(cancel_ask_response) => {
+                assert_eq!(cancel_ask_response.attributes.len(), 1);
+                assert_eq!(
+                    cancel_ask_response.attributes[0],
+                    attr("action", "cancel_ask")
+                );
+                assert_eq!(cancel_ask_response.messages.len(), 1);
+                match &cancel_ask_response.messages.first().unwrap().msg {
+                    CosmosMsg::Custom(ProvenanceMsg {
+                        params:
+                            ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
+                                scope,
+                                signers,
+                            }),
+                        ..
+                    }) => {
+                        assert_eq!(
+                            1,
+                            scope.owners.len(),
+                            "expected the scope to only include one owner after the owner is swapped back to the original value",
+                        );
+                        let scope_owner = scope.owners.first().unwrap();
+                        assert_eq!(
+                            "asker",
+                            scope_owner.address.as_str(),
+                            "expected the asker address to be set as the scope owner",
+                        );
+                        assert_eq!(
+                            PartyType::Owner,
+                            scope_owner.role,
+                            "expected the asker's role to be that of owner",
+                        );
+                        assert_eq!(
+                            "asker",
+                            scope.value_owner_address.as_str(),
+                            "expected the asker to be set as the value owner after a cancellation",
+                        );
+                        assert_eq!(
+                            1,
+                            signers.len(),
+                            "expected only a single signer to be used on the write scope request",
+                        );
+                        assert_eq!(
+                            MOCK_CONTRACT_ADDR,
+                            signers.first().unwrap().as_str(),
+                            "expected the signer for the write scope request to be the contract",
+                        );
+                    }
+                    msg => panic!("unexpected message emitted by cancel ask: {:?}", msg),
+                };
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify ask order removed from storage
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_err());
+
+        // create bid data
+        let bidder_info = mock_info("bidder", &coins(100, "quote_1"));
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: "bid_id".into(),
+            base: BaseType::scope("scope1qraczfp249d3rmysdurne8cxrwmqamu8tk"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
+        };
+
+        // execute create bid
+        if let Err(error) = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // verify bid order stored
+        let bid_storage = get_bid_storage_read_v2(&deps.storage);
+        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_ok(),);
+
+        // cancel bid order
+        let bidder_info = mock_info("bidder", &[]);
+
+        let cancel_bid_msg = ExecuteMsg::CancelBid {
+            id: "bid_id".to_string(),
+        };
+
+        let cancel_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            bidder_info.clone(),
+            cancel_bid_msg,
+        );
+
+        match cancel_bid_response {
+            Ok(cancel_bid_response) => {
+                assert_eq!(cancel_bid_response.attributes.len(), 2);
+                assert_eq!(
+                    cancel_bid_response.attributes[0],
+                    attr("action", "cancel_bid")
+                );
+                assert_eq!(
+                    cancel_bid_response.attributes[1],
+                    attr("base_type", "scope")
+                );
+                assert_eq!(cancel_bid_response.messages.len(), 1);
+                assert_eq!(
+                    cancel_bid_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: bidder_info.sender.to_string(),
+                        amount: coins(100, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify bid order removed from storage
+        let bid_storage = get_bid_storage_read_v2(&deps.storage);
+        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_err());
+    }
+
+    #[test]
+    fn cancel_scope_with_expected_previous_owner() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        deps.querier.with_scope(Scope {
+            scope_id: "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string(),
+            specification_id: "spec_address".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        });
+
+        // the asker declares that the scope was transferred to the contract by another account
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: Some("scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string()),
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: Some("scope_owner".into()),
+            },
+        )
+        .expect("the ask should be created");
+        assert_eq!(
+            Some(Addr::unchecked("scope_owner")),
+            get_ask_storage_read_v2(&deps.storage)
+                .load(b"ask_id")
+                .expect("the ask should be stored")
+                .expected_previous_owner,
+            "expected the declared previous owner to be stored on the ask",
+        );
+
+        // cancelling the ask returns the scope to the declared previous owner rather than the asker
+        let cancel_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk {
+                id: "ask_id".to_string(),
+            },
+        )
+        .expect("the ask should be cancelled");
+        assert_eq!(1, cancel_ask_response.messages.len());
+        match &cancel_ask_response.messages[0].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                ..
+            }) => {
+                assert_eq!(
+                    vec![Party {
+                        address: Addr::unchecked("scope_owner"),
+                        role: PartyType::Owner,
+                    }],
+                    scope.owners,
+                    "expected the declared previous owner to be set as the scope owner",
+                );
+                assert_eq!(
+                    "scope_owner",
+                    scope.value_owner_address.as_str(),
+                    "expected the declared previous owner to be set as the value owner",
+                );
+            }
+            msg => panic!("expected a write scope message, but got: {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn create_ask_with_invalid_expected_previous_owner() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // coins are returned to the asker, so a previous owner can only be declared for a scope
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: Some("scope_owner".into()),
+            },
+        ) {
+            Err(ContractError::InvalidExpectedPreviousOwner { explanation }) => {
+                assert!(explanation.contains("coin"))
+            }
+            result => panic!(
+                "expected an invalid expected previous owner error, but got: {:?}",
+                result
+            ),
+        }
+    }
+
+    #[test]
+    fn test_create_ask_for_marker_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // the asker has granted the contract admin access, but has not yet given up their own
+        deps.querier
+            .with_markers(vec![test_marker("marker1", &[MOCK_CONTRACT_ADDR, "asker"])]);
+
+        let create_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: None,
+                marker_denom: Some("marker1".into()),
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        );
+
+        match create_ask_response {
+            Ok(create_ask_response) => {
+                assert_eq!(
+                    create_ask_response.attributes[0],
+                    attr("action", "create_ask")
+                );
+                assert_eq!(create_ask_response.messages.len(), 1);
+                assert_eq!(
+                    create_ask_response.messages[0].msg,
+                    revoke_marker_access("marker1", Addr::unchecked("asker")).unwrap()
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify ask order stored
+        match get_ask_storage_read_v2(&deps.storage).load("ask_id".as_bytes()) {
+            Ok(stored_order) => assert_eq!(
+                stored_order,
+                AskOrderV2 {
+                    base: BaseType::marker("marker1"),
+                    expiration: None,
+                    expected_previous_owner: None,
+                    id: "ask_id".into(),
+                    owner: Addr::unchecked("asker"),
+                    quote: coins(100, "quote_1"),
+                }
+            ),
+            _ => panic!("ask order was not found in storage"),
+        }
+    }
+
+    #[test]
+    fn create_ask_for_marker_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        deps.querier.with_markers(vec![
+            test_marker("ungranted", &["asker"]),
+            test_marker("shared", &[MOCK_CONTRACT_ADDR, "asker", "other-guy"]),
+        ]);
+
+        let create_marker_ask = |denom: &str| ExecuteMsg::CreateAsk {
+            id: "ask_id".into(),
+            quote: coins(100, "quote_1"),
+            scope_address: None,
+            marker_denom: Some(denom.into()),
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+
+        // funds cannot be sent alongside a marker
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_marker_ask("shared"),
+        ) {
+            Err(ContractError::MarkerAskBaseWithFunds) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // a scope and a marker cannot both be listed
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: Some("scope1234".into()),
+                marker_denom: Some("shared".into()),
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        ) {
+            Err(ContractError::MultipleAskBases) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // the contract must be granted admin access
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_marker_ask("ungranted"),
+        ) {
+            Err(ContractError::InvalidMarkerPermissions { denom, explanation }) => {
+                assert_eq!("ungranted", denom);
+                assert_eq!(
+                    "the contract [cosmos2contract] must be granted admin access",
+                    explanation,
+                );
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // no other account may retain admin access
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            create_marker_ask("shared"),
+        ) {
+            Err(ContractError::InvalidMarkerPermissions { denom, explanation }) => {
+                assert_eq!("shared", denom);
+                assert_eq!(
+                    "only the contract may hold admin access, but [other-guy] also has admin access",
+                    explanation,
+                );
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn cancel_scope_no_longer_held_by_contract() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope("scope_address"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(100, "quote_1"),
+        };
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // the scope was transferred away from the contract after the ask was created
+        deps.querier.with_scope(Scope {
+            scope_id: "scope_address".to_string(),
+            specification_id: "spec_address".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("someone_else"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked("someone_else"),
+        });
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk {
+                id: "ask_id".to_string(),
+            },
+        ) {
+            Err(ContractError::ScopeNotHeldByContract { scope_address, .. }) => {
+                assert_eq!("scope_address", scope_address)
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn cancel_marker_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::marker("marker1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(100, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        let cancel_ask_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk {
+                id: "ask_id".to_string(),
+            },
+        );
+
+        match cancel_ask_response {
+            Ok(cancel_ask_response) => {
+                assert_eq!(cancel_ask_response.attributes.len(), 1);
+                assert_eq!(
+                    cancel_ask_response.attributes[0],
+                    attr("action", "cancel_ask")
+                );
+                assert_eq!(cancel_ask_response.messages.len(), 2);
+                assert_eq!(
+                    cancel_ask_response.messages[0].msg,
+                    grant_marker_access("marker1", Addr::unchecked("asker"), MarkerAccess::all())
+                        .unwrap()
+                );
+                assert_eq!(
+                    cancel_ask_response.messages[1].msg,
+                    revoke_marker_access("marker1", Addr::unchecked(MOCK_CONTRACT_ADDR)).unwrap()
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify ask order removed from storage
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_err());
+    }
+
+    #[test]
+    fn cancel_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let asker_info = mock_info("asker", &[]);
+
+        // cancel ask order with missing id returns ContractError::Unauthorized
+        let cancel_ask_msg = ExecuteMsg::CancelAsk { id: "".to_string() };
+        let cancel_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            asker_info.clone(),
+            cancel_ask_msg,
+        );
+
+        match cancel_response {
+            Err(error) => match error {
+                ContractError::Unauthorized {} => {}
+                _ => {
+                    panic!("unexpected error: {:?}", error)
+                }
+            },
+            Ok(_) => panic!("expected error, but cancel_response ok"),
+        }
+
+        // cancel non-existent ask order returns ContractError::Unauthorized
+        let cancel_ask_msg = ExecuteMsg::CancelAsk {
+            id: "unknown_id".to_string(),
+        };
+
+        let cancel_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            asker_info.clone(),
+            cancel_ask_msg,
+        );
+
+        match cancel_response {
+            Err(error) => match error {
+                ContractError::Unauthorized {} => {}
+                _ => {
+                    panic!("unexpected error: {:?}", error)
+                }
+            },
+            Ok(_) => panic!("expected error, but cancel_response ok"),
+        }
+
+        // cancel ask order with sender not equal to owner returns ContractError::Unauthorized
+        if let Err(error) = get_ask_storage_v2(&mut deps.storage).save(
+            "ask_id".to_string().as_bytes(),
+            &AskOrderV2 {
+                base: BaseType::coin(200, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".into(),
+                owner: Addr::unchecked(""),
+                quote: coins(100, "quote_1"),
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        };
+        let cancel_ask_msg = ExecuteMsg::CancelAsk {
+            id: "ask_id".to_string(),
+        };
+
+        let cancel_response = execute(deps.as_mut(), mock_env(), asker_info, cancel_ask_msg);
+
+        match cancel_response {
+            Err(error) => match error {
+                ContractError::Unauthorized {} => {}
+                _ => {
+                    panic!("unexpected error: {:?}", error)
+                }
+            },
+            Ok(_) => panic!("expected error, but cancel_response ok"),
+        }
+
+        // cancel ask order with sent_funds returns ContractError::CancelWithFunds
+        let asker_info = mock_info("asker", &coins(1, "sent_coin"));
+        let cancel_ask_msg = ExecuteMsg::CancelAsk {
+            id: "ask_id".to_string(),
+        };
+
+        let cancel_response = execute(deps.as_mut(), mock_env(), asker_info, cancel_ask_msg);
+
+        match cancel_response {
+            Err(error) => match error {
+                ContractError::CancelWithFunds {} => {}
+                _ => {
+                    panic!("unexpected error: {:?}", error)
+                }
+            },
+            Ok(_) => panic!("expected error, but cancel_response ok"),
+        }
+    }
+
+    #[test]
+    fn execute_match_with_valid_coin_data() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::coins(vec![coin(100, "base_1"), coin(200, "base_2")]),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store valid bid order
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coins(vec![coin(200, "base_2"), coin(100, "base_1")]),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(200, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        // execute on matched ask order and bid order
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: ask_order.id,
+            bid_id: bid_order.id.clone(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        // validate execute response
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(
+                    execute_response.attributes,
+                    vec![
+                        attr("action", "execute"),
+                        attr("ask_id", "ask_id"),
+                        attr("bid_id", "bid_id"),
+                        attr("asker", "asker"),
+                        attr("bidder", "bidder"),
+                        attr("quote_paid", "200quote_1"),
+                        attr("base_type", "coin"),
+                        attr("base_delivered", "200base_2,100base_1"),
+                    ]
+                );
+                assert_eq!(execute_response.messages.len(), 2);
+                assert_eq!(
+                    execute_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: ask_order.owner.to_string(),
+                        amount: ask_order.quote,
+                    })
+                );
+                handle_expected_coin(&bid_order.base, |coins| {
+                    assert_eq!(
+                        execute_response.messages[1].msg,
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: bid_order.owner.to_string(),
+                            amount: coins.to_vec(),
+                        })
+                    );
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn execute_match_with_match_fee() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        let mut contract_info = ContractInfo::new(
+            Addr::unchecked("contract_admin"),
+            "contract_bind_name".into(),
+            "contract_name".into(),
+            None,
+            None,
+        );
+        contract_info.match_fee = Some(Uint128::new(50));
+        if let Err(error) = set_contract_info(&mut deps.storage, &contract_info) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: vec![coin(200, "nhash"), coin(300, "quote_1")],
+        };
+        if let Err(error) = get_ask_storage_v2(&mut deps.storage).save(b"ask_id", &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: vec![coin(200, "nhash"), coin(300, "quote_1")],
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        if let Err(error) = get_bid_storage_v2(&mut deps.storage).save(b"bid_id", &bid_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "bid_id".into(),
+            },
+        )
+        .expect("executing a match with a match fee should succeed");
+
+        assert_eq!(execute_response.messages.len(), 4);
+        // the asker receives the quote minus the match fee
+        assert_eq!(
+            execute_response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "asker".to_string(),
+                amount: vec![coin(150, "nhash"), coin(300, "quote_1")],
+            })
+        );
+        // the deducted quote covers the fee charged to the admin that signs the match
+        assert_eq!(
+            execute_response.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "contract_admin".to_string(),
+                amount: coins(50, "nhash"),
+            })
+        );
+        match &execute_response.messages[2].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::MsgFees(MsgFeesMsgParams::AssessCustomFee {
+                        amount,
+                        name,
+                        from,
+                        recipient,
+                    }),
+                ..
+            }) => {
+                assert_eq!(&coin(50, "nhash"), amount);
+                assert_eq!("match fee", name.to_owned().unwrap());
+                assert_eq!(MOCK_CONTRACT_ADDR, from.as_str());
+                assert_eq!("contract_admin", recipient.to_owned().unwrap().as_str());
+            }
+            msg => panic!("unexpected msg: {:?}", msg),
+        }
+        assert_eq!(
+            execute_response.messages[3].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "bidder".to_string(),
+                amount: coins(100, "base_1"),
+            })
+        );
+    }
+
+    #[test]
+    fn execute_match_with_match_fee_exceeding_quote() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        let mut contract_info = ContractInfo::new(
+            Addr::unchecked("contract_admin"),
+            "contract_bind_name".into(),
+            "contract_name".into(),
+            None,
+            None,
+        );
+        contract_info.match_fee = Some(Uint128::new(50));
+        if let Err(error) = set_contract_info(&mut deps.storage, &contract_info) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+        if let Err(error) = get_ask_storage_v2(&mut deps.storage).save(b"ask_id", &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(200, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        if let Err(error) = get_bid_storage_v2(&mut deps.storage).save(b"bid_id", &bid_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "bid_id".into(),
+            },
+        )
+        .expect_err("a match fee that cannot be paid from the quote should produce an error");
+        match err {
+            ContractError::InsufficientQuoteForMatchFee { match_fee } => {
+                assert_eq!(Uint128::new(50), match_fee)
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+        // the orders remain available to be matched after the fee is lowered or cleared
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .may_load(b"ask_id")
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn execute_match_with_same_owner() {
+        for allow_self_match in [false, true] {
+            let mut deps = mock_dependencies(&[]);
+            let mut contract_info = ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            );
+            contract_info.allow_self_match = allow_self_match;
+            if let Err(error) = set_contract_info(&mut deps.storage, &contract_info) {
+                panic!("unexpected error: {:?}", error)
+            }
+
+            // both orders belong to the same account
+            let ask_order = AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".into(),
+                owner: Addr::unchecked("trader"),
+                quote: coins(200, "quote_1"),
+            };
+            if let Err(error) = get_ask_storage_v2(&mut deps.storage).save(b"ask_id", &ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+            let bid_order = BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "bid_id".to_string(),
+                owner: Addr::unchecked("trader"),
+                quote: coins(200, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            };
+            if let Err(error) = get_bid_storage_v2(&mut deps.storage).save(b"bid_id", &bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+
+            let match_preview_response =
+                match_preview(deps.as_ref(), mock_env(), "ask_id".into(), "bid_id".into());
+            assert_eq!(allow_self_match, match_preview_response.matches);
+
+            let execute_response = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                ExecuteMsg::ExecuteMatch {
+                    ask_id: "ask_id".into(),
+                    bid_id: "bid_id".into(),
+                },
+            );
+            if allow_self_match {
+                execute_response
+                    .expect("a self match should succeed when self matches are allowed");
+            } else {
+                match execute_response {
+                    Err(ContractError::SelfMatch) => {}
+                    response => panic!("expected a self match error, but got: {:?}", response),
+                }
+                let partial_response = execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info("contract_admin", &[]),
+                    ExecuteMsg::ExecutePartialMatch {
+                        ask_id: "ask_id".into(),
+                        bid_id: "bid_id".into(),
+                        fill_amount: Uint128::new(50),
+                    },
+                );
+                match partial_response {
+                    Err(ContractError::SelfMatch) => {}
+                    response => panic!("expected a self match error, but got: {:?}", response),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn execute_match_with_valid_scope_data() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+
+        let scope_input = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("asker"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked("asker"), // todo: does this need to be the contract's address?
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope(&scope_input.scope_id),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store valid bid order
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::scope(&scope_input.scope_id),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(200, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        // execute on matched ask order and bid order
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: ask_order.id,
+            bid_id: bid_order.id.clone(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        // validate execute response
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(
+                    execute_response.attributes,
+                    vec![
+                        attr("action", "execute"),
+                        attr("ask_id", "ask_id"),
+                        attr("bid_id", "bid_id"),
+                        attr("asker", "asker"),
+                        attr("bidder", "bidder"),
+                        attr("quote_paid", "200quote_1"),
+                        attr("base_type", "scope"),
+                        attr("base_delivered", "scope1234"),
+                    ]
+                );
+                assert_eq!(execute_response.messages.len(), 2);
+                assert_eq!(
+                    execute_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: ask_order.owner.to_string(),
+                        amount: ask_order.quote,
+                    })
+                );
+                handle_expected_scope(&bid_order.base, |scope_id| {
+                    if let CosmosMsg::Custom(ProvenanceMsg { params, .. }) =
+                        &execute_response.messages[1].msg
+                    {
+                        assert_eq!(
+                            params.to_owned(),
+                            ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
+                                scope: Scope {
+                                    scope_id: scope_id.to_string(),
+                                    specification_id: scope_input.specification_id,
+                                    owners: vec![Party {
+                                        address: bid_order.owner.clone(),
+                                        role: PartyType::Owner
+                                    }],
+                                    data_access: scope_input.data_access,
+                                    value_owner_address: bid_order.owner.clone()
+                                },
+                                signers: vec![Addr::unchecked(MOCK_CONTRACT_ADDR)]
+                            }),
+                        );
+                    } else {
+                        panic!("Unexpected second message type for match, expected WriteScope, received {:?}", execute_response.messages[1].msg)
+                    }
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn execute_match_with_scope_data_access() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+
+        // the asker's data access grant lingers from before the scope was transferred to the contract
+        let scope_input = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![Addr::unchecked("asker"), Addr::unchecked("auditor")],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope(&scope_input.scope_id),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::scope(&scope_input.scope_id),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(200, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        if let Err(error) =
+            get_bid_storage_v2(&mut deps.storage).save(bid_order.id.as_bytes(), &bid_order)
+        {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id,
+                bid_id: bid_order.id,
+            },
+        );
+
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => match &execute_response.messages[1].msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                    ..
+                }) => {
+                    assert_eq!(
+                        vec![Addr::unchecked("auditor")],
+                        scope.data_access,
+                        "expected the asker's data access grant to be removed, leaving all others",
+                    );
+                }
+                msg => panic!("unexpected msg: {:?}", msg),
+            },
+        }
+    }
+
+    #[test]
+    fn execute_match_with_scope_requested_data_access() {
+        let mut deps = mock_dependencies(&[]);
+
+        let scope_input = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![Addr::unchecked("asker"), Addr::unchecked("auditor")],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope(&scope_input.scope_id),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // data access can only be requested for scope bases
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(200, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "coin_bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: Some(vec!["bidder".into()]),
+                value_owner_override: None,
+            },
+        ) {
+            Err(ContractError::InvalidRequestedDataAccess { .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // the auditor already has data access, so it should not be granted twice
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(200, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::scope(&scope_input.scope_id),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: Some(vec!["bidder".into(), "auditor".into()]),
+                value_owner_override: None,
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id,
+                bid_id: "bid_id".into(),
+            },
+        );
+
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => match &execute_response.messages[1].msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                    ..
+                }) => {
+                    assert_eq!(
+                        vec![Addr::unchecked("auditor"), Addr::unchecked("bidder")],
+                        scope.data_access,
+                        "expected the requested data access to be granted alongside the existing grants",
+                    );
+                }
+                msg => panic!("unexpected msg: {:?}", msg),
+            },
+        }
+    }
+
+    #[test]
+    fn execute_match_with_scope_value_owner_override() {
+        let mut deps = mock_dependencies(&[]);
+
+        let scope_input = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope(&scope_input.scope_id),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // a value owner can only be requested for scope bases
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(200, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "coin_bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: Some("custodian".into()),
+            },
+        ) {
+            Err(ContractError::InvalidValueOwnerOverride { .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(200, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::scope(&scope_input.scope_id),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: Some("custodian".into()),
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id,
+                bid_id: "bid_id".into(),
+            },
+        );
+
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => match &execute_response.messages[1].msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                    ..
+                }) => {
+                    assert_eq!(
+                        vec![Party {
+                            address: Addr::unchecked("bidder"),
+                            role: PartyType::Owner,
+                        }],
+                        scope.owners,
+                        "expected the bidder to become the scope's owner",
+                    );
+                    assert_eq!(
+                        Addr::unchecked("custodian"),
+                        scope.value_owner_address,
+                        "expected the value owner override to become the scope's value owner",
+                    );
+                }
+                msg => panic!("unexpected msg: {:?}", msg),
+            },
+        }
+    }
+
+    #[test]
+    fn execute_match_with_scope_preserves_specification_id() {
+        let mut deps = mock_dependencies(&[]);
+
+        let scope_input = Scope {
+            scope_id: "scope1qzge0zaztu65tx5x5llv5xc9ztsqxlkwel".to_string(),
+            specification_id: "scopespec1qnwg86nsatx5pl56muw0v9ytlz3qu3jx6m".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![Addr::unchecked("auditor")],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope(&scope_input.scope_id),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::scope(&scope_input.scope_id),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(200, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        if let Err(error) =
+            get_bid_storage_v2(&mut deps.storage).save(bid_order.id.as_bytes(), &bid_order)
+        {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id,
+                bid_id: bid_order.id,
+            },
+        );
+
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => match &execute_response.messages[1].msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope { scope, .. }),
+                    ..
+                }) => {
+                    assert_eq!(
+                        scope_input.specification_id, scope.specification_id,
+                        "expected the written scope to retain its specification id",
+                    );
+                    assert_eq!(scope_input.scope_id, scope.scope_id);
+                    assert_eq!(scope_input.data_access, scope.data_access);
+                    assert_eq!(Addr::unchecked("bidder"), scope.value_owner_address);
+                }
+                msg => panic!("unexpected msg: {:?}", msg),
+            },
+        }
+    }
+
+    #[test]
+    fn test_replace_scope_owner_data_access_policies() {
+        let scope = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("previous_owner"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![Addr::unchecked("previous_owner")],
+            value_owner_address: Addr::unchecked("previous_owner"),
+        };
+
+        let kept = replace_scope_owner(
+            scope.clone(),
+            Addr::unchecked("new_owner"),
+            DataAccessPolicy::Keep,
+        )
+        .unwrap();
+        assert_eq!(vec![Addr::unchecked("previous_owner")], kept.data_access);
+        assert_eq!(Addr::unchecked("new_owner"), kept.value_owner_address);
+
+        let cleared = replace_scope_owner(
+            scope.clone(),
+            Addr::unchecked("new_owner"),
+            DataAccessPolicy::Clear,
+        )
+        .unwrap();
+        assert!(cleared.data_access.is_empty());
+
+        let replaced = replace_scope_owner(
+            scope,
+            Addr::unchecked("new_owner"),
+            DataAccessPolicy::ReplaceWith(vec![Addr::unchecked("new_owner")]),
+        )
+        .unwrap();
+        assert_eq!(vec![Addr::unchecked("new_owner")], replaced.data_access);
+        assert_eq!(
+            vec![Party {
+                address: Addr::unchecked("new_owner"),
+                role: PartyType::Owner,
+            }],
+            replaced.owners
+        );
+    }
+
+    #[test]
+    fn execute_match_with_valid_marker_data() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::marker("marker1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store valid bid order
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::marker("marker1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(200, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id,
+                bid_id: bid_order.id,
+            },
+        );
+
+        // validate execute response
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(
+                    execute_response.attributes,
+                    vec![
+                        attr("action", "execute"),
+                        attr("ask_id", "ask_id"),
+                        attr("bid_id", "bid_id"),
+                        attr("asker", "asker"),
+                        attr("bidder", "bidder"),
+                        attr("quote_paid", "200quote_1"),
+                        attr("base_type", "marker"),
+                        attr("base_delivered", "marker1"),
+                    ]
+                );
+                assert_eq!(execute_response.messages.len(), 3);
+                assert_eq!(
+                    execute_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: ask_order.owner.to_string(),
+                        amount: ask_order.quote,
+                    })
+                );
+                assert_eq!(
+                    execute_response.messages[1].msg,
+                    grant_marker_access("marker1", bid_order.owner, MarkerAccess::all()).unwrap()
+                );
+                assert_eq!(
+                    execute_response.messages[2].msg,
+                    revoke_marker_access("marker1", Addr::unchecked(MOCK_CONTRACT_ADDR)).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn execute_match_with_invalid_coin_data() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(200, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(100, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store valid bid order
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        // execute by non-admin ContractError::Unauthorized
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::Unauthorized {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute on mismatched ask order and bid order returns ContractError::AskBidMismatch
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "no_ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "no_bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute with sent_funds returns ContractError::ExecuteWithFunds
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &coins(100, "funds")),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::ExecuteWithFunds {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+    }
+
+    #[test]
+    fn execute_match_with_invalid_scope_data() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+
+        let scope_input = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked("asker"),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked("asker"), // todo: does this need to be the contract's address?
+        };
+        deps.querier.with_scope(scope_input.clone());
+
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::scope(scope_input.scope_id),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(100, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store invalid bid order
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        // execute by non-admin ContractError::Unauthorized
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::Unauthorized {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute on mismatched ask order and bid order returns ContractError::AskBidMismatch
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "no_ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "no_bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+
+        // execute with sent_funds returns ContractError::ExecuteWithFunds
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ask_id".into(),
+            bid_id: "bid_id".into(),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &coins(100, "funds")),
+            execute_msg,
+        );
+
+        match execute_response {
+            Err(ContractError::ExecuteWithFunds {}) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(_) => panic!("expected error, but execute_response ok"),
+        }
+    }
+
+    #[test]
+    fn execute_match_with_expired_orders() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let env = mock_env();
+
+        // store an ask that expired before the current block time
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(100, "base_1"),
+            expiration: Some(env.block.time.minus_seconds(1)),
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(100, "quote_1"),
+        };
+
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store a bid that does not expire until after the current block time
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: Some(env.block.time.plus_seconds(1)),
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id.clone(),
+                bid_id: bid_order.id.clone(),
+            },
+        );
+
+        match execute_response {
+            Err(ContractError::OrderExpired { id }) => assert_eq!(ask_order.id, id),
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // once the bid has also expired, the ask is still the first order rejected
+        let mut later_env = env;
+        later_env.block.time = later_env.block.time.plus_seconds(2);
+        let execute_response = execute(
+            deps.as_mut(),
+            later_env,
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_order.id.clone(),
+                bid_id: bid_order.id,
+            },
+        );
+
+        match execute_response {
+            Err(ContractError::OrderExpired { id }) => assert_eq!(ask_order.id, id),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn execute_match_batch_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        store_match_batch_orders(&mut deps.storage, 3);
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatchBatch {
+                pairs: (0..3)
+                    .map(|index| MatchPair {
+                        ask_id: format!("ask_{}", index),
+                        bid_id: format!("bid_{}", index),
+                    })
+                    .collect(),
+            },
+        );
+
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(execute_response.attributes.len(), 24);
+                assert_eq!(
+                    execute_response.attributes[0],
+                    attr("action", "execute_batch")
+                );
+                assert_eq!(
+                    execute_response.attributes[1],
+                    attr("matched_asks", "ask_0,ask_1,ask_2")
+                );
+                assert_eq!(
+                    execute_response.attributes[2],
+                    attr("matched_bids", "bid_0,bid_1,bid_2")
+                );
+                // each pair is reported on the trade tape in batch order
+                for index in 0..3 {
+                    assert_eq!(
+                        execute_response.attributes[3 + index * 7..3 + (index + 1) * 7],
+                        [
+                            attr("ask_id", format!("ask_{}", index)),
+                            attr("bid_id", format!("bid_{}", index)),
+                            attr("asker", format!("asker_{}", index)),
+                            attr("bidder", format!("bidder_{}", index)),
+                            attr("quote_paid", "100quote_1"),
+                            attr("base_type", "coin"),
+                            attr("base_delivered", format!("100base_{}", index)),
+                        ]
+                    );
+                }
+                // each pair sends its quote to the asker followed by its base to the bidder
+                assert_eq!(execute_response.messages.len(), 6);
+                for index in 0..3 {
+                    assert_eq!(
+                        execute_response.messages[index * 2].msg,
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: format!("asker_{}", index),
+                            amount: coins(100, "quote_1"),
+                        })
+                    );
+                    assert_eq!(
+                        execute_response.messages[index * 2 + 1].msg,
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: format!("bidder_{}", index),
+                            amount: coins(100, format!("base_{}", index)),
+                        })
+                    );
+                }
+            }
+        }
+
+        // verify every order was removed from storage
+        for index in 0..3 {
+            assert!(get_ask_storage_read_v2(&deps.storage)
+                .load(format!("ask_{}", index).as_bytes())
+                .is_err());
+            assert!(get_bid_storage_read_v2(&deps.storage)
+                .load(format!("bid_{}", index).as_bytes())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn execute_match_batch_with_invalid_pair() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        store_match_batch_orders(&mut deps.storage, 4);
+
+        // the third pair crosses orders with different bases, and the batch must be rejected as a whole
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatchBatch {
+                pairs: vec![
+                    MatchPair {
+                        ask_id: "ask_0".into(),
+                        bid_id: "bid_0".into(),
+                    },
+                    MatchPair {
+                        ask_id: "ask_1".into(),
+                        bid_id: "bid_1".into(),
+                    },
+                    MatchPair {
+                        ask_id: "ask_2".into(),
+                        bid_id: "bid_3".into(),
+                    },
+                ],
+            },
+        );
+
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // verify no orders were removed from storage
+        for index in 0..4 {
+            assert!(get_ask_storage_read_v2(&deps.storage)
+                .load(format!("ask_{}", index).as_bytes())
+                .is_ok());
+            assert!(get_bid_storage_read_v2(&deps.storage)
+                .load(format!("bid_{}", index).as_bytes())
+                .is_ok());
+        }
+
+        // an empty batch is rejected
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatchBatch { pairs: vec![] },
+        );
+
+        match execute_response {
+            Err(ContractError::MissingField { field }) => assert_eq!("pairs", field),
+            result => panic!("unexpected result: {:?}", result),
+        }
     }
 
     #[test]
-    fn instantiate_with_valid_data() {
-        // create valid init data
+    fn execute_match_with_accepted_ask_quote() {
         let mut deps = mock_dependencies(&[]);
-        let info = mock_info("contract_admin", &[]);
-        let init_msg = InstantiateMsg {
-            bind_name: "contract_bind_name".to_string(),
-            contract_name: "contract_name".to_string(),
-            ask_fee: None,
-            bid_fee: None,
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store an ask quoted for less than the bidder will escrow
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(100, "quote_1"),
+        };
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
         };
 
-        // initialize
-        let init_response = instantiate(deps.as_mut(), mock_env(), info, init_msg.clone());
+        // an accepted quote that is not covered by the escrowed funds is rejected
+        let create_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(150, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: Some(coins(200, "quote_1")),
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        );
+        match create_bid_response {
+            Err(ContractError::InvalidAcceptedAskQuote { .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
 
-        // verify initialize response
-        match init_response {
-            Ok(init_response) => {
-                assert_eq!(init_response.messages.len(), 1);
+        // a bid without an accepted quote must escrow exactly the ask's quote
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("escrow_bidder", &coins(150, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "escrow_bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "escrow_bid_id".into(),
+            },
+        );
+        match execute_response {
+            Err(ContractError::AskBidMismatch {}) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // a bid escrowing more than its accepted quote matches the ask, and the excess is returned to the bidder
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(150, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: Some(coins(100, "quote_1")),
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let stored_bid = get_bid_storage_read_v2(&deps.storage)
+            .load(b"bid_id")
+            .expect("the bid should be stored");
+        assert_eq!(coins(150, "quote_1"), stored_bid.quote);
+        assert_eq!(Some(coins(100, "quote_1")), stored_bid.accepted_ask_quote);
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "bid_id".into(),
+            },
+        );
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(execute_response.messages.len(), 3);
                 assert_eq!(
-                    init_response.messages[0].msg,
-                    CosmosMsg::Custom(ProvenanceMsg {
-                        route: ProvenanceRoute::Name,
-                        params: ProvenanceMsgParams::Name(NameMsgParams::BindName {
-                            name: init_msg.bind_name,
-                            address: Addr::unchecked(MOCK_CONTRACT_ADDR),
-                            restrict: true
-                        }),
-                        version: "2.0.0".to_string(),
+                    execute_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(100, "quote_1"),
                     })
                 );
-                let expected_contract_info = ContractInfo {
-                    admin: Addr::unchecked("contract_admin"),
-                    bind_name: "contract_bind_name".to_string(),
-                    contract_name: "contract_name".to_string(),
-                    contract_type: CONTRACT_TYPE.into(),
-                    contract_version: CONTRACT_VERSION.into(),
-                    ask_fee: None,
-                    bid_fee: None,
-                };
-
-                assert_eq!(init_response.attributes.len(), 2);
                 assert_eq!(
-                    init_response.attributes[0],
-                    attr("contract_info", format!("{:?}", expected_contract_info))
+                    execute_response.messages[1].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(100, "base_1"),
+                    })
+                );
+                assert_eq!(
+                    execute_response.messages[2].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(50, "quote_1"),
+                    })
                 );
-                assert_eq!(init_response.attributes[1], attr("action", "init"));
             }
-            error => panic!("failed to initialize: {:?}", error),
         }
     }
 
     #[test]
-    fn instantiate_with_invalid_data() {
-        // create invalid init data
+    fn execute_match_records_trade_stats() {
         let mut deps = mock_dependencies(&[]);
-        let info = mock_info("contract_owner", &[]);
-        let init_msg = InstantiateMsg {
-            bind_name: "".to_string(),
-            contract_name: "contract_name".to_string(),
-            ask_fee: None,
-            bid_fee: None,
-        };
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
 
-        // initialize
-        let init_response = instantiate(deps.as_mut(), mock_env(), info.to_owned(), init_msg);
+        // no matches have been executed yet
+        let trade_stats: TradeStats =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetTradeStats {}).unwrap())
+                .unwrap();
+        assert_eq!(TradeStats::default(), trade_stats);
 
-        // verify initialize response
-        match init_response {
-            Ok(_) => panic!("expected error, but init_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "bind_name")
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
-        };
+        for (index, quote) in [coin(100, "quote_1"), coin(50, "quote_2")]
+            .into_iter()
+            .enumerate()
+        {
+            let ask_order = AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: format!("ask_{}", index),
+                owner: Addr::unchecked("asker"),
+                quote: vec![quote.clone()],
+            };
+            if let Err(error) =
+                get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+            {
+                panic!("unexpected error: {:?}", error)
+            };
+            let bid_order = BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: format!("bid_{}", index),
+                owner: Addr::unchecked("bidder"),
+                quote: vec![quote],
+                requested_data_access: vec![],
+                value_owner_override: None,
+            };
+            if let Err(error) =
+                get_bid_storage_v2(&mut deps.storage).save(bid_order.id.as_bytes(), &bid_order)
+            {
+                panic!("unexpected error: {:?}", error)
+            };
+            if let Err(error) = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                ExecuteMsg::ExecuteMatch {
+                    ask_id: ask_order.id,
+                    bid_id: bid_order.id,
+                },
+            ) {
+                panic!("unexpected error: {:?}", error)
+            }
+        }
 
-        let init_msg = InstantiateMsg {
-            bind_name: "bind_name".to_string(),
-            contract_name: "".to_string(),
-            ask_fee: None,
-            bid_fee: None,
-        };
+        let trade_stats: TradeStats =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetTradeStats {}).unwrap())
+                .unwrap();
+        assert_eq!(2, trade_stats.total_matches);
+        assert_eq!(
+            vec![coin(100, "quote_1"), coin(50, "quote_2")],
+            trade_stats.quote_volume,
+        );
+    }
 
-        // initialize
-        let init_response = instantiate(deps.as_mut(), mock_env(), info.to_owned(), init_msg);
+    // stores the given amount of matching coin ask and bid pairs, each with a distinct base denom
+    fn store_match_batch_orders(storage: &mut dyn Storage, count: usize) {
+        for index in 0..count {
+            let ask_order = AskOrderV2 {
+                base: BaseType::coin(100, format!("base_{}", index)),
+                expiration: None,
+                expected_previous_owner: None,
+                id: format!("ask_{}", index),
+                owner: Addr::unchecked(format!("asker_{}", index)),
+                quote: coins(100, "quote_1"),
+            };
+            if let Err(error) =
+                get_ask_storage_v2(storage).save(ask_order.id.as_bytes(), &ask_order)
+            {
+                panic!("unexpected error: {:?}", error)
+            };
+            let bid_order = BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, format!("base_{}", index)),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: format!("bid_{}", index),
+                owner: Addr::unchecked(format!("bidder_{}", index)),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            };
+            if let Err(error) =
+                get_bid_storage_v2(storage).save(bid_order.id.as_bytes(), &bid_order)
+            {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
+    }
 
-        // verify initialize response
-        match init_response {
-            Ok(_) => panic!("expected error, but init_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "contract_name")
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
-        };
+    #[test]
+    fn reap_expired_orders() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        let env = mock_env();
+        let expired = Some(env.block.time.minus_seconds(1));
 
-        let init_msg = InstantiateMsg {
-            bind_name: "bind_name".to_string(),
-            contract_name: "contract_name".to_string(),
-            ask_fee: Some(Uint128::zero()),
-            bid_fee: None,
+        let scope = Scope {
+            scope_id: "scope1234".to_string(),
+            specification_id: "scopespec1".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
         };
+        deps.querier.with_scope(scope.clone());
 
-        let init_response = instantiate(deps.as_mut(), mock_env(), info.to_owned(), init_msg);
-
-        match init_response {
-            Ok(_) => panic!("expected error, but init_response ok"),
-            Err(error) => match error {
-                ContractError::InvalidFee { fee_type } => {
-                    assert_eq!(fee_type, "ask");
-                }
-                error => panic!("unexpected error: {:?}", error),
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in [
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: expired,
+                expected_previous_owner: None,
+                id: "coin_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
             },
-        };
+            AskOrderV2 {
+                base: BaseType::scope(&scope.scope_id),
+                expiration: expired,
+                expected_previous_owner: None,
+                id: "scope_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "open_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+        ] {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
 
-        let init_msg = InstantiateMsg {
-            bind_name: "bind_name".to_string(),
-            contract_name: "contract_name".to_string(),
-            ask_fee: Some(Uint128::new(100)),
-            bid_fee: Some(Uint128::zero()),
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: expired,
+            id: "coin_bid".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
         };
 
-        let init_response = instantiate(deps.as_mut(), mock_env(), info, init_msg);
-
-        match init_response {
-            Ok(_) => panic!("expected error, but init_response ok"),
-            Err(error) => match error {
-                ContractError::InvalidFee { fee_type } => {
-                    assert_eq!(fee_type, "bid");
-                }
-                error => panic!("unexpected error: {:?}", error),
+        // orders that have not expired cannot be reaped
+        let reap_response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ReapExpired {
+                ids: vec!["open_ask".into()],
             },
-        };
-    }
+        );
 
-    #[test]
-    fn create_ask_for_coin_with_valid_data_no_fee() {
-        test_valid_coin_ask(None);
-    }
+        match reap_response {
+            Err(ContractError::OrderNotExpired { id }) => assert_eq!("open_ask", id),
+            result => panic!("unexpected result: {:?}", result),
+        }
 
-    #[test]
-    fn create_ask_for_coin_with_valid_data_and_fee() {
-        test_valid_coin_ask(Some(500));
-    }
+        // any account can reap expired orders
+        let reap_response = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ReapExpired {
+                ids: vec!["coin_ask".into(), "scope_ask".into(), "coin_bid".into()],
+            },
+        );
 
-    #[test]
-    fn test_create_ask_for_scope_with_valid_data_no_fee() {
-        test_valid_scope_ask(None);
-    }
+        match reap_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(reap_response) => {
+                assert_eq!(
+                    reap_response.attributes,
+                    vec![
+                        attr("action", "reap_expired"),
+                        attr("reaped_asks", "coin_ask,scope_ask"),
+                        attr("reaped_bids", "coin_bid"),
+                    ]
+                );
+                assert_eq!(reap_response.messages.len(), 3);
+                assert_eq!(
+                    reap_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(100, "base_1"),
+                    })
+                );
+                match &reap_response.messages[1].msg {
+                    CosmosMsg::Custom(ProvenanceMsg { params, .. }) => assert_eq!(
+                        params.to_owned(),
+                        ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
+                            scope: Scope {
+                                owners: vec![Party {
+                                    address: Addr::unchecked("asker"),
+                                    role: PartyType::Owner,
+                                }],
+                                value_owner_address: Addr::unchecked("asker"),
+                                ..scope
+                            },
+                            signers: vec![Addr::unchecked(MOCK_CONTRACT_ADDR)],
+                        })
+                    ),
+                    msg => panic!("unexpected msg: {:?}", msg),
+                }
+                assert_eq!(
+                    reap_response.messages[2].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(100, "quote_1"),
+                    })
+                );
+            }
+        }
 
-    #[test]
-    fn test_create_ask_for_scope_with_valid_data_and_fee() {
-        test_valid_scope_ask(Some(1000000));
+        // verify only the unexpired order remains in storage
+        let ask_storage = get_ask_storage_read_v2(&deps.storage);
+        assert!(ask_storage.load("coin_ask".as_bytes()).is_err());
+        assert!(ask_storage.load("scope_ask".as_bytes()).is_err());
+        assert!(ask_storage.load("open_ask".as_bytes()).is_ok());
+        assert!(get_bid_storage_read_v2(&deps.storage)
+            .load("coin_bid".as_bytes())
+            .is_err());
     }
 
     #[test]
-    fn create_ask_with_invalid_data() {
+    fn admin_cancel_orders() {
         let mut deps = mock_dependencies(&[]);
         if let Err(error) = set_contract_info(
             &mut deps.storage,
@@ -972,273 +7784,871 @@ mod tests {
         ) {
             panic!("unexpected error: {:?}", error)
         }
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(200, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        )
+        .expect("the ask should be created");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(200, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        )
+        .expect("the bid should be created");
 
-        // create ask invalid data
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "".into(),
-            quote: vec![],
-            scope_address: None,
-        };
+        // only the admin can cancel another account's order
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            ExecuteMsg::AdminCancel {
+                order_type: AskOrBid::Ask,
+                id: "ask_id".into(),
+                reason: "spam".into(),
+            },
+        ) {
+            Err(ContractError::Unauthorized {}) => {}
+            result => panic!("expected an unauthorized error, but got: {:?}", result),
+        }
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .is_ok());
 
-        // handle create ask
-        let create_ask_response = execute(
+        // the admin cancels the ask, returning the base to the asker
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &[]),
-            create_ask_msg,
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::AdminCancel {
+                order_type: AskOrBid::Ask,
+                id: "ask_id".into(),
+                reason: "spam".into(),
+            },
+        )
+        .expect("the admin should be able to cancel the ask");
+        assert_eq!(
+            response.attributes,
+            vec![
+                attr("action", "admin_cancel"),
+                attr("order_type", "ask"),
+                attr("order_id", "ask_id"),
+                attr("owner", "asker"),
+                attr("reason", "spam"),
+            ]
         );
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "asker".into(),
+                amount: coins(200, "base_1"),
+            })
+        );
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .is_err());
 
-        // verify handle create ask response returns ContractError::MissingField { id }
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but handle_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "id")
-                }
-                error => panic!("unexpected error: {:?}", error),
+        // the admin cancels the bid, returning the quote to the bidder
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::AdminCancel {
+                order_type: AskOrBid::Bid,
+                id: "bid_id".into(),
+                reason: "abandoned".into(),
+            },
+        )
+        .expect("the admin should be able to cancel the bid");
+        assert_eq!(response.attributes[4], attr("reason", "abandoned"));
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "bidder".into(),
+                amount: coins(100, "quote_1"),
+            })
+        );
+        assert!(get_bid_storage_read_v2(&deps.storage)
+            .load(b"bid_id")
+            .is_err());
+
+        // funds and empty reasons are rejected
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &coins(1, "nhash")),
+            ExecuteMsg::AdminCancel {
+                order_type: AskOrBid::Bid,
+                id: "bid_id".into(),
+                reason: "abandoned".into(),
+            },
+        ) {
+            Err(ContractError::CancelWithFunds {}) => {}
+            result => panic!("expected a cancel with funds error, but got: {:?}", result),
+        }
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::AdminCancel {
+                order_type: AskOrBid::Bid,
+                id: "bid_id".into(),
+                reason: "".into(),
             },
+        ) {
+            Err(ContractError::MissingField { field }) => assert_eq!("reason", field),
+            result => panic!("expected a missing field error, but got: {:?}", result),
         }
+    }
 
-        // create ask missing id
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "".into(),
+    #[test]
+    fn create_orders_beyond_max_orders_per_owner() {
+        let mut deps = mock_dependencies(&[]);
+        let mut contract_info = ContractInfo::new(
+            Addr::unchecked("contract_admin"),
+            "contract_bind_name".into(),
+            "contract_name".into(),
+            None,
+            None,
+        );
+        contract_info.max_orders_per_owner = Some(2);
+        if let Err(error) = set_contract_info(&mut deps.storage, &contract_info) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let create_ask = |id: &str| ExecuteMsg::CreateAsk {
+            id: id.into(),
             quote: coins(100, "quote_1"),
             scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+        let create_bid = |id: &str| ExecuteMsg::CreateBid {
+            id: id.into(),
+            base: BaseType::coin(200, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
         };
 
-        // handle create ask
-        let create_ask_response = execute(
+        // an ask and a bid from the same owner fill the limit
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &coins(100, "base_1")),
-            create_ask_msg,
-        );
+            mock_info("trader", &coins(200, "base_1")),
+            create_ask("ask_1"),
+        )
+        .expect("the first ask should be created");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(100, "quote_1")),
+            create_bid("bid_1"),
+        )
+        .expect("the first bid should be created");
 
-        // verify execute create ask response returns ContractError::MissingField { id }
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "id")
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
+        // any further order from that owner is rejected
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(200, "base_1")),
+            create_ask("ask_2"),
+        ) {
+            Err(ContractError::TooManyOpenOrders { owner, max_orders }) => {
+                assert_eq!(owner, "trader");
+                assert_eq!(max_orders, 2);
+            }
+            result => panic!(
+                "expected a too many open orders error, but got: {:?}",
+                result
+            ),
+        }
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(100, "quote_1")),
+            create_bid("bid_2"),
+        ) {
+            Err(ContractError::TooManyOpenOrders { .. }) => {}
+            result => panic!(
+                "expected a too many open orders error, but got: {:?}",
+                result
+            ),
         }
 
-        // create ask missing quote
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "id".into(),
-            quote: vec![],
-            scope_address: None,
-        };
+        // other owners are unaffected
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_trader", &coins(200, "base_1")),
+            create_ask("ask_3"),
+        )
+        .expect("another owner's ask should be created");
 
-        // execute create ask
-        let create_ask_response = execute(
+        // cancelling an order frees a slot
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &coins(100, "base_1")),
-            create_ask_msg,
-        );
+            mock_info("trader", &[]),
+            ExecuteMsg::CancelAsk { id: "ask_1".into() },
+        )
+        .expect("the ask should be cancelled");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(200, "base_1")),
+            create_ask("ask_2"),
+        )
+        .expect("the ask should be created once a slot is freed");
+    }
 
-        // verify execute create ask response returns ContractError::MissingField { quote }
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "quote")
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
+    #[test]
+    fn cancel_all_for_owner_orders() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        for owner in ["owner_1", "owner_2"] {
+            for index in 1..=2 {
+                let id = format!("{}_order_{}", owner, index);
+                get_ask_storage_v2(&mut deps.storage)
+                    .save(
+                        id.as_bytes(),
+                        &AskOrderV2 {
+                            base: BaseType::coin(100, "base_1"),
+                            expiration: None,
+                            expected_previous_owner: None,
+                            id: id.clone(),
+                            owner: Addr::unchecked(owner),
+                            quote: coins(200, "quote_1"),
+                        },
+                    )
+                    .unwrap();
+                get_bid_storage_v2(&mut deps.storage)
+                    .save(
+                        id.as_bytes(),
+                        &BidOrderV2 {
+                            accepted_ask_quote: None,
+                            base: BaseType::coin(100, "base_1"),
+                            effective_time: None,
+                            expiration: None,
+                            id: id.clone(),
+                            owner: Addr::unchecked(owner),
+                            quote: coins(200, "quote_1"),
+                            requested_data_access: vec![],
+                            value_owner_override: None,
+                        },
+                    )
+                    .unwrap();
+            }
         }
 
-        // create ask missing base
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "id".into(),
-            quote: coins(100, "quote_1"),
-            scope_address: None,
-        };
+        // funds cannot be sent when cancelling
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner_1", &coins(1, "nhash")),
+            ExecuteMsg::CancelAllForOwner { owner: None },
+        ) {
+            Err(ContractError::CancelWithFunds {}) => {}
+            response => panic!(
+                "expected a cancel with funds error, but got: {:?}",
+                response
+            ),
+        }
 
-        // execute create ask
-        let create_ask_response = execute(
+        // only the admin may cancel the orders of another account
+        match execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &[]),
-            create_ask_msg,
+            mock_info("owner_1", &[]),
+            ExecuteMsg::CancelAllForOwner {
+                owner: Some("owner_2".into()),
+            },
+        ) {
+            Err(ContractError::Unauthorized {}) => {}
+            response => panic!("expected an unauthorized error, but got: {:?}", response),
+        }
+
+        let cancel_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner_1", &[]),
+            ExecuteMsg::CancelAllForOwner { owner: None },
+        )
+        .expect("cancelling the sender's own orders should succeed");
+        assert_eq!(
+            cancel_response.attributes,
+            vec![
+                attr("action", "cancel_all_for_owner"),
+                attr("owner", "owner_1"),
+                attr("cancelled_asks", "owner_1_order_1,owner_1_order_2"),
+                attr("cancelled_bids", "owner_1_order_1,owner_1_order_2"),
+            ]
+        );
+        assert_eq!(4, cancel_response.messages.len());
+        for message in cancel_response.messages.iter() {
+            match &message.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => {
+                    assert_eq!("owner_1", to_address)
+                }
+                msg => panic!("unexpected msg: {:?}", msg),
+            }
+        }
+        let remaining_ask_owners = get_ask_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .map(|record| record.unwrap().1.owner)
+            .collect::<Vec<Addr>>();
+        assert_eq!(
+            vec![Addr::unchecked("owner_2"), Addr::unchecked("owner_2")],
+            remaining_ask_owners
+        );
+        let remaining_bid_owners = get_bid_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .map(|record| record.unwrap().1.owner)
+            .collect::<Vec<Addr>>();
+        assert_eq!(
+            vec![Addr::unchecked("owner_2"), Addr::unchecked("owner_2")],
+            remaining_bid_owners
         );
 
-        // verify execute create ask response returns ContractError::AskMissingBase
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::MissingAskBase {} => {}
-                error => panic!("unexpected error: {:?}", error),
+        // the admin can clean up the orders of a specific account
+        let cancel_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::CancelAllForOwner {
+                owner: Some("owner_2".into()),
             },
+        )
+        .expect("the admin should be able to cancel another account's orders");
+        assert_eq!(4, cancel_response.messages.len());
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .next()
+            .is_none());
+        assert!(get_bid_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn drain_all_orders() {
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        let scope_address = "scope1qraczfp249d3rmysdurne8cxrwmqamu8tk".to_string();
+        let scope = Scope {
+            scope_id: scope_address.clone(),
+            specification_id: "scopespec1qs0lctxj49wprm9xwxt5wk0paswqzkdaax".to_string(),
+            owners: vec![Party {
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                role: PartyType::Owner,
+            }],
+            data_access: vec![],
+            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
         };
+        deps.querier.with_scope(scope.clone());
+        get_ask_storage_v2(&mut deps.storage)
+            .save(
+                b"coin_ask",
+                &AskOrderV2 {
+                    base: BaseType::coin(100, "base_1"),
+                    expiration: None,
+                    expected_previous_owner: None,
+                    id: "coin_ask".into(),
+                    owner: Addr::unchecked("owner_1"),
+                    quote: coins(200, "quote_1"),
+                },
+            )
+            .unwrap();
+        get_ask_storage_v2(&mut deps.storage)
+            .save(
+                b"scope_ask",
+                &AskOrderV2 {
+                    base: BaseType::scope(&scope_address),
+                    expiration: None,
+                    expected_previous_owner: None,
+                    id: "scope_ask".into(),
+                    owner: Addr::unchecked("owner_2"),
+                    quote: coins(300, "quote_1"),
+                },
+            )
+            .unwrap();
+        for (id, owner, amount) in [("bid_1", "owner_2", 200), ("bid_2", "owner_3", 400)] {
+            get_bid_storage_v2(&mut deps.storage)
+                .save(
+                    id.as_bytes(),
+                    &BidOrderV2 {
+                        accepted_ask_quote: None,
+                        base: BaseType::coin(100, "base_1"),
+                        effective_time: None,
+                        expiration: None,
+                        id: id.into(),
+                        owner: Addr::unchecked(owner),
+                        quote: coins(amount, "quote_1"),
+                        requested_data_access: vec![],
+                        value_owner_override: None,
+                    },
+                )
+                .unwrap();
+        }
+
+        // only the admin may drain the contract
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner_1", &[]),
+            ExecuteMsg::DrainAll {},
+        ) {
+            Err(ContractError::Unauthorized {}) => {}
+            response => panic!("expected an unauthorized error, but got: {:?}", response),
+        }
 
-        // create ask with scope and funds provided
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "id".into(),
-            quote: coins(100, "quote_1"),
-            scope_address: Some("scope-address".to_string()),
-        };
+        // funds cannot be sent when draining
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &coins(1, "nhash")),
+            ExecuteMsg::DrainAll {},
+        ) {
+            Err(ContractError::DrainAllWithFunds {}) => {}
+            response => panic!(
+                "expected a drain all with funds error, but got: {:?}",
+                response
+            ),
+        }
 
-        let create_ask_response = execute(
+        let drain_response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &coins(150, "fakecoin")),
-            create_ask_msg,
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::DrainAll {},
+        )
+        .expect("the admin should be able to drain the contract");
+        assert_eq!(
+            vec![
+                attr("action", "drain_all"),
+                attr("cancelled_asks", "coin_ask,scope_ask"),
+                attr("cancelled_bids", "bid_1,bid_2"),
+                attr("paused", "true"),
+            ],
+            drain_response.attributes,
+        );
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "owner_1".into(),
+                    amount: coins(100, "base_1"),
+                }),
+                CosmosMsg::Custom(ProvenanceMsg {
+                    route: ProvenanceRoute::Metadata,
+                    params: ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
+                        scope: Scope {
+                            owners: vec![Party {
+                                address: Addr::unchecked("owner_2"),
+                                role: PartyType::Owner,
+                            }],
+                            value_owner_address: Addr::unchecked("owner_2"),
+                            ..scope
+                        },
+                        signers: vec![Addr::unchecked(MOCK_CONTRACT_ADDR)],
+                    }),
+                    version: "2.0.0".to_string(),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "owner_2".into(),
+                    amount: coins(200, "quote_1"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "owner_3".into(),
+                    amount: coins(400, "quote_1"),
+                }),
+            ],
+            drain_response
+                .messages
+                .into_iter()
+                .map(|message| message.msg)
+                .collect::<Vec<CosmosMsg<ProvenanceMsg>>>(),
+        );
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .next()
+            .is_none());
+        assert!(get_bid_storage_read_v2(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .next()
+            .is_none());
+        assert!(
+            get_contract_info(&deps.storage).unwrap().paused,
+            "the contract should be paused after being drained",
         );
+    }
 
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::ScopeAskBaseWithFunds => {}
-                error => panic!("unexpected error: {:?}", error),
-            },
+    #[test]
+    fn orders_stored_without_expiration_never_expire() {
+        // orders stored prior to the addition of expirations do not contain the field
+        let ask_order: AskOrderV2 = cosmwasm_std::from_slice(
+            br#"{"base":{"coin":{"coins":[{"denom":"base_1","amount":"100"}]}},"id":"ask_id","owner":"asker","quote":[{"denom":"quote_1","amount":"100"}]}"#,
+        )
+        .unwrap();
+        let bid_order: BidOrderV2 = cosmwasm_std::from_slice(
+            br#"{"base":{"scope":{"scope_address":"scope1234"}},"effective_time":null,"id":"bid_id","owner":"bidder","quote":[{"denom":"quote_1","amount":"100"}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(None, ask_order.expiration);
+        assert_eq!(None, bid_order.expiration);
+        assert!(!ask_order.is_expired(&Timestamp::from_nanos(u64::MAX)));
+        assert!(!bid_order.is_expired(&Timestamp::from_nanos(u64::MAX)));
+    }
+
+    #[test]
+    fn execute_partial_match_with_valid_coin_data() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        if let Err(error) = set_contract_info(
+            &mut deps.storage,
+            &ContractInfo::new(
+                Addr::unchecked("contract_admin"),
+                "contract_bind_name".into(),
+                "contract_name".into(),
+                None,
+                None,
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // store an ask selling 100 base_1 at 2 quote_1 each
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
         };
 
-        // create ask with scope provided with incorrect value owner address
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "id".into(),
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+
+        // store a bid buying 50 base_1 at the same price
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(50, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
             quote: coins(100, "quote_1"),
-            scope_address: Some("scope_address".to_string()),
+            requested_data_access: vec![],
+            value_owner_override: None,
         };
 
-        deps.querier.with_scope(Scope {
-            scope_id: "scope_address".to_string(),
-            specification_id: "spec_address".to_string(),
-            owners: vec![Party {
-                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
-                role: PartyType::Owner,
-            }],
-            data_access: vec![],
-            value_owner_address: Addr::unchecked("not_contract_address"),
-        });
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
 
-        let create_ask_response = execute(
+        // fill only 20 of the base, leaving both orders on the book
+        let execute_response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &[]),
-            create_ask_msg.clone(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecutePartialMatch {
+                ask_id: ask_order.id.clone(),
+                bid_id: bid_order.id.clone(),
+                fill_amount: Uint128::new(20),
+            },
         );
 
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::InvalidScopeOwner {
-                    scope_address,
-                    explanation,
-                } => {
-                    assert_eq!(
-                        "scope_address", scope_address,
-                        "the proper scope address should be found",
-                    );
-                    assert_eq!(
-                        "the scope's value owner was expected to be [cosmos2contract], not [not_contract_address]", explanation,
-                        "the proper explanation must be used in the InvalidScopeOwner error",
-                    );
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
-        };
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(execute_response.attributes.len(), 10);
+                assert_eq!(
+                    execute_response.attributes[0],
+                    attr("action", "execute_partial")
+                );
+                assert_eq!(
+                    execute_response.attributes[1],
+                    attr("ask_remaining_base", "80base_1")
+                );
+                assert_eq!(
+                    execute_response.attributes[2],
+                    attr("bid_remaining_base", "30base_1")
+                );
+                // the trade tape reports only the filled portion of the base and the quote paid for it
+                assert_eq!(
+                    execute_response.attributes[3..],
+                    [
+                        attr("ask_id", "ask_id"),
+                        attr("bid_id", "bid_id"),
+                        attr("asker", "asker"),
+                        attr("bidder", "bidder"),
+                        attr("quote_paid", "40quote_1"),
+                        attr("base_type", "coin"),
+                        attr("base_delivered", "20base_1"),
+                    ]
+                );
+                assert_eq!(execute_response.messages.len(), 2);
+                assert_eq!(
+                    execute_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: ask_order.owner.to_string(),
+                        amount: coins(40, "quote_1"),
+                    })
+                );
+                assert_eq!(
+                    execute_response.messages[1].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: bid_order.owner.to_string(),
+                        amount: coins(20, "base_1"),
+                    })
+                );
+            }
+        }
 
-        // create ask with scope provided with multiple owners specified - re-using previous ask msg
-        deps.querier.with_scope(Scope {
-            scope_id: "scope_address".to_string(),
-            specification_id: "spec_address".to_string(),
-            owners: vec![
-                Party {
-                    address: Addr::unchecked("asker"),
-                    role: PartyType::Owner,
-                },
-                Party {
-                    address: Addr::unchecked("other-guy"),
-                    role: PartyType::Owner,
-                },
-            ],
-            data_access: vec![],
-            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
-        });
+        // verify both orders were reduced rather than removed
+        assert_eq!(
+            get_ask_storage_read_v2(&deps.storage)
+                .load(ask_order.id.as_bytes())
+                .unwrap(),
+            AskOrderV2 {
+                base: BaseType::coin(80, "base_1"),
+                quote: coins(160, "quote_1"),
+                ..ask_order.clone()
+            }
+        );
+        assert_eq!(
+            get_bid_storage_read_v2(&deps.storage)
+                .load(bid_order.id.as_bytes())
+                .unwrap(),
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(30, "base_1"),
+                quote: coins(60, "quote_1"),
+                ..bid_order.clone()
+            }
+        );
 
-        let create_ask_response = execute(
+        // fill the remainder of the bid, which should remove it from storage
+        let execute_response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("asker", &[]),
-            create_ask_msg.clone(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecutePartialMatch {
+                ask_id: ask_order.id.clone(),
+                bid_id: bid_order.id.clone(),
+                fill_amount: Uint128::new(30),
+            },
         );
 
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::InvalidScopeOwner {
-                    scope_address,
-                    explanation,
-                } => {
-                    assert_eq!(
-                        "scope_address", scope_address,
-                        "the proper scope address should be found",
-                    );
-                    assert_eq!(
-                        "the scope should only include a single owner, but found: 2", explanation,
-                        "the proper explanation must be used in the InvalidScopeOwner error",
-                    );
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
-        };
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(
+                    execute_response.attributes[1],
+                    attr("ask_remaining_base", "50base_1")
+                );
+                assert_eq!(
+                    execute_response.attributes[2],
+                    attr("bid_remaining_base", "0base_1")
+                );
+            }
+        }
 
-        // create ask with scope provided with incorrect contract owner specified - re-using previous ask msg
-        deps.querier.with_scope(Scope {
-            scope_id: "scope_address".to_string(),
-            specification_id: "spec_address".to_string(),
-            owners: vec![Party {
-                address: Addr::unchecked("not-contract-address"),
-                role: PartyType::Owner,
-            }],
-            data_access: vec![],
-            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
-        });
+        assert!(get_bid_storage_read_v2(&deps.storage)
+            .load(bid_order.id.as_bytes())
+            .is_err());
+        assert_eq!(
+            get_ask_storage_read_v2(&deps.storage)
+                .load(ask_order.id.as_bytes())
+                .unwrap(),
+            AskOrderV2 {
+                base: BaseType::coin(50, "base_1"),
+                quote: coins(100, "quote_1"),
+                ..ask_order
+            }
+        );
+    }
 
-        let create_ask_response = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("asker", &[]),
-            create_ask_msg,
+    #[test]
+    fn execute_partial_match_with_match_fee() {
+        // setup
+        let mut deps = mock_dependencies(&[]);
+        let mut contract_info = ContractInfo::new(
+            Addr::unchecked("contract_admin"),
+            "contract_bind_name".into(),
+            "contract_name".into(),
+            None,
+            None,
         );
+        contract_info.match_fee = Some(Uint128::new(10));
+        if let Err(error) = set_contract_info(&mut deps.storage, &contract_info) {
+            panic!("unexpected error: {:?}", error)
+        }
 
-        match create_ask_response {
-            Ok(_) => panic!("expected error, but execute_create_ask_response ok"),
-            Err(error) => match error {
-                ContractError::InvalidScopeOwner {
-                    scope_address,
-                    explanation,
-                } => {
-                    assert_eq!(
-                        "scope_address", scope_address,
-                        "the proper scope address should be found",
-                    );
-                    assert_eq!(
-                        "the scope owner was expected to be [cosmos2contract], not [not-contract-address]", explanation,
-                        "the proper explanation must be used in the InvalidScopeOwner error",
-                    );
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
+        // store an ask selling 100 base_1 at 2 nhash each
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "nhash"),
+        };
+        if let Err(error) = get_ask_storage_v2(&mut deps.storage).save(b"ask_id", &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
+        // store a bid buying 50 base_1 at the same price
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(50, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".to_string(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "nhash"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        if let Err(error) = get_bid_storage_v2(&mut deps.storage).save(b"bid_id", &bid_order) {
+            panic!("unexpected error: {:?}", error)
         };
-    }
 
-    #[test]
-    fn test_create_coin_bid_with_valid_data_no_fee() {
-        test_valid_coin_bid(None);
-    }
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecutePartialMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "bid_id".into(),
+                fill_amount: Uint128::new(20),
+            },
+        )
+        .expect("executing a partial match with a match fee should succeed");
+
+        assert_eq!(execute_response.messages.len(), 4);
+        // the asker receives the portion of the quote paid for the fill, minus the match fee
+        assert_eq!(
+            execute_response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "asker".to_string(),
+                amount: coins(30, "nhash"),
+            })
+        );
+        // the deducted quote covers the fee charged to the admin that signs the match
+        assert_eq!(
+            execute_response.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "contract_admin".to_string(),
+                amount: coins(10, "nhash"),
+            })
+        );
+        match &execute_response.messages[2].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::MsgFees(MsgFeesMsgParams::AssessCustomFee {
+                        amount,
+                        name,
+                        from,
+                        recipient,
+                    }),
+                ..
+            }) => {
+                assert_eq!(&coin(10, "nhash"), amount);
+                assert_eq!("match fee", name.to_owned().unwrap());
+                assert_eq!(MOCK_CONTRACT_ADDR, from.as_str());
+                assert_eq!("contract_admin", recipient.to_owned().unwrap().as_str());
+            }
+            msg => panic!("unexpected msg: {:?}", msg),
+        }
+        assert_eq!(
+            execute_response.messages[3].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "bidder".to_string(),
+                amount: coins(20, "base_1"),
+            })
+        );
+        // the bidder paid the full portion of the quote for the fill, including the fee
+        assert_eq!(
+            get_bid_storage_read_v2(&deps.storage)
+                .load(b"bid_id")
+                .unwrap()
+                .quote,
+            coins(60, "nhash")
+        );
 
-    #[test]
-    fn test_create_coin_bid_with_valid_data_and_fee() {
-        test_valid_coin_bid(Some(12345));
+        // a fill whose portion of the quote cannot cover the match fee is rejected
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecutePartialMatch {
+                ask_id: "ask_id".into(),
+                bid_id: "bid_id".into(),
+                fill_amount: Uint128::new(4),
+            },
+        )
+        .expect_err(
+            "a match fee that cannot be paid from the filled quote should produce an error",
+        );
+        match err {
+            ContractError::InsufficientQuoteForMatchFee { match_fee } => {
+                assert_eq!(Uint128::new(10), match_fee)
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
     }
 
     #[test]
-    fn create_bid_with_invalid_data() {
+    fn execute_partial_match_with_expired_orders() {
+        // setup
         let mut deps = mock_dependencies(&[]);
         if let Err(error) = set_contract_info(
             &mut deps.storage,
@@ -1253,95 +8663,93 @@ mod tests {
             panic!("unexpected error: {:?}", error)
         }
 
-        // create bid missing id
-        let create_bid_msg = ExecuteMsg::CreateBid {
-            id: "".into(),
+        let env = mock_env();
+
+        // store an ask that does not expire until after the current block time
+        let ask_order = AskOrderV2 {
             base: BaseType::coin(100, "base_1"),
-            effective_time: Some(Timestamp::default()),
+            expiration: Some(env.block.time.plus_seconds(1)),
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote: coins(200, "quote_1"),
         };
 
-        // execute create bid
-        let create_bid_response = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("bidder", &coins(100, "quote_1")),
-            create_bid_msg,
-        );
-
-        // verify execute create bid response returns ContractError::MissingField { id }
-        match create_bid_response {
-            Ok(_) => panic!("expected error, but create_bid_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "id")
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
-        }
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+            panic!("unexpected error: {:?}", error)
+        };
 
-        // create bid missing base
-        let create_bid_msg = ExecuteMsg::CreateBid {
-            id: "id".into(),
-            base: BaseType::coins(vec![]),
+        // store a bid that expired before the current block time
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(50, "base_1"),
             effective_time: Some(Timestamp::default()),
+            expiration: Some(env.block.time.minus_seconds(1)),
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
         };
 
-        // execute create bid
-        let create_bid_response = execute(
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
+        };
+
+        let partial_match_msg = ExecuteMsg::ExecutePartialMatch {
+            ask_id: ask_order.id.clone(),
+            bid_id: bid_order.id.clone(),
+            fill_amount: Uint128::new(20),
+        };
+
+        // the expired bid cannot be partially filled
+        let execute_response = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("bidder", &coins(100, "quote_1")),
-            create_bid_msg,
+            env.clone(),
+            mock_info("contract_admin", &[]),
+            partial_match_msg.clone(),
         );
 
-        // verify execute create bid response returns ContractError::MissingField { base }
-        match create_bid_response {
-            Ok(_) => panic!("expected error, but create_bid_response ok"),
-            Err(error) => match error {
-                ContractError::MissingField { field } => {
-                    assert_eq!(field, "base")
-                }
-                error => panic!("unexpected error: {:?}", error),
-            },
+        match execute_response {
+            Err(ContractError::OrderExpired { id }) => assert_eq!(bid_order.id, id),
+            result => panic!("unexpected result: {:?}", result),
         }
 
-        // create bid missing quote
-        let create_bid_msg = ExecuteMsg::CreateBid {
-            id: "id".into(),
-            base: BaseType::coin(100, "base_1"),
-            effective_time: Some(Timestamp::default()),
-        };
-
-        // execute create bid
-        let create_bid_response = execute(
+        // once the ask has also expired, the ask is the first order rejected
+        let mut later_env = env;
+        later_env.block.time = later_env.block.time.plus_seconds(2);
+        let execute_response = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("bidder", &[]),
-            create_bid_msg,
+            later_env,
+            mock_info("contract_admin", &[]),
+            partial_match_msg,
         );
 
-        // verify execute create bid response returns ContractError::BidMissingQuote
-        match create_bid_response {
-            Ok(_) => panic!("expected error, but create_bid_response ok"),
-            Err(error) => match error {
-                ContractError::MissingBidQuote {} => {}
-                error => panic!("unexpected error: {:?}", error),
-            },
+        match execute_response {
+            Err(ContractError::OrderExpired { id }) => assert_eq!(ask_order.id, id),
+            result => panic!("unexpected result: {:?}", result),
         }
-    }
-
-    #[test]
-    fn test_create_scope_bid_with_valid_data_no_fee() {
-        test_valid_scope_bid(None);
-    }
 
-    #[test]
-    fn test_create_scope_bid_with_valid_data_and_fee() {
-        test_valid_scope_bid(Some(3434234));
+        // neither order was modified by the rejected fills
+        assert_eq!(
+            get_ask_storage_read_v2(&deps.storage)
+                .load(ask_order.id.as_bytes())
+                .unwrap(),
+            ask_order
+        );
+        assert_eq!(
+            get_bid_storage_read_v2(&deps.storage)
+                .load(bid_order.id.as_bytes())
+                .unwrap(),
+            bid_order
+        );
     }
 
     #[test]
-    fn cancel_coin_with_valid_data() {
+    fn execute_partial_match_with_invalid_data() {
+        // setup
         let mut deps = mock_dependencies(&[]);
         if let Err(error) = set_contract_info(
             &mut deps.storage,
@@ -1356,120 +8764,153 @@ mod tests {
             panic!("unexpected error: {:?}", error)
         }
 
-        // create ask data
-        let asker_info = mock_info("asker", &coins(200, "base_1"));
-
-        let create_ask_msg = ExecuteMsg::CreateAsk {
-            id: "ask_id".into(),
-            quote: coins(100, "quote_1"),
-            scope_address: None,
-        };
-
-        // execute create ask
-        if let Err(error) = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg) {
-            panic!("unexpected error: {:?}", error)
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in [
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "coin_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(200, "quote_1"),
+            },
+            AskOrderV2 {
+                base: BaseType::scope("scope1234"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "scope_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(200, "quote_1"),
+            },
+        ] {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
         }
 
-        // verify ask order stored
-        let ask_storage = get_ask_storage_read_v2(&deps.storage);
-        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_ok());
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        for bid_order in [
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(50, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "coin_bid".into(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            },
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(50, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "cheap_bid".into(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(50, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            },
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::scope("scope1234"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: "scope_bid".into(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(200, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            },
+        ] {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+                panic!("unexpected error: {:?}", error);
+            };
+        }
 
-        // cancel ask order
-        let asker_info = mock_info("asker", &[]);
+        let partial_match =
+            |ask_id: &str, bid_id: &str, fill_amount: u128| ExecuteMsg::ExecutePartialMatch {
+                ask_id: ask_id.into(),
+                bid_id: bid_id.into(),
+                fill_amount: Uint128::new(fill_amount),
+            };
 
-        let cancel_ask_msg = ExecuteMsg::CancelAsk {
-            id: "ask_id".to_string(),
-        };
-        let cancel_ask_response = execute(
+        // only the admin may execute partial matches
+        match execute(
             deps.as_mut(),
             mock_env(),
-            asker_info.clone(),
-            cancel_ask_msg,
-        );
-
-        match cancel_ask_response {
-            Ok(cancel_ask_response) => {
-                assert_eq!(cancel_ask_response.attributes.len(), 1);
-                assert_eq!(
-                    cancel_ask_response.attributes[0],
-                    attr("action", "cancel_ask")
-                );
-                assert_eq!(cancel_ask_response.messages.len(), 1);
-                assert_eq!(
-                    cancel_ask_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: asker_info.sender.to_string(),
-                        amount: coins(200, "base_1"),
-                    })
-                );
-            }
-            Err(error) => panic!("unexpected error: {:?}", error),
+            mock_info("user", &[]),
+            partial_match("coin_ask", "coin_bid", 10),
+        ) {
+            Err(ContractError::Unauthorized {}) => {}
+            result => panic!("unexpected result: {:?}", result),
         }
 
-        // verify ask order removed from storage
-        let ask_storage = get_ask_storage_read_v2(&deps.storage);
-        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_err());
-
-        // create bid data
-        let bidder_info = mock_info("bidder", &coins(100, "quote_1"));
-        let create_bid_msg = ExecuteMsg::CreateBid {
-            id: "bid_id".into(),
-            base: BaseType::coins(vec![Coin {
-                denom: "base_1".into(),
-                amount: Uint128::new(200),
-            }]),
-            effective_time: Some(Timestamp::default()),
-        };
-
-        // execute create bid
-        if let Err(error) = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg) {
-            panic!("unexpected error: {:?}", error)
+        // scopes cannot be partially filled
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            partial_match("scope_ask", "scope_bid", 1),
+        ) {
+            Err(ContractError::PartialMatchNonCoinBase) => {}
+            result => panic!("unexpected result: {:?}", result),
         }
 
-        // verify bid order stored
-        let bid_storage = get_bid_storage_read_v2(&deps.storage);
-        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_ok(),);
-
-        // cancel bid order
-        let bidder_info = mock_info("bidder", &[]);
-
-        let cancel_bid_msg = ExecuteMsg::CancelBid {
-            id: "bid_id".to_string(),
-        };
+        // the bid is offering a lower price per unit of base than the ask
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            partial_match("coin_ask", "cheap_bid", 10),
+        ) {
+            Err(ContractError::AskBidMismatch {}) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
 
-        let cancel_bid_response = execute(
+        // the fill amount cannot exceed the smaller of the two bases
+        match execute(
             deps.as_mut(),
             mock_env(),
-            bidder_info.clone(),
-            cancel_bid_msg,
-        );
+            mock_info("contract_admin", &[]),
+            partial_match("coin_ask", "coin_bid", 51),
+        ) {
+            Err(ContractError::InvalidFillAmount { .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
 
-        match cancel_bid_response {
-            Ok(cancel_bid_response) => {
-                assert_eq!(cancel_bid_response.attributes.len(), 1);
-                assert_eq!(
-                    cancel_bid_response.attributes[0],
-                    attr("action", "cancel_bid")
-                );
-                assert_eq!(cancel_bid_response.messages.len(), 1);
-                assert_eq!(
-                    cancel_bid_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: bidder_info.sender.to_string(),
-                        amount: coins(100, "quote_1"),
-                    })
-                );
-            }
-            Err(error) => panic!("unexpected error: {:?}", error),
+        // a zero fill amount is meaningless
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            partial_match("coin_ask", "coin_bid", 0),
+        ) {
+            Err(ContractError::InvalidFillAmount { .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
         }
 
-        // verify bid order removed from storage
-        let bid_storage = get_bid_storage_read_v2(&deps.storage);
-        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_err());
+        // verify nothing was modified in storage by the failed attempts
+        assert_eq!(
+            get_ask_storage_read_v2(&deps.storage)
+                .load("coin_ask".as_bytes())
+                .unwrap()
+                .base,
+            BaseType::coin(100, "base_1")
+        );
+        assert_eq!(
+            get_bid_storage_read_v2(&deps.storage)
+                .load("coin_bid".as_bytes())
+                .unwrap()
+                .base,
+            BaseType::coin(50, "base_1")
+        );
     }
 
     #[test]
-    fn cancel_scope_with_valid_data() {
+    pub fn query_with_valid_data() {
+        // setup
         let mut deps = mock_dependencies(&[]);
         if let Err(error) = set_contract_info(
             &mut deps.storage,
@@ -1484,271 +8925,615 @@ mod tests {
             panic!("unexpected error: {:?}", error)
         }
 
-        // create ask data - omit funds because a scope is being provided
-        let asker_info = mock_info("asker", &[]);
-
-        let create_ask_msg = ExecuteMsg::CreateAsk {
+        // store valid ask order
+        let ask_order = AskOrderV2 {
+            base: BaseType::coin(200, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
             id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
             quote: coins(100, "quote_1"),
-            scope_address: Some("scope_address".to_string()),
         };
 
-        deps.querier.with_scope(Scope {
-            scope_id: "scope_address".to_string(),
-            specification_id: "spec_address".to_string(),
-            owners: vec![Party {
-                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
-                role: PartyType::Owner,
-            }],
-            data_access: vec![],
-            value_owner_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
-        });
-
-        // execute create ask
-        if let Err(error) = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg) {
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
             panic!("unexpected error: {:?}", error)
-        }
-
-        // verify ask order stored
-        let ask_storage = get_ask_storage_read_v2(&deps.storage);
-        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_ok());
+        };
 
-        // cancel ask order
-        let asker_info = mock_info("asker", &[]);
+        // store valid bid order
+        let bid_order = BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time: Some(Timestamp::default()),
+            expiration: None,
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
 
-        let cancel_ask_msg = ExecuteMsg::CancelAsk {
-            id: "ask_id".to_string(),
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+            panic!("unexpected error: {:?}", error);
         };
-        let cancel_ask_response = execute(
-            deps.as_mut(),
-            mock_env(),
-            asker_info.clone(),
-            cancel_ask_msg,
-        );
 
-        match cancel_ask_response {
-            Ok(cancel_ask_response) => {
-                assert_eq!(cancel_ask_response.attributes.len(), 1);
+        // query for contract_info
+        let query_contract_info_response =
+            query(deps.as_ref(), mock_env(), QueryMsg::GetContractInfo {});
+
+        match query_contract_info_response {
+            Ok(contract_info) => {
                 assert_eq!(
-                    cancel_ask_response.attributes[0],
-                    attr("action", "cancel_ask")
-                );
-                assert_eq!(cancel_ask_response.messages.len(), 1);
-                match &cancel_ask_response.messages.first().unwrap().msg {
-                    CosmosMsg::Custom(ProvenanceMsg {
-                        params:
-                            ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
-                                scope,
-                                signers,
-                            }),
-                        ..
-                    }) => {
-                        assert_eq!(
-                            1,
-                            scope.owners.len(),
-                            "expected the scope to only include one owner after the owner is swapped back to the original value",
-                        );
-                        let scope_owner = scope.owners.first().unwrap();
-                        assert_eq!(
-                            "asker",
-                            scope_owner.address.as_str(),
-                            "expected the asker address to be set as the scope owner",
-                        );
-                        assert_eq!(
-                            PartyType::Owner,
-                            scope_owner.role,
-                            "expected the asker's role to be that of owner",
-                        );
-                        assert_eq!(
-                            "asker",
-                            scope.value_owner_address.as_str(),
-                            "expected the asker to be set as the value owner after a cancellation",
-                        );
-                        assert_eq!(
-                            1,
-                            signers.len(),
-                            "expected only a single signer to be used on the write scope request",
-                        );
-                        assert_eq!(
-                            MOCK_CONTRACT_ADDR,
-                            signers.first().unwrap().as_str(),
-                            "expected the signer for the write scope request to be the contract",
-                        );
-                    }
-                    msg => panic!("unexpected message emitted by cancel ask: {:?}", msg),
-                };
+                    contract_info,
+                    to_binary(&get_contract_info(&deps.storage).unwrap()).unwrap()
+                )
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        // verify ask order removed from storage
-        let ask_storage = get_ask_storage_read_v2(&deps.storage);
-        assert!(ask_storage.load("ask_id".to_string().as_bytes()).is_err());
+        // query for ask order
+        let query_ask_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAsk {
+                id: ask_order.id.clone(),
+            },
+        );
 
-        // create bid data
-        let bidder_info = mock_info("bidder", &coins(100, "quote_1"));
-        let create_bid_msg = ExecuteMsg::CreateBid {
-            id: "bid_id".into(),
-            base: BaseType::scope("scope_address"),
-            effective_time: Some(Timestamp::default()),
-        };
+        assert_eq!(query_ask_response, to_binary(&ask_order));
 
-        // execute create bid
-        if let Err(error) = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg) {
-            panic!("unexpected error: {:?}", error)
-        }
+        // query for bid order
+        let query_bid_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetBid {
+                id: bid_order.id.clone(),
+            },
+        );
 
-        // verify bid order stored
-        let bid_storage = get_bid_storage_read_v2(&deps.storage);
-        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_ok(),);
+        assert_eq!(query_bid_response, to_binary(&bid_order));
+    }
 
-        // cancel bid order
-        let bidder_info = mock_info("bidder", &[]);
+    #[test]
+    fn query_version_returns_contract_type_and_version() {
+        let deps = mock_dependencies(&[]);
+
+        let version_info: VersionInfo =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetVersion {}).unwrap())
+                .unwrap();
+        assert_eq!(CONTRACT_TYPE, version_info.contract_type);
+        assert_eq!(CONTRACT_VERSION, version_info.contract_version);
+    }
 
-        let cancel_bid_msg = ExecuteMsg::CancelBid {
-            id: "bid_id".to_string(),
-        };
+    #[test]
+    fn query_asks_with_empty_storage() {
+        let deps = mock_dependencies(&[]);
 
-        let cancel_bid_response = execute(
-            deps.as_mut(),
+        let query_asks_response = query(
+            deps.as_ref(),
             mock_env(),
-            bidder_info.clone(),
-            cancel_bid_msg,
+            QueryMsg::GetAsks {
+                start_after: None,
+                limit: None,
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
         );
 
-        match cancel_bid_response {
-            Ok(cancel_bid_response) => {
-                assert_eq!(cancel_bid_response.attributes.len(), 1);
-                assert_eq!(
-                    cancel_bid_response.attributes[0],
-                    attr("action", "cancel_bid")
-                );
-                assert_eq!(cancel_bid_response.messages.len(), 1);
-                assert_eq!(
-                    cancel_bid_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: bidder_info.sender.to_string(),
-                        amount: coins(100, "quote_1"),
-                    })
-                );
-            }
-            Err(error) => panic!("unexpected error: {:?}", error),
+        assert_eq!(
+            query_asks_response,
+            to_binary(&GetAsksResponse {
+                asks: vec![],
+                last_key: None,
+            })
+        );
+    }
+
+    #[test]
+    fn query_asks_with_pagination() {
+        let mut deps = mock_dependencies(&[]);
+
+        // store asks out of order to verify that iteration is ordered by id
+        let ask_orders = ["ask_c", "ask_a", "ask_b"]
+            .iter()
+            .map(|id| AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: id.to_string(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            })
+            .collect::<Vec<AskOrderV2>>();
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in ask_orders.iter() {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
         }
 
-        // verify bid order removed from storage
-        let bid_storage = get_bid_storage_read_v2(&deps.storage);
-        assert!(bid_storage.load("bid_id".to_string().as_bytes()).is_err());
+        // a single page containing every ask
+        let query_asks_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAsks {
+                start_after: None,
+                limit: None,
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
+
+        assert_eq!(
+            query_asks_response,
+            to_binary(&GetAsksResponse {
+                asks: vec![
+                    ask_orders[1].clone(),
+                    ask_orders[2].clone(),
+                    ask_orders[0].clone()
+                ],
+                last_key: Some("ask_c".to_string()),
+            })
+        );
+
+        // the first of two pages
+        let query_asks_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAsks {
+                start_after: None,
+                limit: Some(2),
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
+
+        assert_eq!(
+            query_asks_response,
+            to_binary(&GetAsksResponse {
+                asks: vec![ask_orders[1].clone(), ask_orders[2].clone()],
+                last_key: Some("ask_b".to_string()),
+            })
+        );
+
+        // continuing from the last key of the first page
+        let query_asks_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAsks {
+                start_after: Some("ask_b".to_string()),
+                limit: Some(2),
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
+
+        assert_eq!(
+            query_asks_response,
+            to_binary(&GetAsksResponse {
+                asks: vec![ask_orders[0].clone()],
+                last_key: Some("ask_c".to_string()),
+            })
+        );
     }
 
     #[test]
-    fn cancel_with_invalid_data() {
+    fn query_bids_with_pagination() {
         let mut deps = mock_dependencies(&[]);
-        if let Err(error) = set_contract_info(
-            &mut deps.storage,
-            &ContractInfo::new(
-                Addr::unchecked("contract_admin"),
-                "contract_bind_name".into(),
-                "contract_name".into(),
-                None,
-                None,
-            ),
-        ) {
-            panic!("unexpected error: {:?}", error)
+
+        // store bids out of order to verify that iteration is ordered by id
+        let bid_orders = ["bid_c", "bid_a", "bid_b"]
+            .iter()
+            .map(|id| BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                id: id.to_string(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            })
+            .collect::<Vec<BidOrderV2>>();
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        for bid_order in bid_orders.iter() {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
         }
 
-        let asker_info = mock_info("asker", &[]);
+        // the first of two pages
+        let query_bids_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetBids {
+                start_after: None,
+                limit: Some(2),
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
 
-        // cancel ask order with missing id returns ContractError::Unauthorized
-        let cancel_ask_msg = ExecuteMsg::CancelAsk { id: "".to_string() };
-        let cancel_response = execute(
-            deps.as_mut(),
+        assert_eq!(
+            query_bids_response,
+            to_binary(&GetBidsResponse {
+                bids: vec![bid_orders[1].clone(), bid_orders[2].clone()],
+                last_key: Some("bid_b".to_string()),
+            })
+        );
+
+        // continuing from the last key of the first page
+        let query_bids_response = query(
+            deps.as_ref(),
             mock_env(),
-            asker_info.clone(),
-            cancel_ask_msg,
+            QueryMsg::GetBids {
+                start_after: Some("bid_b".to_string()),
+                limit: Some(2),
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
+
+        assert_eq!(
+            query_bids_response,
+            to_binary(&GetBidsResponse {
+                bids: vec![bid_orders[0].clone()],
+                last_key: Some("bid_c".to_string()),
+            })
         );
 
-        match cancel_response {
-            Err(error) => match error {
-                ContractError::Unauthorized {} => {}
-                _ => {
-                    panic!("unexpected error: {:?}", error)
-                }
+        // continuing past the final bid produces an empty page
+        let query_bids_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetBids {
+                start_after: Some("bid_c".to_string()),
+                limit: None,
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
+
+        assert_eq!(
+            query_bids_response,
+            to_binary(&GetBidsResponse {
+                bids: vec![],
+                last_key: None,
+            })
+        );
+    }
+
+    #[test]
+    fn query_bids_returns_every_stored_bid() {
+        let mut deps = mock_dependencies(&[]);
+
+        // bids have no status to filter on, so a bid that is expired or funded with a different base is still listed
+        let bid_orders = vec![
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: Some(Timestamp::from_seconds(1)),
+                id: "expired_bid".into(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
             },
-            Ok(_) => panic!("expected error, but cancel_response ok"),
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::scope("scope1234"),
+                effective_time: Some(Timestamp::from_seconds(10)),
+                expiration: None,
+                id: "scope_bid".into(),
+                owner: Addr::unchecked("other_bidder"),
+                quote: coins(200, "quote_2"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            },
+        ];
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        for bid_order in bid_orders.iter() {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
         }
 
-        // cancel non-existent ask order returns ContractError::Unauthorized
-        let cancel_ask_msg = ExecuteMsg::CancelAsk {
-            id: "unknown_id".to_string(),
-        };
-
-        let cancel_response = execute(
-            deps.as_mut(),
+        let query_bids_response = query(
+            deps.as_ref(),
             mock_env(),
-            asker_info.clone(),
-            cancel_ask_msg,
+            QueryMsg::GetBids {
+                start_after: None,
+                limit: None,
+                filter_base_denom: None,
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
         );
 
-        match cancel_response {
-            Err(error) => match error {
-                ContractError::Unauthorized {} => {}
-                _ => {
-                    panic!("unexpected error: {:?}", error)
-                }
+        assert_eq!(
+            query_bids_response,
+            to_binary(&GetBidsResponse {
+                bids: bid_orders.clone(),
+                last_key: Some("scope_bid".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn query_escrow_summary_totals_open_orders() {
+        let mut deps = mock_dependencies(&[]);
+
+        let ask_orders = [
+            AskOrderV2 {
+                base: BaseType::coins(vec![coin(100, "base_1"), coin(50, "quote_1")]),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "coin_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
             },
-            Ok(_) => panic!("expected error, but cancel_response ok"),
+            AskOrderV2 {
+                base: BaseType::coin(25, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "other_coin_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_2"),
+            },
+            AskOrderV2 {
+                base: BaseType::scope("scope1234"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "scope_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(500, "quote_1"),
+            },
+            AskOrderV2 {
+                base: BaseType::marker("marker1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "marker_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(500, "quote_1"),
+            },
+        ];
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in ask_orders.iter() {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
         }
 
-        // cancel ask order with sender not equal to owner returns ContractError::Unauthorized
-        if let Err(error) = get_ask_storage_v2(&mut deps.storage).save(
-            "ask_id".to_string().as_bytes(),
-            &AskOrderV2 {
-                base: BaseType::coin(200, "base_1"),
-                id: "ask_id".into(),
-                owner: Addr::unchecked(""),
+        let bid_orders = [
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                id: "coin_bid".into(),
+                owner: Addr::unchecked("bidder"),
                 quote: coins(100, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
             },
-        ) {
-            panic!("unexpected error: {:?}", error)
-        };
-        let cancel_ask_msg = ExecuteMsg::CancelAsk {
-            id: "ask_id".to_string(),
-        };
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::scope("scope1234"),
+                effective_time: None,
+                expiration: None,
+                id: "scope_bid".into(),
+                owner: Addr::unchecked("bidder"),
+                quote: vec![coin(500, "quote_1"), coin(10, "quote_2")],
+                requested_data_access: vec![],
+                value_owner_override: None,
+            },
+        ];
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        for bid_order in bid_orders.iter() {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
 
-        let cancel_response = execute(deps.as_mut(), mock_env(), asker_info, cancel_ask_msg);
+        let query_escrow_summary_response =
+            query(deps.as_ref(), mock_env(), QueryMsg::GetEscrowSummary {});
+
+        // asks escrow their coin bases and bids escrow their quotes, while the quotes of asks are never held
+        assert_eq!(
+            query_escrow_summary_response,
+            to_binary(&GetEscrowSummaryResponse {
+                coins: vec![
+                    coin(125, "base_1"),
+                    coin(650, "quote_1"),
+                    coin(10, "quote_2"),
+                ],
+                escrowed_scopes: 1,
+                escrowed_markers: 1,
+            })
+        );
+    }
 
-        match cancel_response {
-            Err(error) => match error {
-                ContractError::Unauthorized {} => {}
-                _ => {
-                    panic!("unexpected error: {:?}", error)
-                }
+    #[test]
+    fn query_orders_with_filters() {
+        let mut deps = mock_dependencies(&[]);
+
+        let ask_orders = [
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_coin_1".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
             },
-            Ok(_) => panic!("expected error, but cancel_response ok"),
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_2"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_coin_2".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_2"),
+            },
+            AskOrderV2 {
+                base: BaseType::marker("base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_marker".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_2"),
+            },
+            AskOrderV2 {
+                base: BaseType::scope("scope_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_scope_1".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            AskOrderV2 {
+                base: BaseType::scope("scope_2"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_scope_2".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_2"),
+            },
+        ];
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in ask_orders.iter() {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
+        let bid_orders = ask_orders
+            .iter()
+            .map(|ask_order| BidOrderV2 {
+                accepted_ask_quote: None,
+                base: ask_order.base.to_owned(),
+                effective_time: None,
+                expiration: None,
+                id: ask_order.id.replace("ask", "bid"),
+                owner: Addr::unchecked("bidder"),
+                quote: ask_order.quote.to_owned(),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            })
+            .collect::<Vec<BidOrderV2>>();
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        for bid_order in bid_orders.iter() {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
         }
 
-        // cancel ask order with sent_funds returns ContractError::CancelWithFunds
-        let asker_info = mock_info("asker", &coins(1, "sent_coin"));
-        let cancel_ask_msg = ExecuteMsg::CancelAsk {
-            id: "ask_id".to_string(),
+        let query_ask_ids = |filter_base_denom: Option<&str>,
+                             filter_quote_denom: Option<&str>,
+                             filter_scope_address: Option<&str>,
+                             limit: Option<u32>| {
+            get_asks(
+                deps.as_ref(),
+                None,
+                limit,
+                OrderFilters {
+                    base_denom: filter_base_denom.map(|denom| denom.to_string()),
+                    quote_denom: filter_quote_denom.map(|denom| denom.to_string()),
+                    scope_address: filter_scope_address.map(|address| address.to_string()),
+                },
+            )
+            .unwrap()
+            .asks
+            .into_iter()
+            .map(|ask_order| ask_order.id)
+            .collect::<Vec<String>>()
         };
 
-        let cancel_response = execute(deps.as_mut(), mock_env(), asker_info, cancel_ask_msg);
+        // coin and marker bases both match a base denom filter
+        assert_eq!(
+            vec!["ask_coin_1", "ask_marker"],
+            query_ask_ids(Some("base_1"), None, None, None)
+        );
+        assert_eq!(
+            vec!["ask_coin_2", "ask_marker", "ask_scope_2"],
+            query_ask_ids(None, Some("quote_2"), None, None)
+        );
+        // the scope sentinel matches every scope base
+        assert_eq!(
+            vec!["ask_scope_1", "ask_scope_2"],
+            query_ask_ids(Some("scope"), None, None, None)
+        );
+        assert_eq!(
+            vec!["ask_scope_2"],
+            query_ask_ids(None, None, Some("scope_2"), None)
+        );
+        // every filter must match
+        assert_eq!(
+            vec!["ask_scope_1"],
+            query_ask_ids(Some("scope"), Some("quote_1"), None, None)
+        );
+        assert!(query_ask_ids(Some("base_2"), Some("quote_1"), None, None).is_empty());
+        // filters are applied before the page is limited
+        assert_eq!(
+            vec!["ask_scope_2"],
+            query_ask_ids(None, Some("quote_2"), Some("scope_2"), Some(1))
+        );
+        assert_eq!(
+            vec!["ask_coin_2"],
+            query_ask_ids(None, Some("quote_2"), None, Some(1))
+        );
 
-        match cancel_response {
-            Err(error) => match error {
-                ContractError::CancelWithFunds {} => {}
-                _ => {
-                    panic!("unexpected error: {:?}", error)
-                }
+        let query_bids_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetBids {
+                start_after: None,
+                limit: None,
+                filter_base_denom: Some("scope".to_string()),
+                filter_quote_denom: Some("quote_2".to_string()),
+                filter_scope_address: None,
             },
-            Ok(_) => panic!("expected error, but cancel_response ok"),
-        }
+        );
+
+        assert_eq!(
+            query_bids_response,
+            to_binary(&GetBidsResponse {
+                bids: vec![bid_orders[4].clone()],
+                last_key: Some("bid_scope_2".to_string()),
+            })
+        );
+
+        let query_bids_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetBids {
+                start_after: Some("bid_coin_1".to_string()),
+                limit: None,
+                filter_base_denom: Some("base_1".to_string()),
+                filter_quote_denom: None,
+                filter_scope_address: None,
+            },
+        );
+
+        assert_eq!(
+            query_bids_response,
+            to_binary(&GetBidsResponse {
+                bids: vec![bid_orders[2].clone()],
+                last_key: Some("bid_marker".to_string()),
+            })
+        );
     }
 
     #[test]
-    fn execute_match_with_valid_coin_data() {
-        // setup
+    fn find_matching_bids_sorted_by_effective_time() {
         let mut deps = mock_dependencies(&[]);
         if let Err(error) = set_contract_info(
             &mut deps.storage,
@@ -1762,91 +9547,224 @@ mod tests {
         ) {
             panic!("unexpected error: {:?}", error)
         }
+        let env = mock_env();
 
-        // store valid ask order
         let ask_order = AskOrderV2 {
-            base: BaseType::coins(vec![coin(100, "base_1"), coin(200, "base_2")]),
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
             id: "ask_id".into(),
             owner: Addr::unchecked("asker"),
-            quote: coins(200, "quote_1"),
+            quote: coins(100, "quote_1"),
         };
-
-        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
-        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+        if let Err(error) =
+            get_ask_storage_v2(&mut deps.storage).save(ask_order.id.as_bytes(), &ask_order)
+        {
             panic!("unexpected error: {:?}", error)
         };
 
-        // store valid bid order
-        let bid_order = BidOrderV2 {
-            base: BaseType::coins(vec![coin(200, "base_2"), coin(100, "base_1")]),
-            effective_time: Some(Timestamp::default()),
-            id: "bid_id".to_string(),
+        let matching_bid = |id: &str, effective_time: Option<Timestamp>| BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
+            effective_time,
+            expiration: None,
+            id: id.into(),
             owner: Addr::unchecked("bidder"),
-            quote: coins(200, "quote_1"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
         };
-
+        let bid_orders = [
+            matching_bid("bid_a", None),
+            matching_bid("bid_b", Some(Timestamp::from_seconds(30))),
+            matching_bid("bid_c", Some(Timestamp::from_seconds(10))),
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                quote: coins(200, "quote_1"),
+                ..matching_bid("bid_d", Some(Timestamp::from_seconds(1)))
+            },
+            BidOrderV2 {
+                expiration: Some(env.block.time.minus_seconds(1)),
+                ..matching_bid("bid_e", Some(Timestamp::from_seconds(2)))
+            },
+            BidOrderV2 {
+                owner: Addr::unchecked("asker"),
+                ..matching_bid("bid_f", Some(Timestamp::from_seconds(3)))
+            },
+        ];
         let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
-        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
-            panic!("unexpected error: {:?}", error);
-        };
-
-        // execute on matched ask order and bid order
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: ask_order.id,
-            bid_id: bid_order.id.clone(),
-        };
+        for bid_order in bid_orders.iter() {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
 
-        let execute_response = execute(
-            deps.as_mut(),
+        let find_matching_bids_response = query(
+            deps.as_ref(),
             mock_env(),
-            mock_info("contract_admin", &[]),
-            execute_msg,
+            QueryMsg::FindMatchingBids {
+                ask_id: "ask_id".into(),
+            },
         );
 
-        // validate execute response
-        match execute_response {
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(execute_response) => {
-                assert_eq!(execute_response.attributes.len(), 1);
-                assert_eq!(execute_response.attributes[0], attr("action", "execute"));
-                assert_eq!(execute_response.messages.len(), 2);
-                assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: ask_order.owner.to_string(),
-                        amount: ask_order.quote,
-                    })
-                );
-                handle_expected_coin(&bid_order.base, |coins| {
-                    assert_eq!(
-                        execute_response.messages[1].msg,
-                        CosmosMsg::Bank(BankMsg::Send {
-                            to_address: bid_order.owner.to_string(),
-                            amount: coins.to_vec(),
-                        })
-                    );
-                });
-            }
-        }
+        // the mismatched, expired and self matching bids are excluded, despite having the earliest effective times
+        assert_eq!(
+            find_matching_bids_response,
+            to_binary(&FindMatchingBidsResponse {
+                bids: vec![
+                    bid_orders[2].clone(),
+                    bid_orders[1].clone(),
+                    bid_orders[0].clone(),
+                ],
+            })
+        );
+
+        // once the ask has expired, no bids can be matched with it
+        let mut later_env = env;
+        later_env.block.time = later_env.block.time.plus_seconds(2);
+        let expired_ask_order = AskOrderV2 {
+            expiration: Some(later_env.block.time.minus_seconds(1)),
+            ..ask_order
+        };
+        if let Err(error) = get_ask_storage_v2(&mut deps.storage)
+            .save(expired_ask_order.id.as_bytes(), &expired_ask_order)
+        {
+            panic!("unexpected error: {:?}", error)
+        };
+        assert_eq!(
+            query(
+                deps.as_ref(),
+                later_env,
+                QueryMsg::FindMatchingBids {
+                    ask_id: "ask_id".into(),
+                },
+            ),
+            to_binary(&FindMatchingBidsResponse { bids: vec![] })
+        );
+
+        // an unknown ask results in an error
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FindMatchingBids {
+                ask_id: "unknown_ask".into(),
+            },
+        )
+        .is_err());
     }
 
     #[test]
-    fn execute_match_with_valid_scope_data() {
-        // setup
+    fn query_match_preview() {
         let mut deps = mock_dependencies(&[]);
+        let env = mock_env();
 
-        let scope_input = Scope {
-            scope_id: "scope1234".to_string(),
-            specification_id: "scopespec1".to_string(),
-            owners: vec![Party {
-                address: Addr::unchecked("asker"),
-                role: PartyType::Owner,
-            }],
-            data_access: vec![],
-            value_owner_address: Addr::unchecked("asker"), // todo: does this need to be the contract's address?
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in [
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "ask_id".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: Some(env.block.time.minus_seconds(1)),
+                expected_previous_owner: None,
+                id: "expired_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(100, "quote_1"),
+            },
+        ] {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
+
+        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
+        for (id, base, quote) in [
+            (
+                "bid_id",
+                BaseType::coin(100, "base_1"),
+                coins(100, "quote_1"),
+            ),
+            (
+                "base_mismatch",
+                BaseType::coin(50, "base_1"),
+                coins(100, "quote_1"),
+            ),
+            (
+                "quote_mismatch",
+                BaseType::coin(100, "base_1"),
+                coins(50, "quote_1"),
+            ),
+        ] {
+            let bid_order = BidOrderV2 {
+                accepted_ask_quote: None,
+                base,
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                id: id.into(),
+                owner: Addr::unchecked("bidder"),
+                quote,
+                requested_data_access: vec![],
+                value_owner_override: None,
+            };
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
+                panic!("unexpected error: {:?}", error);
+            };
+        }
+
+        let preview = |ask_id: &str, bid_id: &str| {
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::MatchPreview {
+                    ask_id: ask_id.into(),
+                    bid_id: bid_id.into(),
+                },
+            )
         };
-        deps.querier.with_scope(scope_input.clone());
+        let mismatch = |reason: &str| {
+            to_binary(&MatchPreviewResponse {
+                matches: false,
+                reason: Some(reason.to_string()),
+            })
+        };
+
+        assert_eq!(
+            preview("ask_id", "bid_id"),
+            to_binary(&MatchPreviewResponse {
+                matches: true,
+                reason: None,
+            })
+        );
+        assert_eq!(
+            preview("ask_id", "base_mismatch"),
+            mismatch("the ask base does not match the bid base")
+        );
+        assert_eq!(
+            preview("ask_id", "quote_mismatch"),
+            mismatch("the ask quote does not match the bid quote")
+        );
+        assert_eq!(
+            preview("expired_ask", "bid_id"),
+            mismatch("ask [expired_ask] has expired")
+        );
+        assert_eq!(
+            preview("missing_ask", "bid_id"),
+            mismatch("ask [missing_ask] does not exist")
+        );
+        assert_eq!(
+            preview("ask_id", "missing_bid"),
+            mismatch("bid [missing_bid] does not exist")
+        );
+    }
 
+    #[test]
+    fn query_order_pair() {
+        let mut deps = mock_dependencies(&[]);
         if let Err(error) = set_contract_info(
             &mut deps.storage,
             &ContractInfo::new(
@@ -1859,465 +9777,1026 @@ mod tests {
         ) {
             panic!("unexpected error: {:?}", error)
         }
-
-        // store valid ask order
+        let expired_time = mock_env().block.time.minus_seconds(1);
         let ask_order = AskOrderV2 {
-            base: BaseType::scope(&scope_input.scope_id),
+            base: BaseType::coin(100, "base_1"),
+            expiration: None,
+            expected_previous_owner: None,
             id: "ask_id".into(),
             owner: Addr::unchecked("asker"),
-            quote: coins(200, "quote_1"),
+            quote: coins(100, "quote_1"),
         };
-
-        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
-        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
-            panic!("unexpected error: {:?}", error)
+        let expired_ask_order = AskOrderV2 {
+            expiration: Some(expired_time),
+            id: "expired_ask".into(),
+            ..ask_order.clone()
         };
-
-        // store valid bid order
+        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
+        for ask_order in [&ask_order, &expired_ask_order] {
+            if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), ask_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
         let bid_order = BidOrderV2 {
-            base: BaseType::scope(&scope_input.scope_id),
+            accepted_ask_quote: None,
+            base: BaseType::coin(100, "base_1"),
             effective_time: Some(Timestamp::default()),
-            id: "bid_id".to_string(),
+            expiration: None,
+            id: "bid_id".into(),
             owner: Addr::unchecked("bidder"),
-            quote: coins(200, "quote_1"),
+            quote: coins(100, "quote_1"),
+            requested_data_access: vec![],
+            value_owner_override: None,
+        };
+        let mismatched_bid_order = BidOrderV2 {
+            id: "quote_mismatch".into(),
+            quote: coins(50, "quote_1"),
+            ..bid_order.clone()
+        };
+        let expired_bid_order = BidOrderV2 {
+            id: "expired_bid".into(),
+            expiration: Some(expired_time),
+            ..bid_order.clone()
+        };
+        let self_match_bid_order = BidOrderV2 {
+            id: "self_match".into(),
+            owner: Addr::unchecked("asker"),
+            ..bid_order.clone()
         };
-
         let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
-        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
-            panic!("unexpected error: {:?}", error);
+        for bid_order in [
+            &bid_order,
+            &mismatched_bid_order,
+            &expired_bid_order,
+            &self_match_bid_order,
+        ] {
+            if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), bid_order) {
+                panic!("unexpected error: {:?}", error)
+            };
+        }
+
+        let order_pair = |ask_id: &str, bid_id: &str| -> GetOrderPairResponse {
+            from_binary(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetOrderPair {
+                        ask_id: ask_id.into(),
+                        bid_id: bid_id.into(),
+                    },
+                )
+                .expect("the order pair query should succeed"),
+            )
+            .unwrap()
         };
 
-        // execute on matched ask order and bid order
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: ask_order.id,
-            bid_id: bid_order.id.clone(),
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: Some(ask_order.clone()),
+                bid: Some(bid_order.clone()),
+                would_match: true,
+            },
+            order_pair("ask_id", "bid_id"),
+        );
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: Some(ask_order.clone()),
+                bid: Some(mismatched_bid_order),
+                would_match: false,
+            },
+            order_pair("ask_id", "quote_mismatch"),
+        );
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: Some(expired_ask_order),
+                bid: Some(bid_order.clone()),
+                would_match: false,
+            },
+            order_pair("expired_ask", "bid_id"),
+            "an expired ask cannot be matched",
+        );
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: Some(ask_order.clone()),
+                bid: Some(expired_bid_order),
+                would_match: false,
+            },
+            order_pair("ask_id", "expired_bid"),
+            "an expired bid cannot be matched",
+        );
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: Some(ask_order.clone()),
+                bid: Some(self_match_bid_order),
+                would_match: false,
+            },
+            order_pair("ask_id", "self_match"),
+            "an ask and bid with the same owner cannot be matched",
+        );
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: None,
+                bid: Some(bid_order),
+                would_match: false,
+            },
+            order_pair("missing_ask", "bid_id"),
+        );
+        assert_eq!(
+            GetOrderPairResponse {
+                ask: Some(ask_order),
+                bid: None,
+                would_match: false,
+            },
+            order_pair("ask_id", "missing_bid"),
+        );
+    }
+
+    #[test]
+    fn query_config() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: Some(Uint128::new(5)),
+                bid_fee: None,
+                allowed_quote_denoms: Some(vec!["nhash".to_string()]),
+                match_fee: None,
+                allow_self_match: Some(true),
+                min_base_amount: Some(coins(10, "base_1")),
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let query_config = |deps: Deps<ProvenanceQuery>| -> GetConfigResponse {
+            from_binary(&query(deps, mock_env(), QueryMsg::GetConfig {}).unwrap()).unwrap()
         };
+        assert_eq!(
+            GetConfigResponse {
+                admin: Addr::unchecked("contract_admin"),
+                ask_fee: Some(Uint128::new(5)),
+                bid_fee: None,
+                match_fee: None,
+                fee_mode: FeeMode::Flat,
+                allowed_quote_denoms: Some(vec!["nhash".to_string()]),
+                min_base_amount: Some(coins(10, "base_1")),
+                min_quote_amount: None,
+                allow_self_match: true,
+                paused: false,
+                max_orders_per_owner: None,
+            },
+            query_config(deps.as_ref())
+        );
 
-        let execute_response = execute(
+        update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            None,
+            Some(Uint128::new(100)),
+            Some(Uint128::new(20)),
+            Some(FeeMode::BasisPoints),
+        )
+        .expect("updating fees should be successful");
+        let config = query_config(deps.as_ref());
+        assert_eq!(None, config.ask_fee);
+        assert_eq!(Some(Uint128::new(100)), config.bid_fee);
+        assert_eq!(Some(Uint128::new(20)), config.match_fee);
+        assert_eq!(FeeMode::BasisPoints, config.fee_mode);
+        // parameters that were not part of the fee update are unchanged
+        assert_eq!(Some(vec!["nhash".to_string()]), config.allowed_quote_denoms);
+        assert_eq!(Some(coins(10, "base_1")), config.min_base_amount);
+        assert!(config.allow_self_match);
+    }
+
+    #[test]
+    fn test_update_fees_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
             mock_info("contract_admin", &[]),
-            execute_msg,
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let contract_info = get_contract_info(deps.as_ref().storage).unwrap();
+        assert_eq!(None, contract_info.ask_fee);
+        assert_eq!(None, contract_info.bid_fee);
+        let response = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            Some(Uint128::new(10)),
+            Some(Uint128::new(15)),
+            Some(Uint128::new(20)),
+            None,
+        )
+        .expect("updating fees should be successful");
+        assert!(response.messages.is_empty());
+        assert_eq!(4, response.attributes.len());
+        assert_eq!(attr("action", "update_fees"), response.attributes[0]);
+        assert_eq!(attr("new_ask_fee", "10nhash"), response.attributes[1]);
+        assert_eq!(attr("new_bid_fee", "15nhash"), response.attributes[2]);
+        assert_eq!(attr("new_match_fee", "20nhash"), response.attributes[3]);
+        assert_eq!(
+            Some(Uint128::new(20)),
+            get_contract_info(deps.as_ref().storage).unwrap().match_fee
         );
+        let response = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("clearing fees should be successful");
+        assert!(response.messages.is_empty());
+        assert_eq!(4, response.attributes.len());
+        assert_eq!(attr("action", "update_fees"), response.attributes[0]);
+        assert_eq!(attr("new_ask_fee", "cleared"), response.attributes[1]);
+        assert_eq!(attr("new_bid_fee", "cleared"), response.attributes[2]);
+        assert_eq!(attr("new_match_fee", "cleared"), response.attributes[3]);
+    }
 
-        // validate execute response
-        match execute_response {
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(execute_response) => {
-                assert_eq!(execute_response.attributes.len(), 1);
-                assert_eq!(execute_response.attributes[0], attr("action", "execute"));
-                assert_eq!(execute_response.messages.len(), 2);
-                assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: ask_order.owner.to_string(),
-                        amount: ask_order.quote,
-                    })
-                );
-                handle_expected_scope(&bid_order.base, |scope_id| {
-                    if let CosmosMsg::Custom(ProvenanceMsg { params, .. }) =
-                        &execute_response.messages[1].msg
-                    {
-                        assert_eq!(
-                            params.to_owned(),
-                            ProvenanceMsgParams::Metadata(MetadataMsgParams::WriteScope {
-                                scope: Scope {
-                                    scope_id: scope_id.to_string(),
-                                    specification_id: scope_input.specification_id,
-                                    owners: vec![Party {
-                                        address: bid_order.owner.clone(),
-                                        role: PartyType::Owner
-                                    }],
-                                    data_access: scope_input.data_access,
-                                    value_owner_address: bid_order.owner.clone()
-                                },
-                                signers: vec![Addr::unchecked(MOCK_CONTRACT_ADDR)]
-                            }),
-                        );
-                    } else {
-                        panic!("Unexpected second message type for match, expected WriteScope, received {:?}", execute_response.messages[1].msg)
-                    }
-                });
+    #[test]
+    fn test_update_fees_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect_err("an error should occur when no contract info exists");
+        assert!(
+            matches!(err, ContractError::Std(StdError::NotFound { .. })),
+            "a not found error should occur when contract info does not exist, but got: {:?}",
+            err,
+        );
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect_err("an error should occur when a non-admin attempts to update fees");
+        assert!(
+            matches!(err, ContractError::Unauthorized {}),
+            "an unauthorized error should occur when a non-admin attempts to update fees, but got: {:?}",
+            err,
+        );
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &coins(1000, "nhash")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the admin provides funds");
+        assert!(
+            matches!(err, ContractError::UpdateFeesWithFunds {}),
+            "an update fees with funds error should occur, but got: {:?}",
+            err,
+        );
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            Some(Uint128::zero()),
+            None,
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the ask fee is zero");
+        match err {
+            ContractError::InvalidFee { fee_type } => {
+                assert_eq!("ask", fee_type);
+            }
+            e => panic!("unexpected error when ask fee is zero: {:?}", e),
+        };
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            None,
+            Some(Uint128::zero()),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the bid fee is zero");
+        match err {
+            ContractError::InvalidFee { fee_type } => {
+                assert_eq!("bid", fee_type);
+            }
+            e => panic!("unexpected error when the bid fee is zero: {:?}", e),
+        };
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            None,
+            None,
+            Some(Uint128::zero()),
+            None,
+        )
+        .expect_err("an error should occur when the match fee is zero");
+        match err {
+            ContractError::InvalidFee { fee_type } => {
+                assert_eq!("match", fee_type);
             }
-        }
+            e => panic!("unexpected error when the match fee is zero: {:?}", e),
+        };
     }
 
     #[test]
-    fn execute_match_with_invalid_coin_data() {
-        // setup
+    fn test_basis_points_fees() {
         let mut deps = mock_dependencies(&[]);
-        if let Err(error) = set_contract_info(
-            &mut deps.storage,
-            &ContractInfo::new(
-                Addr::unchecked("contract_admin"),
-                "contract_bind_name".into(),
-                "contract_name".into(),
-                None,
-                None,
-            ),
-        ) {
-            panic!("unexpected error: {:?}", error)
-        }
-
-        // store valid ask order
-        let ask_order = AskOrderV2 {
-            base: BaseType::coin(200, "base_1"),
-            id: "ask_id".into(),
-            owner: Addr::unchecked("asker"),
-            quote: coins(100, "quote_1"),
-        };
-
-        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
-        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
-            panic!("unexpected error: {:?}", error)
-        };
-
-        // store valid bid order
-        let bid_order = BidOrderV2 {
-            base: BaseType::coin(100, "base_1"),
-            effective_time: Some(Timestamp::default()),
-            id: "bid_id".into(),
-            owner: Addr::unchecked("bidder"),
-            quote: coins(100, "quote_1"),
-        };
-
-        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
-        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
-            panic!("unexpected error: {:?}", error);
-        };
-
-        // execute by non-admin ContractError::Unauthorized
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "bid_id".into(),
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let err = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            Some(Uint128::new(10001)),
+            None,
+            None,
+            Some(FeeMode::BasisPoints),
+        )
+        .expect_err("an error should occur when the ask fee exceeds the entire quote");
+        match err {
+            ContractError::InvalidFeeBasisPoints {
+                fee_type,
+                basis_points,
+            } => {
+                assert_eq!("ask", fee_type);
+                assert_eq!(Uint128::new(10001), basis_points);
+            }
+            e => panic!("unexpected error when the ask fee is too large: {:?}", e),
         };
+        let response = update_fees(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            Some(Uint128::new(250)),
+            Some(Uint128::new(100)),
+            None,
+            Some(FeeMode::BasisPoints),
+        )
+        .expect("updating to basis points fees should be successful");
+        assert_eq!(attr("new_ask_fee", "250bps"), response.attributes[1]);
+        assert_eq!(attr("new_bid_fee", "100bps"), response.attributes[2]);
+        assert_eq!(
+            FeeMode::BasisPoints,
+            get_contract_info(deps.as_ref().storage).unwrap().fee_mode
+        );
 
-        let execute_response = execute(
+        // 250 basis points of a 10000nhash quote is 250nhash
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("user", &[]),
-            execute_msg,
+            mock_info("asker", &coins(2, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(10000, "nhash"),
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
         );
+        match_create_response(response, "Ask", "create_ask", Some(250));
 
-        match execute_response {
-            Err(ContractError::Unauthorized {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute on mismatched ask order and bid order returns ContractError::AskBidMismatch
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "bid_id".into(),
-        };
-
-        let execute_response = execute(
+        // 100 basis points of a 10000nhash quote is 100nhash
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("contract_admin", &[]),
-            execute_msg,
+            mock_info("bidder", &coins(10000, "nhash")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(2, "base_1"),
+                effective_time: Some(Timestamp::default()),
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
         );
+        match_create_response(response, "Bid", "create_bid", Some(100));
 
-        match execute_response {
-            Err(ContractError::AskBidMismatch {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "no_ask_id".into(),
-            bid_id: "bid_id".into(),
-        };
-
-        let execute_response = execute(
+        // the value of a quote containing other denoms cannot be known, so the fee cannot be computed
+        let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("contract_admin", &[]),
-            execute_msg,
+            mock_info("asker", &coins(2, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "mixed_ask_id".into(),
+                quote: vec![coin(10000, "nhash"), coin(100, "quote_1")],
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        )
+        .expect_err("an error should occur when the quote is not entirely nhash");
+        assert!(
+            matches!(err, ContractError::InvalidQuoteForFee { .. }),
+            "an invalid quote for fee error should occur, but got: {:?}",
+            err,
         );
+    }
 
-        match execute_response {
-            Err(ContractError::AskBidMismatch {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "no_bid_id".into(),
-        };
-
-        let execute_response = execute(
+    #[test]
+    fn test_update_admin() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
             mock_info("contract_admin", &[]),
-            execute_msg,
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let err = update_admin(
+            deps.as_mut(),
+            mock_info("not_admin", &[]),
+            "not_admin".to_string(),
+        )
+        .expect_err("an error should occur when a non-admin attempts to update the admin");
+        assert!(
+            matches!(err, ContractError::Unauthorized {}),
+            "an unauthorized error should occur when a non-admin attempts to update the admin, but got: {:?}",
+            err,
         );
-
-        match execute_response {
-            Err(ContractError::AskBidMismatch {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute with sent_funds returns ContractError::ExecuteWithFunds
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "bid_id".into(),
-        };
-
-        let execute_response = execute(
+        let err = update_admin(
+            deps.as_mut(),
+            mock_info("contract_admin", &coins(1000, "nhash")),
+            "new_admin".to_string(),
+        )
+        .expect_err("an error should occur when the admin provides funds");
+        assert!(
+            matches!(err, ContractError::UpdateAdminWithFunds {}),
+            "an update admin with funds error should occur, but got: {:?}",
+            err,
+        );
+        let response = update_admin(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            "new_admin".to_string(),
+        )
+        .expect("updating the admin should be successful");
+        assert!(response.messages.is_empty());
+        assert_eq!(3, response.attributes.len());
+        assert_eq!(attr("action", "update_admin"), response.attributes[0]);
+        assert_eq!(
+            attr("previous_admin", "contract_admin"),
+            response.attributes[1]
+        );
+        assert_eq!(attr("new_admin", "new_admin"), response.attributes[2]);
+        assert_eq!(
+            Addr::unchecked("new_admin"),
+            get_contract_info(deps.as_ref().storage).unwrap().admin
+        );
+        // the previous admin should no longer be able to execute matches
+        let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("contract_admin", &coins(100, "funds")),
-            execute_msg,
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: "ask_id".to_string(),
+                bid_id: "bid_id".to_string(),
+            },
+        )
+        .expect_err("an error should occur when the previous admin attempts to execute a match");
+        assert!(
+            matches!(err, ContractError::Unauthorized {}),
+            "an unauthorized error should occur when the previous admin executes a match, but got: {:?}",
+            err,
         );
-
-        match execute_response {
-            Err(ContractError::ExecuteWithFunds {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
     }
 
     #[test]
-    fn execute_match_with_invalid_scope_data() {
-        // setup
+    fn test_allowed_quote_denoms() {
         let mut deps = mock_dependencies(&[]);
-
-        let scope_input = Scope {
-            scope_id: "scope1234".to_string(),
-            specification_id: "scopespec1".to_string(),
-            owners: vec![Party {
-                address: Addr::unchecked("asker"),
-                role: PartyType::Owner,
-            }],
-            data_access: vec![],
-            value_owner_address: Addr::unchecked("asker"), // todo: does this need to be the contract's address?
-        };
-        deps.querier.with_scope(scope_input.clone());
-
-        if let Err(error) = set_contract_info(
-            &mut deps.storage,
-            &ContractInfo::new(
-                Addr::unchecked("contract_admin"),
-                "contract_bind_name".into(),
-                "contract_name".into(),
-                None,
-                None,
-            ),
-        ) {
-            panic!("unexpected error: {:?}", error)
-        }
-
-        // store valid ask order
-        let ask_order = AskOrderV2 {
-            base: BaseType::scope(scope_input.scope_id),
-            id: "ask_id".into(),
-            owner: Addr::unchecked("asker"),
-            quote: coins(100, "quote_1"),
-        };
-
-        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
-        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
-            panic!("unexpected error: {:?}", error)
-        };
-
-        // store invalid bid order
-        let bid_order = BidOrderV2 {
-            base: BaseType::coin(100, "base_1"),
-            effective_time: Some(Timestamp::default()),
-            id: "bid_id".into(),
-            owner: Addr::unchecked("bidder"),
-            quote: coins(100, "quote_1"),
-        };
-
-        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
-        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
-            panic!("unexpected error: {:?}", error);
-        };
-
-        // execute by non-admin ContractError::Unauthorized
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "bid_id".into(),
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: Some(vec!["usdf".to_string()]),
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: vec![coin(100, "usdf"), coin(100, "quote_1")],
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        )
+        .expect_err("an error should occur when the ask quote includes a disallowed denom");
+        match err {
+            ContractError::DisallowedQuoteDenom { denom } => assert_eq!("quote_1", denom),
+            e => panic!("unexpected error for a disallowed ask quote: {:?}", e),
         };
-
-        let execute_response = execute(
+        let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("user", &[]),
-            execute_msg,
-        );
-
-        match execute_response {
-            Err(ContractError::Unauthorized {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute on mismatched ask order and bid order returns ContractError::AskBidMismatch
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "bid_id".into(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        )
+        .expect_err("an error should occur when the bid funds include a disallowed denom");
+        match err {
+            ContractError::DisallowedQuoteDenom { denom } => assert_eq!("quote_1", denom),
+            e => panic!("unexpected error for disallowed bid funds: {:?}", e),
         };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "usdf"),
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        )
+        .expect("an ask quoted in an allowed denom should be created");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "usdf")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        )
+        .expect("a bid funded with an allowed denom should be created");
+    }
 
-        let execute_response = execute(
+    #[test]
+    fn test_update_quote_denoms_with_valid_data() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
             mock_info("contract_admin", &[]),
-            execute_msg,
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let response = update_quote_denoms(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            Some(vec!["usdf".to_string(), "nhash".to_string()]),
+        )
+        .expect("updating quote denoms should be successful");
+        assert!(response.messages.is_empty());
+        assert_eq!(2, response.attributes.len());
+        assert_eq!(
+            attr("action", "update_quote_denoms"),
+            response.attributes[0]
         );
+        assert_eq!(
+            attr("new_allowed_quote_denoms", "usdf,nhash"),
+            response.attributes[1]
+        );
+        assert_eq!(
+            Some(vec!["usdf".to_string(), "nhash".to_string()]),
+            get_contract_info(deps.as_ref().storage)
+                .unwrap()
+                .allowed_quote_denoms
+        );
+        let response = update_quote_denoms(deps.as_mut(), mock_info("contract_admin", &[]), None)
+            .expect("clearing quote denoms should be successful");
+        assert_eq!(
+            attr("new_allowed_quote_denoms", "cleared"),
+            response.attributes[1]
+        );
+        assert_eq!(
+            None,
+            get_contract_info(deps.as_ref().storage)
+                .unwrap()
+                .allowed_quote_denoms
+        );
+    }
 
-        match execute_response {
-            Err(ContractError::AskBidMismatch {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "no_ask_id".into(),
-            bid_id: "bid_id".into(),
-        };
-
-        let execute_response = execute(
+    #[test]
+    fn test_update_quote_denoms_with_invalid_data() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
             mock_info("contract_admin", &[]),
-            execute_msg,
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let err = update_quote_denoms(deps.as_mut(), mock_info("not_admin", &[]), None)
+            .expect_err("an error should occur when a non-admin attempts to update quote denoms");
+        assert!(
+            matches!(err, ContractError::Unauthorized {}),
+            "an unauthorized error should occur when a non-admin attempts to update quote denoms, but got: {:?}",
+            err,
         );
-
-        match execute_response {
-            Err(ContractError::AskBidMismatch {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute on non-existent ask order and bid order returns ContractError::AskBidMismatch
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "no_bid_id".into(),
+        let err = update_quote_denoms(
+            deps.as_mut(),
+            mock_info("contract_admin", &coins(1000, "nhash")),
+            None,
+        )
+        .expect_err("an error should occur when the admin provides funds");
+        assert!(
+            matches!(err, ContractError::UpdateQuoteDenomsWithFunds {}),
+            "an update quote denoms with funds error should occur, but got: {:?}",
+            err,
+        );
+        let err = update_quote_denoms(
+            deps.as_mut(),
+            mock_info("contract_admin", &[]),
+            Some(vec![]),
+        )
+        .expect_err("an error should occur when the quote denoms are empty");
+        match err {
+            ContractError::MissingField { field } => {
+                assert_eq!("allowed_quote_denoms", field);
+            }
+            e => panic!("unexpected error when quote denoms are empty: {:?}", e),
         };
+    }
 
-        let execute_response = execute(
+    #[test]
+    fn test_min_order_sizes() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
             deps.as_mut(),
             mock_env(),
             mock_info("contract_admin", &[]),
-            execute_msg,
-        );
-
-        match execute_response {
-            Err(ContractError::AskBidMismatch {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
-        }
-
-        // execute with sent_funds returns ContractError::ExecuteWithFunds
-        let execute_msg = ExecuteMsg::ExecuteMatch {
-            ask_id: "ask_id".into(),
-            bid_id: "bid_id".into(),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: Some(coins(100, "base_1")),
+                min_quote_amount: Some(coins(50, "quote_1")),
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let create_ask = |id: &str, quote: Vec<Coin>| ExecuteMsg::CreateAsk {
+            id: id.into(),
+            quote,
+            scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
+        };
+        let create_bid = |id: &str, base: BaseType| ExecuteMsg::CreateBid {
+            id: id.into(),
+            base,
+            effective_time: None,
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
         };
 
-        let execute_response = execute(
+        // orders below the minimum are rejected
+        match execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("contract_admin", &coins(100, "funds")),
-            execute_msg,
-        );
-
-        match execute_response {
-            Err(ContractError::ExecuteWithFunds {}) => {}
-            Err(error) => panic!("unexpected error: {:?}", error),
-            Ok(_) => panic!("expected error, but execute_response ok"),
+            mock_info("asker", &coins(99, "base_1")),
+            create_ask("small_base_ask", coins(50, "quote_1")),
+        ) {
+            Err(ContractError::OrderTooSmall {
+                denom,
+                amount,
+                minimum,
+            }) => {
+                assert_eq!("base_1", denom);
+                assert_eq!(Uint128::new(99), amount);
+                assert_eq!(Uint128::new(100), minimum);
+            }
+            result => panic!("unexpected result: {:?}", result),
         }
-    }
-
-    #[test]
-    pub fn query_with_valid_data() {
-        // setup
-        let mut deps = mock_dependencies(&[]);
-        if let Err(error) = set_contract_info(
-            &mut deps.storage,
-            &ContractInfo::new(
-                Addr::unchecked("contract_admin"),
-                "contract_bind_name".into(),
-                "contract_name".into(),
-                None,
-                None,
-            ),
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, "base_1")),
+            create_ask("small_quote_ask", coins(49, "quote_1")),
         ) {
-            panic!("unexpected error: {:?}", error)
+            Err(ContractError::OrderTooSmall { denom, .. }) => assert_eq!("quote_1", denom),
+            result => panic!("unexpected result: {:?}", result),
+        }
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(49, "quote_1")),
+            create_bid("small_quote_bid", BaseType::coin(100, "base_1")),
+        ) {
+            Err(ContractError::OrderTooSmall { denom, .. }) => assert_eq!("quote_1", denom),
+            result => panic!("unexpected result: {:?}", result),
         }
 
-        // store valid ask order
-        let ask_order = AskOrderV2 {
-            base: BaseType::coin(200, "base_1"),
-            id: "ask_id".into(),
-            owner: Addr::unchecked("asker"),
-            quote: coins(100, "quote_1"),
-        };
-
-        let mut ask_storage = get_ask_storage_v2(&mut deps.storage);
-        if let Err(error) = ask_storage.save(ask_order.id.as_bytes(), &ask_order) {
-            panic!("unexpected error: {:?}", error)
-        };
-
-        // store valid bid order
-        let bid_order = BidOrderV2 {
-            base: BaseType::coin(100, "base_1"),
-            effective_time: Some(Timestamp::default()),
-            id: "bid_id".into(),
-            owner: Addr::unchecked("bidder"),
-            quote: coins(100, "quote_1"),
-        };
-
-        let mut bid_storage = get_bid_storage_v2(&mut deps.storage);
-        if let Err(error) = bid_storage.save(bid_order.id.as_bytes(), &bid_order) {
-            panic!("unexpected error: {:?}", error);
-        };
-
-        // query for contract_info
-        let query_contract_info_response =
-            query(deps.as_ref(), mock_env(), QueryMsg::GetContractInfo {});
+        // orders exactly at the minimum are accepted, as are denoms without a minimum
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[coin(100, "base_1"), coin(1, "base_2")]),
+            create_ask("ask_id", coins(50, "quote_1")),
+        )
+        .expect("an ask exactly at the minimum should be created");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(50, "quote_1")),
+            create_bid("bid_id", BaseType::coin(100, "base_1")),
+        )
+        .expect("a bid exactly at the minimum should be created");
 
-        match query_contract_info_response {
-            Ok(contract_info) => {
-                assert_eq!(
-                    contract_info,
-                    to_binary(&get_contract_info(&deps.storage).unwrap()).unwrap()
-                )
-            }
-            Err(error) => panic!("unexpected error: {:?}", error),
-        }
+        // scope bases have no amount, so only their quote is checked
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(50, "quote_1")),
+            create_bid("scope_bid_id", BaseType::scope("scope1234")),
+        )
+        .expect("a bid for a scope should not be checked against the minimum base amount");
+
+        let err = update_min_order_sizes(deps.as_mut(), mock_info("not_admin", &[]), None, None)
+            .expect_err("an error should occur when a non-admin updates the minimum order sizes");
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        update_min_order_sizes(deps.as_mut(), mock_info("contract_admin", &[]), None, None)
+            .expect("the admin should be able to clear the minimum order sizes");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(1, "base_1")),
+            create_ask("dust_ask", coins(1, "quote_1")),
+        )
+        .expect("any order size should be accepted once the minimums are cleared");
+    }
 
-        // query for ask order
-        let query_ask_response = query(
-            deps.as_ref(),
+    #[test]
+    fn test_set_paused_blocks_order_creation() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
             mock_env(),
-            QueryMsg::GetAsk {
-                id: ask_order.id.clone(),
+            mock_info("asker", &coins(100, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
             },
+        )
+        .expect("creating an ask before the contract is paused should succeed");
+        let err = set_paused(deps.as_mut(), mock_info("not_admin", &[]), true)
+            .expect_err("an error should occur when a non-admin attempts to pause the contract");
+        assert!(
+            matches!(err, ContractError::Unauthorized {}),
+            "an unauthorized error should occur when a non-admin attempts to pause the contract, but got: {:?}",
+            err,
         );
+        let response = set_paused(deps.as_mut(), mock_info("contract_admin", &[]), true)
+            .expect("the admin should be able to pause the contract");
+        assert_eq!(attr("action", "set_paused"), response.attributes[0]);
+        assert_eq!(attr("paused", "true"), response.attributes[1]);
+        assert!(get_contract_info(deps.as_ref().storage).unwrap().paused);
 
-        assert_eq!(query_ask_response, to_binary(&ask_order));
-
-        // query for bid order
-        let query_bid_response = query(
-            deps.as_ref(),
+        let err = execute(
+            deps.as_mut(),
             mock_env(),
-            QueryMsg::GetBid {
-                id: bid_order.id.clone(),
+            mock_info("asker", &coins(100, "base_1")),
+            ExecuteMsg::CreateAsk {
+                id: "other_ask_id".into(),
+                quote: coins(100, "quote_1"),
+                scope_address: None,
+                marker_denom: None,
+                expiration: None,
+                exact_base: None,
+                expected_previous_owner: None,
+            },
+        )
+        .expect_err("creating an ask while the contract is paused should fail");
+        assert!(
+            matches!(err, ContractError::ContractPaused),
+            "a contract paused error should occur when creating an ask, but got: {:?}",
+            err,
+        );
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
             },
+        )
+        .expect_err("creating a bid while the contract is paused should fail");
+        assert!(
+            matches!(err, ContractError::ContractPaused),
+            "a contract paused error should occur when creating a bid, but got: {:?}",
+            err,
         );
 
-        assert_eq!(query_bid_response, to_binary(&bid_order));
+        // existing orders can still be cancelled while paused
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk {
+                id: "ask_id".into(),
+            },
+        )
+        .expect("cancelling an ask while the contract is paused should succeed");
+        assert!(get_ask_storage_read_v2(&deps.storage)
+            .load(b"ask_id")
+            .is_err());
+
+        // unpausing allows orders to be created again
+        set_paused(deps.as_mut(), mock_info("contract_admin", &[]), false)
+            .expect("the admin should be able to unpause the contract");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(100, "quote_1")),
+            ExecuteMsg::CreateBid {
+                id: "bid_id".into(),
+                base: BaseType::coin(100, "base_1"),
+                effective_time: None,
+                expiration: None,
+                accepted_ask_quote: None,
+                requested_data_access: None,
+                value_owner_override: None,
+            },
+        )
+        .expect("creating a bid after the contract is unpaused should succeed");
     }
 
     #[test]
-    fn test_update_fees_with_valid_data() {
+    fn test_migrate_new_version() {
         let mut deps = mock_dependencies(&[]);
         instantiate(
             deps.as_mut(),
@@ -2328,43 +10807,110 @@ mod tests {
                 contract_name: "contract_name".to_string(),
                 ask_fee: None,
                 bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
             },
         )
         .unwrap();
-        let contract_info = get_contract_info(deps.as_ref().storage).unwrap();
-        assert_eq!(None, contract_info.ask_fee);
-        assert_eq!(None, contract_info.bid_fee);
-        let response = update_fees(
-            deps.as_mut(),
-            mock_info("contract_admin", &[]),
-            Some(Uint128::new(10)),
-            Some(Uint128::new(15)),
-        )
-        .expect("updating fees should be successful");
-        assert!(response.messages.is_empty());
-        assert_eq!(3, response.attributes.len());
-        assert_eq!(attr("action", "update_fees"), response.attributes[0]);
-        assert_eq!(attr("new_ask_fee", "10nhash"), response.attributes[1]);
-        assert_eq!(attr("new_bid_fee", "15nhash"), response.attributes[2]);
-        let response = update_fees(deps.as_mut(), mock_info("contract_admin", &[]), None, None)
-            .expect("clearing fees should be successful");
-        assert!(response.messages.is_empty());
-        assert_eq!(3, response.attributes.len());
-        assert_eq!(attr("action", "update_fees"), response.attributes[0]);
-        assert_eq!(attr("new_ask_fee", "cleared"), response.attributes[1]);
-        assert_eq!(attr("new_bid_fee", "cleared"), response.attributes[2]);
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::NewVersion {})
+            .expect_err("migrating to the same version should fail");
+        assert!(
+            matches!(err, ContractError::InvalidVersion { .. }),
+            "an invalid version error should occur when migrating to the same version, but got: {:?}",
+            err,
+        );
+        set_stored_contract_version(&mut deps.storage, "99.0.0");
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::NewVersion {})
+            .expect_err("migrating to a lower version should fail");
+        assert!(
+            matches!(err, ContractError::InvalidVersion { .. }),
+            "an invalid version error should occur when migrating to a lower version, but got: {:?}",
+            err,
+        );
+        set_stored_contract_version(&mut deps.storage, "0.0.1");
+        migrate(deps.as_mut(), mock_env(), MigrateMsg::NewVersion {})
+            .expect("migrating from a lower version should succeed");
+        assert_eq!(
+            CONTRACT_VERSION,
+            get_contract_info(&deps.storage).unwrap().contract_version,
+            "the stored contract version should be updated by the migration",
+        );
     }
 
     #[test]
-    fn test_update_fees_with_invalid_data() {
+    fn test_migrate_new_bind_name() {
         let mut deps = mock_dependencies(&[]);
-        let err = update_fees(deps.as_mut(), mock_info("contract_admin", &[]), None, None)
-            .expect_err("an error should occur when no contract info exists");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        let err = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg::NewBindName {
+                bind_name: "".to_string(),
+            },
+        )
+        .expect_err("migrating to an empty bind name should fail");
         assert!(
-            matches!(err, ContractError::Std(StdError::NotFound { .. })),
-            "a not found error should occur when contract info does not exist, but got: {:?}",
+            matches!(err, ContractError::MissingField { ref field } if field == "bind_name"),
+            "a missing field error should occur for an empty bind name, but got: {:?}",
             err,
         );
+        let response = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg::NewBindName {
+                bind_name: "moved.sc.pb".to_string(),
+            },
+        )
+        .expect("migrating to a new bind name should succeed");
+        assert_eq!(1, response.messages.len());
+        assert_eq!(
+            CosmosMsg::Custom(ProvenanceMsg {
+                route: ProvenanceRoute::Name,
+                params: ProvenanceMsgParams::Name(NameMsgParams::BindName {
+                    name: "moved.sc.pb".to_string(),
+                    address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    restrict: true,
+                }),
+                version: "2.0.0".to_string(),
+            }),
+            response.messages[0].msg,
+        );
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!("moved.sc.pb", contract_info.bind_name);
+        assert_eq!(
+            CONTRACT_VERSION, contract_info.contract_version,
+            "the contract version should be unchanged by a bind name migration",
+        );
+    }
+
+    #[test]
+    fn test_update_bind_name() {
+        let mut deps = mock_dependencies(&[]);
         instantiate(
             deps.as_mut(),
             mock_env(),
@@ -2374,54 +10920,299 @@ mod tests {
                 contract_name: "contract_name".to_string(),
                 ask_fee: None,
                 bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
             },
         )
         .unwrap();
-        let err = update_fees(deps.as_mut(), mock_info("not_admin", &[]), None, None)
-            .expect_err("an error should occur when a non-admin attempts to update fees");
+        let update_bind_name_msg = |bind_name: &str| ExecuteMsg::UpdateBindName {
+            bind_name: bind_name.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not_admin", &[]),
+            update_bind_name_msg("moved.sc.pb"),
+        )
+        .expect_err("an error should occur when a non-admin attempts to update the bind name");
         assert!(
             matches!(err, ContractError::Unauthorized {}),
-            "an unauthorized error should occur when a non-admin attempts to update fees, but got: {:?}",
+            "an unauthorized error should occur when a non-admin attempts to update the bind name, but got: {:?}",
             err,
         );
-        let err = update_fees(
+        let err = execute(
             deps.as_mut(),
+            mock_env(),
             mock_info("contract_admin", &coins(1000, "nhash")),
-            None,
-            None,
+            update_bind_name_msg("moved.sc.pb"),
         )
         .expect_err("an error should occur when the admin provides funds");
         assert!(
-            matches!(err, ContractError::UpdateFeesWithFunds {}),
-            "an update fees with funds error should occur, but got: {:?}",
+            matches!(err, ContractError::UpdateBindNameWithFunds {}),
+            "an update bind name with funds error should occur, but got: {:?}",
             err,
         );
-        let err = update_fees(
+        let err = execute(
             deps.as_mut(),
+            mock_env(),
             mock_info("contract_admin", &[]),
-            Some(Uint128::zero()),
-            None,
+            update_bind_name_msg(""),
         )
-        .expect_err("an error should occur when the ask fee is zero");
-        match err {
-            ContractError::InvalidFee { fee_type } => {
-                assert_eq!("ask", fee_type);
-            }
-            e => panic!("unexpected error when ask fee is zero: {:?}", e),
-        };
-        let err = update_fees(
+        .expect_err("updating to an empty bind name should fail");
+        assert!(
+            matches!(err, ContractError::MissingField { ref field } if field == "bind_name"),
+            "a missing field error should occur for an empty bind name, but got: {:?}",
+            err,
+        );
+        assert_eq!(
+            "examples.sc.pb",
+            get_contract_info(&deps.storage).unwrap().bind_name,
+            "the bind name should be unchanged by rejected updates",
+        );
+        let response = execute(
             deps.as_mut(),
+            mock_env(),
             mock_info("contract_admin", &[]),
-            None,
-            Some(Uint128::zero()),
+            update_bind_name_msg("moved.sc.pb"),
         )
-        .expect_err("an error should occur when the bid fee is zero");
-        match err {
-            ContractError::InvalidFee { fee_type } => {
-                assert_eq!("bid", fee_type);
-            }
-            e => panic!("unexpected error when the bid fee is zero: {:?}", e),
-        };
+        .expect("updating the bind name should succeed");
+        assert_eq!(1, response.messages.len());
+        assert_eq!(
+            CosmosMsg::Custom(ProvenanceMsg {
+                route: ProvenanceRoute::Name,
+                params: ProvenanceMsgParams::Name(NameMsgParams::BindName {
+                    name: "moved.sc.pb".to_string(),
+                    address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    restrict: true,
+                }),
+                version: "2.0.0".to_string(),
+            }),
+            response.messages[0].msg,
+        );
+        assert_eq!(3, response.attributes.len());
+        assert_eq!(attr("action", "update_bind_name"), response.attributes[0]);
+        assert_eq!(
+            attr("previous_bind_name", "examples.sc.pb"),
+            response.attributes[1]
+        );
+        assert_eq!(attr("new_bind_name", "moved.sc.pb"), response.attributes[2]);
+        assert_eq!(
+            "moved.sc.pb",
+            get_contract_info(&deps.storage).unwrap().bind_name
+        );
+    }
+
+    #[test]
+    fn test_migrate_orders() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        set_stored_contract_version(&mut deps.storage, "0.0.1");
+        get_ask_storage(&mut deps.storage)
+            .save(
+                b"v1_ask",
+                &AskOrder {
+                    base: coins(100, "base_1"),
+                    id: "v1_ask".into(),
+                    owner: Addr::unchecked("asker"),
+                    quote: coins(200, "quote_1"),
+                },
+            )
+            .unwrap();
+        get_bid_storage(&mut deps.storage)
+            .save(
+                b"v1_bid",
+                &BidOrder {
+                    base: coins(100, "base_1"),
+                    effective_time: Some(Timestamp::from_seconds(10)),
+                    id: "v1_bid".into(),
+                    owner: Addr::unchecked("bidder"),
+                    quote: coins(200, "quote_1"),
+                },
+            )
+            .unwrap();
+        // seed a v2 ask serialized before expirations were introduced directly into raw storage
+        let legacy_ask_key = [
+            to_length_prefixed(NAMESPACE_ORDER_ASK_V2),
+            b"v2_ask".to_vec(),
+        ]
+        .concat();
+        deps.storage.set(
+            &legacy_ask_key,
+            br#"{"base":{"scope":{"scope_address":"scope1234"}},"id":"v2_ask","owner":"asker","quote":[{"denom":"quote_1","amount":"300"}]}"#,
+        );
+
+        let response = migrate(deps.as_mut(), mock_env(), MigrateMsg::MigrateOrders {})
+            .expect("migrating orders should succeed");
+        assert!(response.attributes.contains(&attr("migrated_asks", "2")));
+        assert!(response.attributes.contains(&attr("migrated_bids", "1")));
+
+        assert_eq!(
+            AskOrderV2 {
+                base: BaseType::coin(100, "base_1"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "v1_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(200, "quote_1"),
+            },
+            get_ask_storage_read_v2(&deps.storage)
+                .load(b"v1_ask")
+                .expect("the v1 ask should be moved to v2 storage"),
+        );
+        assert_eq!(
+            BidOrderV2 {
+                accepted_ask_quote: None,
+                base: BaseType::coin(100, "base_1"),
+                effective_time: Some(Timestamp::from_seconds(10)),
+                expiration: None,
+                id: "v1_bid".into(),
+                owner: Addr::unchecked("bidder"),
+                quote: coins(200, "quote_1"),
+                requested_data_access: vec![],
+                value_owner_override: None,
+            },
+            get_bid_storage_read_v2(&deps.storage)
+                .load(b"v1_bid")
+                .expect("the v1 bid should be moved to v2 storage"),
+        );
+        assert!(get_ask_storage_read(&deps.storage)
+            .may_load(b"v1_ask")
+            .unwrap()
+            .is_none());
+        assert!(get_bid_storage_read(&deps.storage)
+            .may_load(b"v1_bid")
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            AskOrderV2 {
+                base: BaseType::scope("scope1234"),
+                expiration: None,
+                expected_previous_owner: None,
+                id: "v2_ask".into(),
+                owner: Addr::unchecked("asker"),
+                quote: coins(300, "quote_1"),
+            },
+            get_ask_storage_read_v2(&deps.storage)
+                .load(b"v2_ask")
+                .expect("the legacy v2 ask should be readable after migration"),
+        );
+        let migrated_bytes = deps.storage.get(&legacy_ask_key).unwrap();
+        assert!(
+            String::from_utf8(migrated_bytes)
+                .unwrap()
+                .contains("\"expiration\""),
+            "the legacy v2 ask should be re-saved with the expiration field",
+        );
+    }
+
+    #[test]
+    fn test_migrate_orders_with_conflicting_ids() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                bind_name: "examples.sc.pb".to_string(),
+                contract_name: "contract_name".to_string(),
+                ask_fee: None,
+                bid_fee: None,
+                allowed_quote_denoms: None,
+                match_fee: None,
+                allow_self_match: None,
+                min_base_amount: None,
+                min_quote_amount: None,
+                initial_ask: None,
+                fee_mode: None,
+                max_orders_per_owner: None,
+            },
+        )
+        .unwrap();
+        set_stored_contract_version(&mut deps.storage, "0.0.1");
+        get_ask_storage(&mut deps.storage)
+            .save(
+                b"ask_id",
+                &AskOrder {
+                    base: coins(100, "base_1"),
+                    id: "ask_id".into(),
+                    owner: Addr::unchecked("asker"),
+                    quote: coins(200, "quote_1"),
+                },
+            )
+            .unwrap();
+        get_ask_storage_v2(&mut deps.storage)
+            .save(
+                b"ask_id",
+                &AskOrderV2 {
+                    base: BaseType::coin(50, "base_1"),
+                    expiration: None,
+                    expected_previous_owner: None,
+                    id: "ask_id".into(),
+                    owner: Addr::unchecked("other_asker"),
+                    quote: coins(100, "quote_1"),
+                },
+            )
+            .unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg::MigrateOrders {})
+            .expect_err("migrating a v1 ask with an id already in v2 storage should fail");
+        assert!(
+            matches!(err, ContractError::Std(StdError::GenericErr { .. })),
+            "a generic error should occur when order ids conflict, but got: {:?}",
+            err,
+        );
+    }
+
+    fn set_stored_contract_version(storage: &mut dyn Storage, version: &str) {
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.contract_version = version.into();
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn test_marker(denom: &str, admins: &[&str]) -> Marker {
+        Marker {
+            address: Addr::unchecked(format!("{}_address", denom)),
+            coins: vec![],
+            account_number: 1,
+            sequence: 0,
+            manager: "".to_string(),
+            permissions: admins
+                .iter()
+                .map(|admin| AccessGrant {
+                    permissions: vec![MarkerAccess::Admin],
+                    address: Addr::unchecked(*admin),
+                })
+                .collect(),
+            status: MarkerStatus::Active,
+            denom: denom.to_string(),
+            total_supply: Decimal::from_str("1000").unwrap(),
+            marker_type: MarkerType::Restricted,
+            supply_fixed: true,
+        }
     }
 
     fn handle_expected_coin<A: FnOnce(&Vec<Coin>) -> ()>(base_type: &BaseType, action: A) {
@@ -2446,17 +11237,39 @@ mod tests {
     ) {
         match response {
             Ok(response) => {
+                // bids also emit an escrow receipt
                 assert_eq!(
                     response.attributes.len(),
-                    1 + if expected_fee.is_some() { 1 } else { 0 }
+                    4 + if expected_fee_type == "Bid" { 1 } else { 0 }
+                        + if expected_fee.is_some() { 1 } else { 0 }
                 );
                 assert_eq!(
                     response.attributes[0],
                     attr("action", expected_action_attribute_value)
                 );
+                assert_eq!(
+                    response.attributes[1].key,
+                    format!("{}_order", expected_fee_type.to_lowercase()),
+                );
+                assert_eq!(
+                    response.data,
+                    Some(Binary::from(response.attributes[1].value.as_bytes())),
+                    "the order attribute should contain the same JSON as the response data",
+                );
+                assert_eq!(
+                    response.attributes[2],
+                    attr(
+                        "order_id",
+                        format!("{}_id", expected_fee_type.to_lowercase())
+                    ),
+                );
+                assert_eq!(
+                    response.attributes[3],
+                    attr("order_type", expected_fee_type.to_lowercase()),
+                );
                 if let Some(fee) = expected_fee {
                     assert_eq!(
-                        response.attributes[1],
+                        response.attributes.last().unwrap(),
                         attr("fee_charged", format!("{}nhash", fee.to_string())),
                     );
                     assert_eq!(1, response.messages.len());
@@ -2512,6 +11325,10 @@ mod tests {
             id: "ask_id".into(),
             quote: coins(100, "quote_1"),
             scope_address: None,
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
         };
 
         let asker_info = mock_info("asker", &coins(2, "base_1"));
@@ -2533,6 +11350,10 @@ mod tests {
             id,
             quote,
             scope_address: None,
+            marker_denom: None,
+            expiration,
+            exact_base: None,
+            expected_previous_owner: None,
         } = create_ask_msg
         {
             match ask_storage.load("ask_id".to_string().as_bytes()) {
@@ -2541,6 +11362,8 @@ mod tests {
                         stored_order,
                         AskOrderV2 {
                             base: BaseType::coins(asker_info.funds),
+                            expiration,
+                            expected_previous_owner: None,
                             id,
                             owner: asker_info.sender,
                             quote,
@@ -2578,6 +11401,10 @@ mod tests {
             id: "ask_id".into(),
             quote: coins(100, "quote_1"),
             scope_address: Some(scope_address.clone()),
+            marker_denom: None,
+            expiration: None,
+            exact_base: None,
+            expected_previous_owner: None,
         };
 
         let asker_info = mock_info("asker", &[]);
@@ -2610,6 +11437,10 @@ mod tests {
             id,
             quote,
             scope_address,
+            marker_denom: None,
+            expiration,
+            exact_base: None,
+            expected_previous_owner: None,
         } = create_ask_msg
         {
             match ask_storage.load("ask_id".to_string().as_bytes()) {
@@ -2618,6 +11449,8 @@ mod tests {
                         stored_order,
                         AskOrderV2 {
                             base: BaseType::scope(scope_address.unwrap()),
+                            expiration,
+                            expected_previous_owner: None,
                             id,
                             owner: asker_info.sender,
                             quote,
@@ -2653,6 +11486,10 @@ mod tests {
             id: "bid_id".into(),
             base: BaseType::coin(100, "base_1"),
             effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(2, "mark_2"));
@@ -2674,6 +11511,10 @@ mod tests {
             id,
             base,
             effective_time,
+            expiration,
+            accepted_ask_quote,
+            requested_data_access: None,
+            value_owner_override: None,
         } = create_bid_msg
         {
             match bid_storage.load("bid_id".to_string().as_bytes()) {
@@ -2681,11 +11522,15 @@ mod tests {
                     assert_eq!(
                         stored_order,
                         BidOrderV2 {
+                            accepted_ask_quote,
                             base,
                             effective_time,
+                            expiration,
                             id,
                             owner: bidder_info.sender,
                             quote: bidder_info.funds,
+                            requested_data_access: vec![],
+                            value_owner_override: None,
                         }
                     )
                 }
@@ -2718,6 +11563,10 @@ mod tests {
             id: "bid_id".into(),
             base: BaseType::scope("scope1234"),
             effective_time: Some(Timestamp::default()),
+            expiration: None,
+            accepted_ask_quote: None,
+            requested_data_access: None,
+            value_owner_override: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(2, "mark_2"));
@@ -2739,6 +11588,10 @@ mod tests {
             id,
             base,
             effective_time,
+            expiration,
+            accepted_ask_quote,
+            requested_data_access: None,
+            value_owner_override: None,
         } = create_bid_msg
         {
             match bid_storage.load("bid_id".to_string().as_bytes()) {
@@ -2746,11 +11599,15 @@ mod tests {
                     assert_eq!(
                         stored_order,
                         BidOrderV2 {
+                            accepted_ask_quote,
                             base,
                             effective_time,
+                            expiration,
                             id,
                             owner: bidder_info.sender,
                             quote: bidder_info.funds,
+                            requested_data_access: vec![],
+                            value_owner_override: None,
                         }
                     )
                 }
@@ -2763,3 +11620,143 @@ mod tests {
         }
     }
 }
+
+// property tests for is_executable, which generate arbitrary order pairs to catch regressions in how coin vectors are
+// normalized before they are compared
+#[cfg(test)]
+mod is_executable_proptests {
+    use cosmwasm_std::{coin, Addr, Coin};
+    use proptest::prelude::*;
+
+    use super::is_executable;
+    use crate::state::{AskOrderV2, BaseType, BidOrderV2};
+
+    // a non-empty list of coins with distinct denoms, matching how coins are merged by denom before an order is stored
+    fn coins_strategy() -> impl Strategy<Value = Vec<Coin>> {
+        prop::collection::btree_map("[a-z]{1,8}", 1u128..1_000_000, 1..5).prop_map(|coins| {
+            coins
+                .into_iter()
+                .map(|(denom, amount)| coin(amount, denom))
+                .collect()
+        })
+    }
+
+    // a list of coins paired with the same coins in a random order
+    fn shuffled_coins_strategy() -> impl Strategy<Value = (Vec<Coin>, Vec<Coin>)> {
+        coins_strategy().prop_flat_map(|coins| (Just(coins.to_owned()), Just(coins).prop_shuffle()))
+    }
+
+    fn scope_address_strategy() -> impl Strategy<Value = String> {
+        "scope1[a-z0-9]{38}"
+    }
+
+    // renames the first coin's denom so that the resulting list can never contain the same denoms as the original
+    fn with_differing_denom(coins: &[Coin]) -> Vec<Coin> {
+        let mut coins = coins.to_vec();
+        coins[0].denom = format!("{}_other", coins[0].denom);
+        coins
+    }
+
+    fn ask_order(base: BaseType, quote: Vec<Coin>) -> AskOrderV2 {
+        AskOrderV2 {
+            base,
+            expiration: None,
+            expected_previous_owner: None,
+            id: "ask_id".into(),
+            owner: Addr::unchecked("asker"),
+            quote,
+        }
+    }
+
+    fn bid_order(base: BaseType, quote: Vec<Coin>) -> BidOrderV2 {
+        BidOrderV2 {
+            accepted_ask_quote: None,
+            base,
+            effective_time: None,
+            expiration: None,
+            id: "bid_id".into(),
+            owner: Addr::unchecked("bidder"),
+            quote,
+            requested_data_access: vec![],
+            value_owner_override: None,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn matching_orders_match_regardless_of_coin_order(
+            (base, shuffled_base) in shuffled_coins_strategy(),
+            (quote, shuffled_quote) in shuffled_coins_strategy(),
+        ) {
+            let ask = ask_order(BaseType::coins(base.to_owned()), quote.to_owned());
+            prop_assert!(is_executable(
+                &ask,
+                &bid_order(BaseType::coins(shuffled_base.to_owned()), shuffled_quote.to_owned())
+            ));
+            // an accepted ask quote is compared in place of the escrowed quote, so it must be normalized as well
+            let mut bid = bid_order(BaseType::coins(shuffled_base), quote);
+            bid.accepted_ask_quote = Some(shuffled_quote);
+            prop_assert!(is_executable(&ask, &bid));
+        }
+
+        #[test]
+        fn is_executable_is_unaffected_by_coin_order(
+            (ask_base, shuffled_ask_base) in shuffled_coins_strategy(),
+            (ask_quote, shuffled_ask_quote) in shuffled_coins_strategy(),
+            (bid_base, shuffled_bid_base) in shuffled_coins_strategy(),
+            (bid_quote, shuffled_bid_quote) in shuffled_coins_strategy(),
+        ) {
+            prop_assert_eq!(
+                is_executable(
+                    &ask_order(BaseType::coins(ask_base), ask_quote),
+                    &bid_order(BaseType::coins(bid_base), bid_quote),
+                ),
+                is_executable(
+                    &ask_order(BaseType::coins(shuffled_ask_base), shuffled_ask_quote),
+                    &bid_order(BaseType::coins(shuffled_bid_base), shuffled_bid_quote),
+                )
+            );
+        }
+
+        #[test]
+        fn orders_with_differing_base_denoms_never_match(
+            base in coins_strategy(),
+            quote in coins_strategy(),
+        ) {
+            prop_assert!(!is_executable(
+                &ask_order(BaseType::coins(base.to_owned()), quote.to_owned()),
+                &bid_order(BaseType::coins(with_differing_denom(&base)), quote)
+            ));
+        }
+
+        #[test]
+        fn orders_with_differing_quote_denoms_never_match(
+            base in coins_strategy(),
+            quote in coins_strategy(),
+        ) {
+            prop_assert!(!is_executable(
+                &ask_order(BaseType::coins(base.to_owned()), quote.to_owned()),
+                &bid_order(BaseType::coins(base), with_differing_denom(&quote))
+            ));
+        }
+
+        #[test]
+        fn scope_orders_only_match_the_same_scope(
+            ask_scope_address in scope_address_strategy(),
+            bid_scope_address in scope_address_strategy(),
+            quote in coins_strategy(),
+        ) {
+            prop_assert!(is_executable(
+                &ask_order(BaseType::scope(&ask_scope_address), quote.to_owned()),
+                &bid_order(BaseType::scope(&ask_scope_address), quote.to_owned())
+            ));
+            prop_assert_eq!(
+                ask_scope_address == bid_scope_address,
+                is_executable(
+                    &ask_order(BaseType::scope(&ask_scope_address), quote.to_owned()),
+                    &bid_order(BaseType::scope(&bid_scope_address), quote),
+                )
+            );
+        }
+    }
+}
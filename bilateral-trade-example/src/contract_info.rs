@@ -1,6 +1,7 @@
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, Coin, StdResult, Storage, Uint128};
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
+use semver::Version;
 use serde::{Deserialize, Serialize};
 
 use crate::error::ContractError;
@@ -20,6 +21,43 @@ pub struct ContractInfo {
     pub contract_version: String,
     pub ask_fee: Option<Uint128>,
     pub bid_fee: Option<Uint128>,
+    #[serde(default)]
+    pub allowed_quote_denoms: Option<Vec<String>>,
+    #[serde(default)]
+    pub match_fee: Option<Uint128>,
+    #[serde(default)]
+    pub allow_self_match: bool,
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub min_base_amount: Option<Vec<Coin>>,
+    #[serde(default)]
+    pub min_quote_amount: Option<Vec<Coin>>,
+    #[serde(default)]
+    pub fee_mode: FeeMode,
+    #[serde(default)]
+    pub max_orders_per_owner: Option<u32>,
+}
+
+/// Determines how the ask and bid fees are charged.  The match fee is always a flat amount.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeMode {
+    /// The ask and bid fees are flat amounts of nhash, charged regardless of the order's quote.
+    Flat,
+    /// The ask and bid fees are basis points of the order's quote, charged in nhash.  Orders must
+    /// be quoted entirely in nhash, the reference denom, so that the fee can be computed from the
+    /// quote's total value.
+    BasisPoints,
+}
+
+// deriving Default with a #[default] variant requires a newer compiler than the contract optimizer provides
+#[allow(clippy::derivable_impls)]
+impl Default for FeeMode {
+    // contracts stored before fee modes were introduced only charged flat fees
+    fn default() -> Self {
+        FeeMode::Flat
+    }
 }
 
 impl ContractInfo {
@@ -38,6 +76,38 @@ impl ContractInfo {
             contract_version: CONTRACT_VERSION.into(),
             ask_fee,
             bid_fee,
+            allowed_quote_denoms: None,
+            match_fee: None,
+            allow_self_match: false,
+            paused: false,
+            min_base_amount: None,
+            min_quote_amount: None,
+            fee_mode: FeeMode::Flat,
+            max_orders_per_owner: None,
+        }
+    }
+
+    pub fn parse_contract_version(&self) -> Result<Version, ContractError> {
+        Ok(self.contract_version.parse()?)
+    }
+}
+
+/// The type and version of the contract code, allowing tooling to check compatibility without
+/// loading the full ContractInfo.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VersionInfo {
+    /// The name of the contract crate.  Mirrors the contract_type stored in ContractInfo.
+    pub contract_type: String,
+    /// The version of the contract crate.  Mirrors the contract_version stored in ContractInfo
+    /// after instantiation or migration.
+    pub contract_version: String,
+}
+
+impl VersionInfo {
+    pub fn current() -> VersionInfo {
+        VersionInfo {
+            contract_type: CONTRACT_TYPE.into(),
+            contract_version: CONTRACT_VERSION.into(),
         }
     }
 }
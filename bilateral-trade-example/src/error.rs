@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,41 +6,377 @@ pub enum ContractError {
     #[error("Ask Order does not match Bid Order")]
     AskBidMismatch {},
 
+    #[error("Invalid accepted ask quote: {explanation}")]
+    InvalidAcceptedAskQuote { explanation: String },
+
     #[error("Cannot send funds when canceling order")]
     CancelWithFunds {},
 
+    #[error("New orders cannot be created while the contract is paused")]
+    ContractPaused,
+
+    #[error("Quote denom [{denom}] is not allowed by this contract")]
+    DisallowedQuoteDenom { denom: String },
+
     #[error("Cannot send funds when executing match")]
     ExecuteWithFunds {},
 
     #[error("Ask base was not sent")]
     MissingAskBase,
 
+    #[error("Funds sent do not cover the exact base of [{exact_base}]")]
+    InsufficientBaseFunds { exact_base: String },
+
+    #[error("An order with id [{id}] already exists")]
+    OrderIdAlreadyExists { id: String },
+
+    #[error("Order amount [{amount}{denom}] is below the minimum of [{minimum}{denom}]")]
+    OrderTooSmall {
+        denom: String,
+        amount: Uint128,
+        minimum: Uint128,
+    },
+
+    #[error("Order [{id}] has expired")]
+    OrderExpired { id: String },
+
+    #[error("No expired order found with id [{id}]")]
+    OrderNotExpired { id: String },
+
+    #[error("Only coin bases can be partially matched")]
+    PartialMatchNonCoinBase,
+
+    #[error("Ask and bid cannot be owned by the same account")]
+    SelfMatch,
+
     #[error("Scope ask base cannot also be sent funds")]
     ScopeAskBaseWithFunds,
 
+    #[error("Marker ask base cannot also be sent funds")]
+    MarkerAskBaseWithFunds,
+
+    #[error("An ask can only include one of a scope address or a marker denom")]
+    MultipleAskBases,
+
+    #[error("Marker [{denom}] has invalid permissions: {explanation}")]
+    InvalidMarkerPermissions { denom: String, explanation: String },
+
     #[error("Scope at address [{scope_address}] has invalid owner: {explanation}")]
     InvalidScopeOwner {
         scope_address: String,
         explanation: String,
     },
 
+    #[error("Scope at address [{scope_address}] was rebuilt incorrectly: {explanation}")]
+    InvalidScopeRewrite {
+        scope_address: String,
+        explanation: String,
+    },
+
+    #[error("Scope at address [{scope_address}] is no longer held by the contract: {explanation}")]
+    ScopeNotHeldByContract {
+        scope_address: String,
+        explanation: String,
+    },
+
+    #[error("Address [{address}] is not a valid scope address")]
+    InvalidScopeAddress { address: String },
+
     #[error(
         "Invalid {fee_type} fee provided. The value must be omitted, or set to a value above zero"
     )]
     InvalidFee { fee_type: String },
 
+    #[error("Ask quote must include at least {match_fee}nhash to pay the match fee")]
+    InsufficientQuoteForMatchFee { match_fee: Uint128 },
+
+    #[error("Invalid requested data access: {explanation}")]
+    InvalidRequestedDataAccess { explanation: String },
+
+    #[error("Invalid fill amount: {explanation}")]
+    InvalidFillAmount { explanation: String },
+
+    #[error("Invalid contract version: {explanation}")]
+    InvalidVersion { explanation: String },
+
     #[error("Missing field: {field:?}")]
     MissingField { field: String },
 
     #[error("Bid quote was not sent")]
     MissingBidQuote,
 
+    #[error("{0}")]
+    SemVer(#[from] semver::Error),
+
     #[error("{0}")]
     Std(#[from] StdError),
 
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Cannot send funds when updating the admin")]
+    UpdateAdminWithFunds {},
+
     #[error("Cannot send funds when updating fees")]
     UpdateFeesWithFunds {},
+
+    #[error("Cannot send funds when updating quote denoms")]
+    UpdateQuoteDenomsWithFunds {},
+
+    #[error("Cannot send funds when pausing or unpausing the contract")]
+    SetPausedWithFunds {},
+
+    #[error("Cannot send funds when updating minimum order sizes")]
+    UpdateMinOrderSizesWithFunds {},
+
+    #[error("Cannot send funds when draining the contract")]
+    DrainAllWithFunds {},
+
+    #[error("Invalid quote for fee: {explanation}")]
+    InvalidQuoteForFee { explanation: String },
+
+    #[error("Invalid {fee_type} fee of {basis_points} basis points. The fee cannot exceed 10000 basis points")]
+    InvalidFeeBasisPoints {
+        fee_type: String,
+        basis_points: Uint128,
+    },
+
+    #[error("Invalid value owner override: {explanation}")]
+    InvalidValueOwnerOverride { explanation: String },
+
+    #[error("Invalid ask reduction: {explanation}")]
+    InvalidAskReduction { explanation: String },
+
+    #[error("Coin [{denom}] cannot have an amount of zero")]
+    ZeroAmountCoin { denom: String },
+
+    #[error("Account [{owner}] already has the maximum of {max_orders} open orders")]
+    TooManyOpenOrders { owner: String, max_orders: u32 },
+
+    #[error("Invalid expected previous owner: {explanation}")]
+    InvalidExpectedPreviousOwner { explanation: String },
+
+    #[error("Cannot send funds when updating the bind name")]
+    UpdateBindNameWithFunds {},
+}
+
+impl ContractError {
+    /// Returns a stable numeric code for the error's variant, allowing off-chain code to distinguish
+    /// failures without matching on message text.  Codes are never reassigned, so new variants must
+    /// take the next unused code.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AskBidMismatch {} => 1,
+            Self::InvalidAcceptedAskQuote { .. } => 2,
+            Self::CancelWithFunds {} => 3,
+            Self::ContractPaused => 4,
+            Self::DisallowedQuoteDenom { .. } => 5,
+            Self::ExecuteWithFunds {} => 6,
+            Self::MissingAskBase => 7,
+            Self::InsufficientBaseFunds { .. } => 8,
+            Self::OrderIdAlreadyExists { .. } => 9,
+            Self::OrderExpired { .. } => 10,
+            Self::OrderNotExpired { .. } => 11,
+            Self::PartialMatchNonCoinBase => 12,
+            Self::SelfMatch => 13,
+            Self::ScopeAskBaseWithFunds => 14,
+            Self::MarkerAskBaseWithFunds => 15,
+            Self::MultipleAskBases => 16,
+            Self::InvalidMarkerPermissions { .. } => 17,
+            Self::InvalidScopeOwner { .. } => 18,
+            Self::InvalidScopeRewrite { .. } => 19,
+            Self::InvalidFee { .. } => 20,
+            Self::InsufficientQuoteForMatchFee { .. } => 21,
+            Self::InvalidFillAmount { .. } => 22,
+            Self::InvalidVersion { .. } => 23,
+            Self::MissingField { .. } => 24,
+            Self::MissingBidQuote => 25,
+            Self::SemVer(_) => 26,
+            Self::Std(_) => 27,
+            Self::Unauthorized {} => 28,
+            Self::UpdateAdminWithFunds {} => 29,
+            Self::UpdateFeesWithFunds {} => 30,
+            Self::UpdateQuoteDenomsWithFunds {} => 31,
+            Self::SetPausedWithFunds {} => 32,
+            Self::InvalidRequestedDataAccess { .. } => 33,
+            Self::ScopeNotHeldByContract { .. } => 34,
+            Self::OrderTooSmall { .. } => 35,
+            Self::UpdateMinOrderSizesWithFunds {} => 36,
+            Self::InvalidScopeAddress { .. } => 37,
+            Self::DrainAllWithFunds {} => 38,
+            Self::InvalidQuoteForFee { .. } => 39,
+            Self::InvalidFeeBasisPoints { .. } => 40,
+            Self::InvalidValueOwnerOverride { .. } => 41,
+            Self::InvalidAskReduction { .. } => 42,
+            Self::ZeroAmountCoin { .. } => 43,
+            Self::TooManyOpenOrders { .. } => 44,
+            Self::InvalidExpectedPreviousOwner { .. } => 45,
+            Self::UpdateBindNameWithFunds {} => 46,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContractError;
+    use cosmwasm_std::{StdError, Uint128};
+
+    #[test]
+    fn error_codes_are_stable() {
+        let errors = vec![
+            (ContractError::AskBidMismatch {}, 1),
+            (
+                ContractError::InvalidAcceptedAskQuote {
+                    explanation: "".into(),
+                },
+                2,
+            ),
+            (ContractError::CancelWithFunds {}, 3),
+            (ContractError::ContractPaused, 4),
+            (ContractError::DisallowedQuoteDenom { denom: "".into() }, 5),
+            (ContractError::ExecuteWithFunds {}, 6),
+            (ContractError::MissingAskBase, 7),
+            (
+                ContractError::InsufficientBaseFunds {
+                    exact_base: "".into(),
+                },
+                8,
+            ),
+            (ContractError::OrderIdAlreadyExists { id: "".into() }, 9),
+            (ContractError::OrderExpired { id: "".into() }, 10),
+            (ContractError::OrderNotExpired { id: "".into() }, 11),
+            (ContractError::PartialMatchNonCoinBase, 12),
+            (ContractError::SelfMatch, 13),
+            (ContractError::ScopeAskBaseWithFunds, 14),
+            (ContractError::MarkerAskBaseWithFunds, 15),
+            (ContractError::MultipleAskBases, 16),
+            (
+                ContractError::InvalidMarkerPermissions {
+                    denom: "".into(),
+                    explanation: "".into(),
+                },
+                17,
+            ),
+            (
+                ContractError::InvalidScopeOwner {
+                    scope_address: "".into(),
+                    explanation: "".into(),
+                },
+                18,
+            ),
+            (
+                ContractError::InvalidScopeRewrite {
+                    scope_address: "".into(),
+                    explanation: "".into(),
+                },
+                19,
+            ),
+            (
+                ContractError::InvalidFee {
+                    fee_type: "".into(),
+                },
+                20,
+            ),
+            (
+                ContractError::InsufficientQuoteForMatchFee {
+                    match_fee: Uint128::zero(),
+                },
+                21,
+            ),
+            (
+                ContractError::InvalidFillAmount {
+                    explanation: "".into(),
+                },
+                22,
+            ),
+            (
+                ContractError::InvalidVersion {
+                    explanation: "".into(),
+                },
+                23,
+            ),
+            (ContractError::MissingField { field: "".into() }, 24),
+            (ContractError::MissingBidQuote, 25),
+            (
+                ContractError::SemVer("not.a.version".parse::<semver::Version>().unwrap_err()),
+                26,
+            ),
+            (ContractError::Std(StdError::generic_err("")), 27),
+            (ContractError::Unauthorized {}, 28),
+            (ContractError::UpdateAdminWithFunds {}, 29),
+            (ContractError::UpdateFeesWithFunds {}, 30),
+            (ContractError::UpdateQuoteDenomsWithFunds {}, 31),
+            (ContractError::SetPausedWithFunds {}, 32),
+            (
+                ContractError::InvalidRequestedDataAccess {
+                    explanation: "".into(),
+                },
+                33,
+            ),
+            (
+                ContractError::ScopeNotHeldByContract {
+                    scope_address: "".into(),
+                    explanation: "".into(),
+                },
+                34,
+            ),
+            (
+                ContractError::OrderTooSmall {
+                    denom: "".into(),
+                    amount: Uint128::zero(),
+                    minimum: Uint128::zero(),
+                },
+                35,
+            ),
+            (ContractError::UpdateMinOrderSizesWithFunds {}, 36),
+            (
+                ContractError::InvalidScopeAddress { address: "".into() },
+                37,
+            ),
+            (ContractError::DrainAllWithFunds {}, 38),
+            (
+                ContractError::InvalidQuoteForFee {
+                    explanation: "".into(),
+                },
+                39,
+            ),
+            (
+                ContractError::InvalidFeeBasisPoints {
+                    fee_type: "".into(),
+                    basis_points: Uint128::zero(),
+                },
+                40,
+            ),
+            (
+                ContractError::InvalidValueOwnerOverride {
+                    explanation: "".into(),
+                },
+                41,
+            ),
+            (
+                ContractError::InvalidAskReduction {
+                    explanation: "".into(),
+                },
+                42,
+            ),
+            (ContractError::ZeroAmountCoin { denom: "".into() }, 43),
+            (
+                ContractError::TooManyOpenOrders {
+                    owner: "".into(),
+                    max_orders: 0,
+                },
+                44,
+            ),
+            (
+                ContractError::InvalidExpectedPreviousOwner {
+                    explanation: "".into(),
+                },
+                45,
+            ),
+            (ContractError::UpdateBindNameWithFunds {}, 46),
+        ];
+        for (error, code) in errors {
+            assert_eq!(code, error.code(), "unexpected code for error: {:?}", error);
+        }
+    }
 }
@@ -1,8 +1,9 @@
-use cosmwasm_std::{Coin, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::BaseType;
+use crate::contract_info::FeeMode;
+use crate::state::{AskOrderV2, BaseType, BidOrderV2};
 
 /// Constructs a new instance of the smart contract.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -22,6 +23,64 @@ pub struct InstantiateMsg {
     /// Blockchain Fee Module, which will take 50% of the fees sent and redistribute them to various
     /// external entities.  The other 50% will be retained and sent to the contract's admin account.
     pub bid_fee: Option<Uint128>,
+    /// The denoms that asks may be quoted in and bids may be funded with.  If this value is
+    /// omitted, any denom is accepted.  Providing an empty list will produce an error.
+    pub allowed_quote_denoms: Option<Vec<String>>,
+    /// An amount of nhash to be charged when an ask and bid are matched, including each partial
+    /// match.  The fee is deducted from the quote before it reaches the asker, so asks must be
+    /// quoted with at least this much nhash, and asks that are not will be rejected.
+    /// This uses the Provenance Blockchain Fee Module, which will take 50% of the fee and
+    /// redistribute it to various external entities.  The other 50% will be retained by the
+    /// contract's admin account.
+    pub match_fee: Option<Uint128>,
+    /// Whether or not an ask and bid owned by the same account can be matched.  If omitted, orders
+    /// with the same owner cannot be matched, preventing wash trading and accidental self-dealing.
+    pub allow_self_match: Option<bool>,
+    /// The smallest amount of each denom that a coin base may contain.  Denoms that are not listed
+    /// have no minimum, and scope and marker bases are never checked.  If omitted, bases of any
+    /// size are accepted.
+    pub min_base_amount: Option<Vec<Coin>>,
+    /// The smallest amount of each denom that an ask's quote or a bid's funds may contain.  Denoms
+    /// that are not listed have no minimum.  If omitted, quotes of any size are accepted.
+    pub min_quote_amount: Option<Vec<Coin>>,
+    /// How the ask and bid fees are charged.  If omitted, they are flat amounts of nhash.  In the
+    /// basis points mode, each fee is a share of the order's quote, orders must be quoted entirely
+    /// in nhash, and neither fee may exceed 10000 basis points.
+    pub fee_mode: Option<FeeMode>,
+    /// The most asks and bids that a single account may have open at once.  Asks and bids are
+    /// counted together.  If omitted, accounts may open any amount of orders.
+    pub max_orders_per_owner: Option<u32>,
+    /// An ask to record for the instantiating account as soon as the contract is created, like a
+    /// house order.  If omitted, the contract starts with no orders.
+    pub initial_ask: Option<InitialAsk>,
+}
+
+/// A scope ask created during instantiation, owned by the instantiating account.  It receives the
+/// same validation as the CreateScopeAsk route: the instantiating account must be the scope's sole
+/// owner and value owner, and ownership is transferred to the contract in the instantiation
+/// response.  A scope already transferred to the contract cannot be used, because the contract's
+/// address does not exist until it is instantiated.
+///
+/// Only scope bases can be seeded.  A coin base must be escrowed by sending its funds with the
+/// ask, which would require sending them with the instantiation, and a marker base requires the
+/// contract to already hold admin access to the marker.  Either can be listed with CreateAsk once
+/// the contract exists.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InitialAsk {
+    /// The unique identifier for the ask.
+    pub id: String,
+    /// The coins that must be paid for the scope by a matching bid.
+    pub quote: Vec<Coin>,
+    /// The bech32 address of the scope being listed.
+    pub scope_address: String,
+}
+
+/// Identifies which side of the order book an order belongs to.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AskOrBid {
+    Ask,
+    Bid,
 }
 
 /// Executes the smart contract, causing changes reflected in Provenance Blockchain transactions.
@@ -29,12 +88,23 @@ pub struct InstantiateMsg {
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     /// Removes an ask from the contract's storage and refunds the base (Provenance Blockchain
-    /// Metadata Scope or Coin).  Ask creation fees are not refunded.
+    /// Metadata Scope, Provenance Blockchain Marker or Coin).  Ask creation fees are not refunded.
     CancelAsk {
         /// The unique identifier for the ask to cancel.  If no ask with this value exists in
         /// contract storage, an error will be returned.
         id: String,
     },
+    /// Withdraws some of the coins from an ask with a Coin base, refunding them to the ask's owner
+    /// and leaving the remainder listed.  If no coins remain, the ask is removed entirely.  Asks
+    /// for a Provenance Blockchain Metadata Scope or Marker cannot be reduced.
+    ReduceAsk {
+        /// The unique identifier for the ask to reduce.  If no ask with this value exists in
+        /// contract storage, an error will be returned.
+        id: String,
+        /// The coins to withdraw from the ask's base.  Each denom must exist in the base with at
+        /// least the amount being withdrawn.
+        remove: Vec<Coin>,
+    },
     /// Removes a bid from the contract's storage and refunds the quote funds provided.  Bid creation
     /// fees are not refunded.
     CancelBid {
@@ -42,17 +112,82 @@ pub enum ExecuteMsg {
         /// contract storage, an error will be returned.
         id: String,
     },
-    /// Creates a new AskOrder, holding the given base Coin or Provenance Blockchain Metadata Scope
-    /// in the smart contract until a cancellation occurs or a match is made.
+    /// Adds the funds provided in the transaction to the quote of an existing bid, allowing the
+    /// bidder to raise their offer without cancelling and re-creating the bid.  Funds in a denom
+    /// already in the quote are added to it.  Only the bid's owner can use this route, and an
+    /// expired bid cannot be increased.
+    IncreaseBid {
+        /// The unique identifier for the bid to increase.  If no bid with this value exists in
+        /// contract storage, an error will be returned.
+        id: String,
+    },
+    /// Removes every ask and bid owned by an account from the contract's storage, refunding the base
+    /// of each ask and the quote of each bid.  Order creation fees are not refunded.
+    CancelAllForOwner {
+        /// The bech32 address of the account whose orders will be cancelled.  If omitted, the
+        /// sender's orders are cancelled.  Only the contract's admin account can cancel the orders
+        /// of a different account.
+        owner: Option<String>,
+    },
+    /// Removes any ask or bid from the contract's storage on behalf of its owner, refunding the
+    /// ask's base or the bid's quote to the owner.  The reason is emitted as an attribute so that
+    /// the removal can be audited.  Only the contract's admin account can use this route.
+    AdminCancel {
+        /// Whether the order to cancel is an ask or a bid.
+        order_type: AskOrBid,
+        /// The unique identifier for the order to cancel.  If no order of the given type exists
+        /// with this value, an error will be returned.
+        id: String,
+        /// An explanation of why the order was cancelled.  Cannot be empty.
+        reason: String,
+    },
+    /// Creates a new AskOrder, holding the given base Coin, Provenance Blockchain Metadata Scope or
+    /// Provenance Blockchain Marker in the smart contract until a cancellation occurs or a match is
+    /// made.
     CreateAsk {
         /// The unique identifier for the new ask to create.  If an ask already exists with the
         /// given id, an error will be returned.
         id: String,
         /// The funds that a bidder must provide for a match to be successfully executed.
         quote: Vec<Coin>,
-        /// The address of a scope to list for trade.  If this value is omitted, funds must be
-        /// provided in the execute message transaction.
+        /// The address of a scope to list for trade.  If this value and marker_denom are omitted,
+        /// funds must be provided in the execute message transaction.
         scope_address: Option<String>,
+        /// The denom of a marker to list for trade.  The contract must already have been granted
+        /// admin access to the marker, and the sender's access to the marker will be revoked.  If
+        /// this value and scope_address are omitted, funds must be provided in the execute message
+        /// transaction.
+        marker_denom: Option<String>,
+        /// An optional time after which the ask can no longer be matched.  Expired asks can be
+        /// removed by anyone via the ReapExpired route, which returns the base to the asker.  If
+        /// omitted, the ask never expires.
+        expiration: Option<Timestamp>,
+        /// The exact coins to list as the base when funds are provided.  Any funds sent beyond this
+        /// amount are refunded to the sender.  If omitted, all provided funds become the base.
+        /// Cannot be used when listing a scope or marker.
+        exact_base: Option<Vec<Coin>>,
+        /// The account that owned the scope before it was transferred to the contract.  When
+        /// provided, a cancelled ask returns the scope to this account instead of the asker, so
+        /// listing a scope that was transferred by another account cannot redirect it.  Can only be
+        /// used when listing a scope.
+        expected_previous_owner: Option<String>,
+    },
+    /// Creates a new AskOrder for a Provenance Blockchain Metadata Scope owned by the sender,
+    /// transferring ownership of the scope to the smart contract in the same message.  This avoids
+    /// transferring the scope to the contract in a separate message before the ask is created.
+    CreateScopeAsk {
+        /// The unique identifier for the new ask to create.  If an ask already exists with the
+        /// given id, an error will be returned.
+        id: String,
+        /// The funds that a bidder must provide for a match to be successfully executed.
+        quote: Vec<Coin>,
+        /// The address of the scope to list for trade.  The sender must be the scope's sole owner
+        /// and its value owner, or an error will be returned.
+        scope_address: String,
+        /// An optional time after which the ask can no longer be matched.  Expired asks can be
+        /// removed by anyone via the ReapExpired route, which returns the scope to the asker.  If
+        /// omitted, the ask never expires.
+        expiration: Option<Timestamp>,
     },
     /// Creates a new BidOrder, holding the given quote Coin in the smart contract until a
     /// cancellation occurs or a match is made.
@@ -60,10 +195,28 @@ pub enum ExecuteMsg {
         /// The unique identifier for the new bid to create.  If a bid already exists with the
         /// given id, an error will be returned.
         id: String,
-        /// Indicates the type of exchange that will be made: scope or coin.
+        /// Indicates the type of exchange that will be made: scope, marker or coin.
         base: BaseType,
         /// An optional timestamp denoting when the bid was created.
         effective_time: Option<Timestamp>,
+        /// An optional time after which the bid can no longer be matched.  Expired bids can be
+        /// removed by anyone via the ReapExpired route, which returns the quote to the bidder.  If
+        /// omitted, the bid never expires.
+        expiration: Option<Timestamp>,
+        /// The quote that an ask must have for a match to be executed.  If omitted, the ask's quote
+        /// must equal the funds provided in the execute message transaction.  Each coin must be
+        /// covered by the funds provided, which remain held by the smart contract.  Any funds in
+        /// excess of this quote are returned to the bidder when a match is executed.
+        accepted_ask_quote: Option<Vec<Coin>>,
+        /// The bech32 addresses to grant data access to the scope when a match is executed, in
+        /// addition to any data access the scope already grants to accounts other than the asker.
+        /// Can only be provided when bidding on a scope.
+        requested_data_access: Option<Vec<String>>,
+        /// The bech32 address to set as the scope's value owner when a match is executed, allowing
+        /// the value owner to remain with a custodian while the bidder becomes the scope's owner.
+        /// If omitted, the bidder becomes the value owner.  Can only be provided when bidding on a
+        /// scope.
+        value_owner_override: Option<String>,
     },
     /// Changes the contract's fees to the specified values.  Only the contract's admin account can
     /// execute this route.
@@ -76,10 +229,69 @@ pub enum ExecuteMsg {
         /// the value in contract storage will be cleared.  Providing zero or a negative value will
         /// produce an error.
         bid_fee: Option<Uint128>,
+        /// The new amount of nhash to charge when asks and bids are matched.  If this value is
+        /// omitted, the value in contract storage will be cleared.  Providing zero or a negative
+        /// value will produce an error.  While a match fee is set, new asks must be quoted with at
+        /// least this much nhash, and existing asks that are not cannot be matched until the fee is
+        /// lowered or cleared.
+        match_fee: Option<Uint128>,
+        /// The new mode for charging the ask and bid fees.  If this value is omitted, the ask and
+        /// bid fees will be charged as flat amounts of nhash.  In the basis points mode, neither
+        /// fee may exceed 10000 basis points.
+        fee_mode: Option<FeeMode>,
+    },
+    /// Transfers administration of the contract to a different account.  After this route is
+    /// executed, only the new admin will be able to execute admin-only routes.  Only the
+    /// contract's current admin account can execute this route.
+    UpdateAdmin {
+        /// The bech32 address of the account that will become the contract's admin.  An invalid
+        /// address will produce an error.
+        new_admin: String,
+    },
+    /// Stores a new bind name in the contract info, and binds that name to the contract address
+    /// with a restricted binding.  The bind message is dispatched with the transaction, so this
+    /// route fails if the contract is not allowed to bind the new name (for example, when its
+    /// parent name is restricted to a different owner).  The previously bound name is not unbound,
+    /// and remains attached to the contract address.  Only the contract's admin account can
+    /// execute this route.
+    UpdateBindName {
+        /// The new name to bind to the contract address.  Cannot be empty.
+        bind_name: String,
+    },
+    /// Changes the denoms that asks may be quoted in and bids may be funded with.  Existing orders
+    /// are not affected.  Only the contract's admin account can execute this route.
+    UpdateQuoteDenoms {
+        /// The new list of allowed quote denoms.  If this value is omitted, the value in contract
+        /// storage will be cleared, allowing any denom.  Providing an empty list will produce an
+        /// error.
+        allowed_quote_denoms: Option<Vec<String>>,
+    },
+    /// Changes the minimum sizes of new asks and bids, keeping dust orders out of the contract.
+    /// Existing orders are not affected.  Only the contract's admin account can execute this route.
+    UpdateMinOrderSizes {
+        /// The new minimum amount of each denom in a coin base.  If this value is omitted, the
+        /// value in contract storage will be cleared, allowing bases of any size.
+        min_base_amount: Option<Vec<Coin>>,
+        /// The new minimum amount of each denom in a quote.  If this value is omitted, the value
+        /// in contract storage will be cleared, allowing quotes of any size.
+        min_quote_amount: Option<Vec<Coin>>,
     },
+    /// Pauses or unpauses the creation of new asks and bids.  Existing orders can still be
+    /// cancelled, matched and reaped while the contract is paused.  Only the contract's admin
+    /// account can execute this route.
+    SetPaused {
+        /// Whether or not the creation of new orders should be blocked.
+        paused: bool,
+    },
+    /// Winds down the contract by removing every ask and bid from the contract's storage, refunding
+    /// the base of each ask and the quote of each bid to its owner, and then pausing the contract
+    /// so that no new orders can be created.  Order creation fees are not refunded.  Only the
+    /// contract's admin account can execute this route.
+    DrainAll {},
     /// Attempts to match an AskOrder with a BidOrder, performing an exchange of the asker's base
     /// with the bidder's quote.  This will only be successful if the bidder's base matches the
-    /// asker's base, and the asker's quote matches the bidder's quote.
+    /// asker's base, and the asker's quote matches the bidder's quote.  Orders owned by the same
+    /// account cannot be matched unless self matches were allowed at instantiation.
     ExecuteMatch {
         /// The unique identifier of the ask to attempt a match on.  If no ask exists within the
         /// contract storage with this id, an error will be returned.
@@ -88,6 +300,52 @@ pub enum ExecuteMsg {
         /// contract storage with this id, an error will be returned.
         bid_id: String,
     },
+    /// Attempts to match multiple AskOrders with BidOrders in a single transaction, exactly as if
+    /// ExecuteMatch were executed for each pair.  Every pair is validated before any exchange
+    /// occurs, so if any pair cannot be matched, no orders are matched.  Only the contract's admin
+    /// account can execute this route.
+    ExecuteMatchBatch {
+        /// The ask and bid ids to match.  Each ask and bid can only be included in one pair.
+        /// Providing an empty list will produce an error.
+        pairs: Vec<MatchPair>,
+    },
+    /// Removes expired orders from the contract's storage, refunding the base of each expired ask
+    /// and the quote of each expired bid to their owners.  Any account can execute this route.
+    ReapExpired {
+        /// The unique identifiers of the orders to remove.  Both asks and bids with each id will be
+        /// removed if they have expired.  If no expired order exists for an id, an error will be
+        /// returned.
+        ids: Vec<String>,
+    },
+    /// Attempts to partially match an AskOrder with a BidOrder that both have a single Coin base of
+    /// the same denom.  The fill amount of the base is sent to the bidder, and the proportional
+    /// amount of the quote is sent to the asker.  Both orders must be offering the same price per
+    /// unit of base.  The orders are reduced by the filled amounts and remain in contract storage
+    /// until they are fully filled, at which point they are removed.  Scope bases cannot be
+    /// partially filled and will produce an error.
+    ExecutePartialMatch {
+        /// The unique identifier of the ask to attempt a partial match on.  If no ask exists within
+        /// the contract storage with this id, an error will be returned.
+        ask_id: String,
+        /// The unique identifier of the bid to attempt a partial match on.  If no bid exists within
+        /// the contract storage with this id, an error will be returned.
+        bid_id: String,
+        /// The amount of the base to transfer from the asker to the bidder.  This value must be
+        /// above zero, cannot exceed the base amount of either order, and must produce a whole
+        /// amount of each quote coin.
+        fill_amount: Uint128,
+    },
+}
+
+/// The unique identifiers of an AskOrder and BidOrder to match within an ExecuteMatchBatch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MatchPair {
+    /// The unique identifier of the ask to attempt a match on.  If no ask exists within the
+    /// contract storage with this id, an error will be returned.
+    pub ask_id: String,
+    /// The unique identifier of the bid to attempt a match on.  If no bid exists within the
+    /// contract storage with this id, an error will be returned.
+    pub bid_id: String,
 }
 
 /// Fetches data from the smart contract.  No query routes make changes to blockchain data.
@@ -106,10 +364,181 @@ pub enum QueryMsg {
         /// given id, an error will be returned.
         id: String,
     },
+    /// Fetches a page of existing AskOrders from contract storage, ordered by their ids.  Filters are
+    /// applied before the page is limited, so a page contains only orders matching every filter.
+    GetAsks {
+        /// The id of the last AskOrder received in a previous page.  If provided, only AskOrders
+        /// with ids after this value will be returned.  If omitted, iteration starts with the
+        /// first AskOrder in storage.
+        start_after: Option<String>,
+        /// The maximum amount of AskOrders to return.  If omitted, a default of 25 will be used.
+        /// Values above 100 will be reduced to 100.
+        limit: Option<u32>,
+        /// If provided, only AskOrders with a base of this denom will be returned.  Coin bases match
+        /// if any of their coins use the denom, and marker bases match if the marker has the denom.
+        /// Providing "scope" will match every scope base.
+        filter_base_denom: Option<String>,
+        /// If provided, only AskOrders with a quote containing this denom will be returned.
+        filter_quote_denom: Option<String>,
+        /// If provided, only AskOrders with a base of the scope at this address will be returned.
+        filter_scope_address: Option<String>,
+    },
+    /// Fetches a page of existing BidOrders from contract storage, ordered by their ids.  Filters are
+    /// applied before the page is limited, so a page contains only orders matching every filter.
+    GetBids {
+        /// The id of the last BidOrder received in a previous page.  If provided, only BidOrders
+        /// with ids after this value will be returned.  If omitted, iteration starts with the
+        /// first BidOrder in storage.
+        start_after: Option<String>,
+        /// The maximum amount of BidOrders to return.  If omitted, a default of 25 will be used.
+        /// Values above 100 will be reduced to 100.
+        limit: Option<u32>,
+        /// If provided, only BidOrders with a base of this denom will be returned.  Coin bases match
+        /// if any of their coins use the denom, and marker bases match if the marker has the denom.
+        /// Providing "scope" will match every scope base.
+        filter_base_denom: Option<String>,
+        /// If provided, only BidOrders with a quote containing this denom will be returned.
+        filter_quote_denom: Option<String>,
+        /// If provided, only BidOrders with a base of the scope at this address will be returned.
+        filter_scope_address: Option<String>,
+    },
     /// Fetches the ContractInfo from contract storage.  This value is created as part of the
     /// instantiation process, so this query should only ever fail if the blockchain is experiencing
     /// downtime.
     GetContractInfo {},
+    /// Determines whether or not an existing AskOrder and BidOrder would be successfully matched by
+    /// the ExecuteMatch route, without making any changes.
+    MatchPreview {
+        /// The unique identifier of the AskOrder to compare.
+        ask_id: String,
+        /// The unique identifier of the BidOrder to compare.
+        bid_id: String,
+    },
+    /// Fetches an existing AskOrder and BidOrder in a single query, along with whether or not
+    /// their bases and quotes match.
+    GetOrderPair {
+        /// The unique identifier of the AskOrder to fetch.  If no order exists in storage for the
+        /// given id, the ask will be omitted from the response rather than returning an error.
+        ask_id: String,
+        /// The unique identifier of the BidOrder to fetch.  If no order exists in storage for the
+        /// given id, the bid will be omitted from the response rather than returning an error.
+        bid_id: String,
+    },
+    /// Fetches every BidOrder with a base and quote that match an existing AskOrder, ordered by
+    /// effective_time ascending.  BidOrders without an effective_time are ordered last.  Expired
+    /// BidOrders, and BidOrders that the contract does not allow to be matched with an AskOrder
+    /// owned by the same account, are omitted.  If the AskOrder has expired, no BidOrders are
+    /// returned.
+    FindMatchingBids {
+        /// The unique identifier of the AskOrder to find matching BidOrders for.  If no order exists
+        /// in storage for the given id, an error will be returned.
+        ask_id: String,
+    },
+    /// Fetches the TradeStats from contract storage, containing the amount of matches executed and
+    /// the cumulative quote paid to askers in each denom.  Partial matches are included.
+    GetTradeStats {},
+    /// Fetches the VersionInfo of the contract code, containing its contract_type and
+    /// contract_version.
+    GetVersion {},
+    /// Totals the coins escrowed by the smart contract across every open AskOrder and BidOrder,
+    /// allowing the contract's bank balance to be reconciled against its orders.
+    GetEscrowSummary {},
+    /// Fetches every parameter of the contract that its admin can set, in a single response.
+    /// Descriptive values that never change after instantiation, like the contract's name, are
+    /// excluded and can be fetched with GetContractInfo.
+    GetConfig {},
+}
+
+/// The response to a GetAsks query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetAsksResponse {
+    /// All AskOrders found in the requested page.
+    pub asks: Vec<AskOrderV2>,
+    /// The id of the final AskOrder in this page.  This value can be provided as start_after to
+    /// fetch the next page.  Omitted if the page is empty.
+    pub last_key: Option<String>,
+}
+
+/// The response to a GetBids query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetBidsResponse {
+    /// All BidOrders found in the requested page.
+    pub bids: Vec<BidOrderV2>,
+    /// The id of the final BidOrder in this page.  This value can be provided as start_after to
+    /// fetch the next page.  Omitted if the page is empty.
+    pub last_key: Option<String>,
+}
+
+/// The response to a FindMatchingBids query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FindMatchingBidsResponse {
+    /// All BidOrders matching the AskOrder, with the earliest effective_time first.
+    pub bids: Vec<BidOrderV2>,
+}
+
+/// The response to a MatchPreview query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MatchPreviewResponse {
+    /// Whether or not the AskOrder and BidOrder would be successfully matched.
+    pub matches: bool,
+    /// A description of why the orders would not be matched.  Omitted when the orders match.
+    pub reason: Option<String>,
+}
+
+/// The response to a GetOrderPair query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetOrderPairResponse {
+    /// The requested AskOrder.  Omitted when no AskOrder exists with the requested id.
+    pub ask: Option<AskOrderV2>,
+    /// The requested BidOrder.  Omitted when no BidOrder exists with the requested id.
+    pub bid: Option<BidOrderV2>,
+    /// Whether or not the base and quote of the AskOrder and BidOrder match.  Always false when
+    /// either order is missing or expired, or when both orders share an owner and self matches
+    /// are not allowed, because ExecuteMatch would reject the pair.
+    pub would_match: bool,
+}
+
+/// The response to a GetEscrowSummary query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetEscrowSummaryResponse {
+    /// The coin bases of all AskOrders and the quotes of all BidOrders, summed by denom and sorted
+    /// by denom.
+    pub coins: Vec<Coin>,
+    /// The amount of AskOrders holding a scope base.  Scopes are not included in the coin totals.
+    pub escrowed_scopes: u64,
+    /// The amount of AskOrders holding a marker base.  Markers are not included in the coin totals.
+    pub escrowed_markers: u64,
+}
+
+/// The response to a GetConfig query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetConfigResponse {
+    /// The account that can execute matches and change the contract's configuration.
+    pub admin: Addr,
+    /// The fee charged when an AskOrder is created.  Omitted when no fee is charged.
+    pub ask_fee: Option<Uint128>,
+    /// The fee charged when a BidOrder is created.  Omitted when no fee is charged.
+    pub bid_fee: Option<Uint128>,
+    /// The flat fee in nhash taken from the AskOrder's quote when a match is executed.  Omitted
+    /// when no fee is charged.
+    pub match_fee: Option<Uint128>,
+    /// Whether the ask and bid fees are flat amounts or basis points of the order's quote.
+    pub fee_mode: FeeMode,
+    /// The denoms that orders can be quoted in.  Omitted when every denom is allowed.
+    pub allowed_quote_denoms: Option<Vec<String>>,
+    /// The smallest base amount, by denom, that an order can be created with.  Omitted when no
+    /// minimums are enforced.
+    pub min_base_amount: Option<Vec<Coin>>,
+    /// The smallest quote amount, by denom, that an order can be created with.  Omitted when no
+    /// minimums are enforced.
+    pub min_quote_amount: Option<Vec<Coin>>,
+    /// Whether or not an AskOrder and BidOrder owned by the same account can be matched.
+    pub allow_self_match: bool,
+    /// Whether or not the creation of new orders is currently paused.
+    pub paused: bool,
+    /// The most asks and bids that a single account may have open at once.  Omitted when no limit
+    /// is enforced.
+    pub max_orders_per_owner: Option<u32>,
 }
 
 /// Migrates the smart contract to a new version of its source code.
@@ -117,6 +546,21 @@ pub enum QueryMsg {
 #[serde(rename_all = "snake_case")]
 pub enum MigrateMsg {
     /// Overwrites the contract's base code with a new version.  This route will never modify values
-    /// stored in contract storage (like Ask or Bid orders).
+    /// stored in contract storage (like Ask or Bid orders).  If the stored contract version is
+    /// greater than or equal to the new version, an error will be returned.
     NewVersion {},
+    /// Overwrites the contract's base code with a new version, and converts all stored Ask and Bid
+    /// orders to the current storage format.  Orders stored in the V1 format are moved to V2
+    /// storage, and existing V2 orders are re-saved with defaults populated for any fields added
+    /// after they were stored.  If a V1 order shares an id with an existing V2 order of the same
+    /// type, an error will be returned.
+    MigrateOrders {},
+    /// Stores a new bind name in the contract info.  The contract version and stored orders are
+    /// left untouched.
+    ///
+    /// A bind message for the new name is added to the migration response, but any messages added
+    /// to a migration response are ignored, so the name is never actually bound by this migration.
+    /// Use the UpdateBindName execute route instead, which dispatches the bind message and fails
+    /// if the contract is not allowed to bind the new name.
+    NewBindName { bind_name: String },
 }
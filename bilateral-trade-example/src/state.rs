@@ -1,5 +1,8 @@
-use cosmwasm_std::{coin as cosm_coin, Addr, Coin, Storage, Timestamp};
-use cosmwasm_storage::{bucket, bucket_read, Bucket, ReadonlyBucket};
+use cosmwasm_std::{coin as cosm_coin, Addr, Coin, StdError, StdResult, Storage, Timestamp};
+use cosmwasm_storage::{
+    bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
+    Singleton,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -46,6 +49,7 @@ pub static NAMESPACE_ORDER_BID_V2: &[u8] = b"bid_v2";
 pub enum BaseType {
     Coin { coins: Vec<Coin> },
     Scope { scope_address: String },
+    Marker { denom: String },
 }
 impl BaseType {
     pub fn coin(amount: u128, denom: impl Into<String>) -> BaseType {
@@ -62,6 +66,21 @@ impl BaseType {
         }
     }
 
+    pub fn marker(denom: impl Into<String>) -> BaseType {
+        BaseType::Marker {
+            denom: denom.into(),
+        }
+    }
+
+    // the snake_case name of the variant, matching its serialized form
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            BaseType::Coin { .. } => "coin",
+            BaseType::Scope { .. } => "scope",
+            BaseType::Marker { .. } => "marker",
+        }
+    }
+
     pub fn sorted(&mut self) -> BaseType {
         match self {
             BaseType::Coin { coins } => {
@@ -75,7 +94,7 @@ impl BaseType {
                     coins: coins.to_vec(),
                 }
             }
-            BaseType::Scope { .. } => self.to_owned(),
+            BaseType::Scope { .. } | BaseType::Marker { .. } => self.to_owned(),
         }
     }
 }
@@ -83,17 +102,87 @@ impl BaseType {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct AskOrderV2 {
     pub base: BaseType,
+    // orders stored before expirations were introduced will not have this value, and never expire
+    #[serde(default)]
+    pub expiration: Option<Timestamp>,
+    // the account that transferred a scope base to the contract, to which the scope is returned on cancellation
+    #[serde(default)]
+    pub expected_previous_owner: Option<Addr>,
     pub id: String,
     pub owner: Addr,
     pub quote: Vec<Coin>,
 }
+
+impl AskOrderV2 {
+    // converts an order stored in the V1 format.  V1 asks could only hold coin bases and predate expirations
+    pub fn from_v1(ask_order: AskOrder) -> AskOrderV2 {
+        AskOrderV2 {
+            base: BaseType::coins(ask_order.base),
+            expiration: None,
+            expected_previous_owner: None,
+            id: ask_order.id,
+            owner: ask_order.owner,
+            quote: ask_order.quote,
+        }
+    }
+
+    pub fn is_expired(&self, time: &Timestamp) -> bool {
+        is_expired(&self.expiration, time)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BidOrderV2 {
+    // the quote the bidder will accept from an ask, when it differs from the escrowed quote.  Orders stored before
+    // this value was introduced will not have it, and only match asks quoted exactly as their escrow
+    #[serde(default)]
+    pub accepted_ask_quote: Option<Vec<Coin>>,
     pub base: BaseType,
     pub effective_time: Option<Timestamp>,
+    // orders stored before expirations were introduced will not have this value, and never expire
+    #[serde(default)]
+    pub expiration: Option<Timestamp>,
     pub id: String,
     pub owner: Addr,
     pub quote: Vec<Coin>,
+    // the addresses granted data access to a scope base when the bid is matched.  Orders stored before this value
+    // was introduced will not have it, and request no data access
+    #[serde(default)]
+    pub requested_data_access: Vec<Addr>,
+    // the address set as the value owner of a scope base when the bid is matched, instead of the bidder.  Orders stored
+    // before this value was introduced will not have it, and the bidder becomes the value owner
+    #[serde(default)]
+    pub value_owner_override: Option<Addr>,
+}
+
+impl BidOrderV2 {
+    // the quote that an ask must have to be matched with this bid
+    pub fn effective_quote(&self) -> &[Coin] {
+        self.accepted_ask_quote.as_deref().unwrap_or(&self.quote)
+    }
+
+    // converts an order stored in the V1 format.  V1 bids could only request coin bases and predate expirations
+    pub fn from_v1(bid_order: BidOrder) -> BidOrderV2 {
+        BidOrderV2 {
+            accepted_ask_quote: None,
+            base: BaseType::coins(bid_order.base),
+            effective_time: bid_order.effective_time,
+            expiration: None,
+            id: bid_order.id,
+            owner: bid_order.owner,
+            quote: bid_order.quote,
+            requested_data_access: vec![],
+            value_owner_override: None,
+        }
+    }
+
+    pub fn is_expired(&self, time: &Timestamp) -> bool {
+        is_expired(&self.expiration, time)
+    }
+}
+
+fn is_expired(expiration: &Option<Timestamp>, time: &Timestamp) -> bool {
+    matches!(expiration, Some(expiration) if time > expiration)
 }
 
 pub fn get_ask_storage_v2(storage: &mut dyn Storage) -> Bucket<AskOrderV2> {
@@ -108,3 +197,54 @@ pub fn get_bid_storage_v2(storage: &mut dyn Storage) -> Bucket<BidOrderV2> {
 pub fn get_bid_storage_read_v2(storage: &dyn Storage) -> ReadonlyBucket<BidOrderV2> {
     bucket_read(storage, NAMESPACE_ORDER_BID_V2)
 }
+
+// Trade Stats Storage
+pub static NAMESPACE_TRADE_STATS: &[u8] = b"trade_stats";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct TradeStats {
+    pub total_matches: u64,
+    // the cumulative amount of each quote denom paid to askers, sorted by denom
+    pub quote_volume: Vec<Coin>,
+}
+
+impl TradeStats {
+    // counts a single executed match that paid the given quote
+    pub fn record_match(&mut self, quote: &[Coin]) -> StdResult<()> {
+        self.total_matches = self
+            .total_matches
+            .checked_add(1)
+            .ok_or_else(|| StdError::generic_err("total matches overflowed"))?;
+        for quote_coin in quote {
+            match self
+                .quote_volume
+                .iter_mut()
+                .find(|volume| volume.denom == quote_coin.denom)
+            {
+                Some(volume) => volume.amount = volume.amount.checked_add(quote_coin.amount)?,
+                None => self.quote_volume.push(quote_coin.clone()),
+            }
+        }
+        self.quote_volume.sort_by(|a, b| a.denom.cmp(&b.denom));
+        Ok(())
+    }
+}
+
+pub fn get_trade_stats_storage(storage: &mut dyn Storage) -> Singleton<TradeStats> {
+    singleton(storage, NAMESPACE_TRADE_STATS)
+}
+pub fn get_trade_stats_storage_read(storage: &dyn Storage) -> ReadonlySingleton<TradeStats> {
+    singleton_read(storage, NAMESPACE_TRADE_STATS)
+}
+
+// Open Order Count Storage
+// the amount of asks and bids each owner currently has open, keyed by owner address.  Orders stored before counts were
+// introduced are not included
+pub static NAMESPACE_OPEN_ORDER_COUNT: &[u8] = b"open_order_count";
+
+pub fn get_open_order_count_storage(storage: &mut dyn Storage) -> Bucket<u32> {
+    bucket(storage, NAMESPACE_OPEN_ORDER_COUNT)
+}
+pub fn get_open_order_count_storage_read(storage: &dyn Storage) -> ReadonlyBucket<u32> {
+    bucket_read(storage, NAMESPACE_OPEN_ORDER_COUNT)
+}
@@ -1,13 +1,28 @@
 use crate::core::error::ContractError;
 use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
 use crate::execute::register_name::register_name;
+use crate::execute::register_names::register_names;
+use crate::execute::register_sub_name::register_sub_name;
+use crate::execute::release_name::release_name;
+use crate::execute::renew_name::renew_name;
+use crate::execute::transfer_name::transfer_name;
+use crate::execute::update_fee_config::update_fee_config;
+use crate::execute::update_fee_exempt_addresses::update_fee_exempt_addresses;
+use crate::execute::update_reserved_names::update_reserved_names;
 use crate::instantiate::instantiate_contract::instantiate_contract;
 use crate::migrate::migrate_contract::migrate_contract;
+use crate::query::check_names_available::check_names_available;
+use crate::query::estimate_registration::estimate_registration;
+use crate::query::get_names_by_address::get_names_by_address;
+use crate::query::get_stats::get_stats;
 use crate::query::query_address_by_name::query_address_by_name;
+use crate::query::query_name_expiration::query_name_expiration;
 use crate::query::query_names_by_address::query_names_by_address;
 use crate::query::query_state::query_state;
 use crate::query::query_version::query_version;
+use crate::query::resolve_name::resolve_name;
 use crate::query::search_for_names::search_for_names;
+use crate::query::simulate_register::simulate_register;
 use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
@@ -29,36 +44,71 @@ pub fn instantiate(
 /// is registered with a name (within a particular name contract's namespace). This is similar to a DNS
 /// lookup for urls on the web resolving to a server's underlying IP address. Additionally, you can determine which names are
 /// bound to a particular address (though this information is available by querying the attribute module directly, this is more for convenience).
-/// You can even perform a 'fuzzy' search (contains substring) type of query.
+/// You can even perform a 'fuzzy' search (contains substring) type of query, or check whether a name is available
+/// and what registering it would cost before sending a registration.
 #[entry_point]
 pub fn query(
     deps: Deps<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::QueryRequest {} => query_state(deps),
         QueryMsg::QueryAddressByName { name } => query_address_by_name(deps, name),
         QueryMsg::QueryNamesByAddress { address } => query_names_by_address(deps, address),
+        QueryMsg::GetNamesByAddress {
+            address,
+            start_after,
+            limit,
+        } => get_names_by_address(deps, address, start_after, limit),
+        QueryMsg::ResolveName { name } => resolve_name(deps, name),
         QueryMsg::SearchForNames { search } => search_for_names(deps, search),
+        QueryMsg::EstimateRegistration { name } => estimate_registration(deps, env, name),
+        QueryMsg::CheckNamesAvailable { names } => check_names_available(deps, env, names),
+        QueryMsg::SimulateRegister { name, sent_funds } => {
+            simulate_register(deps, env, name, sent_funds)
+        }
+        QueryMsg::QueryNameExpiration { name } => query_name_expiration(deps, env, name),
+        QueryMsg::GetStats {} => get_stats(deps),
         QueryMsg::Version {} => query_version(deps),
     }
 }
 
 /// Execution entrypoints for enacting the contract's purpose: registering names to addresses.
 ///
-/// We only have one simple route in this contract, so this isn't doing much, but in the future
-/// it could be conceivable that we add functionality to unregister a name (either as the user the name is bound to, or as some admin), or
-/// maybe some other functionality
+/// Names can be registered individually or in batches, sub-names can be registered beneath an owned name, and a registered name can be released, transferred, or renewed by the address it is bound to.
+/// The contract admin may additionally update the registration fee configuration, the list of reserved names, and the
+/// addresses exempt from registration fees.
 #[entry_point]
 pub fn execute(
     deps: DepsMut<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     match msg {
-        ExecuteMsg::Register { name } => register_name(deps, info, name),
+        ExecuteMsg::Register {
+            name,
+            memo,
+            fee_recipient_override,
+        } => register_name(deps, env, info, name, memo, fee_recipient_override),
+        ExecuteMsg::RegisterNames { names } => register_names(deps, env, info, names),
+        ExecuteMsg::ReleaseName { name } => release_name(deps, info, name),
+        ExecuteMsg::RenewName { name } => renew_name(deps, env, info, name),
+        ExecuteMsg::RegisterSubName { parent, sub } => {
+            register_sub_name(deps, env, info, parent, sub)
+        }
+        ExecuteMsg::TransferName { name, new_owner } => transfer_name(deps, info, name, new_owner),
+        ExecuteMsg::UpdateFeeConfig {
+            fee_amount,
+            fee_collection_address,
+        } => update_fee_config(deps, info, fee_amount, fee_collection_address),
+        ExecuteMsg::UpdateReservedNames { reserved_names } => {
+            update_reserved_names(deps, info, reserved_names)
+        }
+        ExecuteMsg::UpdateFeeExemptAddresses {
+            fee_exempt_addresses,
+        } => update_fee_exempt_addresses(deps, info, fee_exempt_addresses),
     }
 }
 
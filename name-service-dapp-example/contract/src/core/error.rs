@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -17,10 +17,13 @@ pub enum ContractError {
     #[error("Name serialization failed due to {cause:?}")]
     NameSerializationFailure { cause: StdError },
 
+    #[error("Name [{name}] is reserved and cannot be registered")]
+    NameReserved { name: String },
+
     #[error("Name not found")]
     NameNotFound,
 
-    #[error("No nhash amount provided during name registration")]
+    #[error("No fee denomination amount provided during name registration")]
     NoFundsProvidedForRegistration,
 
     #[error("Current contract name [{current_contract}] does not match provided migration name [{migration_contract}]")]
@@ -35,18 +38,26 @@ pub enum ContractError {
         migration_version: String,
     },
 
-    #[error("Non nhash coin provided for transaction {types:?}")]
+    #[error("Coin other than the fee denomination provided for transaction {types:?}")]
     InvalidFundsProvided { types: Vec<String> },
 
-    #[error("Name has invalid format. Names should be all lowercase with no spaces or special characters. Name used: [{name}]")]
+    #[error("Name has invalid format. Names should be alphanumeric with no spaces or special characters. Name used: [{name}]")]
     InvalidNameFormat { name: String },
 
+    #[error(
+        "Name [{name}] has invalid length. Names must be between {min} and {max} characters long"
+    )]
+    InvalidNameLength { name: String, min: u32, max: u32 },
+
     #[error("Insufficient funds provided for name registration. Provided {amount_provided:?} but required {amount_required:?}")]
     InsufficientFundsProvided {
         amount_provided: u128,
         amount_required: u128,
     },
 
+    #[error("Fee distribution basis points must sum to {required}, but summed to {provided}")]
+    InvalidFeeDistribution { provided: u64, required: u32 },
+
     #[error("Invalid fields: {fields:?}")]
     InvalidFields { fields: Vec<String> },
 
@@ -55,6 +66,26 @@ pub enum ContractError {
 
     #[error("Query failed: {0}")]
     QueryError(String),
+
+    #[error("Memo is {length} characters long, but must be no longer than {max} characters")]
+    MemoTooLong { length: usize, max: usize },
+
+    #[error("Excess funds provided for name registration. Provided {excess:?} more than required")]
+    ExcessFundsProvided { excess: Vec<Coin> },
+
+    #[error("Address must wait {blocks_remaining} more blocks before registering another name")]
+    RegistrationCooldown { blocks_remaining: u64 },
+
+    #[error(
+        "Internal error: fee refund underflowed. Provided {amount_sent} but charged {fee_amount}"
+    )]
+    FeeRefundUnderflow { amount_sent: u128, fee_amount: u128 },
+
+    #[error("Address [{address}] is not allowed to override the fee recipient")]
+    FeeRecipientOverrideNotAllowed { address: String },
+
+    #[error("Names cannot be renewed because registrations do not expire")]
+    NameRenewalNotEnabled,
 }
 impl ContractError {
     /// Allows ContractError instances to be generically returned as a Response in a fluent manner
@@ -69,6 +100,35 @@ impl ContractError {
     pub fn std_err<T>(msg: impl Into<String>) -> Result<T, ContractError> {
         Err(ContractError::Std(StdError::generic_err(msg)))
     }
+    /// A stable numeric code for each variant, letting off-chain clients identify a failure
+    /// without parsing its message.  Codes are never reassigned; new variants take the next code.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Std(_) => 1,
+            Self::Unauthorized => 2,
+            Self::NameRegistered { .. } => 3,
+            Self::NameSerializationFailure { .. } => 4,
+            Self::NameReserved { .. } => 5,
+            Self::NameNotFound => 6,
+            Self::NoFundsProvidedForRegistration => 7,
+            Self::InvalidContractName { .. } => 8,
+            Self::InvalidContractVersion { .. } => 9,
+            Self::InvalidFundsProvided { .. } => 10,
+            Self::InvalidNameFormat { .. } => 11,
+            Self::InvalidNameLength { .. } => 12,
+            Self::InsufficientFundsProvided { .. } => 13,
+            Self::InvalidFeeDistribution { .. } => 14,
+            Self::InvalidFields { .. } => 15,
+            Self::SemVer(_) => 16,
+            Self::QueryError(_) => 17,
+            Self::MemoTooLong { .. } => 18,
+            Self::ExcessFundsProvided { .. } => 19,
+            Self::RegistrationCooldown { .. } => 20,
+            Self::FeeRefundUnderflow { .. } => 21,
+            Self::FeeRecipientOverrideNotAllowed { .. } => 22,
+            Self::NameRenewalNotEnabled => 23,
+        }
+    }
 }
 impl From<semver::Error> for ContractError {
     /// Enables SemVer issues to cast convert implicitly to contract error
@@ -76,3 +136,123 @@ impl From<semver::Error> for ContractError {
         Self::SemVer(err.to_string())
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use cosmwasm_std::{coin, StdError};
+
+    #[test]
+    fn test_error_codes() {
+        let errors = vec![
+            (ContractError::Std(StdError::generic_err("error")), 1),
+            (ContractError::Unauthorized, 2),
+            (
+                ContractError::NameRegistered {
+                    name: "name".into(),
+                },
+                3,
+            ),
+            (
+                ContractError::NameSerializationFailure {
+                    cause: StdError::generic_err("error"),
+                },
+                4,
+            ),
+            (
+                ContractError::NameReserved {
+                    name: "name".into(),
+                },
+                5,
+            ),
+            (ContractError::NameNotFound, 6),
+            (ContractError::NoFundsProvidedForRegistration, 7),
+            (
+                ContractError::InvalidContractName {
+                    current_contract: "current".into(),
+                    migration_contract: "migration".into(),
+                },
+                8,
+            ),
+            (
+                ContractError::InvalidContractVersion {
+                    current_version: "1.0.0".into(),
+                    migration_version: "0.1.0".into(),
+                },
+                9,
+            ),
+            (
+                ContractError::InvalidFundsProvided {
+                    types: vec!["denom".into()],
+                },
+                10,
+            ),
+            (
+                ContractError::InvalidNameFormat {
+                    name: "name".into(),
+                },
+                11,
+            ),
+            (
+                ContractError::InvalidNameLength {
+                    name: "name".into(),
+                    min: 1,
+                    max: 2,
+                },
+                12,
+            ),
+            (
+                ContractError::InsufficientFundsProvided {
+                    amount_provided: 1,
+                    amount_required: 2,
+                },
+                13,
+            ),
+            (
+                ContractError::InvalidFeeDistribution {
+                    provided: 1,
+                    required: 2,
+                },
+                14,
+            ),
+            (
+                ContractError::InvalidFields {
+                    fields: vec!["field".into()],
+                },
+                15,
+            ),
+            (ContractError::SemVer("error".into()), 16),
+            (ContractError::QueryError("error".into()), 17),
+            (ContractError::MemoTooLong { length: 2, max: 1 }, 18),
+            (
+                ContractError::ExcessFundsProvided {
+                    excess: vec![coin(1, "nhash")],
+                },
+                19,
+            ),
+            (
+                ContractError::RegistrationCooldown {
+                    blocks_remaining: 1,
+                },
+                20,
+            ),
+            (
+                ContractError::FeeRefundUnderflow {
+                    amount_sent: 1,
+                    fee_amount: 2,
+                },
+                21,
+            ),
+            (
+                ContractError::FeeRecipientOverrideNotAllowed {
+                    address: "address".into(),
+                },
+                22,
+            ),
+            (ContractError::NameRenewalNotEnabled, 23),
+        ];
+        for (error, code) in errors {
+            assert_eq!(code, error.code(), "unexpected code for error {:?}", error);
+        }
+    }
+}
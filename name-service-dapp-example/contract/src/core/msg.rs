@@ -1,7 +1,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::core::state::{NameMeta, State};
+use crate::core::state::{FeeRecipient, NameMeta, State};
+use cosmwasm_std::{Coin, Uint128};
 
 /// A message sent to initialize the contract state.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -9,13 +10,54 @@ pub struct InitMsg {
     pub name: String,
     pub fee_amount: String,
     pub fee_collection_address: String,
+    pub fee_denomination: Option<String>,
+    pub min_name_length: Option<u32>,
+    pub max_name_length: Option<u32>,
+    pub fee_distribution: Option<Vec<FeeRecipient>>,
+    pub reserved_names: Option<Vec<String>>,
+    pub strict_fee_mode: Option<bool>,
+    pub registration_cooldown_blocks: Option<u64>,
+    pub fee_recipient_override_addresses: Option<Vec<String>>,
+    pub additional_fees: Option<Vec<Coin>>,
+    pub registration_duration_blocks: Option<u64>,
 }
 
 /// A message sent to register a name with the name service
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    Register { name: String },
+    Register {
+        name: String,
+        memo: Option<String>,
+        fee_recipient_override: Option<String>,
+    },
+    RegisterNames {
+        names: Vec<String>,
+    },
+    ReleaseName {
+        name: String,
+    },
+    RenewName {
+        name: String,
+    },
+    RegisterSubName {
+        parent: String,
+        sub: String,
+    },
+    TransferName {
+        name: String,
+        new_owner: String,
+    },
+    UpdateFeeConfig {
+        fee_amount: Option<Uint128>,
+        fee_collection_address: Option<String>,
+    },
+    UpdateReservedNames {
+        reserved_names: Vec<String>,
+    },
+    UpdateFeeExemptAddresses {
+        fee_exempt_addresses: Vec<String>,
+    },
 }
 
 /// A message sent to query contract config state.
@@ -23,9 +65,37 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     QueryRequest {},
-    QueryAddressByName { name: String },
-    QueryNamesByAddress { address: String },
-    SearchForNames { search: String },
+    QueryAddressByName {
+        name: String,
+    },
+    QueryNamesByAddress {
+        address: String,
+    },
+    GetNamesByAddress {
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ResolveName {
+        name: String,
+    },
+    SearchForNames {
+        search: String,
+    },
+    EstimateRegistration {
+        name: String,
+    },
+    CheckNamesAvailable {
+        names: Vec<String>,
+    },
+    SimulateRegister {
+        name: String,
+        sent_funds: Vec<Coin>,
+    },
+    QueryNameExpiration {
+        name: String,
+    },
+    GetStats {},
     Version {},
 }
 
@@ -75,3 +145,42 @@ impl NameSearchResponse {
         NameSearchResponse { search, names }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct EstimateRegistrationResponse {
+    pub available: bool,
+    pub fee: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NameAvailability {
+    pub name: String,
+    pub available: bool,
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NameExpirationResponse {
+    pub name: String,
+    pub expiration_height: Option<u64>,
+    pub expired: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SimulateRegisterResponse {
+    pub attribute_name: String,
+    pub attribute_value: String,
+    pub fee_transfers: Vec<SimulatedTransfer>,
+    pub refund: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SimulatedTransfer {
+    pub to_address: String,
+    pub amount: Vec<Coin>,
+}
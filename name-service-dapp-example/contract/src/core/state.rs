@@ -1,7 +1,12 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::Storage;
+use crate::util::constants::{
+    DEFAULT_FEE_DENOMINATION, DEFAULT_MAX_NAME_LENGTH, DEFAULT_MIN_NAME_LENGTH,
+    TOTAL_FEE_BASIS_POINTS,
+};
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{coin, Coin, StdError, StdResult, Storage, Uint128};
 use cosmwasm_storage::{
     bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
     Singleton,
@@ -9,13 +14,140 @@ use cosmwasm_storage::{
 
 pub static CONFIG_KEY: &[u8] = b"config";
 pub static NAME_META_KEY: &[u8] = b"name_meta";
+pub static NAME_INDEX_KEY: &[u8] = b"name_index";
+pub static LAST_REGISTRATION_KEY: &[u8] = b"last_registration";
+pub static REGISTRATION_STATS_KEY: &[u8] = b"registration_stats";
 
 /// Fields that comprise the smart contract state
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct State {
     pub name: String,
-    pub fee_amount: String,
+    /// The amount charged to register a single name.  Uint128 is serialized as a numeric string,
+    /// so state stored by versions of this contract that held the fee as a String still loads.
+    pub fee_amount: Uint128,
     pub fee_collection_address: String,
+    /// The denomination that registration fees are charged in.  Contracts instantiated before this
+    /// value existed will not have it set, and will continue to use the default denomination.
+    pub fee_denomination: Option<String>,
+    /// The minimum amount of characters a registered name may contain.  Falls back to the default
+    /// minimum when absent.
+    pub min_name_length: Option<u32>,
+    /// The maximum amount of characters a registered name may contain.  Falls back to the default
+    /// maximum when absent.
+    pub max_name_length: Option<u32>,
+    /// The address allowed to update the fee configuration.  Contracts instantiated before this
+    /// value existed will not have an admin, and can only change their fees through a migration.
+    pub admin: Option<String>,
+    /// Splits each registration fee between multiple addresses.  When absent, the full fee is sent
+    /// to the fee_collection_address.
+    pub fee_distribution: Option<Vec<FeeRecipient>>,
+    /// Names that can never be registered, regardless of casing.  Contracts instantiated before
+    /// this value existed load with no reserved names.
+    #[serde(default)]
+    pub reserved_names: Vec<String>,
+    /// Addresses that can register names without paying the registration fee.  Contracts
+    /// instantiated before this value existed load with no exempt addresses.
+    #[serde(default)]
+    pub fee_exempt_addresses: Vec<String>,
+    /// When enabled, registrations that send more than the required fee are rejected instead of
+    /// refunded.  Contracts instantiated before this value existed load with refunds enabled.
+    #[serde(default)]
+    pub strict_fee_mode: bool,
+    /// The amount of blocks an address must wait after registering before it may register again.
+    /// Zero disables the cooldown, which is also the value for contracts instantiated before it
+    /// existed.
+    #[serde(default)]
+    pub registration_cooldown_blocks: u64,
+    /// Addresses that can route the fee for a registration to a different recipient, such as a
+    /// reseller.  Contracts instantiated before this value existed load with no such addresses.
+    #[serde(default)]
+    pub fee_recipient_override_addresses: Vec<String>,
+    /// Fees charged for each registration in denominations other than the fee_denomination, in
+    /// addition to the fee_amount.  Contracts instantiated before this value existed load with no
+    /// additional fees, and only accept the fee_denomination.
+    #[serde(default)]
+    pub additional_fees: Vec<Coin>,
+    /// The amount of blocks that a registration lasts before the name expires and can be claimed
+    /// by another address.  When absent, which is also the value for contracts instantiated before
+    /// it existed, names never expire.
+    pub registration_duration_blocks: Option<u64>,
+}
+
+/// A recipient of a share of each registration fee.  The basis points of every recipient in a
+/// distribution must sum to 10000 (100%).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeRecipient {
+    pub address: String,
+    pub basis_points: u32,
+}
+impl State {
+    /// Resolves the denomination that registration fees should be charged in, falling back to the
+    /// default denomination when none has been configured.
+    pub fn get_fee_denomination(&self) -> &str {
+        self.fee_denomination
+            .as_deref()
+            .unwrap_or(DEFAULT_FEE_DENOMINATION)
+    }
+
+    /// Resolves every coin charged to register a single name: the fee_amount in the fee denomination,
+    /// followed by any additional fees.  The denominations of these coins are the only ones that
+    /// registrations accept.
+    pub fn get_fee_coins(&self) -> Vec<Coin> {
+        let mut fee_coins = vec![coin(self.fee_amount.u128(), self.get_fee_denomination())];
+        fee_coins.extend(self.additional_fees.iter().cloned());
+        fee_coins
+    }
+
+    /// Resolves the block height at which a registration made or renewed at the given height
+    /// expires.  Registrations never expire when no registration duration is configured.
+    pub fn get_expiration_height(&self, block_height: u64) -> Option<u64> {
+        self.registration_duration_blocks
+            .map(|duration| block_height.saturating_add(duration))
+    }
+
+    /// Resolves the minimum allowed name length, falling back to the default when not configured.
+    pub fn get_min_name_length(&self) -> u32 {
+        self.min_name_length.unwrap_or(DEFAULT_MIN_NAME_LENGTH)
+    }
+
+    /// Resolves the maximum allowed name length, falling back to the default when not configured.
+    pub fn get_max_name_length(&self) -> u32 {
+        self.max_name_length.unwrap_or(DEFAULT_MAX_NAME_LENGTH)
+    }
+
+    /// Determines if the given name matches a reserved name, ignoring case.
+    pub fn is_reserved_name(&self, name: &str) -> bool {
+        let normalized_name = normalize_name(name);
+        self.reserved_names
+            .iter()
+            .any(|reserved_name| normalize_name(reserved_name) == normalized_name)
+    }
+
+    /// Determines if the given address is exempt from paying the registration fee.
+    pub fn is_fee_exempt(&self, address: &str) -> bool {
+        self.fee_exempt_addresses
+            .iter()
+            .any(|exempt_address| exempt_address == address)
+    }
+
+    /// Determines if the given address is allowed to override the recipient of its registration fee.
+    pub fn can_override_fee_recipient(&self, address: &str) -> bool {
+        self.fee_recipient_override_addresses
+            .iter()
+            .any(|override_address| override_address == address)
+    }
+
+    /// Resolves the recipients of each registration fee.  A contract without a fee distribution
+    /// sends the entire fee to its fee collection address.
+    pub fn get_fee_recipients(&self) -> Vec<FeeRecipient> {
+        match &self.fee_distribution {
+            Some(distribution) => distribution.clone(),
+            None => vec![FeeRecipient {
+                address: self.fee_collection_address.clone(),
+                basis_points: TOTAL_FEE_BASIS_POINTS,
+            }],
+        }
+    }
 }
 
 pub fn config(storage: &mut dyn Storage) -> Singleton<State> {
@@ -30,6 +162,19 @@ pub fn config_read(storage: &dyn Storage) -> ReadonlySingleton<State> {
 pub struct NameMeta {
     pub name: String,
     pub address: String,
+    /// The block height at which this registration expires, after which the name can be claimed by
+    /// another address.  Names registered without a configured registration duration, including
+    /// every name registered before expiry existed, never expire.
+    #[serde(default)]
+    pub expiration_height: Option<u64>,
+}
+impl NameMeta {
+    /// Determines if this registration has expired as of the given block height.
+    pub fn is_expired(&self, block_height: u64) -> bool {
+        self.expiration_height
+            .map(|expiration_height| block_height >= expiration_height)
+            .unwrap_or(false)
+    }
 }
 
 pub fn meta(storage: &mut dyn Storage) -> Bucket<NameMeta> {
@@ -39,3 +184,78 @@ pub fn meta(storage: &mut dyn Storage) -> Bucket<NameMeta> {
 pub fn meta_read(storage: &dyn Storage) -> ReadonlyBucket<NameMeta> {
     bucket_read(storage, NAME_META_KEY)
 }
+
+/// A secondary index of registered names, namespaced by the address that owns them.  The keys
+/// within each address's bucket are the names themselves, which allows the names owned by an
+/// address to be ranged over in order without scanning the entire meta bucket.
+pub fn name_index<'a>(storage: &'a mut dyn Storage, address: &str) -> Bucket<'a, String> {
+    Bucket::multilevel(storage, &[NAME_INDEX_KEY, address.as_bytes()])
+}
+
+pub fn name_index_read<'a>(storage: &'a dyn Storage, address: &str) -> ReadonlyBucket<'a, String> {
+    ReadonlyBucket::multilevel(storage, &[NAME_INDEX_KEY, address.as_bytes()])
+}
+
+/// The block height of the most recent registration made by each address, keyed by address.  Only
+/// maintained while a registration cooldown is configured.
+pub fn last_registration(storage: &mut dyn Storage) -> Bucket<u64> {
+    bucket(storage, LAST_REGISTRATION_KEY)
+}
+
+pub fn last_registration_read(storage: &dyn Storage) -> ReadonlyBucket<u64> {
+    bucket_read(storage, LAST_REGISTRATION_KEY)
+}
+
+/// Running totals of the contract's registration activity, maintained as names are registered so
+/// that they can be queried without scanning every registered name.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct RegistrationStats {
+    /// The amount of names registered, including names registered in batches.
+    pub total_registrations: u64,
+    /// The fees sent to fee recipients for registrations and renewals, one coin per denomination.
+    /// Refunded overpayments are never included.
+    pub total_fees_collected: Vec<Coin>,
+}
+impl RegistrationStats {
+    /// Adds the given registrations and collected fees to the totals, failing rather than wrapping
+    /// if any total would overflow.
+    pub fn record(&mut self, registrations: u64, fees_collected: &[Coin]) -> StdResult<()> {
+        self.total_registrations = self
+            .total_registrations
+            .checked_add(registrations)
+            .ok_or_else(|| StdError::generic_err("total registrations overflowed"))?;
+        for fee in fees_collected {
+            match self
+                .total_fees_collected
+                .iter_mut()
+                .find(|total| total.denom == fee.denom)
+            {
+                Some(total) => total.amount = total.amount.checked_add(fee.amount)?,
+                None => self.total_fees_collected.push(fee.clone()),
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn registration_stats(storage: &mut dyn Storage) -> Singleton<RegistrationStats> {
+    singleton(storage, REGISTRATION_STATS_KEY)
+}
+
+pub fn registration_stats_read(storage: &dyn Storage) -> ReadonlySingleton<RegistrationStats> {
+    singleton_read(storage, REGISTRATION_STATS_KEY)
+}
+
+/// Adds registrations and the fees collected for them to the stored registration stats.  Contracts
+/// that have not recorded any registrations yet start from zero.
+pub fn record_registration_stats(
+    storage: &mut dyn Storage,
+    registrations: u64,
+    fees_collected: &[Coin],
+) -> StdResult<()> {
+    let mut stats = registration_stats_read(storage)
+        .may_load()?
+        .unwrap_or_default();
+    stats.record(registrations, fees_collected)?;
+    registration_stats(storage).save(&stats)
+}
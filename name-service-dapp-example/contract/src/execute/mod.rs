@@ -1 +1,9 @@
 pub mod register_name;
+pub mod register_names;
+pub mod register_sub_name;
+pub mod release_name;
+pub mod renew_name;
+pub mod transfer_name;
+pub mod update_fee_config;
+pub mod update_fee_exempt_addresses;
+pub mod update_reserved_names;
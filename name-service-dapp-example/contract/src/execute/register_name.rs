@@ -1,12 +1,16 @@
 use crate::core::error::ContractError;
-use crate::core::state::{config, meta, NameMeta, State};
-use crate::util::constants::FEE_DENOMINATION;
-use crate::util::helper_functions::fee_amount_from_string;
+use crate::core::state::{
+    config, last_registration, last_registration_read, meta, meta_read, name_index,
+    record_registration_stats, FeeRecipient, NameMeta, State,
+};
+use crate::util::constants::{MAX_MEMO_LENGTH, TOTAL_FEE_BASIS_POINTS};
+use crate::util::helper_functions::normalize_name;
 use cosmwasm_std::{
-    coin, to_binary, Api, BankMsg, CosmosMsg, DepsMut, MessageInfo, Response, Uint128,
+    coin, to_binary, Api, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, Storage,
+    Uint128,
 };
-use cosmwasm_storage::Bucket;
-use provwasm_std::{add_attribute, ProvenanceMsg, ProvenanceQuery};
+use cosmwasm_storage::ReadonlyBucket;
+use provwasm_std::{add_attribute, delete_distinct_attribute, ProvenanceMsg, ProvenanceQuery};
 
 // register a name
 // This will bind a name to the account that invoked this contract (self-registration)
@@ -14,136 +18,330 @@ use provwasm_std::{add_attribute, ProvenanceMsg, ProvenanceQuery};
 // account invoking this contract when they construct the message to do so.
 // note that if something within this execution were to fail, no fee would be taken, and the funds
 // would be returned to the invoker, though gas fees may still be paid by the invoker for work performed.
+// An optional memo is emitted as an event attribute for the registration, but is never stored.
+// An optional fee recipient override routes the entire fee to a different address, such as a reseller,
+// but can only be used by addresses that the contract allows to do so.
 pub fn register_name(
     deps: DepsMut<ProvenanceQuery>,
+    env: Env,
     info: MessageInfo,
     name: String,
+    memo: Option<String>,
+    fee_recipient_override: Option<String>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     let config = config(deps.storage).load()?;
 
-    // Fetch the name registry bucket from storage for use in dupe verification, as well as
-    // storing the new name if validation passes
-    let mut meta_storage = meta(deps.storage);
-
-    // Ensure the provided name has not yet been registered. Bubble up the error if the lookup
-    // succeeds in finding the value
-    validate_name(name.clone(), &meta_storage)?;
+    // The memo only appears in the emitted events, but is bounded to keep them a reasonable size
+    if let Some(memo) = &memo {
+        let length = memo.chars().count();
+        if length > MAX_MEMO_LENGTH {
+            return ContractError::MemoTooLong {
+                length,
+                max: MAX_MEMO_LENGTH,
+            }
+            .to_result();
+        }
+    }
 
-    // Serialize the proposed name as binary, allowing it to be sent via the ProvenanceClient as
-    // a new attribute under the registrar
-    let name_bin = match to_binary(&name) {
-        Ok(bin) => bin,
-        Err(e) => {
-            return ContractError::NameSerializationFailure { cause: e }.to_result();
+    // Only allowlisted senders may redirect the fee away from the configured recipients
+    if let Some(fee_recipient_override) = &fee_recipient_override {
+        if !config.can_override_fee_recipient(info.sender.as_str()) {
+            return ContractError::FeeRecipientOverrideNotAllowed {
+                address: info.sender.to_string(),
+            }
+            .to_result();
         }
-    };
+        deps.api.addr_validate(fee_recipient_override)?;
+    }
 
-    // Construct the new attribute message for dispatch
-    // This is a Provenance Blockchain message that will attach an attribute containing the value of the name (as a String) to
-    // the account invoking this contract entrypoint. This name is visible outside of this contract, via Provenance Blockchain Explorer, or queries,
-    // or by inspecting the state of this contract itself.
-    // Note that a user may register multiple names and they will all appear under the same name as an array of attributes on their account.
-    let add_attribute_message = add_attribute(
-        info.sender.clone(),
-        config.clone().name,
-        name_bin,
-        provwasm_std::AttributeValueType::String,
+    // Validate the name and fees, and build the messages that the registration will dispatch.  The
+    // SimulateRegister query shares this logic, ensuring that its output always matches execution
+    let RegistrationMessages {
+        add_attribute_message,
+        charge_response,
+    } = build_registration_messages(
+        deps.api,
+        &meta_read(deps.storage),
+        &config,
+        &info,
+        env.block.height,
+        &name,
+        fee_recipient_override.as_deref(),
     )?;
 
-    // Validate that fees are payable and correctly constructed. Errors are properly packaged within
-    // the target function, which makes this a perfect candidate for bubbling up via the ? operator
-    let charge_response = validate_fee_params_get_messages(deps.api, &info, &config)?;
+    // Throttle addresses that register names faster than the configured cooldown allows
+    check_registration_cooldown(deps.storage, &env, &info, &config)?;
 
     // Construct and store a NameMeta to the internal bucket.  This is important, because this
     // registry ensures duplicates names cannot be added, as well as allow addresses to be looked
     // up by name
+    // The registry is keyed by the normalized name so that names differing only in case collide,
+    // while the NameMeta retains the casing the registrant chose
+    let name_key = normalize_name(&name);
+    // Validation only allows an existing registration for this name if it has expired, in which
+    // case the previous owner's claim to it is removed before it is replaced
+    let release_expired_message =
+        release_expired_registration(deps.api, deps.storage, &config, &name_key)?;
     let name_meta = NameMeta {
         name: name.clone(),
         address: info.sender.into_string(),
+        expiration_height: config.get_expiration_height(env.block.height),
     };
-    meta_storage.save(name.as_bytes(), &name_meta)?;
+    meta(deps.storage).save(name_key.as_bytes(), &name_meta)?;
+    // Mirror the registration in the reverse index, allowing the names owned by an address to be
+    // listed without scanning every registered name
+    name_index(deps.storage, &name_meta.address).save(name_key.as_bytes(), &name)?;
+    // Only the fees that were charged count as collected, so any refunded overpayment is excluded
+    record_registration_stats(deps.storage, 1, &charge_response.fee_charged)?;
 
     // Return a response that will dispatch the marker messages and emit events.
-    let mut response = Response::new()
+    let mut response = Response::new();
+    // The previous owner's attribute must be deleted before the new one is added.  A previous owner
+    // reclaiming their own expired name would otherwise have the attribute just added to them deleted
+    if let Some(release_expired_message) = release_expired_message {
+        response = response.add_message(release_expired_message);
+    }
+    response = response
         // adding this message to the response results in the blockchain executing this action as the contract in the same transaction after this message is processed.
         // if something were to fail in the processing of this message, all other messages in the same transaction, including the one that invoked this entrypoint
         // would be rolled back, and the contract's state store would not contain a name, and no name would be attached to the invoker's account via an attribute.
         .add_message(add_attribute_message)
         .add_attribute("action", "name_register")
         .add_attribute("name", name);
-
-    // If a fee charge is requested, append it
-    if let Some(fee_message) = charge_response.fee_charge_message {
-        response = response.add_message(fee_message);
+    if let Some(memo) = memo {
+        response = response.add_attribute("memo", memo);
+    }
+    if let Some(fee_recipient_override) = fee_recipient_override {
+        response = response.add_attribute("fee_recipient", fee_recipient_override);
+    }
+    if let Some(expiration_height) = name_meta.expiration_height {
+        response = response.add_attribute("expiration_height", expiration_height.to_string());
     }
 
+    let fee_refund = charge_response.format_fee_refund();
+    // If a fee charge is requested, append a message for each fee recipient
+    response = response.add_messages(charge_response.fee_charge_messages);
+
     // If a fee refund must occur, append the constructed message as well as an attribute explicitly
     // detailing the amount of "denom" refunded
     // This functionality is more of a convenience to the invoker of this registration, so they can safely overpay
     // and not lose funds (or receive an error if the contract was configured to do so on provided vs. actual fee mismatch)
     if let Some(refund_message) = charge_response.fee_refund_message {
-        response = response.add_message(refund_message).add_attribute(
-            "fee_refund",
-            format!("{}{}", charge_response.fee_refund_amount, FEE_DENOMINATION),
-        );
+        response = response
+            .add_message(refund_message)
+            .add_attribute("fee_refund", fee_refund);
     }
     Ok(response)
 }
+/// The messages that registering a single name will dispatch, built before any storage is changed.
+pub struct RegistrationMessages {
+    pub add_attribute_message: CosmosMsg<ProvenanceMsg>,
+    pub charge_response: FeeChargeResponse,
+}
+
+/// Performs every read-only step of registering a single name: validating the name, constructing
+/// the attribute message that binds it to the sender, and validating the funds sent to produce
+/// the fee and refund messages.  Registration cooldowns are not checked, because checking one
+/// records the registration's block height.  When a fee recipient override is provided, it must
+/// already have been validated, and receives the entire fee.
+pub fn build_registration_messages(
+    api: &dyn Api,
+    meta: &ReadonlyBucket<NameMeta>,
+    config: &State,
+    info: &MessageInfo,
+    block_height: u64,
+    name: &str,
+    fee_recipient_override: Option<&str>,
+) -> Result<RegistrationMessages, ContractError> {
+    // Ensure the provided name has not yet been registered, or that its registration has expired.
+    // Bubble up the error if the lookup succeeds in finding an active registration
+    validate_name(name.to_string(), meta, config, block_height)?;
+
+    // Serialize the proposed name as binary, allowing it to be sent via the ProvenanceClient as
+    // a new attribute under the registrar
+    let name_bin = match to_binary(&name) {
+        Ok(bin) => bin,
+        Err(e) => {
+            return ContractError::NameSerializationFailure { cause: e }.to_result();
+        }
+    };
+
+    // Construct the new attribute message for dispatch
+    // This is a Provenance Blockchain message that will attach an attribute containing the value of the name (as a String) to
+    // the account invoking this contract entrypoint. This name is visible outside of this contract, via Provenance Blockchain Explorer, or queries,
+    // or by inspecting the state of this contract itself.
+    // Note that a user may register multiple names and they will all appear under the same name as an array of attributes on their account.
+    let add_attribute_message = add_attribute(
+        info.sender.clone(),
+        config.clone().name,
+        name_bin,
+        provwasm_std::AttributeValueType::String,
+    )?;
+
+    // Validate that fees are payable and correctly constructed. Errors are properly packaged within
+    // the target function, which makes this a perfect candidate for bubbling up via the ? operator
+    let charge_response =
+        validate_fee_params_get_messages(api, info, config, 1, fee_recipient_override)?;
+
+    Ok(RegistrationMessages {
+        add_attribute_message,
+        charge_response,
+    })
+}
+
 /// Validates that a name can be added.  Makes the following checks:
-/// - The name is not already registered. Core validation to ensure duplicate registrations cannot occur
-/// - The name is all lowercase and does not contain special characters. Ensures all names are easy to recognize.
-fn validate_name(name: String, meta: &Bucket<NameMeta>) -> Result<String, ContractError> {
-    // If the load doesn't error out, that means it found the input name
-    if meta.load(name.as_bytes()).is_ok() {
-        return ContractError::NameRegistered { name }.to_result();
-    }
-    // Ensures that the given name is all lowercase and has no special characters or spaces
+/// - The name is not already registered in any casing, unless that registration has expired as of the given block height. Core validation to ensure duplicate registrations cannot occur
+/// - The name is not reserved. Allows operators to protect offensive or trademarked names.
+/// - The name does not contain special characters. Ensures all names are easy to recognize.
+/// - The name length falls within the configured bounds. Prevents unreadable or storage-wasting names.
+pub fn validate_name(
+    name: String,
+    meta: &ReadonlyBucket<NameMeta>,
+    config: &State,
+    block_height: u64,
+) -> Result<String, ContractError> {
+    // If the load doesn't error out, that means it found the input name.  An expired registration
+    // no longer holds the name, so it can be claimed again
+    if let Ok(name_meta) = meta.load(normalize_name(&name).as_bytes()) {
+        if !name_meta.is_expired(block_height) {
+            return ContractError::NameRegistered { name }.to_result();
+        }
+    }
+    // Reserved names are rejected in any casing
+    if config.is_reserved_name(&name) {
+        return ContractError::NameReserved { name }.to_result();
+    }
+    // Ensures that the given name has no special characters or spaces
     // Note: This would be a great place to have a regex, but the regex cargo itself adds 500K to
     // the file size after optimization, excluding it as an option
-    if name.is_empty()
-        || name
-            .chars()
-            .any(|char| !char.is_alphanumeric() || (!char.is_lowercase() && !char.is_numeric()))
-    {
+    if name.is_empty() || name.chars().any(|char| !char.is_alphanumeric()) {
         return ContractError::InvalidNameFormat { name }.to_result();
     }
+    // Ensures that the name is neither too short to be meaningful nor long enough to waste storage
+    let min = config.get_min_name_length();
+    let max = config.get_max_name_length();
+    let name_length = name.chars().count();
+    if name_length < min as usize || name_length > max as usize {
+        return ContractError::InvalidNameLength { name, min, max }.to_result();
+    }
     Ok("successful validation".into())
 }
 
+/// Removes an expired registration for the given normalized name, if one exists, from the reverse
+/// index of its previous owner.  Returns a message that deletes the previous owner's attribute for
+/// the name, which must be dispatched along with the new registration.  The NameMeta itself is left
+/// in place to be overwritten by the new registration.
+pub fn release_expired_registration(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    config: &State,
+    name_key: &str,
+) -> Result<Option<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    let previous_meta = match meta_read(storage).may_load(name_key.as_bytes())? {
+        Some(previous_meta) => previous_meta,
+        None => return Ok(None),
+    };
+    name_index(storage, &previous_meta.address).remove(name_key.as_bytes());
+    // Only the attribute with this name's value is deleted, leaving the previous owner's other
+    // names intact
+    let name_bin = match to_binary(&previous_meta.name) {
+        Ok(bin) => bin,
+        Err(e) => {
+            return ContractError::NameSerializationFailure { cause: e }.to_result();
+        }
+    };
+    Ok(Some(delete_distinct_attribute(
+        api.addr_validate(&previous_meta.address)?,
+        config.name.clone(),
+        name_bin,
+    )?))
+}
+
+/// Rejects a registration when the sender registered a name fewer than the configured cooldown
+/// blocks ago, and otherwise records the current block height as the sender's latest registration.
+/// No heights are tracked when the cooldown is zero.
+pub fn check_registration_cooldown(
+    storage: &mut dyn Storage,
+    env: &Env,
+    info: &MessageInfo,
+    config: &State,
+) -> Result<(), ContractError> {
+    if config.registration_cooldown_blocks == 0 {
+        return Ok(());
+    }
+    let sender = info.sender.as_bytes();
+    if let Some(last_height) = last_registration_read(storage).may_load(sender)? {
+        let available_height = last_height.saturating_add(config.registration_cooldown_blocks);
+        if env.block.height < available_height {
+            return ContractError::RegistrationCooldown {
+                blocks_remaining: available_height - env.block.height,
+            }
+            .to_result();
+        }
+    }
+    last_registration(storage).save(sender, &env.block.height)?;
+    Ok(())
+}
+
 /// Helper struct to make the validate fee params function response more readable
-struct FeeChargeResponse {
-    fee_charge_message: Option<CosmosMsg<ProvenanceMsg>>,
-    fee_refund_message: Option<CosmosMsg<ProvenanceMsg>>,
-    fee_refund_amount: u128,
+pub struct FeeChargeResponse {
+    pub fee_charge_messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    pub fee_refund_message: Option<CosmosMsg<ProvenanceMsg>>,
+    pub fee_refund: Vec<Coin>,
+    pub fee_charged: Vec<Coin>,
+}
+impl FeeChargeResponse {
+    /// Describes the coins refunded for use as an event attribute, like "10nhash,5usd".
+    pub fn format_fee_refund(&self) -> String {
+        self.fee_refund
+            .iter()
+            .map(|coin| coin.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
 }
 
 /// Verifies that funds provided are correct and enough for a fee charge, and then constructs
 /// provenance messages that will provide the correct output during the name registration process.
 ///
 /// The validation performed is:
-/// - Ensure no funds provided are of an incorrect denomination.
-/// - Ensure that the provided funds sent are >= the fee charge for transactions
-/// - Ensure that, if more funds are provided than are needed by for the fee, that the excess is caught and refunded
+/// - Ensure no funds provided are of a denomination other than the configured fee denominations.
+/// - Ensure that the provided funds sent are >= the fee charge in each fee denomination for every name being registered, unless the sender is fee exempt
+/// - Ensure that, if more funds are provided than are needed by for the fee, that the excess is caught and refunded,
+///   or rejected entirely when the contract is in strict fee mode
 ///
 /// Returns:
-/// - 1: The message to allocate provided funds to the fee collection account (None if the fee collection amount is instantiated as zero with the contract)
-/// - 2: The message to refund the sender with any excess fees (None if the funds provided are exactly equal to the amount of fee required)
-/// - 3: The amount refunded.  Will be zero if the perfect fund amount if sent.
+/// - 1: The messages to allocate provided funds to each fee recipient (empty if the fee collection amount is instantiated as zero with the contract)
+/// - 2: The message to refund the sender with any excess fees, aggregating every overpaid denomination (None if the funds provided are exactly equal to the amount of fee required)
+/// - 3: The coins refunded.  Will be empty if the perfect fund amount if sent.
+/// - 4: The coins charged, excluding any refund.  Will be empty if no fee is required.
 /// - Various errors if funds provided are not enough or incorrectly formatted
-fn validate_fee_params_get_messages(
+///
+/// When a fee recipient override is provided, the entire fee is sent to it instead of being split
+/// between the configured fee recipients.
+pub fn validate_fee_params_get_messages(
     api: &dyn Api,
     info: &MessageInfo,
     config: &State,
+    name_count: u128,
+    fee_recipient_override: Option<&str>,
 ) -> Result<FeeChargeResponse, ContractError> {
-    // Determine if any funds sent are not of the correct denom
+    // All fee validation and messages use the denominations configured for the contract
+    let fee_coins = config.get_fee_coins();
+
+    // Determine if any funds sent are not of an allowed fee denom
     let invalid_funds = info
         .funds
         .iter()
-        .filter(|coin| coin.denom != FEE_DENOMINATION)
+        .filter(|coin| {
+            !fee_coins
+                .iter()
+                .any(|fee_coin| fee_coin.denom == coin.denom)
+        })
         .map(|coin| coin.denom.clone())
         .collect::<Vec<String>>();
 
-    // If any funds are found that do not match the fee denom, exit prematurely to prevent
+    // If any funds are found that do not match a fee denom, exit prematurely to prevent
     // contract from siphoning random funds for no reason
     if !invalid_funds.is_empty() {
         return ContractError::InvalidFundsProvided {
@@ -152,85 +350,161 @@ fn validate_fee_params_get_messages(
         .to_result();
     }
 
-    let nhash_fee_amount = fee_amount_from_string(&config.fee_amount)?;
+    // Each fee denomination is charged, and any excess refunded, independently of the others
+    let mut fee_charges: Vec<Coin> = vec![];
+    let mut fee_refund: Vec<Coin> = vec![];
+    for fee_coin in fee_coins {
+        // The configured fee is charged once per name registered.  Fee exempt addresses are charged
+        // nothing, so any fee funds they send are refunded in full below
+        let fee_amount = if config.is_fee_exempt(info.sender.as_str()) {
+            0
+        } else {
+            match fee_coin.amount.u128().checked_mul(name_count) {
+                Some(amount) => amount,
+                None => {
+                    return ContractError::std_err(format!(
+                        "fee amount overflowed when charging for {} names",
+                        name_count
+                    ));
+                }
+            }
+        };
+
+        // Pull the fee funds sent for this denomination.  Funds never contain more than one coin
+        // of the same denomination
+        let fee_sent = match info.funds.iter().find(|coin| coin.denom == fee_coin.denom) {
+            Some(coin) => coin.amount,
+            None => {
+                // If fees are required, then a coin of the fee denomination should be sent and the
+                // absence of one is an error.  Otherwise, treat omission as purposeful definition of
+                // zero money fronted for a fee
+                if fee_amount > 0 {
+                    return ContractError::NoFundsProvidedForRegistration.to_result();
+                } else {
+                    Uint128::zero()
+                }
+            }
+        };
 
-    // Pull the nhash sent by verifying that only one fund sent is of the nhash variety
-    let nhash_sent = match info
-        .clone()
-        .funds
-        .into_iter()
-        .find(|coin| coin.denom == FEE_DENOMINATION)
-    {
-        Some(coin) => coin.amount,
-        None => {
-            // If fees are required, then a coin of type FEE_DENOMINATION should be sent and the
-            // absence of one is an error.  Otherwise, treat omission as purposeful definition of
-            // zero money fronted for a fee
-            if nhash_fee_amount > 0 {
-                return ContractError::NoFundsProvidedForRegistration.to_result();
-            } else {
-                Uint128::zero()
+        // If the amount provided is too low, reject the request because the fee cannot be paid
+        if fee_sent.u128() < fee_amount {
+            return ContractError::InsufficientFundsProvided {
+                amount_provided: fee_sent.u128(),
+                amount_required: fee_amount,
             }
+            .to_result();
         }
-    };
 
-    // If the amount provided is too low, reject the request because the fee cannot be paid
-    if nhash_sent.u128() < nhash_fee_amount {
-        return ContractError::InsufficientFundsProvided {
-            amount_provided: nhash_sent.u128(),
-            amount_required: nhash_fee_amount,
+        // The refund amount is == the total fee denomination sent - fee charged.  The insufficient
+        // funds check above prevents an underflow, but the subtraction is still checked in case that
+        // guard is ever moved or changed
+        let fee_refund_amount = match fee_sent.u128().checked_sub(fee_amount) {
+            Some(amount) => amount,
+            None => {
+                return ContractError::FeeRefundUnderflow {
+                    amount_sent: fee_sent.u128(),
+                    fee_amount,
+                }
+                .to_result();
+            }
+        };
+
+        if fee_amount > 0 {
+            fee_charges.push(coin(fee_amount, &fee_coin.denom));
+        }
+        if fee_refund_amount > 0 {
+            fee_refund.push(coin(fee_refund_amount, &fee_coin.denom));
         }
-        .to_result();
     }
 
-    // Pull the fee amount from the sender for name registration
-    let fee_charge_message = if nhash_fee_amount > 0 {
-        Some(CosmosMsg::Bank(BankMsg::Send {
-            // The fee collection address is validated on contract instantiation, so there's no need to
-            // define custom error messages here
-            to_address: api.addr_validate(&config.fee_collection_address)?.into(),
-            // The same goes for the fee_amount - it is guaranteed to pass this check
-            amount: vec![coin(nhash_fee_amount, FEE_DENOMINATION)],
-        }))
-    } else {
-        None
-    };
+    // A contract in strict fee mode requires the exact fee, so any excess is rejected rather than refunded
+    if config.strict_fee_mode && !fee_refund.is_empty() {
+        return ContractError::ExcessFundsProvided { excess: fee_refund }.to_result();
+    }
 
-    // The refund amount is == the total nhash sent - fee charged
-    let fee_refund_amount = nhash_sent.u128() - nhash_fee_amount;
+    // Pull the fee amount from the sender for name registration, splitting it between each fee
+    // recipient by their share of basis points.  Each recipient receives a single message containing
+    // their share of every fee denomination
+    let recipients = match fee_recipient_override {
+        Some(address) => vec![FeeRecipient {
+            address: address.to_string(),
+            basis_points: TOTAL_FEE_BASIS_POINTS,
+        }],
+        None => config.get_fee_recipients(),
+    };
+    let mut recipient_amounts: Vec<Vec<Coin>> = vec![vec![]; recipients.len()];
+    for fee_charge in &fee_charges {
+        let mut shares = recipients
+            .iter()
+            .map(|recipient| {
+                fee_charge
+                    .amount
+                    .multiply_ratio(recipient.basis_points, TOTAL_FEE_BASIS_POINTS)
+                    .u128()
+            })
+            .collect::<Vec<u128>>();
+        // Integer division can leave a remainder that no share accounts for.  It is given to the
+        // first recipient to ensure that the entire fee is always collected
+        let remainder = fee_charge.amount.u128() - shares.iter().sum::<u128>();
+        if let Some(first_share) = shares.first_mut() {
+            *first_share += remainder;
+        }
+        for (amounts, share) in recipient_amounts.iter_mut().zip(shares) {
+            if share > 0 {
+                amounts.push(coin(share, &fee_charge.denom));
+            }
+        }
+    }
+    let mut fee_charge_messages = vec![];
+    for (recipient, amount) in recipients.iter().zip(recipient_amounts) {
+        if amount.is_empty() {
+            continue;
+        }
+        fee_charge_messages.push(CosmosMsg::Bank(BankMsg::Send {
+            // The fee recipient addresses are validated on contract instantiation, so there's no
+            // need to define custom error messages here
+            to_address: api.addr_validate(&recipient.address)?.into(),
+            // The fee amounts are parsed and stored as numeric values on instantiation as well
+            amount,
+        }));
+    }
 
     // If more than the fee amount is sent, then respond with an additional message that sends the
-    // excess back into the sender's account
-    let fee_refund_message = if fee_refund_amount > 0 {
+    // excess of every overpaid denomination back into the sender's account
+    let fee_refund_message = if !fee_refund.is_empty() {
         Some(CosmosMsg::Bank(BankMsg::Send {
             to_address: info.sender.clone().into(),
-            amount: vec![coin(fee_refund_amount, FEE_DENOMINATION)],
+            amount: fee_refund.clone(),
         }))
     } else {
         None
     };
 
     Ok(FeeChargeResponse {
-        fee_charge_message,
+        fee_charge_messages,
         fee_refund_message,
-        fee_refund_amount,
+        fee_refund,
+        fee_charged: fee_charges,
     })
 }
 
 #[cfg(test)]
 pub mod tests {
     use crate::core::error::ContractError;
-    use crate::core::state::meta;
-    use crate::execute::register_name::{register_name, validate_name};
+    use crate::core::state::{config_read, FeeRecipient, State};
+    use crate::core::state::{last_registration_read, meta_read, name_index_read};
+    use crate::execute::register_name::{
+        register_name, validate_fee_params_get_messages, validate_name,
+    };
     use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
     use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
-    use crate::util::constants::FEE_DENOMINATION;
+    use crate::util::constants::{DEFAULT_FEE_DENOMINATION, MAX_MEMO_LENGTH};
     use crate::util::helper_functions::fee_amount_from_string;
-    use cosmwasm_std::testing::mock_info;
-    use cosmwasm_std::{coin, from_binary, BankMsg, Coin, CosmosMsg};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, BankMsg, Coin, CosmosMsg, DepsMut};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{
-        AttributeMsgParams, AttributeValueType, ProvenanceMsg, ProvenanceMsgParams,
+        AttributeMsgParams, AttributeValueType, ProvenanceMsg, ProvenanceMsgParams, ProvenanceQuery,
     };
 
     #[test]
@@ -251,8 +525,11 @@ pub mod tests {
 
         let res = register_name(
             deps.as_mut(),
+            mock_env(),
             mock_info("somedude", &vec![coin(150, "nhash")]),
             "mycoolname".into(),
+            None,
+            None,
         )
         .unwrap();
 
@@ -276,7 +553,7 @@ pub mod tests {
                     transferred_coin.amount.u128(),
                     fee_amount_from_string("150").unwrap()
                 );
-                assert_eq!(transferred_coin.denom.as_str(), FEE_DENOMINATION);
+                assert_eq!(transferred_coin.denom.as_str(), DEFAULT_FEE_DENOMINATION);
             }
             _ => panic!("unexpected message type"),
         });
@@ -307,8 +584,11 @@ pub mod tests {
         // Send 50 more than the required fee amount
         let response = register_name(
             deps.as_mut(),
-            mock_info("sender_wallet", &vec![coin(200, FEE_DENOMINATION)]),
+            mock_env(),
+            mock_info("sender_wallet", &vec![coin(200, DEFAULT_FEE_DENOMINATION)]),
             "thebestnameever".into(),
+            None,
+            None,
         )
         .unwrap();
 
@@ -368,118 +648,604 @@ pub mod tests {
     }
 
     #[test]
-    fn test_zero_fee_allows_no_amounts() {
+    fn test_fee_refund_boundaries() {
         let mut deps = mock_dependencies(&[]);
         test_instantiate(
             deps.as_mut(),
             InstArgs {
-                fee_amount: 0,
-                fee_collection_address: "feebucket",
+                fee_amount: 150,
                 ..Default::default()
             },
         )
         .unwrap();
-        // Send no coin with the request under the assumption that zero fee should allow this
-        let zero_fee_resp = register_name(
+        let config = config_read(deps.as_ref().storage).load().unwrap();
+        let charge_for = |amount_sent: u128| {
+            validate_fee_params_get_messages(
+                deps.as_ref().api,
+                &mock_info(
+                    "sender_wallet",
+                    &[coin(amount_sent, DEFAULT_FEE_DENOMINATION)],
+                ),
+                &config,
+                1,
+                None,
+            )
+        };
+        // Sending exactly the fee leaves nothing to refund
+        let exact = charge_for(150).expect("sending exactly the fee should be accepted");
+        assert!(exact.fee_refund.is_empty());
+        assert!(
+            exact.fee_refund_message.is_none(),
+            "no refund should be sent when exactly the fee is provided",
+        );
+        // Sending one more than the fee refunds exactly one
+        let overage = charge_for(151).expect("sending more than the fee should be accepted");
+        assert_eq!(vec![coin(1, DEFAULT_FEE_DENOMINATION)], overage.fee_refund);
+        assert!(
+            overage.fee_refund_message.is_some(),
+            "a refund should be sent when more than the fee is provided",
+        );
+        // Sending one less than the fee is rejected before a refund is ever computed
+        let error = match charge_for(149) {
+            Ok(_) => panic!("expected sending less than the fee to be rejected"),
+            Err(error) => error,
+        };
+        assert!(
+            matches!(
+                error,
+                ContractError::InsufficientFundsProvided {
+                    amount_provided: 149,
+                    amount_required: 150,
+                }
+            ),
+            "expected sending less than the fee to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_fee_overage_in_one_of_multiple_denoms_is_refunded() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
             deps.as_mut(),
-            mock_info("senderwallet", &[]),
-            "nameofmine".into(),
+            InstArgs {
+                fee_amount: 150,
+                fee_collection_address: "fee_bucket",
+                additional_fees: Some(vec![coin(20, "usdf")]),
+                ..Default::default()
+            },
         )
         .unwrap();
-        assert_eq!(1, zero_fee_resp.messages.len(), "only one message should be responded with because no fee occurred and no refund occurred");
-        zero_fee_resp
+        // Overpay the fee denomination by 50 and send exactly the additional fee
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender_wallet",
+                &[coin(200, DEFAULT_FEE_DENOMINATION), coin(20, "usdf")],
+            ),
+            "thebestnameever".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            response.messages.len(),
+            3,
+            "the add attribute, fee charge and a single refund message should be returned",
+        );
+        let bank_sends = response
             .messages
             .into_iter()
-            .for_each(|msg| match msg.msg {
-                CosmosMsg::Custom(ProvenanceMsg { params, .. }) => {
-                    verify_add_attribute_result(params, "wallet.pb", "nameofmine");
-                }
-                _ => panic!("unexpected response message type"),
-            });
-        let refund_attr = zero_fee_resp
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<(String, Vec<Coin>)>>();
+        assert_eq!(
+            vec![
+                (
+                    "fee_bucket".to_string(),
+                    vec![coin(150, DEFAULT_FEE_DENOMINATION), coin(20, "usdf")],
+                ),
+                (
+                    "sender_wallet".to_string(),
+                    vec![coin(50, DEFAULT_FEE_DENOMINATION)],
+                ),
+            ],
+            bank_sends,
+            "the fee collector should receive both fees and only the overpaid denom should be refunded",
+        );
+        let fee_refund_attr = response
             .attributes
             .into_iter()
-            .find(|attr| attr.key.as_str() == "fee_refund");
-        assert!(
-            refund_attr.is_none(),
-            "no refund should occur with no amount passed in"
-        );
+            .find(|attr| attr.key.as_str() == "fee_refund")
+            .unwrap();
+        assert_eq!(fee_refund_attr.value.as_str(), "50nhash");
     }
 
     #[test]
-    fn test_zero_fee_and_fee_overage_provided_results_in_full_refund() {
+    fn test_fee_overages_in_multiple_denoms_are_refunded_in_one_message() {
         let mut deps = mock_dependencies(&[]);
         test_instantiate(
             deps.as_mut(),
             InstArgs {
-                fee_amount: 0,
-                fee_collection_address: "feebucket",
+                fee_amount: 150,
+                additional_fees: Some(vec![coin(20, "usdf")]),
                 ..Default::default()
             },
         )
         .unwrap();
-        // Send a coin overage of nhash to ensure all of it gets returned as a refund
-        let refund_resp = register_name(
-            deps.as_mut(),
-            mock_info("sender_wallet", &vec![coin(200, FEE_DENOMINATION)]),
-            "nametouse".into(),
+        let config = config_read(deps.as_ref().storage).load().unwrap();
+        let charge_response = validate_fee_params_get_messages(
+            deps.as_ref().api,
+            &mock_info(
+                "sender_wallet",
+                &[coin(151, DEFAULT_FEE_DENOMINATION), coin(25, "usdf")],
+            ),
+            &config,
+            1,
+            None,
         )
         .unwrap();
         assert_eq!(
-            2,
-            refund_resp.messages.len(),
-            "two messages should be responded with when a fee is not charged, but a refund is made"
+            vec![coin(1, DEFAULT_FEE_DENOMINATION), coin(5, "usdf")],
+            charge_response.fee_refund,
         );
-        refund_resp.messages.into_iter().for_each(|msg| match msg.msg {
-            CosmosMsg::Custom(ProvenanceMsg { params, .. }) => {
-                verify_add_attribute_result(params, "wallet.pb", "nametouse");
-            }
-            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
-                assert_eq!(to_address.as_str(), "sender_wallet", "the recipient of the transaction should be the sender because all funds allocated were refunded");
-                let coin_amount_sent = validate_and_get_nhash_sent(amount);
-                assert_eq!(coin_amount_sent, 200, "all funds sent should be refunded");
-            }
-            _ => panic!("unexpected response message type"),
-        });
-        let fee_refund_attr = refund_resp
-            .attributes
-            .into_iter()
-            .find(|attr| attr.key.as_str() == "fee_refund")
-            .expect("the refunded fee amount should be added as an attribute");
         assert_eq!(
-            fee_refund_attr.value.as_str(),
-            "200nhash",
-            "expected the refund amount to be indicated as nhash"
+            Some(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "sender_wallet".to_string(),
+                amount: vec![coin(1, DEFAULT_FEE_DENOMINATION), coin(5, "usdf")],
+            })),
+            charge_response.fee_refund_message,
+            "every overpaid denom should be refunded in a single message",
+        );
+        // Omitting the additional fee denom entirely is rejected
+        let error = validate_fee_params_get_messages(
+            deps.as_ref().api,
+            &mock_info("sender_wallet", &[coin(150, DEFAULT_FEE_DENOMINATION)]),
+            &config,
+            1,
+            None,
+        )
+        .err()
+        .expect("omitting a required fee denom should be rejected");
+        assert!(
+            matches!(error, ContractError::NoFundsProvidedForRegistration),
+            "expected omitting a required fee denom to be rejected, but got: {:?}",
+            error,
         );
     }
 
     #[test]
-    fn test_duplicate_registrations_are_rejected() {
-        // Create mocks
+    fn test_fee_overage_is_rejected_in_strict_fee_mode() {
         let mut deps = mock_dependencies(&[]);
-
-        // Create config state
-        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
-        let m_info = mock_info("somedude", &vec![coin(DEFAULT_FEE_AMOUNT, "nhash")]);
-        // Do first execution to ensure the new name is in there
-        register_name(deps.as_mut(), m_info.clone(), "mycoolname".into()).unwrap();
-        // Try a duplicate request
-        let rejected = register_name(deps.as_mut(), m_info, "mycoolname".into()).unwrap_err();
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 150,
+                strict_fee_mode: Some(true),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(
+            config_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .strict_fee_mode,
+            "strict fee mode should be stored when enabled at instantiation",
+        );
+        let rejected = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &[coin(200, DEFAULT_FEE_DENOMINATION)]),
+            "overpaid".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
         match rejected {
-            ContractError::NameRegistered { name } => {
-                assert_eq!("mycoolname".to_string(), name);
+            ContractError::ExcessFundsProvided { excess } => {
+                assert_eq!(vec![coin(50, DEFAULT_FEE_DENOMINATION)], excess)
             }
-            _ => panic!("unexpected error for proposed duplicate message"),
+            _ => panic!(
+                "expected overpayment to be rejected in strict fee mode, but got: {:?}",
+                rejected
+            ),
         };
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .load("overpaid".as_bytes())
+                .is_err(),
+            "the name should not be registered when the overpayment is rejected",
+        );
+        // Sending the exact fee is still accepted without a refund
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &[coin(150, DEFAULT_FEE_DENOMINATION)]),
+            "exactpayment".into(),
+            None,
+            None,
+        )
+        .expect("expected the exact fee to be accepted in strict fee mode");
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "only the name attribute and fee messages should be sent for an exact payment",
+        );
     }
 
     #[test]
-    fn test_missing_fee_amount_for_registration() {
+    fn test_fee_overages_in_multiple_denoms_are_all_reported_in_strict_fee_mode() {
         let mut deps = mock_dependencies(&[]);
-        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
-        // No fees provided in mock info - this should cause a rejection
-        let rejected_no_coin =
-            register_name(deps.as_mut(), mock_info("theguy", &[]), "newname".into()).unwrap_err();
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 150,
+                additional_fees: Some(vec![coin(20, "usdf")]),
+                strict_fee_mode: Some(true),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let rejected = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender_wallet",
+                &[coin(151, DEFAULT_FEE_DENOMINATION), coin(25, "usdf")],
+            ),
+            "overpaid".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        match rejected {
+            ContractError::ExcessFundsProvided { excess } => assert_eq!(
+                vec![coin(1, DEFAULT_FEE_DENOMINATION), coin(5, "usdf")],
+                excess,
+                "every overpaid denomination should be reported",
+            ),
+            _ => panic!(
+                "expected overpayment to be rejected in strict fee mode, but got: {:?}",
+                rejected
+            ),
+        };
+    }
+
+    #[test]
+    fn test_fee_overage_is_refunded_when_strict_fee_mode_is_disabled() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 150,
+                strict_fee_mode: Some(false),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &[coin(200, DEFAULT_FEE_DENOMINATION)]),
+            "overpaid".into(),
+            None,
+            None,
+        )
+        .expect("expected overpayment to be refunded when strict fee mode is disabled");
+        let refund = response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "fee_refund")
+            .expect("expected a fee refund attribute for an overpayment");
+        assert_eq!(format!("50{}", DEFAULT_FEE_DENOMINATION), refund.value);
+    }
+
+    #[test]
+    fn test_register_with_custom_fee_denomination() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 150,
+                fee_collection_address: "fee_bucket",
+                fee_denomination: Some("customcoin"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        // The default denomination is no longer accepted once a custom one is configured
+        let error = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &[coin(150, DEFAULT_FEE_DENOMINATION)]),
+            "customname".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFundsProvided { .. }),
+            "expected the default denomination to be rejected when a custom one is configured",
+        );
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &[coin(200, "customcoin")]),
+            "customname".into(),
+            None,
+            None,
+        )
+        .expect("registration should succeed when paying in the configured denomination");
+        response.messages.into_iter().for_each(|msg| match msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg { params, .. }) => {
+                verify_add_attribute_result(params, "wallet.pb", "customname");
+            }
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(1, amount.len(), "expected a single coin to be sent");
+                let sent_coin = amount.first().unwrap();
+                assert_eq!(
+                    "customcoin", sent_coin.denom,
+                    "expected the configured denomination to be used"
+                );
+                match to_address.as_str() {
+                    "fee_bucket" => assert_eq!(150, sent_coin.amount.u128()),
+                    "sender_wallet" => assert_eq!(50, sent_coin.amount.u128()),
+                    _ => panic!("unexpected to_address encountered"),
+                }
+            }
+            _ => panic!("unexpected message type"),
+        });
+        let refund_attr = response
+            .attributes
+            .iter()
+            .find(|attr| attr.key.as_str() == "fee_refund")
+            .expect("a refund attribute should be emitted for the overage");
+        assert_eq!("50customcoin", refund_attr.value.as_str());
+    }
+
+    #[test]
+    fn test_fee_is_split_between_fee_recipients() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 100,
+                fee_distribution: Some(vec![
+                    FeeRecipient {
+                        address: "first_bucket".into(),
+                        basis_points: 3333,
+                    },
+                    FeeRecipient {
+                        address: "second_bucket".into(),
+                        basis_points: 3333,
+                    },
+                    FeeRecipient {
+                        address: "third_bucket".into(),
+                        basis_points: 3334,
+                    },
+                ]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &[coin(100, DEFAULT_FEE_DENOMINATION)]),
+            "splitname".into(),
+            None,
+            None,
+        )
+        .expect("registration should succeed with a fee distribution");
+        let fee_sends = response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    assert_eq!(1, amount.len(), "expected a single coin to be sent");
+                    Some((to_address, amount.first().unwrap().amount.u128()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<(String, u128)>>();
+        // Each recipient receives 33, and the remainder of 1 is given to the first recipient
+        assert_eq!(
+            vec![
+                ("first_bucket".to_string(), 34),
+                ("second_bucket".to_string(), 33),
+                ("third_bucket".to_string(), 33),
+            ],
+            fee_sends,
+            "expected the fee to be split by basis points, with the remainder sent to the first recipient",
+        );
+    }
+
+    #[test]
+    fn test_zero_fee_allows_no_amounts() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 0,
+                fee_collection_address: "feebucket",
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        // Send no coin with the request under the assumption that zero fee should allow this
+        let zero_fee_resp = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("senderwallet", &[]),
+            "nameofmine".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(1, zero_fee_resp.messages.len(), "only one message should be responded with because no fee occurred and no refund occurred");
+        zero_fee_resp
+            .messages
+            .into_iter()
+            .for_each(|msg| match msg.msg {
+                CosmosMsg::Custom(ProvenanceMsg { params, .. }) => {
+                    verify_add_attribute_result(params, "wallet.pb", "nameofmine");
+                }
+                _ => panic!("unexpected response message type"),
+            });
+        let refund_attr = zero_fee_resp
+            .attributes
+            .into_iter()
+            .find(|attr| attr.key.as_str() == "fee_refund");
+        assert!(
+            refund_attr.is_none(),
+            "no refund should occur with no amount passed in"
+        );
+    }
+
+    #[test]
+    fn test_zero_fee_and_fee_overage_provided_results_in_full_refund() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 0,
+                fee_collection_address: "feebucket",
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        // Send a coin overage of nhash to ensure all of it gets returned as a refund
+        let refund_resp = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender_wallet", &vec![coin(200, DEFAULT_FEE_DENOMINATION)]),
+            "nametouse".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            2,
+            refund_resp.messages.len(),
+            "two messages should be responded with when a fee is not charged, but a refund is made"
+        );
+        refund_resp.messages.into_iter().for_each(|msg| match msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg { params, .. }) => {
+                verify_add_attribute_result(params, "wallet.pb", "nametouse");
+            }
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(to_address.as_str(), "sender_wallet", "the recipient of the transaction should be the sender because all funds allocated were refunded");
+                let coin_amount_sent = validate_and_get_nhash_sent(amount);
+                assert_eq!(coin_amount_sent, 200, "all funds sent should be refunded");
+            }
+            _ => panic!("unexpected response message type"),
+        });
+        let fee_refund_attr = refund_resp
+            .attributes
+            .into_iter()
+            .find(|attr| attr.key.as_str() == "fee_refund")
+            .expect("the refunded fee amount should be added as an attribute");
+        assert_eq!(
+            fee_refund_attr.value.as_str(),
+            "200nhash",
+            "expected the refund amount to be indicated as nhash"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_registrations_are_rejected() {
+        // Create mocks
+        let mut deps = mock_dependencies(&[]);
+
+        // Create config state
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let m_info = mock_info("somedude", &vec![coin(DEFAULT_FEE_AMOUNT, "nhash")]);
+        // Do first execution to ensure the new name is in there
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info.clone(),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        // Try a duplicate request
+        let rejected = register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info,
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        match rejected {
+            ContractError::NameRegistered { name } => {
+                assert_eq!("mycoolname".to_string(), name);
+            }
+            _ => panic!("unexpected error for proposed duplicate message"),
+        };
+    }
+
+    #[test]
+    fn test_names_differing_only_in_case_collide() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let m_info = mock_info("somedude", &[coin(DEFAULT_FEE_AMOUNT, "nhash")]);
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info.clone(),
+            "MyCoolName".into(),
+            None,
+            None,
+        )
+        .expect("expected a name containing uppercase characters to be registered");
+        let name_meta = meta_read(deps.as_ref().storage)
+            .load("mycoolname".as_bytes())
+            .expect("expected the name to be stored under its lowercase form");
+        assert_eq!(
+            "MyCoolName", name_meta.name,
+            "expected the original casing to be retained for display",
+        );
+        for duplicate in ["mycoolname", "MYCOOLNAME", "MyCoolName"] {
+            let rejected = register_name(
+                deps.as_mut(),
+                mock_env(),
+                m_info.clone(),
+                duplicate.into(),
+                None,
+                None,
+            )
+            .unwrap_err();
+            assert!(
+                matches!(rejected, ContractError::NameRegistered { .. }),
+                "expected {} to collide with the registered name, but got: {:?}",
+                duplicate,
+                rejected,
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_fee_amount_for_registration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        // No fees provided in mock info - this should cause a rejection
+        let rejected_no_coin = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("theguy", &[]),
+            "newname".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
         assert!(matches!(
             rejected_no_coin,
             ContractError::NoFundsProvidedForRegistration
@@ -496,8 +1262,15 @@ pub mod tests {
                 coin(DEFAULT_FEE_AMOUNT, "nhash"),
             ],
         );
-        let rejected_incorrect_type_coin =
-            register_name(deps.as_mut(), incorrect_denom_info, "newname".into()).unwrap_err();
+        let rejected_incorrect_type_coin = register_name(
+            deps.as_mut(),
+            mock_env(),
+            incorrect_denom_info,
+            "newname".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
         match rejected_incorrect_type_coin {
             ContractError::InvalidFundsProvided { types } => {
                 assert_eq!(
@@ -525,7 +1298,8 @@ pub mod tests {
     #[test]
     fn test_invalid_name_format_scenarios() {
         let mut deps = mock_dependencies(&[]);
-        let empty_bucket = meta(deps.as_mut().storage);
+        let config = test_config(deps.as_mut());
+        let empty_bucket = meta_read(deps.as_ref().storage);
         // Establish a decent set of non-alphanumeric characters to test against
         let special_characters = vec![
             ".", ",", "<", ">", "/", "?", ";", ":", "'", "\"", "[", "]", "{", "}", "-", "_", "+",
@@ -533,28 +1307,672 @@ pub mod tests {
         ];
         special_characters.into_iter().for_each(|character| {
             let test_name = format!("name{}", character);
-            let response = validate_name(test_name.clone(), &empty_bucket).unwrap_err();
+            let response = validate_name(
+                test_name.clone(),
+                &empty_bucket,
+                &config,
+                mock_env().block.height,
+            )
+            .unwrap_err();
             assert!(
                 matches!(response, ContractError::InvalidNameFormat { .. }),
                 "Expected the name {} to be rejected as an invalid name",
                 test_name,
             );
         });
-        let empty_name_response = validate_name("".into(), &empty_bucket).unwrap_err();
+        let empty_name_response =
+            validate_name("".into(), &empty_bucket, &config, mock_env().block.height).unwrap_err();
         assert!(
             matches!(empty_name_response, ContractError::InvalidNameFormat { .. }),
             "Expected an empty name to be rejected as invalid input",
         );
-        let uppercase_name_response = validate_name("A".into(), &empty_bucket).unwrap_err();
+        validate_name(
+            "MyName".into(),
+            &empty_bucket,
+            &config,
+            mock_env().block.height,
+        )
+        .expect("expected a name containing uppercase characters to be valid");
+        validate_name(
+            "abc1".into(),
+            &empty_bucket,
+            &config,
+            mock_env().block.height,
+        )
+        .expect("expected a name containing a number to be valid");
+    }
+
+    #[test]
+    fn test_invalid_name_length_scenarios() {
+        let mut deps = mock_dependencies(&[]);
+        let config = test_config(deps.as_mut());
+        let empty_bucket = meta_read(deps.as_ref().storage);
+        let min = config.get_min_name_length() as usize;
+        let max = config.get_max_name_length() as usize;
+        let too_short_response = validate_name(
+            "a".repeat(min - 1),
+            &empty_bucket,
+            &config,
+            mock_env().block.height,
+        )
+        .unwrap_err();
         assert!(
-            matches!(
-                uppercase_name_response,
-                ContractError::InvalidNameFormat { .. }
+            matches!(too_short_response, ContractError::InvalidNameLength { .. }),
+            "Expected a name shorter than the minimum length to be rejected",
+        );
+        let too_long_response = validate_name(
+            "a".repeat(max + 1),
+            &empty_bucket,
+            &config,
+            mock_env().block.height,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(too_long_response, ContractError::InvalidNameLength { .. }),
+            "Expected a name longer than the maximum length to be rejected",
+        );
+        validate_name(
+            "a".repeat(min),
+            &empty_bucket,
+            &config,
+            mock_env().block.height,
+        )
+        .expect("expected a name of exactly the minimum length to be valid");
+        validate_name(
+            "a".repeat(max),
+            &empty_bucket,
+            &config,
+            mock_env().block.height,
+        )
+        .expect("expected a name of exactly the maximum length to be valid");
+    }
+
+    #[test]
+    fn test_configured_name_length_bounds_are_enforced() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                min_name_length: Some(5),
+                max_name_length: Some(6),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let error = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "abcd".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        match error {
+            ContractError::InvalidNameLength { name, min, max } => {
+                assert_eq!(
+                    "abcd", name,
+                    "expected the rejected name to be included in the error"
+                );
+                assert_eq!(
+                    5, min,
+                    "expected the configured minimum to be included in the error"
+                );
+                assert_eq!(
+                    6, max,
+                    "expected the configured maximum to be included in the error"
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "abcdef".into(),
+            None,
+            None,
+        )
+        .expect("expected a name within the configured bounds to be registered");
+    }
+
+    #[test]
+    fn test_reserved_names_are_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                reserved_names: Some(vec!["admin".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let m_info = mock_info(
+            "sender",
+            &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+        );
+        for reserved in ["admin", "Admin"] {
+            let rejected = register_name(
+                deps.as_mut(),
+                mock_env(),
+                m_info.clone(),
+                reserved.into(),
+                None,
+                None,
+            )
+            .unwrap_err();
+            match rejected {
+                ContractError::NameReserved { name } => assert_eq!(reserved, name),
+                _ => panic!(
+                    "expected {} to be rejected as a reserved name, but got: {:?}",
+                    reserved, rejected
+                ),
+            };
+        }
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info,
+            "administrator".into(),
+            None,
+            None,
+        )
+        .expect("expected a name that is not reserved to be registered");
+    }
+
+    #[test]
+    fn test_registration_cooldown_is_enforced() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_cooldown_blocks: Some(10),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let m_info = mock_info(
+            "sender",
+            &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+        );
+        let mut env = mock_env();
+        let registration_height = env.block.height;
+        register_name(
+            deps.as_mut(),
+            env.clone(),
+            m_info.clone(),
+            "first".into(),
+            None,
+            None,
+        )
+        .expect("expected the first registration to succeed");
+        env.block.height = registration_height + 3;
+        let rejected = register_name(
+            deps.as_mut(),
+            env.clone(),
+            m_info.clone(),
+            "second".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        match rejected {
+            ContractError::RegistrationCooldown { blocks_remaining } => {
+                assert_eq!(7, blocks_remaining)
+            }
+            _ => panic!(
+                "expected a registration within the cooldown to be rejected, but got: {:?}",
+                rejected
+            ),
+        };
+        // Other addresses are not affected by the sender's cooldown
+        register_name(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(
+                "other",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "other".into(),
+            None,
+            None,
+        )
+        .expect("expected a different address to register during the sender's cooldown");
+        env.block.height = registration_height + 10;
+        register_name(deps.as_mut(), env, m_info, "second".into(), None, None)
+            .expect("expected a registration to succeed once the cooldown has elapsed");
+    }
+
+    #[test]
+    fn test_zero_registration_cooldown_is_disabled() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_cooldown_blocks: Some(0),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let m_info = mock_info(
+            "sender",
+            &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+        );
+        for name in ["first", "second"] {
+            register_name(
+                deps.as_mut(),
+                mock_env(),
+                m_info.clone(),
+                name.into(),
+                None,
+                None,
+            )
+            .expect("expected repeated registrations to succeed without a cooldown");
+        }
+        assert!(
+            last_registration_read(deps.as_ref().storage)
+                .may_load("sender".as_bytes())
+                .unwrap()
+                .is_none(),
+            "no registration heights should be tracked when the cooldown is disabled",
+        );
+    }
+
+    #[test]
+    fn test_memo_is_emitted_as_an_attribute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let m_info = mock_info(
+            "sender",
+            &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+        );
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info.clone(),
+            "withmemo".into(),
+            Some("contact me at the usual place".into()),
+            None,
+        )
+        .expect("expected a name with a valid memo to be registered");
+        let memo = response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "memo")
+            .expect("expected the memo to be emitted as an attribute");
+        assert_eq!("contact me at the usual place", memo.value);
+        // A memo at exactly the maximum length is accepted
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info.clone(),
+            "longmemo".into(),
+            Some("a".repeat(MAX_MEMO_LENGTH)),
+            None,
+        )
+        .expect("expected a memo at the maximum length to be accepted");
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            m_info,
+            "withoutmemo".into(),
+            None,
+            None,
+        )
+        .expect("expected a name without a memo to be registered");
+        assert!(
+            response.attributes.iter().all(|attr| attr.key != "memo"),
+            "no memo attribute should be emitted when a memo is omitted",
+        );
+    }
+
+    #[test]
+    fn test_memo_that_is_too_long_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let rejected = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "toolong".into(),
+            Some("a".repeat(MAX_MEMO_LENGTH + 1)),
+            None,
+        )
+        .unwrap_err();
+        match rejected {
+            ContractError::MemoTooLong { length, max } => {
+                assert_eq!(MAX_MEMO_LENGTH + 1, length);
+                assert_eq!(MAX_MEMO_LENGTH, max);
+            }
+            _ => panic!("expected a memo too long error, but got: {:?}", rejected),
+        };
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .load("toolong".as_bytes())
+                .is_err(),
+            "the name should not be registered when the memo is rejected",
+        );
+    }
+
+    #[test]
+    fn test_allowlisted_fee_recipient_override_receives_the_fee() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_recipient_override_addresses: Some(vec!["partner".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "partner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "resold".into(),
+            None,
+            Some("reseller".into()),
+        )
+        .expect("an allowlisted address should be able to override the fee recipient");
+        let fee_sends = response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<(String, Vec<Coin>)>>();
+        assert_eq!(
+            vec![(
+                "reseller".to_string(),
+                vec![coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)]
+            )],
+            fee_sends,
+            "expected the entire fee to be sent to the override recipient",
+        );
+        let fee_recipient = response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "fee_recipient")
+            .expect("expected the fee recipient to be emitted as an attribute");
+        assert_eq!("reseller", fee_recipient.value);
+    }
+
+    #[test]
+    fn test_fee_recipient_override_is_rejected_for_non_allowlisted_address() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_recipient_override_addresses: Some(vec!["partner".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let rejected = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "resold".into(),
+            None,
+            Some("reseller".into()),
+        )
+        .unwrap_err();
+        match rejected {
+            ContractError::FeeRecipientOverrideNotAllowed { address } => {
+                assert_eq!("sender", address)
+            }
+            _ => panic!(
+                "expected a fee recipient override not allowed error, but got: {:?}",
+                rejected
+            ),
+        };
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .load("resold".as_bytes())
+                .is_err(),
+            "the name should not be registered when the override is rejected",
+        );
+    }
+
+    #[test]
+    fn test_registration_records_expiration_height() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let env = mock_env();
+        let response = register_name(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(
+                "first_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "expiring".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let expiration_height = env.block.height + 100;
+        assert_eq!(
+            Some(expiration_height),
+            meta_read(deps.as_ref().storage)
+                .load("expiring".as_bytes())
+                .unwrap()
+                .expiration_height,
+            "the registration should expire one registration duration after it was made",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "expiration_height"
+                    && attr.value == expiration_height.to_string()),
+            "the expiration height should be emitted as an attribute",
+        );
+        // Another address cannot claim the name before it expires
+        let mut env = mock_env();
+        env.block.height = expiration_height - 1;
+        let error = register_name(
+            deps.as_mut(),
+            env,
+            mock_info(
+                "second_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "expiring".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameRegistered { .. }),
+            "expected an unexpired name to remain registered, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_expired_name_can_be_claimed_by_another_address() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "first_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "expiring".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.height += 100;
+        let response = register_name(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(
+                "second_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
             ),
-            "Expected an uppercase name to be rejected as invalid input",
+            "expiring".into(),
+            None,
+            None,
+        )
+        .expect("an expired name should be claimable by another address");
+        let name_meta = meta_read(deps.as_ref().storage)
+            .load("expiring".as_bytes())
+            .unwrap();
+        assert_eq!(
+            "second_owner", name_meta.address,
+            "the name should be owned by the claiming address",
+        );
+        assert_eq!(
+            Some(env.block.height + 100),
+            name_meta.expiration_height,
+            "the claim should start a new registration duration",
         );
-        validate_name("a1".into(), &empty_bucket)
-            .expect("expected a name containing a number to be valid");
+        assert!(
+            name_index_read(deps.as_ref().storage, "first_owner")
+                .may_load("expiring".as_bytes())
+                .unwrap()
+                .is_none(),
+            "the name should be removed from the previous owner's index",
+        );
+        assert!(
+            name_index_read(deps.as_ref().storage, "second_owner")
+                .may_load("expiring".as_bytes())
+                .unwrap()
+                .is_some(),
+            "the name should be added to the new owner's index",
+        );
+        assert!(
+            response.messages.iter().any(|msg| matches!(
+                &msg.msg,
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params: ProvenanceMsgParams::Attribute(
+                        AttributeMsgParams::DeleteDistinctAttribute { address, .. }
+                    ),
+                    ..
+                }) if address.as_str() == "first_owner"
+            )),
+            "the previous owner's attribute for the name should be deleted",
+        );
+    }
+
+    #[test]
+    fn test_expired_name_can_be_reclaimed_by_its_previous_owner() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let fee = [coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)];
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &fee),
+            "expiring".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.height += 100;
+        let response = register_name(
+            deps.as_mut(),
+            env,
+            mock_info("owner", &fee),
+            "expiring".into(),
+            None,
+            None,
+        )
+        .expect("an expired name should be claimable by its previous owner");
+        // The previous attribute must be deleted before the new one is added, or the owner would be
+        // left without an attribute for the name
+        match &response.messages[0].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteDistinctAttribute {
+                        address,
+                        value,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("owner", address.as_str());
+                assert_eq!("expiring", from_binary::<String>(value).unwrap());
+            }
+            msg => panic!(
+                "expected the expired attribute to be deleted first, but got: {:?}",
+                msg
+            ),
+        }
+        match &response.messages[1].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                        address,
+                        value,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("owner", address.as_str());
+                assert_eq!("expiring", from_binary::<String>(value).unwrap());
+            }
+            msg => panic!(
+                "expected the new attribute to be added after the deletion, but got: {:?}",
+                msg
+            ),
+        }
+        assert!(
+            name_index_read(deps.as_ref().storage, "owner")
+                .may_load("expiring".as_bytes())
+                .unwrap()
+                .is_some(),
+            "the name should remain in the owner's index",
+        );
+    }
+
+    /// Helper to instantiate the contract with default values and fetch the resulting config.
+    fn test_config(mut deps: DepsMut<ProvenanceQuery>) -> State {
+        test_instantiate(deps.branch(), InstArgs::default()).unwrap();
+        config_read(deps.storage).load().unwrap()
     }
 
     /// Helper to verify that a name was properly registered under the appropriate registrar.
@@ -592,11 +2010,11 @@ pub mod tests {
         );
         amount
             .into_iter()
-            .find(|coin| coin.denom == FEE_DENOMINATION)
+            .find(|coin| coin.denom == DEFAULT_FEE_DENOMINATION)
             .expect(
                 format!(
                     "there should be a coin entry of type [{}]",
-                    FEE_DENOMINATION
+                    DEFAULT_FEE_DENOMINATION
                 )
                 .as_str(),
             )
@@ -0,0 +1,364 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config, meta, meta_read, name_index, record_registration_stats, NameMeta,
+};
+use crate::execute::register_name::{
+    check_registration_cooldown, release_expired_registration, validate_fee_params_get_messages,
+    validate_name,
+};
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{add_attribute, ProvenanceMsg, ProvenanceQuery};
+
+// register multiple names
+// This will bind every provided name to the account that invoked this contract in a single
+// transaction.  Every name is validated before anything is stored, so a single invalid or
+// already-registered name rejects the entire batch.  The configured fee is charged once per name
+// in a single fee message, and any overage is refunded once at the end.
+pub fn register_names(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    names: Vec<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if names.is_empty() {
+        return ContractError::InvalidFields {
+            fields: vec!["names".to_string()],
+        }
+        .to_result();
+    }
+    let config = config(deps.storage).load()?;
+    let meta_storage = meta_read(deps.storage);
+
+    // Validate every name up front.  Names repeated within the batch, in any casing, are rejected
+    // in the same manner as names that were registered before this request
+    let mut validated_names: Vec<String> = vec![];
+    for name in names {
+        if validated_names
+            .iter()
+            .any(|validated_name| normalize_name(validated_name) == normalize_name(&name))
+        {
+            return ContractError::NameRegistered { name }.to_result();
+        }
+        validate_name(name.clone(), &meta_storage, &config, env.block.height)?;
+        validated_names.push(name);
+    }
+
+    // A batch counts as a single registration toward the sender's cooldown
+    check_registration_cooldown(deps.storage, &env, &info, &config)?;
+
+    // Fees are validated for the entire batch at once, ensuring the sender is only charged and
+    // refunded a single time
+    let charge_response = validate_fee_params_get_messages(
+        deps.api,
+        &info,
+        &config,
+        validated_names.len() as u128,
+        None,
+    )?;
+    record_registration_stats(
+        deps.storage,
+        validated_names.len() as u64,
+        &charge_response.fee_charged,
+    )?;
+
+    let mut response = Response::new().add_attribute("action", "name_register_batch");
+    for name in validated_names {
+        let name_bin = match to_binary(&name) {
+            Ok(bin) => bin,
+            Err(e) => {
+                return ContractError::NameSerializationFailure { cause: e }.to_result();
+            }
+        };
+        let add_attribute_message = add_attribute(
+            info.sender.clone(),
+            config.name.clone(),
+            name_bin,
+            provwasm_std::AttributeValueType::String,
+        )?;
+        let name_key = normalize_name(&name);
+        // Only expired registrations pass validation, so any existing one is released first
+        if let Some(release_expired_message) =
+            release_expired_registration(deps.api, deps.storage, &config, &name_key)?
+        {
+            response = response.add_message(release_expired_message);
+        }
+        let name_meta = NameMeta {
+            name: name.clone(),
+            address: info.sender.to_string(),
+            expiration_height: config.get_expiration_height(env.block.height),
+        };
+        meta(deps.storage).save(name_key.as_bytes(), &name_meta)?;
+        name_index(deps.storage, &name_meta.address).save(name_key.as_bytes(), &name)?;
+        response = response
+            .add_message(add_attribute_message)
+            .add_attribute("name", name);
+    }
+
+    let fee_refund = charge_response.format_fee_refund();
+    response = response.add_messages(charge_response.fee_charge_messages);
+    if let Some(refund_message) = charge_response.fee_refund_message {
+        response = response
+            .add_message(refund_message)
+            .add_attribute("fee_refund", fee_refund);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{meta_read, name_index_read};
+    use crate::execute::register_name::register_name;
+    use crate::execute::register_names::register_names;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AttributeMsgParams, ProvenanceMsg, ProvenanceMsgParams};
+
+    #[test]
+    fn test_valid_batch_registration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 100,
+                fee_collection_address: "fee_bucket",
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let names = vec!["firstname", "secondname", "thirdname"];
+        let response = register_names(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &[coin(300, DEFAULT_FEE_DENOMINATION)]),
+            names.iter().map(|name| name.to_string()).collect(),
+        )
+        .expect("expected a batch of valid names to be registered");
+        let attribute_names = response
+            .messages
+            .iter()
+            .filter_map(|msg| match &msg.msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                            address, ..
+                        }),
+                    ..
+                }) => Some(address.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+        assert_eq!(
+            3,
+            attribute_names.len(),
+            "expected one add attribute message per registered name",
+        );
+        assert!(
+            attribute_names.iter().all(|address| address == "sender"),
+            "expected every attribute to be added to the sender's account",
+        );
+        let bank_messages = response
+            .messages
+            .iter()
+            .filter(|msg| matches!(msg.msg, CosmosMsg::Bank(_)))
+            .count();
+        assert_eq!(
+            1, bank_messages,
+            "expected only a single fee message to be sent when the exact fee is provided",
+        );
+        for name in names {
+            let name_meta = meta_read(deps.as_ref().storage)
+                .load(name.as_bytes())
+                .expect("expected every name in the batch to be stored");
+            assert_eq!("sender", name_meta.address);
+            name_index_read(deps.as_ref().storage, "sender")
+                .load(name.as_bytes())
+                .expect("expected every name in the batch to be indexed by address");
+        }
+    }
+
+    #[test]
+    fn test_batch_with_registered_name_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "other",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "takenname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let error = register_names(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT * 2, DEFAULT_FEE_DENOMINATION)],
+            ),
+            vec!["freename".into(), "takenname".into()],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameRegistered { .. }),
+            "expected the batch to be rejected because it contained a registered name",
+        );
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .may_load("freename".as_bytes())
+                .unwrap()
+                .is_none(),
+            "expected no names from a rejected batch to be stored",
+        );
+        let duplicate_error = register_names(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT * 2, DEFAULT_FEE_DENOMINATION)],
+            ),
+            vec!["freename".into(), "FreeName".into()],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(duplicate_error, ContractError::NameRegistered { .. }),
+            "expected the batch to be rejected because it contained the same name twice in different casings",
+        );
+        let empty_error =
+            register_names(deps.as_mut(), mock_env(), mock_info("sender", &[]), vec![])
+                .unwrap_err();
+        assert!(
+            matches!(empty_error, ContractError::InvalidFields { .. }),
+            "expected an empty batch to be rejected",
+        );
+    }
+
+    #[test]
+    fn test_batch_fee_and_refund_math() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 100,
+                fee_collection_address: "fee_bucket",
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let insufficient_error = register_names(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &[coin(299, DEFAULT_FEE_DENOMINATION)]),
+            vec!["namea".into(), "nameb".into(), "namec".into()],
+        )
+        .unwrap_err();
+        match insufficient_error {
+            ContractError::InsufficientFundsProvided {
+                amount_provided,
+                amount_required,
+            } => {
+                assert_eq!(299, amount_provided);
+                assert_eq!(
+                    300, amount_required,
+                    "expected the fee to be charged once per name in the batch",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", insufficient_error),
+        };
+        let response = register_names(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &[coin(350, DEFAULT_FEE_DENOMINATION)]),
+            vec!["namea".into(), "nameb".into(), "namec".into()],
+        )
+        .expect("expected the batch to be registered when overpaying");
+        let mut fee_sent = false;
+        let mut refund_sent = false;
+        response.messages.iter().for_each(|msg| {
+            if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = &msg.msg {
+                assert_eq!(1, amount.len(), "expected a single coin to be sent");
+                let sent_coin = amount.first().unwrap();
+                match to_address.as_str() {
+                    "fee_bucket" => {
+                        assert_eq!(300, sent_coin.amount.u128());
+                        assert!(!fee_sent, "expected the fee to be charged only once");
+                        fee_sent = true;
+                    }
+                    "sender" => {
+                        assert_eq!(50, sent_coin.amount.u128());
+                        assert!(!refund_sent, "expected the refund to be sent only once");
+                        refund_sent = true;
+                    }
+                    _ => panic!("unexpected to_address encountered"),
+                }
+            }
+        });
+        assert!(fee_sent, "expected the fee to be charged");
+        assert!(refund_sent, "expected the overage to be refunded");
+        let refund_attr = response
+            .attributes
+            .iter()
+            .find(|attr| attr.key.as_str() == "fee_refund")
+            .expect("a refund attribute should be emitted for the overage");
+        assert_eq!(format!("50{}", DEFAULT_FEE_DENOMINATION), refund_attr.value);
+    }
+
+    #[test]
+    fn test_batch_registration_counts_toward_cooldown() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_cooldown_blocks: Some(5),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut env = mock_env();
+        register_names(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT * 2, DEFAULT_FEE_DENOMINATION)],
+            ),
+            vec!["first".into(), "second".into()],
+        )
+        .expect("expected every name in the batch to be registered at once");
+        let m_info = mock_info(
+            "sender",
+            &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+        );
+        let error = register_name(
+            deps.as_mut(),
+            env.clone(),
+            m_info.clone(),
+            "third".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::RegistrationCooldown {
+                    blocks_remaining: 5
+                }
+            ),
+            "expected a registration directly after a batch to be rejected, but got: {:?}",
+            error,
+        );
+        env.block.height += 5;
+        register_name(deps.as_mut(), env, m_info, "third".into(), None, None)
+            .expect("expected a registration to succeed once the cooldown has elapsed");
+    }
+}
@@ -0,0 +1,490 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config, meta, meta_read, name_index, NameMeta};
+use crate::execute::register_name::release_expired_registration;
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{add_attribute, AttributeValueType, ProvenanceMsg, ProvenanceQuery};
+
+// register a sub-name
+// This will register a name nested beneath a name owned by the account that invoked this contract,
+// named "{sub}.{parent}".  Only the owner of an unexpired parent name may register names beneath it.
+// The sub-name is recorded under the key "{sub}.{parent}", which can never collide with a flat name
+// because flat names cannot contain periods.  Like a flat name, the sub-name is attached to the
+// sender as an attribute under the contract's root name, so it can be released, transferred and
+// renewed in the same way.
+// Registering a sub-name is free, because the parent name's registration has already been paid for.
+pub fn register_sub_name(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    parent: String,
+    sub: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Registering a sub-name is free, so any funds sent would be stuck in the contract
+    if !info.funds.is_empty() {
+        return ContractError::std_err(
+            "funds are not allowed to be sent when registering a sub-name",
+        );
+    }
+    let config = config(deps.storage).load()?;
+    let parent_meta = match meta_read(deps.storage).may_load(normalize_name(&parent).as_bytes())? {
+        Some(parent_meta) => parent_meta,
+        None => return ContractError::NameNotFound.to_result(),
+    };
+    // Only the owner of the parent name may register names beneath it.  An expired parent can be
+    // claimed by any address, so its previous owner no longer controls it
+    if parent_meta.address != info.sender.as_str() || parent_meta.is_expired(env.block.height) {
+        return ContractError::Unauthorized.to_result();
+    }
+    // The sub-name is a single segment of the full name, so it follows the same reservation, format
+    // and length rules as a flat name
+    if config.is_reserved_name(&sub) {
+        return ContractError::NameReserved { name: sub }.to_result();
+    }
+    if sub.is_empty() || sub.chars().any(|char| !char.is_alphanumeric()) {
+        return ContractError::InvalidNameFormat { name: sub }.to_result();
+    }
+    let min = config.get_min_name_length();
+    let max = config.get_max_name_length();
+    let sub_length = sub.chars().count();
+    if sub_length < min as usize || sub_length > max as usize {
+        return ContractError::InvalidNameLength {
+            name: sub,
+            min,
+            max,
+        }
+        .to_result();
+    }
+    let name = format!("{}.{}", sub, parent_meta.name);
+    let name_key = normalize_name(&name);
+    if let Some(existing_meta) = meta_read(deps.storage).may_load(name_key.as_bytes())? {
+        if !existing_meta.is_expired(env.block.height) {
+            return ContractError::NameRegistered { name }.to_result();
+        }
+    }
+    // An expired sub-name is reclaimed by removing the previous owner's attribute for it
+    let release_expired_message =
+        release_expired_registration(deps.api, deps.storage, &config, &name_key)?;
+
+    let name_bin = match to_binary(&name) {
+        Ok(bin) => bin,
+        Err(e) => {
+            return ContractError::NameSerializationFailure { cause: e }.to_result();
+        }
+    };
+    let add_attribute_message = add_attribute(
+        info.sender.clone(),
+        &config.name,
+        name_bin,
+        AttributeValueType::String,
+    )?;
+
+    let name_meta = NameMeta {
+        name: name.clone(),
+        address: info.sender.into_string(),
+        expiration_height: config.get_expiration_height(env.block.height),
+    };
+    meta(deps.storage).save(name_key.as_bytes(), &name_meta)?;
+    name_index(deps.storage, &name_meta.address).save(name_key.as_bytes(), &name)?;
+
+    // The previous owner's attribute is deleted before the new one is added, so a parent owner
+    // reclaiming their own expired sub-name keeps the attribute
+    let mut response = Response::new();
+    if let Some(release_expired_message) = release_expired_message {
+        response = response.add_message(release_expired_message);
+    }
+    Ok(response
+        .add_message(add_attribute_message)
+        .add_attribute("action", "name_register_sub_name")
+        .add_attribute("parent", parent_meta.name)
+        .add_attribute("name", name))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{meta_read, name_index_read};
+    use crate::execute::register_name::register_name;
+    use crate::execute::register_sub_name::register_sub_name;
+    use crate::execute::renew_name::renew_name;
+    use crate::execute::transfer_name::transfer_name;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::{DEFAULT_CONTRACT_NAME, DEFAULT_FEE_AMOUNT};
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AttributeMsgParams, ProvenanceMsg, ProvenanceMsgParams};
+
+    #[test]
+    fn test_valid_sub_name_registration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "parent_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "parent".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let response = register_sub_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &[]),
+            "Parent".into(),
+            "sub".into(),
+        )
+        .expect("the owner of the parent name should be able to register a sub-name");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single add attribute message should be sent"
+        );
+        match &response.messages[0].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                        address,
+                        name,
+                        value,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("parent_owner", address.as_str());
+                assert_eq!(
+                    DEFAULT_CONTRACT_NAME, name,
+                    "the sub-name should be added under the contract's root name, like a flat name",
+                );
+                assert_eq!("sub.parent", from_binary::<String>(value).unwrap());
+            }
+            msg => panic!(
+                "unexpected message sent for sub-name registration: {:?}",
+                msg
+            ),
+        }
+        let name_meta = meta_read(deps.as_ref().storage)
+            .load("sub.parent".as_bytes())
+            .expect("a NameMeta should be recorded for the sub-name");
+        assert_eq!("sub.parent", name_meta.name);
+        assert_eq!("parent_owner", name_meta.address);
+        assert!(
+            name_index_read(deps.as_ref().storage, "parent_owner")
+                .may_load("sub.parent".as_bytes())
+                .unwrap()
+                .is_some(),
+            "the sub-name should be added to the owner's index",
+        );
+        let error = register_sub_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &[]),
+            "parent".into(),
+            "SUB".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameRegistered { .. }),
+            "expected a duplicate sub-name to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_sub_name_rejected_when_caller_does_not_own_parent() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "parent_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "parent".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let error = register_sub_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("someone_else", &[]),
+            "parent".into(),
+            "sub".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "expected a caller that does not own the parent to be rejected, but got: {:?}",
+            error,
+        );
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .may_load("sub.parent".as_bytes())
+                .unwrap()
+                .is_none(),
+            "no sub-name should be recorded when the registration is rejected",
+        );
+        let error = register_sub_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("someone_else", &[]),
+            "missingparent".into(),
+            "sub".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameNotFound),
+            "expected a missing parent to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_sub_name_follows_flat_name_rules() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                min_name_length: Some(3),
+                max_name_length: Some(10),
+                reserved_names: Some(vec!["admin".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "parent_owner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "parent".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let mut register_sub = |sub: &str| {
+            register_sub_name(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("parent_owner", &[]),
+                "parent".into(),
+                sub.into(),
+            )
+            .unwrap_err()
+        };
+        let error = register_sub("Admin");
+        assert!(
+            matches!(error, ContractError::NameReserved { .. }),
+            "expected a reserved sub-name to be rejected, but got: {:?}",
+            error,
+        );
+        let error = register_sub("ab");
+        assert!(
+            matches!(error, ContractError::InvalidNameLength { .. }),
+            "expected a sub-name that is too short to be rejected, but got: {:?}",
+            error,
+        );
+        let error = register_sub("abcdefghijk");
+        assert!(
+            matches!(error, ContractError::InvalidNameLength { .. }),
+            "expected a sub-name that is too long to be rejected, but got: {:?}",
+            error,
+        );
+        let error = register_sub("sub.name");
+        assert!(
+            matches!(error, ContractError::InvalidNameFormat { .. }),
+            "expected a sub-name containing a period to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_expired_sub_name_is_reclaimed_from_previous_owner() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let fee = [coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)];
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &fee),
+            "parent".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        register_sub_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &[]),
+            "parent".into(),
+            "sub".into(),
+        )
+        .unwrap();
+        transfer_name(
+            deps.as_mut(),
+            mock_info("parent_owner", &[]),
+            "sub.parent".into(),
+            "sub_owner".into(),
+        )
+        .unwrap();
+        // Keep the parent registered while the sub-name expires
+        renew_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &fee),
+            "parent".into(),
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.height += 100;
+        let response = register_sub_name(
+            deps.as_mut(),
+            env,
+            mock_info("parent_owner", &[]),
+            "parent".into(),
+            "sub".into(),
+        )
+        .expect("the parent owner should be able to reclaim an expired sub-name");
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "the sub-name should be added to the new owner and removed from the previous owner",
+        );
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteDistinctAttribute {
+                        address,
+                        value,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("sub_owner", address.as_str());
+                assert_eq!("sub.parent", from_binary::<String>(value).unwrap());
+            }
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                        address,
+                        value,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("parent_owner", address.as_str());
+                assert_eq!("sub.parent", from_binary::<String>(value).unwrap());
+            }
+            msg => panic!("unexpected message type: {:?}", msg),
+        });
+        assert!(
+            name_index_read(deps.as_ref().storage, "sub_owner")
+                .may_load("sub.parent".as_bytes())
+                .unwrap()
+                .is_none(),
+            "the sub-name should be removed from the previous owner's index",
+        );
+        assert_eq!(
+            "parent_owner",
+            meta_read(deps.as_ref().storage)
+                .load("sub.parent".as_bytes())
+                .unwrap()
+                .address,
+            "the sub-name should be owned by the reclaiming address",
+        );
+    }
+
+    #[test]
+    fn test_expired_sub_name_is_reclaimed_by_its_previous_owner() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let fee = [coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)];
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &fee),
+            "parent".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        register_sub_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &[]),
+            "parent".into(),
+            "sub".into(),
+        )
+        .unwrap();
+        // Keep the parent registered while the sub-name expires
+        renew_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("parent_owner", &fee),
+            "parent".into(),
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.height += 100;
+        let response = register_sub_name(
+            deps.as_mut(),
+            env,
+            mock_info("parent_owner", &[]),
+            "parent".into(),
+            "sub".into(),
+        )
+        .expect("the parent owner should be able to reclaim their own expired sub-name");
+        // The previous attribute must be deleted before the new one is added, or the owner would be
+        // left without an attribute for the sub-name
+        assert_eq!(2, response.messages.len());
+        assert!(
+            matches!(
+                &response.messages[0].msg,
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params: ProvenanceMsgParams::Attribute(
+                        AttributeMsgParams::DeleteDistinctAttribute { address, .. }
+                    ),
+                    ..
+                }) if address.as_str() == "parent_owner"
+            ),
+            "expected the expired attribute to be deleted first, but got: {:?}",
+            response.messages[0].msg,
+        );
+        assert!(
+            matches!(
+                &response.messages[1].msg,
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params: ProvenanceMsgParams::Attribute(
+                        AttributeMsgParams::AddAttribute { address, .. }
+                    ),
+                    ..
+                }) if address.as_str() == "parent_owner"
+            ),
+            "expected the new attribute to be added after the deletion, but got: {:?}",
+            response.messages[1].msg,
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config, meta, name_index};
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, DepsMut, MessageInfo, Response};
+use provwasm_std::{delete_distinct_attribute, ProvenanceMsg, ProvenanceQuery};
+
+// release a name
+// This will unbind a name from the account that invoked this contract, making it available for
+// registration again.  Only the account that the name is bound to is allowed to release it.
+// The registration fee is not refunded when a name is released.
+pub fn release_name(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Releasing a name is free, so any funds sent would be stuck in the contract
+    if !info.funds.is_empty() {
+        return ContractError::std_err("funds are not allowed to be sent when releasing a name");
+    }
+    let config = config(deps.storage).load()?;
+    let name_key = normalize_name(&name);
+    let mut meta_storage = meta(deps.storage);
+    let name_meta = match meta_storage.may_load(name_key.as_bytes())? {
+        Some(name_meta) => name_meta,
+        None => return ContractError::NameNotFound.to_result(),
+    };
+    // Only the owner of a name may release it
+    if name_meta.address != info.sender.as_str() {
+        return ContractError::Unauthorized.to_result();
+    }
+    meta_storage.remove(name_key.as_bytes());
+    name_index(deps.storage, &name_meta.address).remove(name_key.as_bytes());
+
+    // All names registered by an account are stored as attributes with the same attribute name
+    // (the contract's root name), so only the attribute with this name's value can be deleted.
+    // Deleting all attributes by name would also strip every other name owned by the account.
+    let name_bin = match to_binary(&name_meta.name) {
+        Ok(bin) => bin,
+        Err(e) => {
+            return ContractError::NameSerializationFailure { cause: e }.to_result();
+        }
+    };
+    let delete_attribute_message = delete_distinct_attribute(info.sender, config.name, name_bin)?;
+
+    Ok(Response::new()
+        .add_message(delete_attribute_message)
+        .add_attribute("action", "name_release")
+        .add_attribute("name", name_meta.name))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{meta_read, name_index_read};
+    use crate::execute::register_name::register_name;
+    use crate::execute::release_name::release_name;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AttributeMsgParams, ProvenanceMsg, ProvenanceMsgParams};
+
+    #[test]
+    fn test_valid_release() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "somedude",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let response = release_name(
+            deps.as_mut(),
+            mock_info("somedude", &[]),
+            "mycoolname".into(),
+        )
+        .unwrap();
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single attribute deletion message should be returned"
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteDistinctAttribute {
+                        address,
+                        name,
+                        value,
+                    }),
+                ..
+            }) => {
+                assert_eq!("somedude", address.as_str());
+                assert_eq!("wallet.pb", name);
+                assert_eq!(
+                    "mycoolname",
+                    from_binary::<String>(value).unwrap(),
+                    "only the attribute for the released name should be deleted",
+                );
+            }
+            msg => panic!("unexpected message type: {:?}", msg),
+        }
+        response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "action" && attr.value == "name_release")
+            .expect("the action attribute should be emitted");
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .may_load("mycoolname".as_bytes())
+                .unwrap()
+                .is_none(),
+            "the name should be removed from the registry",
+        );
+        assert!(
+            name_index_read(deps.as_ref().storage, "somedude")
+                .may_load("mycoolname".as_bytes())
+                .unwrap()
+                .is_none(),
+            "the name should be removed from the reverse index",
+        );
+        // The released name should be available for anyone to register again
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "someotherdude",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .expect("a released name should be able to be registered again");
+    }
+
+    #[test]
+    fn test_release_by_wrong_owner_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "somedude",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let error = release_name(
+            deps.as_mut(),
+            mock_info("notthatdude", &[]),
+            "mycoolname".into(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized));
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .may_load("mycoolname".as_bytes())
+                .unwrap()
+                .is_some(),
+            "the name should remain registered after a rejected release",
+        );
+    }
+
+    #[test]
+    fn test_release_nonexistent_name_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = release_name(
+            deps.as_mut(),
+            mock_info("somedude", &[]),
+            "nosuchname".into(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::NameNotFound));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config, meta, record_registration_stats};
+use crate::execute::register_name::validate_fee_params_get_messages;
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// renew a name
+// This will extend the registration of a name owned by the account that invoked this contract by
+// the configured registration duration, charging the same fee as registering it.  A name that has
+// expired can still be renewed by its owner, as long as no other account has claimed it since.
+// Renewals extend from the later of the current expiration and the current block height, so renewing
+// early never loses any of the remaining registration.
+pub fn renew_name(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let config = config(deps.storage).load()?;
+    let registration_duration_blocks = match config.registration_duration_blocks {
+        Some(registration_duration_blocks) => registration_duration_blocks,
+        None => return ContractError::NameRenewalNotEnabled.to_result(),
+    };
+    let name_key = normalize_name(&name);
+    let mut meta_storage = meta(deps.storage);
+    let mut name_meta = match meta_storage.may_load(name_key.as_bytes())? {
+        Some(name_meta) => name_meta,
+        None => return ContractError::NameNotFound.to_result(),
+    };
+    // Only the owner of a name may renew it
+    if name_meta.address != info.sender.as_str() {
+        return ContractError::Unauthorized.to_result();
+    }
+
+    // Renewing a name costs the same as registering a single name
+    let charge_response = validate_fee_params_get_messages(deps.api, &info, &config, 1, None)?;
+
+    let renewed_from = name_meta
+        .expiration_height
+        .unwrap_or(env.block.height)
+        .max(env.block.height);
+    let expiration_height = renewed_from.saturating_add(registration_duration_blocks);
+    name_meta.expiration_height = Some(expiration_height);
+    meta_storage.save(name_key.as_bytes(), &name_meta)?;
+    // A renewal collects a fee without registering a new name
+    record_registration_stats(deps.storage, 0, &charge_response.fee_charged)?;
+
+    let fee_refund = charge_response.format_fee_refund();
+    let mut response = Response::new()
+        .add_messages(charge_response.fee_charge_messages)
+        .add_attribute("action", "name_renew")
+        .add_attribute("name", name_meta.name)
+        .add_attribute("expiration_height", expiration_height.to_string());
+    if let Some(refund_message) = charge_response.fee_refund_message {
+        response = response
+            .add_message(refund_message)
+            .add_attribute("fee_refund", fee_refund);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::meta_read;
+    use crate::execute::register_name::register_name;
+    use crate::execute::renew_name::renew_name;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::{DEFAULT_FEE_AMOUNT, DEFAULT_FEE_COLLECTION_ADDRESS};
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Env};
+    use provwasm_mocks::mock_dependencies;
+
+    fn env_at_height(height: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height = height;
+        env
+    }
+
+    #[test]
+    fn test_renewal_extends_expiration_and_charges_the_fee() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        register_name(
+            deps.as_mut(),
+            env_at_height(1000),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "renewme".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        // Renewing before expiration extends from the current expiration, not the current height
+        let response = renew_name(
+            deps.as_mut(),
+            env_at_height(1050),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "renewme".into(),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(1200),
+            meta_read(deps.as_ref().storage)
+                .load("renewme".as_bytes())
+                .unwrap()
+                .expiration_height,
+            "the renewal should extend the registration by one duration past its previous expiration",
+        );
+        assert_eq!(
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEFAULT_FEE_COLLECTION_ADDRESS.to_string(),
+                amount: vec![coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            })],
+            response
+                .messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<CosmosMsg<_>>>(),
+            "the registration fee should be charged for the renewal",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "expiration_height" && attr.value == "1200"),
+            "the new expiration height should be emitted as an attribute",
+        );
+        // Renewing an expired name that nobody has claimed extends from the current height
+        renew_name(
+            deps.as_mut(),
+            env_at_height(1500),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "renewme".into(),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(1600),
+            meta_read(deps.as_ref().storage)
+                .load("renewme".as_bytes())
+                .unwrap()
+                .expiration_height,
+            "renewing an expired name should extend the registration from the current height",
+        );
+    }
+
+    #[test]
+    fn test_renewal_failures() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "renewme".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let error = renew_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "other_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "renewme".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "expected only the owner to be able to renew a name, but got: {:?}",
+            error,
+        );
+        let error = renew_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner_address", &[]),
+            "renewme".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NoFundsProvidedForRegistration),
+            "expected a renewal without the fee to be rejected, but got: {:?}",
+            error,
+        );
+        let error = renew_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "missingname".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameNotFound),
+            "expected renewing an unregistered name to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_renewal_is_rejected_when_names_do_not_expire() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "forever".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let error = renew_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "forever".into(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameRenewalNotEnabled),
+            "expected renewals to be rejected when registrations never expire, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config, meta, name_index};
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, DepsMut, MessageInfo, Response};
+use provwasm_std::{
+    add_attribute, delete_distinct_attribute, AttributeValueType, ProvenanceMsg, ProvenanceQuery,
+};
+
+// transfer a name
+// This will move a name from the account that invoked this contract to a different account.  The
+// name's attribute is removed from the current owner and added to the new owner in the same
+// transaction, so the name is never unbound or available for registration during the transfer.
+pub fn transfer_name(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    name: String,
+    new_owner: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Transferring a name is free, so any funds sent would be stuck in the contract
+    if !info.funds.is_empty() {
+        return ContractError::std_err("funds are not allowed to be sent when transferring a name");
+    }
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let config = config(deps.storage).load()?;
+    let name_key = normalize_name(&name);
+    let mut meta_storage = meta(deps.storage);
+    let mut name_meta = match meta_storage.may_load(name_key.as_bytes())? {
+        Some(name_meta) => name_meta,
+        None => return ContractError::NameNotFound.to_result(),
+    };
+    // Only the owner of a name may transfer it
+    if name_meta.address != info.sender.as_str() {
+        return ContractError::Unauthorized.to_result();
+    }
+    name_meta.address = new_owner.to_string();
+    meta_storage.save(name_key.as_bytes(), &name_meta)?;
+    // Move the name between the two owners in the reverse index
+    name_index(deps.storage, info.sender.as_str()).remove(name_key.as_bytes());
+    name_index(deps.storage, new_owner.as_str()).save(name_key.as_bytes(), &name_meta.name)?;
+
+    let name_bin = match to_binary(&name_meta.name) {
+        Ok(bin) => bin,
+        Err(e) => {
+            return ContractError::NameSerializationFailure { cause: e }.to_result();
+        }
+    };
+    // Only the attribute holding this name's value is removed from the previous owner, leaving any
+    // other names they own intact
+    let delete_attribute_message =
+        delete_distinct_attribute(info.sender.clone(), &config.name, name_bin.clone())?;
+    let add_attribute_message = add_attribute(
+        new_owner.clone(),
+        &config.name,
+        name_bin,
+        AttributeValueType::String,
+    )?;
+
+    Ok(Response::new()
+        .add_message(delete_attribute_message)
+        .add_message(add_attribute_message)
+        .add_attribute("action", "name_transfer")
+        .add_attribute("name", name_meta.name)
+        .add_attribute("previous_owner", info.sender)
+        .add_attribute("new_owner", new_owner))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{meta_read, name_index_read};
+    use crate::execute::register_name::register_name;
+    use crate::execute::transfer_name::transfer_name;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AttributeMsgParams, ProvenanceMsg, ProvenanceMsgParams};
+
+    #[test]
+    fn test_valid_transfer() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "somedude",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let response = transfer_name(
+            deps.as_mut(),
+            mock_info("somedude", &[]),
+            "mycoolname".into(),
+            "newdude".into(),
+        )
+        .unwrap();
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "a delete and an add attribute message should be returned"
+        );
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteDistinctAttribute {
+                        address,
+                        name,
+                        value,
+                    }),
+                ..
+            }) => {
+                assert_eq!("somedude", address.as_str());
+                assert_eq!("wallet.pb", name);
+                assert_eq!("mycoolname", from_binary::<String>(value).unwrap());
+            }
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                        address,
+                        name,
+                        value,
+                        ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("newdude", address.as_str());
+                assert_eq!("wallet.pb", name);
+                assert_eq!("mycoolname", from_binary::<String>(value).unwrap());
+            }
+            msg => panic!("unexpected message type: {:?}", msg),
+        });
+        assert_eq!(
+            "newdude",
+            meta_read(deps.as_ref().storage)
+                .load("mycoolname".as_bytes())
+                .unwrap()
+                .address,
+            "the name's owner should be updated in the registry",
+        );
+        assert!(
+            name_index_read(deps.as_ref().storage, "somedude")
+                .may_load("mycoolname".as_bytes())
+                .unwrap()
+                .is_none(),
+            "the name should be removed from the previous owner's index",
+        );
+        name_index_read(deps.as_ref().storage, "newdude")
+            .load("mycoolname".as_bytes())
+            .expect("the name should be added to the new owner's index");
+        // The previous owner no longer owns the name, so they should not be able to transfer it again
+        let error = transfer_name(
+            deps.as_mut(),
+            mock_info("somedude", &[]),
+            "mycoolname".into(),
+            "thirddude".into(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized));
+    }
+
+    #[test]
+    fn test_transfer_by_wrong_owner_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "somedude",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let error = transfer_name(
+            deps.as_mut(),
+            mock_info("notthatdude", &[]),
+            "mycoolname".into(),
+            "notthatdude".into(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized));
+        assert_eq!(
+            "somedude",
+            meta_read(deps.as_ref().storage)
+                .load("mycoolname".as_bytes())
+                .unwrap()
+                .address,
+            "the name's owner should be unchanged after a rejected transfer",
+        );
+    }
+
+    #[test]
+    fn test_transfer_nonexistent_name_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = transfer_name(
+            deps.as_mut(),
+            mock_info("somedude", &[]),
+            "nosuchname".into(),
+            "newdude".into(),
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::NameNotFound));
+    }
+}
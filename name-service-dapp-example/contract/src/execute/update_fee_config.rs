@@ -0,0 +1,192 @@
+use crate::core::error::ContractError;
+use crate::core::state::config;
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// update the fee configuration
+// This will change the amount charged for registering a name and/or the address that collects
+// registration fees.  Only the contract admin is allowed to make these changes, and at least one of
+// the values must be provided.  Names that are already registered are unaffected.  Setting a new
+// fee collection address replaces any configured fee distribution, sending the entire fee to it.
+// A zero fee cannot be set alongside a new fee collection address, because there would be nothing to
+// collect.
+pub fn update_fee_config(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    fee_amount: Option<Uint128>,
+    fee_collection_address: Option<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Updating the fee configuration is free, so any funds sent would be stuck in the contract
+    if !info.funds.is_empty() {
+        return ContractError::std_err(
+            "funds are not allowed to be sent when updating the fee configuration",
+        );
+    }
+    // A request that changes nothing is almost certainly a malformed message
+    if fee_amount.is_none() && fee_collection_address.is_none() {
+        return ContractError::InvalidFields {
+            fields: vec![
+                "fee_amount".to_string(),
+                "fee_collection_address".to_string(),
+            ],
+        }
+        .to_result();
+    }
+    // Naming a collector for a fee that is being removed is contradictory, and most likely means that
+    // one of the two values was provided by mistake
+    if fee_amount == Some(Uint128::zero()) && fee_collection_address.is_some() {
+        return ContractError::InvalidFields {
+            fields: vec![
+                "fee_amount".to_string(),
+                "fee_collection_address".to_string(),
+            ],
+        }
+        .to_result();
+    }
+    let mut config = config(deps.storage);
+    let mut state = config.load()?;
+    // Contracts without an admin cannot have their fees changed outside of a migration
+    if state.admin.as_deref() != Some(info.sender.as_str()) {
+        return ContractError::Unauthorized.to_result();
+    }
+    let mut response = Response::new().add_attribute("action", "update_fee_config");
+    if let Some(fee_amount) = fee_amount {
+        state.fee_amount = fee_amount;
+        response = response.add_attribute("fee_amount", fee_amount.to_string());
+    }
+    if let Some(fee_collection_address) = fee_collection_address {
+        state.fee_collection_address = deps.api.addr_validate(&fee_collection_address)?.into();
+        state.fee_distribution = None;
+        response = response.add_attribute("fee_collection_address", fee_collection_address);
+    }
+    config.save(&state)?;
+    Ok(response)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::config_read;
+    use crate::execute::update_fee_config::update_fee_config;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::{
+        DEFAULT_FEE_AMOUNT, DEFAULT_FEE_COLLECTION_ADDRESS, DEFAULT_INFO_NAME,
+    };
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_admin_updates_fee_config() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let response = update_fee_config(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            Some(Uint128::new(500)),
+            Some("new_fee_bucket".to_string()),
+        )
+        .expect("the admin should be able to update the fee configuration");
+        let attribute_value = |key: &str| {
+            response
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+                .unwrap_or_else(|| panic!("expected the {} attribute to be emitted", key))
+        };
+        assert_eq!("update_fee_config", attribute_value("action"));
+        assert_eq!("500", attribute_value("fee_amount"));
+        assert_eq!("new_fee_bucket", attribute_value("fee_collection_address"));
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(Uint128::new(500), state.fee_amount);
+        assert_eq!("new_fee_bucket", state.fee_collection_address);
+        // Omitted values should be left untouched
+        let response = update_fee_config(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            Some(Uint128::zero()),
+            None,
+        )
+        .expect("the admin should be able to update only the fee amount");
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "fee_collection_address"),
+            "no attribute should be emitted for a value that was not changed",
+        );
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(Uint128::zero(), state.fee_amount);
+        assert_eq!("new_fee_bucket", state.fee_collection_address);
+    }
+
+    #[test]
+    fn test_non_admin_update_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = update_fee_config(
+            deps.as_mut(),
+            mock_info("notadmin", &[]),
+            Some(Uint128::new(1)),
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized));
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(
+            Uint128::new(DEFAULT_FEE_AMOUNT),
+            state.fee_amount,
+            "the fee amount should be unchanged after a rejected update",
+        );
+    }
+
+    #[test]
+    fn test_invalid_fee_config_updates_are_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = update_fee_config(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            Some(Uint128::new(1)),
+            Some("a".to_string()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "expected an invalid fee collection address to be rejected, but got: {:?}",
+            error,
+        );
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(Uint128::new(DEFAULT_FEE_AMOUNT), state.fee_amount);
+        assert_eq!(DEFAULT_FEE_COLLECTION_ADDRESS, state.fee_collection_address);
+        let empty_error =
+            update_fee_config(deps.as_mut(), mock_info(DEFAULT_INFO_NAME, &[]), None, None)
+                .unwrap_err();
+        assert!(
+            matches!(empty_error, ContractError::InvalidFields { .. }),
+            "expected an update with no values to be rejected",
+        );
+    }
+
+    #[test]
+    fn test_zero_fee_with_collection_address_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = update_fee_config(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            Some(Uint128::zero()),
+            Some("new_fee_bucket".to_string()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "expected a zero fee with a new fee collection address to be rejected, but got: {:?}",
+            error,
+        );
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(Uint128::new(DEFAULT_FEE_AMOUNT), state.fee_amount);
+        assert_eq!(DEFAULT_FEE_COLLECTION_ADDRESS, state.fee_collection_address);
+    }
+}
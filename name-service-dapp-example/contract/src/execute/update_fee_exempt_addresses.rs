@@ -0,0 +1,151 @@
+use crate::core::error::ContractError;
+use crate::core::state::config;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// update the fee exempt addresses
+// This will replace the list of addresses that can register names without paying the registration
+// fee.  Only the contract admin is allowed to make this change, and an empty list requires every
+// address to pay the fee again.
+pub fn update_fee_exempt_addresses(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    fee_exempt_addresses: Vec<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Updating the fee exempt addresses is free, so any funds sent would be stuck in the contract
+    if !info.funds.is_empty() {
+        return ContractError::std_err(
+            "funds are not allowed to be sent when updating the fee exempt addresses",
+        );
+    }
+    let mut config = config(deps.storage);
+    let mut state = config.load()?;
+    if state.admin.as_deref() != Some(info.sender.as_str()) {
+        return ContractError::Unauthorized.to_result();
+    }
+    // Exemptions are matched against the sender's address, so each one must be a valid address
+    for address in &fee_exempt_addresses {
+        deps.api.addr_validate(address)?;
+    }
+    let response = Response::new()
+        .add_attribute("action", "update_fee_exempt_addresses")
+        .add_attribute("fee_exempt_addresses", fee_exempt_addresses.join(","));
+    state.fee_exempt_addresses = fee_exempt_addresses;
+    config.save(&state)?;
+    Ok(response)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::config_read;
+    use crate::execute::register_name::register_name;
+    use crate::execute::update_fee_exempt_addresses::update_fee_exempt_addresses;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::{DEFAULT_FEE_AMOUNT, DEFAULT_INFO_NAME};
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_exempt_address_registers_without_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        update_fee_exempt_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            vec!["partner".to_string()],
+        )
+        .expect("the admin should be able to update the fee exempt addresses");
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(vec!["partner".to_string()], state.fee_exempt_addresses);
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("partner", &[]),
+            "partner".into(),
+            None,
+            None,
+        )
+        .expect("a fee exempt address should register a name without sending funds");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "only the name attribute message should be sent for a fee exempt registration",
+        );
+        // Addresses that are not exempt are still charged the fee
+        let error = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &[]),
+            "other".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NoFundsProvidedForRegistration),
+            "expected a non-exempt address without funds to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_exempt_address_is_refunded_accidental_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        update_fee_exempt_addresses(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            vec!["partner".to_string()],
+        )
+        .unwrap();
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "partner",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "partner".into(),
+            None,
+            None,
+        )
+        .expect("a fee exempt address should register a name when sending funds");
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "the name attribute message and a refund should be sent for a fee exempt registration with funds",
+        );
+        match &response.messages[1].msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!("partner", to_address);
+                assert_eq!(
+                    &vec![coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+                    amount,
+                    "the entire amount sent should be refunded",
+                );
+            }
+            msg => panic!("expected a refund bank send, but got: {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn test_non_admin_fee_exempt_update_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = update_fee_exempt_addresses(
+            deps.as_mut(),
+            mock_info("notadmin", &[]),
+            vec!["notadmin".to_string()],
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized));
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert!(
+            state.fee_exempt_addresses.is_empty(),
+            "the fee exempt addresses should be unchanged after a rejected update",
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::core::error::ContractError;
+use crate::core::state::config;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+// update the reserved names
+// This will replace the list of names that can never be registered.  Only the contract admin is
+// allowed to make this change, and an empty list removes every reservation.  Names that are
+// already registered are unaffected, even if they become reserved.
+pub fn update_reserved_names(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    reserved_names: Vec<String>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Updating the reserved names is free, so any funds sent would be stuck in the contract
+    if !info.funds.is_empty() {
+        return ContractError::std_err(
+            "funds are not allowed to be sent when updating the reserved names",
+        );
+    }
+    let mut config = config(deps.storage);
+    let mut state = config.load()?;
+    if state.admin.as_deref() != Some(info.sender.as_str()) {
+        return ContractError::Unauthorized.to_result();
+    }
+    let response = Response::new()
+        .add_attribute("action", "update_reserved_names")
+        .add_attribute("reserved_names", reserved_names.join(","));
+    state.reserved_names = reserved_names;
+    config.save(&state)?;
+    Ok(response)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::config_read;
+    use crate::execute::register_name::register_name;
+    use crate::execute::update_reserved_names::update_reserved_names;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::{DEFAULT_FEE_AMOUNT, DEFAULT_INFO_NAME};
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::coin;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_admin_updates_reserved_names() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        update_reserved_names(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            vec!["trademark".to_string()],
+        )
+        .expect("the admin should be able to update the reserved names");
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(vec!["trademark".to_string()], state.reserved_names);
+        let error = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "trademark".into(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameReserved { .. }),
+            "expected a newly reserved name to be rejected, but got: {:?}",
+            error,
+        );
+        // Clearing the reservations makes the name available again
+        update_reserved_names(deps.as_mut(), mock_info(DEFAULT_INFO_NAME, &[]), vec![])
+            .expect("the admin should be able to clear the reserved names");
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "sender",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "trademark".into(),
+            None,
+            None,
+        )
+        .expect("expected a name to be registered after its reservation is removed");
+    }
+
+    #[test]
+    fn test_non_admin_reserved_names_update_is_rejected() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = update_reserved_names(
+            deps.as_mut(),
+            mock_info("notadmin", &[]),
+            vec!["trademark".to_string()],
+        )
+        .unwrap_err();
+        assert!(matches!(error, ContractError::Unauthorized));
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert!(
+            state.reserved_names.is_empty(),
+            "the reserved names should be unchanged after a rejected update",
+        );
+    }
+}
@@ -2,8 +2,9 @@ use crate::core::error::ContractError;
 use crate::core::msg::InitMsg;
 use crate::core::state::{config, State};
 use crate::migrate::version_info::migrate_version_info;
+use crate::util::constants::TOTAL_FEE_BASIS_POINTS;
 use crate::util::helper_functions::fee_amount_from_string;
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
 use provwasm_std::{bind_name, NameBinding, ProvenanceMsg, ProvenanceQuery};
 
 pub fn instantiate_contract(
@@ -16,14 +17,91 @@ pub fn instantiate_contract(
     if !info.funds.is_empty() {
         return ContractError::std_err("purchase funds are not allowed to be sent during init");
     }
-    // Verify the fee amount can be converted from string successfully
-    fee_amount_from_string(&msg.fee_amount)?;
-    // Create and save contract config state. The name is used for setting attributes on user accounts
-    match config(deps.storage).save(&State {
+    // Parse the fee amount once up front, rejecting the instantiation if it is not numeric
+    let fee_amount = Uint128::new(fee_amount_from_string(&msg.fee_amount)?);
+    // A provided fee denomination must be usable as a coin denom
+    if let Some(fee_denomination) = &msg.fee_denomination {
+        if fee_denomination.is_empty() {
+            return ContractError::InvalidFields {
+                fields: vec!["fee_denomination".to_string()],
+            }
+            .to_result();
+        }
+    }
+    let state = State {
         name: msg.name.clone(),
-        fee_amount: msg.fee_amount.clone(),
+        fee_amount,
         fee_collection_address: msg.fee_collection_address.clone(),
-    }) {
+        fee_denomination: msg.fee_denomination.clone(),
+        min_name_length: msg.min_name_length,
+        max_name_length: msg.max_name_length,
+        admin: Some(info.sender.to_string()),
+        fee_distribution: msg.fee_distribution.clone(),
+        reserved_names: msg.reserved_names.clone().unwrap_or_default(),
+        fee_exempt_addresses: vec![],
+        strict_fee_mode: msg.strict_fee_mode.unwrap_or(false),
+        registration_cooldown_blocks: msg.registration_cooldown_blocks.unwrap_or(0),
+        fee_recipient_override_addresses: msg
+            .fee_recipient_override_addresses
+            .clone()
+            .unwrap_or_default(),
+        additional_fees: msg.additional_fees.clone().unwrap_or_default(),
+        registration_duration_blocks: msg.registration_duration_blocks,
+    };
+    // A registration that expires in the block it is made in could never be used
+    if state.registration_duration_blocks == Some(0) {
+        return ContractError::InvalidFields {
+            fields: vec!["registration_duration_blocks".to_string()],
+        }
+        .to_result();
+    }
+    // Name length bounds must allow at least one name to be registered
+    if state.get_min_name_length() == 0 || state.get_min_name_length() > state.get_max_name_length()
+    {
+        return ContractError::InvalidFields {
+            fields: vec!["min_name_length".to_string(), "max_name_length".to_string()],
+        }
+        .to_result();
+    }
+    // Every fee recipient must be a valid address, and together they must receive the entire fee
+    if let Some(fee_distribution) = &msg.fee_distribution {
+        for recipient in fee_distribution {
+            deps.api.addr_validate(&recipient.address)?;
+        }
+        let total_basis_points = fee_distribution
+            .iter()
+            .map(|recipient| recipient.basis_points as u64)
+            .sum::<u64>();
+        if total_basis_points != TOTAL_FEE_BASIS_POINTS as u64 {
+            return ContractError::InvalidFeeDistribution {
+                provided: total_basis_points,
+                required: TOTAL_FEE_BASIS_POINTS,
+            }
+            .to_result();
+        }
+    }
+    // Every fee must be charged in its own usable denomination, or funds could not be matched to it
+    let fee_denominations = state
+        .get_fee_coins()
+        .into_iter()
+        .map(|fee_coin| fee_coin.denom)
+        .collect::<Vec<String>>();
+    if fee_denominations
+        .iter()
+        .enumerate()
+        .any(|(index, denom)| denom.is_empty() || fee_denominations[..index].contains(denom))
+    {
+        return ContractError::InvalidFields {
+            fields: vec!["additional_fees".to_string()],
+        }
+        .to_result();
+    }
+    // Overrides are matched against the sender's address, so each one must be a valid address
+    for address in &state.fee_recipient_override_addresses {
+        deps.api.addr_validate(address)?;
+    }
+    // Create and save contract config state. The name is used for setting attributes on user accounts
+    match config(deps.storage).save(&state) {
         Ok(_) => {}
         Err(e) => {
             return ContractError::std_err(format!("failed to init state: {:?}", e));
@@ -56,9 +134,15 @@ pub fn instantiate_contract(
 
 #[cfg(test)]
 pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::InitMsg;
+    use crate::core::state::{config_read, FeeRecipient};
+    use crate::instantiate::instantiate_contract::instantiate_contract;
     use crate::migrate::version_info::{get_version_info, CONTRACT_NAME, CONTRACT_VERSION};
     use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
-    use cosmwasm_std::CosmosMsg;
+    use crate::testutil::test_constants::{DEFAULT_FEE_AMOUNT, DEFAULT_INFO_NAME};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{CosmosMsg, Uint128};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{NameMsgParams, ProvenanceMsgParams};
 
@@ -94,4 +178,95 @@ pub mod tests {
             "the contract version should be stored in version info on a successful instantiation",
         );
     }
+
+    #[test]
+    fn init_stores_parsed_fee_amount() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(
+            Uint128::new(DEFAULT_FEE_AMOUNT),
+            state.fee_amount,
+            "the fee amount should be stored as the numeric value provided",
+        );
+    }
+
+    #[test]
+    fn init_with_malformed_fee_amount() {
+        let mut deps = mock_dependencies(&[]);
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            InitMsg {
+                name: "wallet.pb".into(),
+                fee_amount: "not a number".into(),
+                fee_collection_address: "fee_bucket".into(),
+                fee_denomination: None,
+                min_name_length: None,
+                max_name_length: None,
+                fee_distribution: None,
+                reserved_names: None,
+                strict_fee_mode: None,
+                registration_cooldown_blocks: None,
+                fee_recipient_override_addresses: None,
+                additional_fees: None,
+                registration_duration_blocks: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "expected a malformed fee amount to be rejected at instantiation, but got: {:?}",
+            error,
+        );
+        assert!(
+            config_read(deps.as_ref().storage)
+                .may_load()
+                .unwrap()
+                .is_none(),
+            "no contract state should be stored when the fee amount is malformed",
+        );
+    }
+
+    #[test]
+    fn init_with_fee_distribution_not_totaling_all_basis_points() {
+        let mut deps = mock_dependencies(&[]);
+        let error = test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_distribution: Some(vec![
+                    FeeRecipient {
+                        address: "first_bucket".into(),
+                        basis_points: 5000,
+                    },
+                    FeeRecipient {
+                        address: "second_bucket".into(),
+                        basis_points: 4999,
+                    },
+                ]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        match error {
+            ContractError::InvalidFeeDistribution { provided, required } => {
+                assert_eq!(9999, provided);
+                assert_eq!(10000, required);
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+        let empty_error = test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_distribution: Some(vec![]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(empty_error, ContractError::InvalidFeeDistribution { .. }),
+            "expected an empty fee distribution to be rejected",
+        );
+    }
 }
@@ -1,11 +1,11 @@
 use crate::core::error::ContractError;
 use crate::core::msg::MigrateMsg;
-use crate::core::state::config;
+use crate::core::state::{config, meta_read, name_index, NameMeta};
 use crate::migrate::version_info::{
     get_version_info, migrate_version_info, CONTRACT_NAME, CONTRACT_VERSION,
 };
-use crate::util::helper_functions::fee_amount_from_string;
-use cosmwasm_std::{DepsMut, Response};
+use crate::util::helper_functions::{fee_amount_from_string, normalize_name};
+use cosmwasm_std::{DepsMut, Order, Response, StdResult, Storage, Uint128};
 use provwasm_std::ProvenanceQuery;
 use semver::Version;
 
@@ -42,12 +42,9 @@ pub fn migrate_contract(
         let mut state = config.load()?;
         state.fee_amount = match msg.new_fee_amount {
             Some(amount) => {
-                fee_amount_from_string(amount.as_str())?;
-                attributes.push(cosmwasm_std::Attribute::new(
-                    "fee_amount_updated",
-                    amount.clone(),
-                ));
-                amount
+                let fee_amount = Uint128::new(fee_amount_from_string(amount.as_str())?);
+                attributes.push(cosmwasm_std::Attribute::new("fee_amount_updated", amount));
+                fee_amount
             }
             None => state.fee_amount,
         };
@@ -58,26 +55,48 @@ pub fn migrate_contract(
                     "fee_collection_address_updated",
                     addr_str.clone(),
                 ));
+                // A single collection address replaces any configured fee distribution
+                state.fee_distribution = None;
                 addr_str
             }
             None => state.fee_collection_address,
         };
         config.save(&state)?;
     }
+    // Contracts registered before the reverse name index existed will not have their names indexed
+    // by address.  Rebuilding the index from the meta bucket is idempotent, so it is always done
+    // to guarantee that the index reflects every registered name.
+    rebuild_name_index(deps.storage)?;
     // Ensure that the new contract version is stored for future migrations to reference
     migrate_version_info(deps.storage)?;
     Ok(Response::new().add_attributes(attributes))
 }
 
+/// Writes every registered name into the address-keyed reverse index.
+fn rebuild_name_index(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let name_metas = meta_read(storage)
+        .range(None, None, Order::Ascending)
+        .map(|element| element.map(|(_, name_meta)| name_meta))
+        .collect::<StdResult<Vec<NameMeta>>>()?;
+    for name_meta in name_metas {
+        name_index(storage, &name_meta.address)
+            .save(normalize_name(&name_meta.name).as_bytes(), &name_meta.name)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::error::ContractError;
     use crate::core::msg::MigrateMsg;
+    use crate::core::state::{config_read, meta, name_index_read, NameMeta, CONFIG_KEY};
     use crate::migrate::migrate_contract::migrate_contract;
     use crate::migrate::version_info::{
         get_version_info, set_version_info, VersionInfoV1, CONTRACT_NAME, CONTRACT_VERSION,
     };
     use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use cosmwasm_std::{Storage, Uint128};
+    use cosmwasm_storage::to_length_prefixed;
     use provwasm_mocks::mock_dependencies;
 
     #[test]
@@ -91,6 +110,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_migration_rebuilds_name_index() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        // Simulate a name that was registered before the reverse index existed
+        meta(deps.as_mut().storage)
+            .save(
+                "oldname".as_bytes(),
+                &NameMeta {
+                    name: "oldname".to_string(),
+                    address: "old_owner".to_string(),
+                    expiration_height: None,
+                },
+            )
+            .unwrap();
+        assert!(
+            name_index_read(deps.as_ref().storage, "old_owner")
+                .may_load("oldname".as_bytes())
+                .unwrap()
+                .is_none(),
+            "the name should not be indexed before the migration runs",
+        );
+        migrate_contract(deps.as_mut(), MigrateMsg::empty()).unwrap();
+        assert_eq!(
+            "oldname",
+            name_index_read(deps.as_ref().storage, "old_owner")
+                .load("oldname".as_bytes())
+                .expect("the name should be indexed after the migration runs"),
+        );
+    }
+
     #[test]
     fn test_migration_to_new_version_sets_version_info() {
         let mut deps = mock_dependencies(&[]);
@@ -157,6 +207,40 @@ mod tests {
             attribute.value.as_str(),
             "Expected the value to show the new value that the fee amount was updated to",
         );
+        assert_eq!(
+            Uint128::new(150),
+            config_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .fee_amount,
+            "Expected the new fee amount to be stored in the contract state",
+        );
+    }
+
+    #[test]
+    fn test_migration_with_legacy_string_fee_amount() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        // Overwrite the state with the format used when the fee amount was stored as a String
+        deps.storage.set(
+            &to_length_prefixed(CONFIG_KEY),
+            br#"{"name":"wallet.pb","fee_amount":"150","fee_collection_address":"fake_address"}"#,
+        );
+        migrate_contract(
+            deps.as_mut(),
+            MigrateMsg {
+                new_fee_amount: None,
+                new_fee_collection_address: Some("new_address".to_string()),
+            },
+        )
+        .expect("a migration should succeed against state containing a string fee amount");
+        let state = config_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(
+            Uint128::new(150),
+            state.fee_amount,
+            "the legacy fee amount should be retained as a numeric value",
+        );
+        assert_eq!("new_address", state.fee_collection_address);
     }
 
     #[test]
@@ -0,0 +1,158 @@
+use crate::core::error::ContractError;
+use crate::core::msg::NameAvailability;
+use crate::core::state::{config_read, meta_read};
+use crate::execute::register_name::validate_name;
+use crate::util::constants::MAX_NAME_AVAILABILITY_CHECKS;
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Checks whether each of the given names could be registered, in the order provided, without
+/// making any changes.  Unlike EstimateRegistration, a name that could never be registered is
+/// reported as unavailable along with the reason rather than failing the query.  Names repeated
+/// within the request, in any casing, are reported as unavailable after their first occurrence,
+/// mirroring a batch registration.  At most MAX_NAME_AVAILABILITY_CHECKS names can be checked at
+/// once.
+pub fn check_names_available(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    names: Vec<String>,
+) -> Result<Binary, ContractError> {
+    if names.len() > MAX_NAME_AVAILABILITY_CHECKS {
+        return ContractError::QueryError(format!(
+            "cannot check the availability of {} names. At most {} names can be checked at once",
+            names.len(),
+            MAX_NAME_AVAILABILITY_CHECKS
+        ))
+        .to_result();
+    }
+    let config = config_read(deps.storage).load()?;
+    let meta_storage = meta_read(deps.storage);
+    let mut checked_names: Vec<String> = vec![];
+    let mut results: Vec<NameAvailability> = vec![];
+    for name in names {
+        let reason = if checked_names.contains(&normalize_name(&name)) {
+            Some(format!(
+                "Name [{}] was already checked in this request",
+                name
+            ))
+        } else {
+            validate_name(name.clone(), &meta_storage, &config, env.block.height)
+                .err()
+                .map(|e| e.to_string())
+        };
+        checked_names.push(normalize_name(&name));
+        results.push(NameAvailability {
+            name,
+            available: reason.is_none(),
+            reason,
+        });
+    }
+    Ok(to_binary(&results)?)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::NameAvailability;
+    use crate::execute::register_name::register_name;
+    use crate::query::check_names_available::check_names_available;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::{DEFAULT_FEE_DENOMINATION, MAX_NAME_AVAILABILITY_CHECKS};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_check_names_available() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "takenname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let result_bin = check_names_available(
+            deps.as_ref(),
+            mock_env(),
+            vec![
+                "freename".into(),
+                "takenname".into(),
+                "Invalid Name".into(),
+                "FreeName".into(),
+            ],
+        )
+        .expect("expected availability to be checked for every name");
+        let results = from_binary::<Vec<NameAvailability>>(&result_bin)
+            .expect("expected the response to deserialize to a list of NameAvailability");
+        assert_eq!(
+            vec!["freename", "takenname", "Invalid Name", "FreeName"],
+            results
+                .iter()
+                .map(|result| result.name.as_str())
+                .collect::<Vec<&str>>(),
+            "expected the results to be in the same order as the requested names",
+        );
+        assert_eq!(
+            NameAvailability {
+                name: "freename".into(),
+                available: true,
+                reason: None,
+            },
+            results[0],
+        );
+        assert!(
+            !results[1].available,
+            "expected a registered name to be unavailable"
+        );
+        assert!(
+            results[1]
+                .reason
+                .as_ref()
+                .unwrap()
+                .contains("already registered"),
+            "expected the reason to explain that the name is registered, but got: {:?}",
+            results[1].reason,
+        );
+        assert!(
+            !results[2].available,
+            "expected a malformed name to be unavailable"
+        );
+        assert!(
+            results[2]
+                .reason
+                .as_ref()
+                .unwrap()
+                .contains("invalid format"),
+            "expected the reason to explain that the name is malformed, but got: {:?}",
+            results[2].reason,
+        );
+        assert!(
+            !results[3].available,
+            "expected a name repeated in a different casing to be unavailable"
+        );
+    }
+
+    #[test]
+    fn test_check_too_many_names() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let names = (0..=MAX_NAME_AVAILABILITY_CHECKS)
+            .map(|index| format!("name{}", index))
+            .collect::<Vec<String>>();
+        let error = check_names_available(deps.as_ref(), mock_env(), names).unwrap_err();
+        assert!(
+            matches!(error, ContractError::QueryError(_)),
+            "expected checking too many names to be rejected, but got: {:?}",
+            error,
+        );
+    }
+}
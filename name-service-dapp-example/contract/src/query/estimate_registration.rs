@@ -0,0 +1,131 @@
+use crate::core::error::ContractError;
+use crate::core::msg::EstimateRegistrationResponse;
+use crate::core::state::{config_read, meta_read};
+use crate::execute::register_name::validate_name;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Performs a dry run of registering a single name, allowing clients to determine the exact fee to
+/// send before invoking the Register route.  The fee includes a coin for every denomination that
+/// registration charges.  A name that has already been registered is reported
+/// as unavailable, while a name that could never be registered (bad format or length) responds
+/// with the same error that registration would produce.
+pub fn estimate_registration(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    name: String,
+) -> Result<Binary, ContractError> {
+    let config = config_read(deps.storage).load()?;
+    let available = match validate_name(name, &meta_read(deps.storage), &config, env.block.height) {
+        Ok(_) => true,
+        Err(ContractError::NameRegistered { .. }) => false,
+        Err(e) => return Err(e),
+    };
+    Ok(to_binary(&EstimateRegistrationResponse {
+        available,
+        fee: config.get_fee_coins(),
+    })?)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::EstimateRegistrationResponse;
+    use crate::execute::register_name::register_name;
+    use crate::query::estimate_registration::estimate_registration;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_estimate_available_name() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let result_bin = estimate_registration(deps.as_ref(), mock_env(), "freename".into())
+            .expect("expected an estimate to be produced for a valid name");
+        let estimate = from_binary::<EstimateRegistrationResponse>(&result_bin)
+            .expect("expected the response to deserialize to an EstimateRegistrationResponse");
+        assert!(
+            estimate.available,
+            "expected an unregistered name to be available"
+        );
+        assert_eq!(
+            vec![coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            estimate.fee
+        );
+        let error =
+            estimate_registration(deps.as_ref(), mock_env(), "Invalid Name".into()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidNameFormat { .. }),
+            "expected a malformed name to produce the same error as registration, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_estimate_registered_name() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "takenname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let result_bin = estimate_registration(deps.as_ref(), mock_env(), "takenname".into())
+            .expect("expected an estimate to be produced for a registered name");
+        let estimate = from_binary::<EstimateRegistrationResponse>(&result_bin)
+            .expect("expected the response to deserialize to an EstimateRegistrationResponse");
+        assert!(
+            !estimate.available,
+            "expected a registered name to be unavailable",
+        );
+        assert_eq!(
+            vec![coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            estimate.fee
+        );
+    }
+
+    #[test]
+    fn test_estimate_includes_additional_fees() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                additional_fees: Some(vec![coin(20, "usdf")]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let result_bin = estimate_registration(deps.as_ref(), mock_env(), "freename".into())
+            .expect("expected an estimate to be produced for a valid name");
+        let estimate = from_binary::<EstimateRegistrationResponse>(&result_bin)
+            .expect("expected the response to deserialize to an EstimateRegistrationResponse");
+        assert_eq!(
+            vec![
+                coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION),
+                coin(20, "usdf")
+            ],
+            estimate.fee,
+            "expected every fee denomination to be estimated",
+        );
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("somedude", &estimate.fee),
+            "freename".into(),
+            None,
+            None,
+        )
+        .expect("expected sending the estimated fee to register the name");
+    }
+}
@@ -0,0 +1,161 @@
+use crate::core::error::ContractError;
+use crate::core::msg::NameResponse;
+use crate::core::state::name_index_read;
+use crate::util::constants::{DEFAULT_NAME_QUERY_LIMIT, MAX_NAME_QUERY_LIMIT};
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, Binary, Deps, Order, StdResult};
+use provwasm_std::ProvenanceQuery;
+
+/// Lists the names registered to an address using the contract's own reverse index, rather than
+/// the attribute module.  Results are returned in ascending order, and can be paged through by
+/// providing the final name of the previous page as start_after.  At most MAX_NAME_QUERY_LIMIT
+/// names are returned in a single page.
+pub fn get_names_by_address(
+    deps: Deps<ProvenanceQuery>,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let validated_address = match deps.api.addr_validate(address.as_str()) {
+        Ok(addr) => addr,
+        Err(e) => {
+            return ContractError::QueryError(format!(
+                "invalid address provided [{}]: {:?}",
+                address, e
+            ))
+            .to_result();
+        }
+    };
+    let limit = limit
+        .unwrap_or(DEFAULT_NAME_QUERY_LIMIT)
+        .min(MAX_NAME_QUERY_LIMIT) as usize;
+    // Bucket ranges are inclusive of their start key, so appending a zero byte to the previous
+    // name moves the start to the very next possible key
+    let start = start_after.map(|name| {
+        let mut key = normalize_name(&name).into_bytes();
+        key.push(0);
+        key
+    });
+    let names = name_index_read(deps.storage, validated_address.as_str())
+        .range(start.as_deref(), None, Order::Ascending)
+        .take(limit)
+        .map(|element| element.map(|(_, name)| name))
+        .collect::<StdResult<Vec<String>>>()?;
+    Ok(to_binary(&NameResponse::new(
+        validated_address.into_string(),
+        names,
+    ))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::NameResponse;
+    use crate::execute::register_name::register_name;
+    use crate::query::get_names_by_address::get_names_by_address;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::{DEFAULT_FEE_DENOMINATION, MAX_NAME_QUERY_LIMIT};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_get_names_by_address_pagination() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        // Register names out of order to verify that results are sorted
+        for (sender, name) in [
+            ("owner", "delta"),
+            ("owner", "alpha"),
+            ("owner", "echo"),
+            ("owner", "charlie"),
+            ("owner", "bravo"),
+            ("someone_else", "foxtrot"),
+        ] {
+            register_name(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(
+                    sender,
+                    &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+                ),
+                name.to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+        }
+        let query_page = |start_after: Option<&str>, limit: Option<u32>| {
+            let bin = get_names_by_address(
+                deps.as_ref(),
+                "owner".to_string(),
+                start_after.map(|name| name.to_string()),
+                limit,
+            )
+            .expect("the query should succeed");
+            from_binary::<NameResponse>(&bin).expect("the response should deserialize")
+        };
+        let first_page = query_page(None, Some(2));
+        assert_eq!("owner", first_page.address);
+        assert_eq!(vec!["alpha", "bravo"], first_page.names);
+        let second_page = query_page(Some("bravo"), Some(2));
+        assert_eq!(vec!["charlie", "delta"], second_page.names);
+        let final_page = query_page(Some("delta"), Some(2));
+        assert_eq!(
+            vec!["echo"],
+            final_page.names,
+            "the final page should only contain the remaining name",
+        );
+        let empty_page = query_page(Some("echo"), Some(2));
+        assert!(
+            empty_page.names.is_empty(),
+            "paging past the final name should produce no results",
+        );
+        let all_names = query_page(None, None);
+        assert_eq!(
+            vec!["alpha", "bravo", "charlie", "delta", "echo"],
+            all_names.names,
+            "names owned by other addresses should not be included",
+        );
+    }
+
+    #[test]
+    fn test_get_names_by_address_limit_is_capped() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        for i in 0..(MAX_NAME_QUERY_LIMIT + 5) {
+            register_name(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(
+                    "owner",
+                    &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+                ),
+                format!("name{}", i),
+                None,
+                None,
+            )
+            .unwrap();
+        }
+        let bin =
+            get_names_by_address(deps.as_ref(), "owner".to_string(), None, Some(u32::MAX)).unwrap();
+        let response = from_binary::<NameResponse>(&bin).unwrap();
+        assert_eq!(
+            MAX_NAME_QUERY_LIMIT as usize,
+            response.names.len(),
+            "the number of results should never exceed the maximum limit",
+        );
+    }
+
+    #[test]
+    fn test_get_names_by_address_invalid_address() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = get_names_by_address(deps.as_ref(), "".to_string(), None, None).unwrap_err();
+        assert!(
+            matches!(error, ContractError::QueryError(..)),
+            "an invalid address should produce a query error",
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::core::error::ContractError;
+use crate::core::state::registration_stats_read;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Reports the total amount of names registered and the total fees collected by the contract.  These
+/// values are maintained as names are registered, so this query never scans the registered names.  A
+/// contract that has not registered any names yet responds with zeroed totals.
+pub fn get_stats(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
+    let stats = registration_stats_read(deps.storage)
+        .may_load()?
+        .unwrap_or_default();
+    Ok(to_binary(&stats)?)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::state::RegistrationStats;
+    use crate::execute::register_name::register_name;
+    use crate::execute::register_names::register_names;
+    use crate::query::get_stats::get_stats;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_stats_count_registrations_and_net_fees() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_amount: 150,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            RegistrationStats::default(),
+            from_binary::<RegistrationStats>(&get_stats(deps.as_ref()).unwrap()).unwrap(),
+            "a contract without registrations should report zeroed totals",
+        );
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("first_owner", &[coin(150, DEFAULT_FEE_DENOMINATION)]),
+            "firstname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        // Overpay by 50, which is refunded and must not count toward the fees collected
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("second_owner", &[coin(200, DEFAULT_FEE_DENOMINATION)]),
+            "secondname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        register_names(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("third_owner", &[coin(300, DEFAULT_FEE_DENOMINATION)]),
+            vec!["thirdname".into(), "fourthname".into()],
+        )
+        .unwrap();
+        assert_eq!(
+            RegistrationStats {
+                total_registrations: 4,
+                total_fees_collected: vec![coin(600, DEFAULT_FEE_DENOMINATION)],
+            },
+            from_binary::<RegistrationStats>(&get_stats(deps.as_ref()).unwrap()).unwrap(),
+            "every registration should be counted, and only the net fee of each should be collected",
+        );
+    }
+
+    #[test]
+    fn test_stats_record_rejects_overflow() {
+        let mut stats = RegistrationStats {
+            total_registrations: u64::MAX,
+            total_fees_collected: vec![],
+        };
+        stats
+            .record(1, &[])
+            .expect_err("the registration count should not wrap around");
+        let mut stats = RegistrationStats {
+            total_registrations: 0,
+            total_fees_collected: vec![coin(u128::MAX, DEFAULT_FEE_DENOMINATION)],
+        };
+        stats
+            .record(1, &[coin(1, DEFAULT_FEE_DENOMINATION)])
+            .expect_err("the fees collected should not wrap around");
+    }
+}
@@ -1,5 +1,12 @@
+pub mod check_names_available;
+pub mod estimate_registration;
+pub mod get_names_by_address;
+pub mod get_stats;
 pub mod query_address_by_name;
+pub mod query_name_expiration;
 pub mod query_names_by_address;
 pub mod query_state;
 pub mod query_version;
+pub mod resolve_name;
 pub mod search_for_names;
+pub mod simulate_register;
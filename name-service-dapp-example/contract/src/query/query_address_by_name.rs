@@ -1,5 +1,6 @@
 use crate::core::error::ContractError;
 use crate::core::state::meta_read;
+use crate::util::helper_functions::normalize_name;
 use cosmwasm_std::{to_binary, Binary, Deps};
 use provwasm_std::ProvenanceQuery;
 
@@ -16,6 +17,6 @@ pub fn query_address_by_name(
     name: String,
 ) -> Result<Binary, ContractError> {
     let meta_storage = meta_read(deps.storage);
-    let name_meta = meta_storage.load(name.as_bytes())?;
+    let name_meta = meta_storage.load(normalize_name(&name).as_bytes())?;
     Ok(to_binary(&name_meta)?)
 }
@@ -0,0 +1,124 @@
+use crate::core::error::ContractError;
+use crate::core::msg::NameExpirationResponse;
+use crate::core::state::meta_read;
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Reports the block height at which a registered name expires, and whether it has expired as of
+/// the current block.  An expired name can be claimed by any address that registers it.  Names
+/// registered without a configured registration duration have no expiration height and never
+/// expire.  Responds with a NameNotFound error if the name has not been registered.
+pub fn query_name_expiration(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    name: String,
+) -> Result<Binary, ContractError> {
+    match meta_read(deps.storage).may_load(normalize_name(&name).as_bytes())? {
+        Some(name_meta) => Ok(to_binary(&NameExpirationResponse {
+            expired: name_meta.is_expired(env.block.height),
+            expiration_height: name_meta.expiration_height,
+            name: name_meta.name,
+        })?),
+        None => ContractError::NameNotFound.to_result(),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::NameExpirationResponse;
+    use crate::execute::register_name::register_name;
+    use crate::query::query_name_expiration::query_name_expiration;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_name_expires_at_its_expiration_height() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                registration_duration_blocks: Some(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let registration_env = mock_env();
+        register_name(
+            deps.as_mut(),
+            registration_env.clone(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "expiringname".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let expiration_height = registration_env.block.height + 100;
+        let query_at_height = |height: u64| {
+            let mut env = mock_env();
+            env.block.height = height;
+            from_binary::<NameExpirationResponse>(
+                &query_name_expiration(deps.as_ref(), env, "expiringname".into()).unwrap(),
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            NameExpirationResponse {
+                name: "expiringname".to_string(),
+                expiration_height: Some(expiration_height),
+                expired: false,
+            },
+            query_at_height(expiration_height - 1),
+            "the name should not be expired before its expiration height",
+        );
+        assert!(
+            query_at_height(expiration_height).expired,
+            "the name should be expired once its expiration height is reached",
+        );
+    }
+
+    #[test]
+    fn test_name_without_duration_never_expires() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "forever".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.height = u64::MAX;
+        assert_eq!(
+            NameExpirationResponse {
+                name: "forever".to_string(),
+                expiration_height: None,
+                expired: false,
+            },
+            from_binary::<NameExpirationResponse>(
+                &query_name_expiration(deps.as_ref(), env, "forever".into()).unwrap(),
+            )
+            .unwrap(),
+        );
+        let error = query_name_expiration(deps.as_ref(), mock_env(), "missing".into()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameNotFound),
+            "expected an unregistered name to produce a NameNotFound error, but got: {:?}",
+            error,
+        );
+    }
+}
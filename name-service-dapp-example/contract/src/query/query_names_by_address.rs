@@ -83,7 +83,7 @@ pub mod tests {
     use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
     use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
     use crate::testutil::test_utilities::create_fake_name_attribute;
-    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{coin, from_binary, Addr};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::Attributes;
@@ -130,8 +130,11 @@ pub mod tests {
         let sender = "registration_guy";
         register_name(
             deps.as_mut(),
+            mock_env(),
             mock_info(sender, &vec![coin(DEFAULT_FEE_AMOUNT, "nhash")]),
             name.clone(),
+            None,
+            None,
         )
         .unwrap();
         let name_response_binary =
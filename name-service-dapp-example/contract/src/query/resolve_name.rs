@@ -0,0 +1,72 @@
+use crate::core::error::ContractError;
+use crate::core::state::meta_read;
+use crate::util::helper_functions::normalize_name;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Resolves a name to the address that owns it, using only the registry stored within this
+/// contract.  This avoids a round-trip to the Provenance name module, and is suitable for clients
+/// that trust this contract's own registry.  Responds with the name and its owning address, or a
+/// NameNotFound error if the name has not been registered.
+pub fn resolve_name(deps: Deps<ProvenanceQuery>, name: String) -> Result<Binary, ContractError> {
+    match meta_read(deps.storage).may_load(normalize_name(&name).as_bytes())? {
+        Some(name_meta) => Ok(to_binary(&name_meta)?),
+        None => ContractError::NameNotFound.to_result(),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::NameMeta;
+    use crate::execute::register_name::register_name;
+    use crate::query::resolve_name::resolve_name;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_resolve_registered_name() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "owner_address",
+                &[coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
+            ),
+            "resolvable".into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let result_bin = resolve_name(deps.as_ref(), "resolvable".into())
+            .expect("expected a registered name to resolve");
+        let name_meta = from_binary::<NameMeta>(&result_bin)
+            .expect("expected the response to deserialize to a NameMeta");
+        assert_eq!(
+            "resolvable", name_meta.name,
+            "expected the resolved name to be returned"
+        );
+        assert_eq!(
+            "owner_address", name_meta.address,
+            "expected the name to resolve to the address that registered it",
+        );
+    }
+
+    #[test]
+    fn test_resolve_missing_name() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = resolve_name(deps.as_ref(), "missingname".into()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameNotFound),
+            "expected an unregistered name to produce a NameNotFound error, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -2,24 +2,25 @@ use crate::core::error::ContractError;
 use crate::core::msg::NameSearchResponse;
 use crate::core::state::meta_read;
 use crate::util::constants::MAX_NAME_SEARCH_RESULTS;
+use crate::util::helper_functions::normalize_name;
 use cosmwasm_std::{to_binary, Binary, Deps, Order};
 use provwasm_std::ProvenanceQuery;
 
 /// Scans the entire storage for the target name string by doing substring matches.
 /// Will only ever return a maximum of MAX_NAME_SEARCH_RESULTS.
-/// This allows for some basic pseudo fuzzy-search style results of names
+/// This allows for some basic pseudo fuzzy-search style results of names, ignoring case
 pub fn search_for_names(
     deps: Deps<ProvenanceQuery>,
     search: String,
 ) -> Result<Binary, ContractError> {
     let meta_storage = meta_read(deps.storage);
-    let search_str = search.as_str();
+    let search_str = normalize_name(&search);
     let names = meta_storage
         .range(None, None, Order::Ascending)
         .into_iter()
         .filter(|element| element.is_ok())
         .map(|element| element.unwrap().1)
-        .filter(|name_meta| name_meta.name.contains(search_str))
+        .filter(|name_meta| normalize_name(&name_meta.name).contains(&search_str))
         .take(MAX_NAME_SEARCH_RESULTS)
         .collect();
     to_binary(&NameSearchResponse {
@@ -36,15 +37,23 @@ mod tests {
     use crate::query::search_for_names::search_for_names;
     use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
     use crate::testutil::test_constants::DEFAULT_FEE_AMOUNT;
-    use crate::util::constants::{FEE_DENOMINATION, MAX_NAME_SEARCH_RESULTS};
-    use cosmwasm_std::testing::mock_info;
+    use crate::util::constants::{DEFAULT_FEE_DENOMINATION, MAX_NAME_SEARCH_RESULTS};
+    use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{coin, from_binary};
     use provwasm_mocks::mock_dependencies;
 
     #[test]
     fn test_search_for_names() {
         let mut deps = mock_dependencies(&[]);
-        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        // Allow single character names to ensure the search covers the shortest possible values
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                min_name_length: Some(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let mut names: Vec<String> = vec![
             "a".into(),
             "aa".into(),
@@ -60,11 +69,14 @@ mod tests {
         names.into_iter().for_each(|name| {
             register_name(
                 deps.as_mut(),
+                mock_env(),
                 mock_info(
                     "fake_address",
-                    &vec![coin(DEFAULT_FEE_AMOUNT, FEE_DENOMINATION)],
+                    &vec![coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION)],
                 ),
                 name.into(),
+                None,
+                None,
             )
             .unwrap();
         });
@@ -0,0 +1,212 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{SimulateRegisterResponse, SimulatedTransfer};
+use crate::core::state::{config_read, meta_read};
+use crate::execute::register_name::{build_registration_messages, RegistrationMessages};
+use cosmwasm_std::{to_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, Env, MessageInfo};
+use provwasm_std::ProvenanceQuery;
+
+/// Runs the same validation and fee computation as registering a single name with the given funds,
+/// describing the attribute, fee and refund that registration would produce without changing any
+/// storage.  A query has no sender, so the registration is simulated for an address that is not
+/// fee exempt, and registration cooldowns are not checked.  Any error that registration would
+/// produce is returned as-is.
+pub fn simulate_register(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    name: String,
+    sent_funds: Vec<Coin>,
+) -> Result<Binary, ContractError> {
+    let config = config_read(deps.storage).load()?;
+    // The contract itself stands in for the sender, which only determines the recipient of the
+    // attribute and refund messages, neither of which are included in the response
+    let info = MessageInfo {
+        sender: env.contract.address,
+        funds: sent_funds,
+    };
+    let RegistrationMessages {
+        charge_response, ..
+    } = build_registration_messages(
+        deps.api,
+        &meta_read(deps.storage),
+        &config,
+        &info,
+        env.block.height,
+        &name,
+        None,
+    )?;
+    let fee_transfers = charge_response
+        .fee_charge_messages
+        .into_iter()
+        .filter_map(|message| match message {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                Some(SimulatedTransfer { to_address, amount })
+            }
+            _ => None,
+        })
+        .collect::<Vec<SimulatedTransfer>>();
+    Ok(to_binary(&SimulateRegisterResponse {
+        attribute_name: config.name.clone(),
+        attribute_value: name,
+        fee_transfers,
+        // Overpayments in every fee denomination are refunded, so each is reported
+        refund: charge_response.fee_refund,
+    })?)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::{SimulateRegisterResponse, SimulatedTransfer};
+    use crate::core::state::{meta_read, FeeRecipient};
+    use crate::execute::register_name::register_name;
+    use crate::query::simulate_register::simulate_register;
+    use crate::testutil::instantiation_helpers::{test_instantiate, InstArgs};
+    use crate::testutil::test_constants::{DEFAULT_CONTRACT_NAME, DEFAULT_FEE_AMOUNT};
+    use crate::util::constants::DEFAULT_FEE_DENOMINATION;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, BankMsg, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_simulation_matches_registration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_distribution: Some(vec![
+                    FeeRecipient {
+                        address: "first_recipient".to_string(),
+                        basis_points: 7500,
+                    },
+                    FeeRecipient {
+                        address: "second_recipient".to_string(),
+                        basis_points: 2500,
+                    },
+                ]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let sent_funds = vec![coin(DEFAULT_FEE_AMOUNT + 50, DEFAULT_FEE_DENOMINATION)];
+        let result_bin = simulate_register(
+            deps.as_ref(),
+            mock_env(),
+            "mycoolname".into(),
+            sent_funds.clone(),
+        )
+        .expect("expected the registration to be simulated");
+        let simulation = from_binary::<SimulateRegisterResponse>(&result_bin)
+            .expect("expected the response to deserialize to a SimulateRegisterResponse");
+        assert_eq!(DEFAULT_CONTRACT_NAME, simulation.attribute_name);
+        assert_eq!("mycoolname", simulation.attribute_value);
+        assert_eq!(vec![coin(50, DEFAULT_FEE_DENOMINATION)], simulation.refund);
+        assert!(
+            meta_read(deps.as_ref().storage)
+                .may_load(b"mycoolname")
+                .unwrap()
+                .is_none(),
+            "expected the simulation to leave the name unregistered",
+        );
+
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("somedude", &sent_funds),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .expect("expected the simulated registration to succeed");
+        // The attribute message comes first, followed by each fee transfer and then the refund
+        let executed_transfers = response
+            .messages
+            .iter()
+            .filter_map(|message| match &message.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some(SimulatedTransfer {
+                    to_address: to_address.to_owned(),
+                    amount: amount.to_owned(),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<SimulatedTransfer>>();
+        assert_eq!(
+            simulation.fee_transfers,
+            executed_transfers[..executed_transfers.len() - 1],
+            "expected the simulated fee transfers to match the executed fee transfers",
+        );
+        assert_eq!(
+            SimulatedTransfer {
+                to_address: "somedude".to_string(),
+                amount: simulation.refund,
+            },
+            executed_transfers[executed_transfers.len() - 1],
+            "expected the simulated refund amount to match the executed refund",
+        );
+    }
+
+    #[test]
+    fn test_simulation_reports_refunds_in_every_denom() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                additional_fees: Some(vec![coin(20, "usdf")]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let sent_funds = vec![
+            coin(DEFAULT_FEE_AMOUNT, DEFAULT_FEE_DENOMINATION),
+            coin(25, "usdf"),
+        ];
+        let result_bin = simulate_register(
+            deps.as_ref(),
+            mock_env(),
+            "mycoolname".into(),
+            sent_funds.clone(),
+        )
+        .expect("expected the registration to be simulated");
+        let simulation = from_binary::<SimulateRegisterResponse>(&result_bin)
+            .expect("expected the response to deserialize to a SimulateRegisterResponse");
+        assert_eq!(
+            vec![coin(5, "usdf")],
+            simulation.refund,
+            "expected an overpayment in an additional fee denomination to be reported",
+        );
+        let response = register_name(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("somedude", &sent_funds),
+            "mycoolname".into(),
+            None,
+            None,
+        )
+        .expect("expected the simulated registration to succeed");
+        assert!(
+            response.messages.iter().any(|message| message.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "somedude".to_string(),
+                    amount: simulation.refund.clone(),
+                })),
+            "expected the simulated refund to match the executed refund",
+        );
+    }
+
+    #[test]
+    fn test_simulation_returns_registration_errors() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = simulate_register(
+            deps.as_ref(),
+            mock_env(),
+            "mycoolname".into(),
+            vec![coin(DEFAULT_FEE_AMOUNT - 1, DEFAULT_FEE_DENOMINATION)],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InsufficientFundsProvided { .. }),
+            "expected insufficient funds to be rejected as in registration, but got: {:?}",
+            error,
+        );
+    }
+}
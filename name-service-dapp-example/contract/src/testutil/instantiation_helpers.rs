@@ -1,11 +1,12 @@
 use crate::core::error::ContractError;
 use crate::core::msg::InitMsg;
+use crate::core::state::FeeRecipient;
 use crate::instantiate::instantiate_contract::instantiate_contract;
 use crate::testutil::test_constants::{
     DEFAULT_CONTRACT_NAME, DEFAULT_FEE_AMOUNT, DEFAULT_FEE_COLLECTION_ADDRESS, DEFAULT_INFO_NAME,
 };
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 /// Holds all instantiation arguments for a test environment, and provides a default implementation
@@ -16,6 +17,16 @@ pub struct InstArgs<'a> {
     pub name: &'a str,
     pub fee_amount: u128,
     pub fee_collection_address: &'a str,
+    pub fee_denomination: Option<&'a str>,
+    pub min_name_length: Option<u32>,
+    pub max_name_length: Option<u32>,
+    pub fee_distribution: Option<Vec<FeeRecipient>>,
+    pub reserved_names: Option<Vec<String>>,
+    pub strict_fee_mode: Option<bool>,
+    pub registration_cooldown_blocks: Option<u64>,
+    pub fee_recipient_override_addresses: Option<Vec<String>>,
+    pub additional_fees: Option<Vec<Coin>>,
+    pub registration_duration_blocks: Option<u64>,
 }
 impl Default for InstArgs<'_> {
     fn default() -> Self {
@@ -25,6 +36,16 @@ impl Default for InstArgs<'_> {
             name: DEFAULT_CONTRACT_NAME,
             fee_amount: DEFAULT_FEE_AMOUNT,
             fee_collection_address: DEFAULT_FEE_COLLECTION_ADDRESS,
+            fee_denomination: None,
+            min_name_length: None,
+            max_name_length: None,
+            fee_distribution: None,
+            reserved_names: None,
+            strict_fee_mode: None,
+            registration_cooldown_blocks: None,
+            fee_recipient_override_addresses: None,
+            additional_fees: None,
+            registration_duration_blocks: None,
         }
     }
 }
@@ -43,6 +64,16 @@ pub fn test_instantiate(
             name: args.name.into(),
             fee_amount: args.fee_amount.to_string(),
             fee_collection_address: args.fee_collection_address.into(),
+            fee_denomination: args.fee_denomination.map(|denom| denom.into()),
+            min_name_length: args.min_name_length,
+            max_name_length: args.max_name_length,
+            fee_distribution: args.fee_distribution,
+            reserved_names: args.reserved_names,
+            strict_fee_mode: args.strict_fee_mode,
+            registration_cooldown_blocks: args.registration_cooldown_blocks,
+            fee_recipient_override_addresses: args.fee_recipient_override_addresses,
+            additional_fees: args.additional_fees,
+            registration_duration_blocks: args.registration_duration_blocks,
         },
     )
 }
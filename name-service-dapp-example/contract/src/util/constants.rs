@@ -1,2 +1,9 @@
-pub const FEE_DENOMINATION: &str = "nhash";
+pub const DEFAULT_FEE_DENOMINATION: &str = "nhash";
+pub const DEFAULT_MIN_NAME_LENGTH: u32 = 3;
+pub const DEFAULT_MAX_NAME_LENGTH: u32 = 32;
+pub const TOTAL_FEE_BASIS_POINTS: u32 = 10000;
 pub const MAX_NAME_SEARCH_RESULTS: usize = 25;
+pub const DEFAULT_NAME_QUERY_LIMIT: u32 = 25;
+pub const MAX_NAME_QUERY_LIMIT: u32 = 100;
+pub const MAX_MEMO_LENGTH: usize = 256;
+pub const MAX_NAME_AVAILABILITY_CHECKS: usize = 25;
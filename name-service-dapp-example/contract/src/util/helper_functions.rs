@@ -1,5 +1,11 @@
 use crate::core::error::ContractError;
 
+/// Names are unique regardless of casing, so every storage key is derived from the lowercase form
+/// of a name.  The casing provided at registration is kept in the stored NameMeta for display.
+pub fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+}
+
 pub fn fee_amount_from_string(fee_amount_string: &str) -> Result<u128, ContractError> {
     match fee_amount_string.parse::<u128>() {
         Ok(amount) => Ok(amount),
@@ -63,6 +63,12 @@ export interface QuerySettingsResponse {
     name: string,
     fee_amount: string,
     fee_collection_address: string,
+    fee_denomination?: string,
+    min_name_length?: number,
+    max_name_length?: number,
+    admin?: string,
+    fee_distribution?: { address: string, basis_points: number }[],
+    reserved_names?: string[],
 }
 
 export class RegisterName extends ContractMsg {
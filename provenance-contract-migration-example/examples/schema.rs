@@ -3,7 +3,10 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use provenance_contract_migration_example::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
+use provenance_contract_migration_example::msg::{
+    CheckIncrementFundsResponse, ExecuteMsg, InitMsg, MigrateMsg, QueryContractInfoResponse,
+    QueryMsg,
+};
 
 /// This rust file is used to automatically generate a schema output for all entrypoint values.
 /// This is to help users of the contract get an idea of how to format the json used when calling
@@ -14,8 +17,10 @@ fn main() {
     create_dir_all(&out_dir).unwrap();
     remove_schemas(&out_dir).unwrap();
 
+    export_schema(&schema_for!(CheckIncrementFundsResponse), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(InitMsg), &out_dir);
     export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(QueryContractInfoResponse), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
 }
@@ -1,6 +1,6 @@
 use cosmwasm_std::{
-    entry_point, to_binary, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, Response, Uint128,
+    entry_point, to_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Response, StdResult, Uint128,
 };
 use provwasm_std::{
     add_attribute, bind_name, AttributeValueType, NameBinding, ProvenanceMsg, ProvenanceQuerier,
@@ -9,9 +9,18 @@ use provwasm_std::{
 
 use crate::{
     error::ContractError,
-    msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg},
-    state::{state, state_read, State},
-    version_info::{get_version_info, migrate_version_info, VersionInfo},
+    msg::{
+        CheckIncrementFundsResponse, ExecuteMsg, InitMsg, MigrateMsg, QueryContractInfoResponse,
+        QueryMsg,
+    },
+    state::{
+        migrate_legacy_state, pending_counter_change, recent_request_ids, state, state_read,
+        PendingCounterChange, State, MAX_RECENT_REQUEST_IDS, MAX_REQUEST_ID_LENGTH,
+    },
+    version_info::{
+        get_migration_history, get_version_info, migrate_version_info, record_migration,
+        MigrationRecord, VersionInfo,
+    },
 };
 
 /// The instantiation entry_point is the first function that is ever executed in a smart contract, and
@@ -30,6 +39,9 @@ pub fn instantiate(
         info.funds,
         "funds should not be provided when instantiating the contract",
     )?;
+    // Verify that the base name is well-formed before it is stored and bound.  The name module would reject a
+    // malformed name anyway, but its error would be far less clear than the one produced here.
+    validate_contract_base_name(&msg.contract_base_name)?;
     // If a fee detail was provided, it should be validated to ensure that bad interactions do not occur downstream
     if let Some(fee_detail) = &msg.increment_counter_fee {
         fee_detail.self_validate(deps.api)?
@@ -46,6 +58,20 @@ pub fn instantiate(
         // automatically allows this u128 value to be converted with a simple .into() call.
         contract_counter: msg.starting_counter.unwrap_or(0).into(),
         increment_counter_fee: msg.increment_counter_fee,
+        // The instantiating address becomes the admin, which is the only address that can change the list
+        // of authorized addresses later
+        admin: Some(info.sender),
+        // Each provided address is validated before it is stored.  This ensures that a typo in the list is
+        // caught right away, instead of silently locking out the intended address.
+        authorized_addresses: msg
+            .authorized_addresses
+            .map(|addresses| {
+                addresses
+                    .iter()
+                    .map(|address| deps.api.addr_validate(address))
+                    .collect::<StdResult<Vec<Addr>>>()
+            })
+            .transpose()?,
     };
     // Store the initial state in the contract's internal storage, which can be referenced during execution
     // and query routes later.
@@ -98,13 +124,19 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     match msg {
-        ExecuteMsg::IncrementCounter { increment_amount } => {
-            increment_counter(deps, info, increment_amount)
-        }
+        ExecuteMsg::IncrementCounter {
+            increment_amount,
+            request_id,
+        } => increment_counter(deps, info, increment_amount, request_id),
         ExecuteMsg::AddAttribute {
             attribute_prefix,
             attribute_text,
         } => add_attribute_to_contract(deps, info, env, attribute_prefix, attribute_text),
+        ExecuteMsg::AddAuthorizedAddress { address } => add_authorized_address(deps, info, address),
+        ExecuteMsg::RemoveAuthorizedAddress { address } => {
+            remove_authorized_address(deps, info, address)
+        }
+        ExecuteMsg::ApplyPendingChange {} => apply_pending_change(deps, env, info),
         ExecuteMsg::SendFunds { recipient_address } => send_funds(deps, info, recipient_address),
     }
 }
@@ -155,23 +187,61 @@ pub fn query(
         // The state  derives Serialize and Deserialize, so it is safe to use to_binary on it to use the
         // entire value as a response and serialize it to a Binary struct.
         QueryMsg::QueryState {} => Ok(to_binary(&state_read(deps.storage).load()?)?),
+        // Only the counter is serialized, keeping the response as small as possible
+        QueryMsg::QueryCounter {} => Ok(to_binary(
+            &state_read(deps.storage).load()?.contract_counter,
+        )?),
+        // An absent fee is serialized as null, which clients can use to detect a free increment
+        QueryMsg::QueryFeeConfig {} => Ok(to_binary(
+            &state_read(deps.storage).load()?.increment_counter_fee,
+        )?),
+        // Run the same funds check that IncrementCounter uses, reporting a rejection as an insufficient result rather
+        // than an error
+        QueryMsg::CheckIncrementFunds { funds } => {
+            let contract_state = state_read(deps.storage).load()?;
+            let response = match check_increment_funds(&contract_state, &funds) {
+                Ok(()) => CheckIncrementFundsResponse {
+                    sufficient: true,
+                    explanation: None,
+                },
+                Err(ContractError::InvalidFunds { explanation }) => CheckIncrementFundsResponse {
+                    sufficient: false,
+                    explanation: Some(explanation),
+                },
+                Err(error) => return Err(error),
+            };
+            Ok(to_binary(&response)?)
+        }
         // Load the version info in the same way that the state is loaded.  It also derives Serialize and Deserialize,
         // so returning the entire VersionInfo struct as Binary is safe.
         QueryMsg::QueryVersion {} => Ok(to_binary(&get_version_info(deps.storage)?)?),
+        QueryMsg::QueryMigrationHistory {} => Ok(to_binary(&get_migration_history(deps.storage)?)?),
+        QueryMsg::QueryContractInfo {} => {
+            let contract_state = state_read(deps.storage).load()?;
+            // Omitting the name returns every attribute on the contract's address, including any that were not
+            // added by the AddAttribute route
+            let attribute_wrapper = ProvenanceQuerier::new(&deps.querier)
+                .get_attributes(env.contract.address, None::<String>)?;
+            Ok(to_binary(&QueryContractInfoResponse {
+                contract_base_name: contract_state.contract_base_name,
+                attributes: attribute_wrapper.attributes,
+            })?)
+        }
     }
 }
 
 #[entry_point]
 pub fn migrate(
     deps: DepsMut<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
     // If a previous version has been declared, it's important to ensure that the code that is being migrated
     // is not an older version of the contract.  Otherwise, future migrations can be downgrades, which is not
     // a desired state!  This check attempts to get the version info, but if none can be found due to an error,
     // it is because no version info exists in storage in an older version.
-    if let Ok(version_info) = get_version_info(deps.storage) {
+    let previous_version_info = get_version_info(deps.storage).ok();
+    if let Some(version_info) = &previous_version_info {
         let stored_version = version_info.parse_sem_ver()?;
         let current_version = VersionInfo::current_version().parse_sem_ver()?;
         // This is why VersionInfo leverages the semver crate.  Contract versions can be declared in any fashion one
@@ -185,25 +255,70 @@ pub fn migrate(
     // simply invoke the migrate function, which will establish in memory the new version declared in the
     // migrating contract codebase.
     let version_info = migrate_version_info(deps.storage)?;
+    // The VersionInfo only ever holds the latest version, so the transition is also appended to the
+    // migration history to preserve a record of every upgrade.
+    record_migration(
+        deps.storage,
+        MigrationRecord {
+            from_version: previous_version_info.map(|version_info| version_info.version),
+            to_version: version_info.version.to_owned(),
+            block_height: env.block.height,
+        },
+    )?;
+    // Older contract versions stored the increment_counter_fee with a single coin.  That state must be converted
+    // before anything attempts to load it as the current State, which would otherwise fail to deserialize.
+    let converted_legacy_state = migrate_legacy_state(deps.storage)?;
+    // An effective height only delays a counter change, so it has no meaning without one
+    if msg.effective_height.is_some() && msg.new_counter_value.is_none() {
+        return Err(ContractError::generic_err(
+            "an effective_height can only be provided with a new_counter_value",
+        ));
+    }
     // Similarly to how messages are appened in the increment_counter function, this declaration of a mutable
     // vector will store attributes that denote when optional values in the MigrateMsg are encountered. They
     // will be added to the response after all other migration tasks have been completed.
     let mut attributes: Vec<Attribute> = vec![];
+    if converted_legacy_state {
+        attributes.push(Attribute::new("converted_legacy_state", "true"));
+    }
     // Do an up-front check to see if any optional values are set.  If this becomes more complex, it may eventually
     // make sense to migrate this logic directly into an impl for MigrateMsg.  However, MigrateMsg currently only
-    // contains two fields, so this if-statement is not currently logically cumbersome.
-    if msg.new_counter_value.is_some() || msg.increment_counter_fee.is_some() {
-        // Both optional values are requests for the contract State struct to be mutated, and it has been confirmed
+    // contains a few fields, so this if-statement is not currently logically cumbersome.
+    if msg.new_counter_value.is_some()
+        || msg.increment_counter_fee.is_some()
+        || msg.new_admin.is_some()
+    {
+        // All optional values are requests for the contract State struct to be mutated, and it has been confirmed
         // that at least one of them has been requested.  Due to this, preemptively loading the state at this point
         // will never be pointless.
         let mut contract_state = state(deps.storage);
         let mut state = contract_state.load()?;
-        if let Some(new_counter_value) = msg.new_counter_value {
-            attributes.push(Attribute::new(
-                "modified_counter_value",
-                format!("{new_counter_value}"),
-            ));
-            state.contract_counter = Uint128::new(new_counter_value);
+        let mut pending_change: Option<PendingCounterChange> = None;
+        match (msg.new_counter_value, msg.effective_height) {
+            // A delayed counter change is stored on its own, leaving the counter untouched until the change is
+            // applied by the ApplyPendingChange route.  Any change that was already pending is replaced.
+            (Some(new_counter_value), Some(effective_height)) => {
+                attributes.push(Attribute::new(
+                    "pending_counter_value",
+                    format!("{new_counter_value}"),
+                ));
+                attributes.push(Attribute::new(
+                    "pending_effective_height",
+                    format!("{effective_height}"),
+                ));
+                pending_change = Some(PendingCounterChange {
+                    new_counter_value: Uint128::new(new_counter_value),
+                    effective_height,
+                });
+            }
+            (Some(new_counter_value), None) => {
+                attributes.push(Attribute::new(
+                    "modified_counter_value",
+                    format!("{new_counter_value}"),
+                ));
+                state.contract_counter = Uint128::new(new_counter_value);
+            }
+            _ => {}
         }
         if let Some(increment_counter_fee) = msg.increment_counter_fee {
             // Ensure that the newly-provided fee detail is valid. Otherwise, the migration endpoint
@@ -219,9 +334,17 @@ pub fn migrate(
             ));
             state.increment_counter_fee = Some(increment_counter_fee);
         }
+        if let Some(new_admin) = msg.new_admin {
+            let new_admin = deps.api.addr_validate(&new_admin)?;
+            attributes.push(Attribute::new("modified_admin", &new_admin));
+            state.admin = Some(new_admin);
+        }
         // After modifying the state with one or more optional values, it must be saved for the changes
         // to be persisted into the contract's internal storage
         contract_state.save(&state)?;
+        if let Some(pending_change) = pending_change {
+            pending_counter_change(deps.storage).save(&pending_change)?;
+        }
     }
     // Keep in mind: Attributes can be added to a Response for the migrate entry_point.  The entry_point
     // can even be declared similarly to the execute entry_point, including a CosmosMsg<ProvenanceMsg> generic
@@ -233,6 +356,72 @@ pub fn migrate(
         .add_attributes(attributes))
 }
 
+/// Applies the counter change scheduled by a migration with an effective_height.  The change can be applied by any
+/// address, but only once the chain has reached the scheduled height, and is removed after it is applied.
+fn apply_pending_change(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when applying a pending change",
+    )?;
+    let pending_change = match pending_counter_change(deps.storage).may_load()? {
+        Some(pending_change) => pending_change,
+        None => return Err(ContractError::generic_err("no pending change exists")),
+    };
+    if env.block.height < pending_change.effective_height {
+        return Err(ContractError::PendingChangeNotReady {
+            effective_height: pending_change.effective_height,
+            current_height: env.block.height,
+        });
+    }
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    contract_state.contract_counter = pending_change.new_counter_value;
+    state_storage.save(&contract_state)?;
+    // The change has been applied, so it must not be applied again
+    pending_counter_change(deps.storage).remove();
+    Ok(Response::new()
+        .add_attribute("action", "execute_apply_pending_change")
+        .add_attribute(
+            "modified_counter_value",
+            pending_change.new_counter_value.to_string(),
+        ))
+}
+
+/// Ensures that the provided base name can be bound to the contract.  A valid base name is non-empty,
+/// and consists of one or more dot-separated segments that contain only lowercase alphanumeric
+/// characters, like "examples.pio".
+fn validate_contract_base_name(base_name: &str) -> Result<(), ContractError> {
+    let invalid_base_name = |explanation: &str| {
+        Err(ContractError::InvalidBaseName {
+            name: base_name.to_string(),
+            explanation: explanation.to_string(),
+        })
+    };
+    if base_name.is_empty() {
+        return invalid_base_name("the base name cannot be empty");
+    }
+    for segment in base_name.split('.') {
+        if segment.is_empty() {
+            return invalid_base_name(
+                "each dot-separated segment of the base name must be non-empty",
+            );
+        }
+        if !segment
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        {
+            return invalid_base_name(
+                "the base name can only contain lowercase alphanumeric characters and dots",
+            );
+        }
+    }
+    Ok(())
+}
+
 /// A function for standardizing the format for sub-names of the base contract name.
 /// Ensures that all contract functionality that interacts with created base attributes
 /// will produce the same names, given the same input.
@@ -265,52 +454,118 @@ fn check_funds_are_empty<S: Into<String>>(
     }
 }
 
+/// Verifies that the provided funds are exactly what the IncrementCounter route requires.  When the contract
+/// charges a fee, the funds must match the fee detail exactly.  Otherwise, no funds can be provided at all.
+/// This check is shared by the IncrementCounter route and the CheckIncrementFunds query, ensuring that the
+/// query always agrees with the route.
+fn check_increment_funds(state: &State, funds: &[Coin]) -> Result<(), ContractError> {
+    match &state.increment_counter_fee {
+        Some(fee_detail) => fee_detail.check_funds(funds),
+        // Leverage the funds check to ensure that this free execution route does not receive funds at all
+        None => check_funds_are_empty(
+            funds.to_vec(),
+            "funds should not be provided when incrementing the counter",
+        ),
+    }
+}
+
+/// Verifies that the sender is allowed to increment the counter.  Contracts that were instantiated without an
+/// authorized_addresses list are open to all senders, which retains the original behavior of the counter.
+fn check_sender_is_authorized(state: &State, sender: &Addr) -> Result<(), ContractError> {
+    match &state.authorized_addresses {
+        Some(addresses) if !addresses.contains(sender) => Err(ContractError::Unauthorized {
+            explanation: format!("address [{sender}] is not authorized to increment the counter"),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Verifies that the sender is the contract's admin.  Contracts that were instantiated before the admin value
+/// existed will reject all senders until an admin is established through a migration.
+fn check_sender_is_admin(state: &State, sender: &Addr) -> Result<(), ContractError> {
+    match &state.admin {
+        Some(admin) if admin == sender => Ok(()),
+        Some(admin) => Err(ContractError::Unauthorized {
+            explanation: format!(
+                "only the contract admin [{admin}] can modify the authorized addresses"
+            ),
+        }),
+        None => Err(ContractError::Unauthorized {
+            explanation: "no contract admin has been set. one can be set by migrating the contract"
+                .to_string(),
+        }),
+    }
+}
+
 fn increment_counter(
     deps: DepsMut<ProvenanceQuery>,
     info: MessageInfo,
     increment_amount: Option<u128>,
+    request_id: Option<String>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    let mut state_storage = state(deps.storage);
     // Load the contract state in a mutable manner, allowing the internals to be modified in this execution route
-    let mut contract_state = state_storage.load()?;
+    let mut contract_state = state(deps.storage).load()?;
+    // Reject unauthorized senders before any fee checks are made, ensuring they are never charged for an
+    // increment that cannot occur
+    check_sender_is_authorized(&contract_state, &info.sender)?;
+    if let Some(request_id) = &request_id {
+        if request_id.is_empty() {
+            return Err(ContractError::generic_err(
+                "request_id must not be empty when provided",
+            ));
+        }
+        if request_id.len() > MAX_REQUEST_ID_LENGTH {
+            return Err(ContractError::generic_err(format!(
+                "request_id must be at most {MAX_REQUEST_ID_LENGTH} characters long"
+            )));
+        }
+        // Request_ids are chosen by each client independently, so they are only compared to those provided by
+        // the same sender.  Otherwise, one sender's increment could be skipped by reusing another's request_id.
+        let sender_request_id = format!("{}/{}", info.sender, request_id);
+        // A repeated request_id indicates that the client is retrying an increment that has already been
+        // processed.  Rather than counting it twice, respond with the current counter value.  Any funds
+        // sent with the retry are returned to the sender, because the fee for this increment was already
+        // collected when the request_id was first seen.
+        let mut request_ids = recent_request_ids(deps.storage)
+            .may_load()?
+            .unwrap_or_default();
+        if request_ids.contains(&sender_request_id) {
+            let mut response = Response::new();
+            if !info.funds.is_empty() {
+                response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: info.sender.to_string(),
+                    amount: info.funds,
+                }));
+            }
+            return Ok(response
+                .add_attribute("action", "execute_increment_counter")
+                .add_attribute(
+                    "new_counter_value",
+                    contract_state.contract_counter.to_string(),
+                )
+                .add_attribute("idempotent_replay", "true"));
+        }
+        // Remember the new request_id, dropping the oldest one if the list has reached its maximum size
+        request_ids.push(sender_request_id);
+        if request_ids.len() > MAX_RECENT_REQUEST_IDS {
+            request_ids.remove(0);
+        }
+        recent_request_ids(deps.storage).save(&request_ids)?;
+    }
     // Establish a mutable vector of messages that will get appended to the response after all checks have been made
     let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    // Ensure that the sender sent exactly the fee required by the contract, or no funds at all if the contract
+    // does not charge a fee
+    check_increment_funds(&contract_state, &info.funds)?;
     if let Some(fee_detail) = &contract_state.increment_counter_fee {
-        // Before attempting to charge the fee, ensure that the sender sent the exact fee amount into the contract.
-        // If the user any number of coin definitions other than 1, then they either didn't provide any coin, or provided extra
-        // coin denominations that won't be detected by the contract.  Rejecting this prevents funds from being stuck in the contract.
-        // The second check to ensure that the funds sent are identical to the fee collection detail's amount ensures two things:
-        // 1: The sender sent enough coin,
-        // 2: The sender did not send too much coin.  Sending too much coin would cause the overage amount to be held in the contract's bank balances,
-        // which would essentially "steal" those funds from the sender.
-        if info.funds.len() != 1 || info.funds[0] != fee_detail.fee_collection_amount {
-            return Err(ContractError::InvalidFunds {
-                explanation: format!(
-                    "the charge to increment the counter is [{}]. found funds: {:?}",
-                    fee_detail.get_fee_amount_msg(),
-                    // Map out the funds to a more readable manner, ensuring that they can be printed in a format like "50nhash" as opposed to
-                    // the fully-expanded debug explanation for a Coin
-                    info.funds
-                        .iter()
-                        .map(|coin| format!("{}{}", coin.amount.u128(), coin.denom))
-                        .collect::<Vec<String>>(),
-                ),
-            });
-        }
         // Append a bank send message that targets the fee collector specified in the fee detail, and
-        // sends the exact amount specified in the fee detail.  Due to the verification above, it is
-        // assured that this amount is the amount that the sender provided.  This will be a direct
+        // sends the exact amounts specified in the fee detail.  Due to the verification above, it is
+        // assured that these amounts are the amounts that the sender provided.  This will be a direct
         // pass-through for the provided funds.
         messages.push(CosmosMsg::Bank(BankMsg::Send {
             to_address: fee_detail.fee_collector_address.clone(),
-            amount: vec![fee_detail.fee_collection_amount.clone()],
+            amount: fee_detail.fee_collection_amounts.clone(),
         }));
-    } else {
-        // Leverage the funds check to ensure that this free execution route does not receive funds at all
-        check_funds_are_empty(
-            info.funds,
-            "funds should not be provided when incrementing the counter",
-        )?;
     }
     // If the increment amount provided in the message was present, use it.
     // Otherwise, default to the standard increment amount of 1. This allows the user to
@@ -320,7 +575,7 @@ fn increment_counter(
     // After incrementing the counter, it must be saved to the contract's internal state. This will persist
     // the value, and subsequent increments will see the new value. This will also be available and evident in
     // the query routes.
-    state_storage.save(&contract_state)?;
+    state(deps.storage).save(&contract_state)?;
     Ok(Response::new()
         // Include the messages vector from above in the response. If no fee is required by the contract, this vector
         // will be empty, which is completely fine and will not cause errors
@@ -332,6 +587,64 @@ fn increment_counter(
         ))
 }
 
+fn add_authorized_address(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when adding an authorized address",
+    )?;
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    check_sender_is_admin(&contract_state, &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+    // If no list existed before, the counter was open to everyone.  Adding the first address creates the list,
+    // which restricts the counter to only that address from this point forward.
+    let mut authorized_addresses = contract_state.authorized_addresses.unwrap_or_default();
+    if authorized_addresses.contains(&address) {
+        return Err(ContractError::generic_err(format!(
+            "address [{address}] is already authorized"
+        )));
+    }
+    authorized_addresses.push(address.clone());
+    contract_state.authorized_addresses = Some(authorized_addresses);
+    state_storage.save(&contract_state)?;
+    Ok(Response::new()
+        .add_attribute("action", "execute_add_authorized_address")
+        .add_attribute("authorized_address", address))
+}
+
+fn remove_authorized_address(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when removing an authorized address",
+    )?;
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    check_sender_is_admin(&contract_state, &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+    let mut authorized_addresses = contract_state.authorized_addresses.unwrap_or_default();
+    if !authorized_addresses.contains(&address) {
+        return Err(ContractError::generic_err(format!(
+            "address [{address}] is not currently authorized"
+        )));
+    }
+    // Note: If this removes the final address, the list is left empty rather than being removed.  An empty list
+    // locks the counter, which is safer than silently re-opening it to every address.
+    authorized_addresses.retain(|authorized| authorized != &address);
+    contract_state.authorized_addresses = Some(authorized_addresses);
+    state_storage.save(&contract_state)?;
+    Ok(Response::new()
+        .add_attribute("action", "execute_remove_authorized_address")
+        .add_attribute("removed_address", address))
+}
+
 fn add_attribute_to_contract(
     deps: DepsMut<ProvenanceQuery>,
     info: MessageInfo,
@@ -448,12 +761,14 @@ mod tests {
         coin, from_binary,
         testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
     };
+    use cosmwasm_storage::singleton;
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{AttributeMsgParams, NameMsgParams, ProvenanceMsgParams};
     use serde_json_wasm::to_string;
 
     use crate::{
-        types::FeeCollectionDetail,
+        state::{recent_request_ids_read, LegacyState, MAX_REQUEST_ID_LENGTH},
+        types::{FeeCollectionDetail, LegacyFeeCollectionDetail},
         version_info::{get_version_info, set_version_info, CONTRACT_NAME, CONTRACT_VERSION},
     };
 
@@ -483,6 +798,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: Some(150),
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully when all arguments are as expected");
@@ -591,6 +907,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should succeed when arguments are properly supplied, even without a starting_counter value");
@@ -616,8 +933,9 @@ mod tests {
                 starting_counter: None,
                 increment_counter_fee: Some(FeeCollectionDetail {
                     fee_collector_address: "fee-collector".to_string(),
-                    fee_collection_amount: coin(100, "nhash"),
+                    fee_collection_amounts: vec![coin(100, "nhash")],
                 }),
+                authorized_addresses: None,
             },
         )
         .expect("expected instantiation to succeed");
@@ -631,13 +949,72 @@ mod tests {
             "expected the fee_collector_address of the fee detail to match the provided argument"
         );
         assert_eq!(
-            100,
-            fee_detail.fee_collection_amount.amount.u128(),
-            "expected the fee_collection_amount's amount value to match the provided argument",
+            vec![coin(100, "nhash")],
+            fee_detail.fee_collection_amounts,
+            "expected the fee_collection_amounts value to match the provided argument",
+        );
+    }
+
+    #[test]
+    fn test_query_fee_config() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: Some(FeeCollectionDetail {
+                    fee_collector_address: "fee-collector".to_string(),
+                    fee_collection_amounts: vec![coin(100, "nhash")],
+                }),
+                authorized_addresses: None,
+            },
+        )
+        .expect("expected instantiation to succeed");
+        let fee_binary = query(deps.as_ref(), mock_env(), QueryMsg::QueryFeeConfig {})
+            .expect("expected the fee config query to respond with a binary");
+        assert_eq!(
+            Some(FeeCollectionDetail {
+                fee_collector_address: "fee-collector".to_string(),
+                fee_collection_amounts: vec![coin(100, "nhash")],
+            }),
+            from_binary::<Option<FeeCollectionDetail>>(&fee_binary).expect(
+                "expected the resulting binary to deserialize to an optional FeeCollectionDetail",
+            ),
+            "expected the fee config query to return the fee provided during instantiation",
+        );
+    }
+
+    #[test]
+    fn test_query_fee_config_without_fee() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("expected instantiation to succeed");
+        let fee_binary = query(deps.as_ref(), mock_env(), QueryMsg::QueryFeeConfig {})
+            .expect("expected the fee config query to respond with a binary");
+        assert_eq!(
+            "null",
+            String::from_utf8(fee_binary.to_vec()).unwrap(),
+            "expected the fee config query to respond with null when no fee is configured",
         );
         assert_eq!(
-            "nhash", fee_detail.fee_collection_amount.denom,
-            "expected the fee_collection_amount's denom value to match the provided argument",
+            None,
+            from_binary::<Option<FeeCollectionDetail>>(&fee_binary).expect(
+                "expected the resulting binary to deserialize to an optional FeeCollectionDetail",
+            ),
+            "expected no fee to be returned when the contract was instantiated without one",
         );
     }
 
@@ -654,6 +1031,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .unwrap_err();
@@ -672,12 +1050,13 @@ mod tests {
                 starting_counter: None,
                 increment_counter_fee: Some(FeeCollectionDetail {
                     fee_collector_address: "fee-collector".to_string(),
-                    fee_collection_amount: Coin {
+                    fee_collection_amounts: vec![Coin {
                         // A fee collection amount with zero as its chosen amount should be rejected with an error
                         amount: Uint128::zero(),
                         denom: "nhash".to_string(),
-                    },
+                    }],
                 }),
+                authorized_addresses: None,
             },
         )
         .unwrap_err();
@@ -693,6 +1072,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_instantiation_base_name_validation() {
+        let mut deps = mock_dependencies(&[]);
+        for invalid_name in ["", "Test.pio", "test..pio", "test.pio.", "test-name.pio"] {
+            let error = instantiate(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                InitMsg {
+                    contract_base_name: invalid_name.to_string(),
+                    starting_counter: None,
+                    increment_counter_fee: None,
+                    authorized_addresses: None,
+                },
+            )
+            .unwrap_err();
+            match error {
+                ContractError::InvalidBaseName { name, .. } => assert_eq!(
+                    invalid_name, name,
+                    "expected the invalid base name to be included in the error",
+                ),
+                _ => panic!(
+                    "expected base name [{}] to cause an InvalidBaseName ContractError, but got error: {:?}",
+                    invalid_name, error,
+                ),
+            }
+        }
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "my1.example.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should succeed with a properly-formed base name");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the contract state to be created by instantiation");
+        assert_eq!("my1.example.pio", state.contract_base_name);
+    }
+
     // This test showcases a round-trip through the contract.  It runs an execution route to
     // increment the contract's internal state's counter, and then runs a query to acquire the
     // value as Binary.
@@ -708,6 +1132,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: Some(1),
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -717,6 +1142,7 @@ mod tests {
             mock_info("example_sender", &[]),
             ExecuteMsg::IncrementCounter {
                 increment_amount: Some(5),
+                request_id: None,
             },
         )
         .expect("expected the increment counter response to complete successfully");
@@ -754,6 +1180,20 @@ mod tests {
             6, state.contract_counter.u128(),
             "expected the counter in the contract state to correctly equate to 6, the result of the initial value of 1 + the input value of 5",
         );
+        // The counter query should report the same value as the state query, without the surrounding struct
+        let counter_binary = query(deps.as_ref(), mock_env(), QueryMsg::QueryCounter {})
+            .expect("expected the counter query to respond with a binary");
+        assert_eq!(
+            "\"6\"",
+            String::from_utf8(counter_binary.to_vec()).unwrap(),
+            "expected the counter query to respond with only the serialized counter value",
+        );
+        assert_eq!(
+            state.contract_counter,
+            from_binary::<Uint128>(&counter_binary)
+                .expect("expected the resulting binary to deserialize to a Uint128 without issue"),
+            "expected the counter query to report the same value as the state query",
+        );
     }
 
     // This test showcases excluding an Option parameter from an execute functionality.  When executing a smart contract
@@ -769,6 +1209,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -778,6 +1219,7 @@ mod tests {
             mock_info("example_sender", &[]),
             ExecuteMsg::IncrementCounter {
                 increment_amount: None,
+                request_id: None,
             },
         )
         .expect("expected the increment counter response to complete successfully");
@@ -802,8 +1244,9 @@ mod tests {
                 starting_counter: None,
                 increment_counter_fee: Some(FeeCollectionDetail {
                     fee_collector_address: "fee-collector".to_string(),
-                    fee_collection_amount: coin(100, "nhash"),
+                    fee_collection_amounts: vec![coin(100, "nhash")],
                 }),
+                authorized_addresses: None,
             },
         )
         .expect("expected instantiation to succeed");
@@ -813,6 +1256,7 @@ mod tests {
             mock_info("example_sender", &[coin(100, "nhash")]),
             ExecuteMsg::IncrementCounter {
                 increment_amount: None,
+                request_id: None,
             },
         )
         .expect(
@@ -864,6 +1308,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -873,6 +1318,7 @@ mod tests {
             mock_info("example_sender", &[coin(10, "fakecoin")]),
             ExecuteMsg::IncrementCounter {
                 increment_amount: None,
+                request_id: None,
             },
         )
         .unwrap_err();
@@ -895,8 +1341,9 @@ mod tests {
                 starting_counter: None,
                 increment_counter_fee: Some(FeeCollectionDetail {
                     fee_collector_address: "fee-collector".to_string(),
-                    fee_collection_amount: coin(100, "nhash"),
+                    fee_collection_amounts: vec![coin(100, "nhash")],
                 }),
+                authorized_addresses: None,
             },
         )
         .expect("expected instantiation to succeed");
@@ -909,6 +1356,7 @@ mod tests {
                     mock_info("admin", funds),
                     ExecuteMsg::IncrementCounter {
                         increment_amount: None,
+                        request_id: None,
                     },
                 )
                 .unwrap_err();
@@ -952,11 +1400,76 @@ mod tests {
         );
     }
 
-    // This test showcases how to use provwasm's MockQuerier (encapsulated within the response from mock_dependencies())
-    // to mock out responses from the Provenance Attribute module.  Although this test only uses the attribute mock functionality,
-    // there are also mocks for the other modules that provwasm covers (like the name module).
     #[test]
-    fn test_add_attribute_and_query_flow() {
+    fn test_check_increment_funds_with_fee_charge() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: Some(FeeCollectionDetail {
+                    fee_collector_address: "fee-collector".to_string(),
+                    fee_collection_amounts: vec![coin(100, "nhash")],
+                }),
+                authorized_addresses: None,
+            },
+        )
+        .expect("expected instantiation to succeed");
+        // Declare a re-usable closure to test the same scenario with multiple different inputs
+        let check_funds = |funds: &[Coin]| {
+            from_binary::<CheckIncrementFundsResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::CheckIncrementFunds {
+                        funds: funds.to_vec(),
+                    },
+                )
+                .expect("expected the check increment funds query to respond with a binary"),
+            )
+            .expect("expected the binary to deserialize to a CheckIncrementFundsResponse")
+        };
+        assert_eq!(
+            CheckIncrementFundsResponse {
+                sufficient: true,
+                explanation: None,
+            },
+            check_funds(&[coin(100, "nhash")]),
+            "expected the exact fee amount to be reported as sufficient",
+        );
+        let test_invalid_funds = |funds: &[Coin], test_reason: &str, expected_error_text: &str| {
+            assert_eq!(
+                CheckIncrementFundsResponse {
+                    sufficient: false,
+                    explanation: Some(expected_error_text.to_string()),
+                },
+                check_funds(funds),
+                "{}: expected the funds to be reported as insufficient with the IncrementCounter route's explanation",
+                test_reason,
+            );
+        };
+        test_invalid_funds(
+            &[coin(99, "nhash")],
+            "too few funds provided",
+            "the charge to increment the counter is [100nhash]. found funds: [\"99nhash\"]",
+        );
+        test_invalid_funds(
+            &[coin(101, "nhash")],
+            "too many funds provided",
+            "the charge to increment the counter is [100nhash]. found funds: [\"101nhash\"]",
+        );
+        test_invalid_funds(
+            &[coin(100, "fakecoin")],
+            "incorrect funds type provided",
+            "the charge to increment the counter is [100nhash]. found funds: [\"100fakecoin\"]",
+        );
+    }
+
+    #[test]
+    fn test_check_increment_funds_without_fee_charge() {
         let mut deps = mock_dependencies(&[]);
         instantiate(
             deps.as_mut(),
@@ -966,31 +1479,507 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully");
+        let check_funds = |funds: Vec<Coin>| {
+            from_binary::<CheckIncrementFundsResponse>(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::CheckIncrementFunds { funds },
+                )
+                .expect("expected the check increment funds query to respond with a binary"),
+            )
+            .expect("expected the binary to deserialize to a CheckIncrementFundsResponse")
+        };
+        assert!(
+            check_funds(vec![]).sufficient,
+            "expected no funds to be sufficient when the contract does not charge a fee",
+        );
+        let response = check_funds(vec![coin(10, "fakecoin")]);
+        assert!(
+            !response.sufficient,
+            "expected any funds to be insufficient when the contract does not charge a fee",
+        );
+        assert!(
+            response.explanation.is_some(),
+            "expected an explanation to be provided when the funds are insufficient",
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_with_multiple_coin_fee_charge() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: Some(FeeCollectionDetail {
+                    fee_collector_address: "fee-collector".to_string(),
+                    fee_collection_amounts: vec![coin(100, "nhash"), coin(5, "usdf")],
+                }),
+                authorized_addresses: None,
+            },
+        )
+        .expect("expected instantiation to succeed");
+        // Funds are provided in a different order than the fee coins to verify that order does not matter
         let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("example_sender", &[]),
-            ExecuteMsg::AddAttribute {
-                attribute_prefix: "example".to_string(),
-                attribute_text: "my amazing text".to_string(),
+            mock_info("example_sender", &[coin(5, "usdf"), coin(100, "nhash")]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+                request_id: None,
             },
         )
-        .expect("expected the add attribute execution route to complete successfully");
-        // A bind name message, and an add attribute message should be included in the response
+        .expect(
+            "expected the exact fee coins provided to increment counter to execute successfully",
+        );
         assert_eq!(
-            2,
+            1,
             response.messages.len(),
-            "expected two messages to be included in the response"
+            "expected a single message to be returned when a fee is required for incrementing the counter",
         );
-        response.messages.iter().for_each(|msg| match &msg.msg {
-            CosmosMsg::Custom(ProvenanceMsg {
-                params:
-                    ProvenanceMsgParams::Name(NameMsgParams::BindName {
-                        name,
-                        address,
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(
+                    "fee-collector", to_address,
+                    "expected the to_address value to equate to the address provided during instantiation",
+                );
+                assert_eq!(
+                    &vec![coin(100, "nhash"), coin(5, "usdf")],
+                    amount,
+                    "expected every fee coin to be sent in a single bank send",
+                );
+            }
+            msg => panic!(
+                "unexpected message sent after fee charge for increment: {:?}",
+                msg
+            ),
+        };
+        let bad_funds = vec![
+            // Underpaid: one of the fee coins is missing entirely
+            vec![coin(100, "nhash")],
+            // Underpaid: one of the fee coins is below its fee amount
+            vec![coin(100, "nhash"), coin(4, "usdf")],
+            // Overpaid: one of the fee coins exceeds its fee amount
+            vec![coin(100, "nhash"), coin(6, "usdf")],
+            // Overpaid: an extra coin is provided alongside the exact fee coins
+            vec![coin(100, "nhash"), coin(5, "usdf"), coin(1, "fakecoin")],
+        ];
+        for funds in bad_funds {
+            let error = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("example_sender", &funds),
+                ExecuteMsg::IncrementCounter {
+                    increment_amount: None,
+                    request_id: None,
+                },
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidFunds { .. }),
+                "expected funds {:?} to cause an InvalidFunds ContractError, but got error: {:?}",
+                funds,
+                error,
+            );
+        }
+        assert_eq!(
+            Uint128::new(1),
+            state_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .contract_counter,
+            "expected only the exactly-paid increment to change the counter",
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_with_authorized_addresses() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: Some(FeeCollectionDetail {
+                    fee_collector_address: "fee-collector".to_string(),
+                    fee_collection_amounts: vec![coin(100, "nhash")],
+                }),
+                authorized_addresses: Some(vec!["operator".to_string()]),
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            Some(Addr::unchecked("admin")),
+            state.admin,
+            "expected the instantiating address to be set as the admin",
+        );
+        assert_eq!(
+            Some(vec![Addr::unchecked("operator")]),
+            state.authorized_addresses,
+            "expected the authorized addresses to be stored in the state",
+        );
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[coin(100, "nhash")]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+                request_id: None,
+            },
+        )
+        .expect("an authorized address should be able to increment the counter");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[coin(100, "nhash")]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+                request_id: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an unauthorized sender to be rejected with an Unauthorized error, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            1,
+            state.contract_counter.u128(),
+            "expected only the authorized increment to be applied to the counter",
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_with_request_id() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let increment_with_id = |request_id: &str| ExecuteMsg::IncrementCounter {
+            increment_amount: Some(5),
+            request_id: Some(request_id.to_string()),
+        };
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            increment_with_id("request-1"),
+        )
+        .expect("expected an increment with a fresh request_id to succeed");
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "idempotent_replay"),
+            "a fresh request_id should not be flagged as a replay",
+        );
+        assert_eq!(
+            5,
+            state_read(deps.as_ref().storage)
+                .load()
+                .expect("expected the state to load correctly")
+                .contract_counter
+                .u128(),
+            "expected a fresh request_id to increment the counter",
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            increment_with_id("request-1"),
+        )
+        .expect("expected a repeated request_id to succeed");
+        assert_eq!(
+            vec![
+                Attribute::new("action", "execute_increment_counter"),
+                Attribute::new("new_counter_value", "5"),
+                Attribute::new("idempotent_replay", "true"),
+            ],
+            response.attributes,
+            "expected a repeated request_id to respond with the current counter and the replay attribute",
+        );
+        assert_eq!(
+            5,
+            state_read(deps.as_ref().storage)
+                .load()
+                .expect("expected the state to load correctly")
+                .contract_counter
+                .u128(),
+            "expected a repeated request_id to leave the counter unchanged",
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_request_ids_are_scoped_to_the_sender() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let increment_with_id = |request_id: String| ExecuteMsg::IncrementCounter {
+            increment_amount: Some(5),
+            request_id: Some(request_id),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("first_sender", &[]),
+            increment_with_id("request-1".to_string()),
+        )
+        .expect("expected the first sender's increment to succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("second_sender", &[]),
+            increment_with_id("request-1".to_string()),
+        )
+        .expect("expected the second sender's increment to succeed");
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "idempotent_replay"),
+            "a request_id used by a different sender should not be flagged as a replay",
+        );
+        assert_eq!(
+            10,
+            state_read(deps.as_ref().storage)
+                .load()
+                .expect("expected the state to load correctly")
+                .contract_counter
+                .u128(),
+            "expected both senders' increments to be counted",
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("first_sender", &[]),
+            increment_with_id("a".repeat(MAX_REQUEST_ID_LENGTH + 1)),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::GenericError(_)),
+            "expected a request_id over the maximum length to be rejected, but got error: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_request_ids_are_bounded() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        for index in 0..=MAX_RECENT_REQUEST_IDS {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("example_sender", &[]),
+                ExecuteMsg::IncrementCounter {
+                    increment_amount: None,
+                    request_id: Some(format!("request-{index}")),
+                },
+            )
+            .expect("expected each fresh request_id to increment the counter");
+        }
+        let request_ids = recent_request_ids_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the recent request_ids to load correctly");
+        assert_eq!(
+            MAX_RECENT_REQUEST_IDS,
+            request_ids.len(),
+            "expected the stored request_ids to never exceed the maximum size",
+        );
+        assert!(
+            !request_ids.contains(&"example_sender/request-0".to_string()),
+            "expected the oldest request_id to be dropped once the maximum size was exceeded",
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_authorized_addresses() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "stranger".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a non-admin to be rejected when adding an address, but got error: {:?}",
+            error,
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .expect("the admin should be able to add an authorized address");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_add_authorized_address"),
+            "expected the action attribute to have the proper value",
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+                request_id: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unlisted address to be rejected after the list was created, but got error: {:?}",
+            error,
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .expect("the admin should be able to remove an authorized address");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action"
+                    && attr.value == "execute_remove_authorized_address"),
+            "expected the action attribute to have the proper value",
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::GenericError(..)),
+            "expected removing an address that is not in the list to be rejected, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            Some(vec![]),
+            state.authorized_addresses,
+            "expected an empty list to remain after the final address is removed",
+        );
+    }
+
+    // This test showcases how to use provwasm's MockQuerier (encapsulated within the response from mock_dependencies())
+    // to mock out responses from the Provenance Attribute module.  Although this test only uses the attribute mock functionality,
+    // there are also mocks for the other modules that provwasm covers (like the name module).
+    #[test]
+    fn test_add_attribute_and_query_flow() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .expect("expected the add attribute execution route to complete successfully");
+        // A bind name message, and an add attribute message should be included in the response
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "expected two messages to be included in the response"
+        );
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Name(NameMsgParams::BindName {
+                        name,
+                        address,
                         restrict,
                     }),
                 ..
@@ -1095,6 +2084,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_contract_info() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        // Simulate attributes that were attached to the contract's address, including one that was not added
+        // by the AddAttribute route
+        let first_value = to_string("first text").unwrap();
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[
+                ("first.test.pio", &first_value, "string"),
+                ("external.attribute", "{\"key\":\"value\"}", "json"),
+            ],
+        );
+        let query_binary = query(deps.as_ref(), mock_env(), QueryMsg::QueryContractInfo {})
+            .expect("the contract info query should execute successfully");
+        let response = from_binary::<QueryContractInfoResponse>(&query_binary)
+            .expect("the binary should deserialize to a QueryContractInfoResponse successfully");
+        assert_eq!(
+            "test.pio", response.contract_base_name,
+            "expected the contract base name to be returned from the contract's state",
+        );
+        assert_eq!(
+            vec!["first.test.pio", "external.attribute"],
+            response
+                .attributes
+                .iter()
+                .map(|attribute| attribute.name.as_str())
+                .collect::<Vec<&str>>(),
+            "expected every attribute on the contract's address to be returned",
+        );
+        assert_eq!(
+            "first text",
+            from_binary::<String>(&response.attributes[0].value).unwrap(),
+            "expected the attribute values to be returned from the attribute module",
+        );
+        assert_eq!(
+            AttributeValueType::Json,
+            response.attributes[1].value_type,
+            "expected the attribute value types to be returned from the attribute module",
+        );
+    }
+
     // This test is an example of using provwasm's MockQuerier to mock out a name module response
     // in order to demonstrate a potential error that can be encountered during contract execution.
     #[test]
@@ -1108,6 +2151,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -1163,6 +2207,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -1241,11 +2286,13 @@ mod tests {
                 contract_counter: Uint128::new(10),
                 // A previous contract would not have this Option value, so set it to None to start with
                 increment_counter_fee: None,
+                admin: None,
+                authorized_addresses: None,
             })
             .expect("state save should succeed");
         let migration_fee_detail = FeeCollectionDetail {
             fee_collector_address: "fee-collector".to_string(),
-            fee_collection_amount: coin(10, "nhash"),
+            fee_collection_amounts: vec![coin(10, "nhash")],
         };
         let response = migrate(
             deps.as_mut(),
@@ -1253,6 +2300,8 @@ mod tests {
             MigrateMsg {
                 new_counter_value: Some(3),
                 increment_counter_fee: Some(migration_fee_detail.clone()),
+                new_admin: None,
+                effective_height: None,
             },
         )
         .expect("migration should execute successfully");
@@ -1329,6 +2378,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_migration_sets_admin_for_older_state() {
+        let mut deps = mock_dependencies(&[]);
+        // Simulate a contract that was instantiated before the admin value existed
+        state(deps.as_mut().storage)
+            .save(&State {
+                contract_base_name: "test.pio".to_string(),
+                contract_counter: Uint128::new(10),
+                increment_counter_fee: None,
+                admin: None,
+                authorized_addresses: None,
+            })
+            .expect("state save should succeed");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected all senders to be rejected when no admin exists, but got error: {:?}",
+            error,
+        );
+        let response = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                new_counter_value: None,
+                increment_counter_fee: None,
+                new_admin: Some("admin".to_string()),
+                effective_height: None,
+            },
+        )
+        .expect("migration should execute successfully");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "modified_admin" && attr.value == "admin"),
+            "the modified_admin attribute should have the correct value",
+        );
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .expect("the migrated admin should be able to add an authorized address");
+    }
+
+    #[test]
+    fn test_migration_converts_legacy_single_coin_fee() {
+        let mut deps = mock_dependencies(&[]);
+        // Simulate a contract that stored its fee before multiple coins were supported
+        singleton(deps.as_mut().storage, b"contract_state")
+            .save(&LegacyState {
+                contract_base_name: "test.pio".to_string(),
+                contract_counter: Uint128::new(10),
+                increment_counter_fee: Some(LegacyFeeCollectionDetail {
+                    fee_collector_address: "fee-collector".to_string(),
+                    fee_collection_amount: coin(100, "nhash"),
+                }),
+                admin: Some(Addr::unchecked("admin")),
+                authorized_addresses: None,
+            })
+            .expect("legacy state save should succeed");
+        let response = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                new_counter_value: None,
+                increment_counter_fee: None,
+                new_admin: None,
+                effective_height: None,
+            },
+        )
+        .expect("migration should execute successfully");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "converted_legacy_state" && attr.value == "true"),
+            "the converted_legacy_state attribute should be emitted when a legacy state is converted",
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("the converted state should load in the current format");
+        assert_eq!(
+            Some(FeeCollectionDetail {
+                fee_collector_address: "fee-collector".to_string(),
+                fee_collection_amounts: vec![coin(100, "nhash")],
+            }),
+            state.increment_counter_fee,
+            "the legacy fee coin should be converted to a single-coin fee",
+        );
+        assert_eq!(
+            Uint128::new(10),
+            state.contract_counter,
+            "the remaining state values should be unchanged by the conversion",
+        );
+    }
+
     #[test]
     fn test_migration_from_older_version() {
         let mut deps = mock_dependencies(&[]);
@@ -1340,6 +2497,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should succeed");
@@ -1362,8 +2520,10 @@ mod tests {
                 new_counter_value: Some(150),
                 increment_counter_fee: Some(FeeCollectionDetail {
                     fee_collector_address: "fee-collector".to_string(),
-                    fee_collection_amount: coin(1234, "bitcoin"),
+                    fee_collection_amounts: vec![coin(1234, "bitcoin")],
                 }),
+                new_admin: None,
+                effective_height: None,
             },
         )
         .expect("the migration should succeed");
@@ -1381,6 +2541,75 @@ mod tests {
         assert_eq!(CONTRACT_VERSION, version_info.version, "the contract version should be successfully reset to the proper value after the migration");
     }
 
+    #[test]
+    fn test_migration_history_records_each_migration() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should succeed");
+        let query_history = |deps: Deps<ProvenanceQuery>| -> Vec<MigrationRecord> {
+            from_binary(
+                &query(deps, mock_env(), QueryMsg::QueryMigrationHistory {})
+                    .expect("the migration history query should succeed"),
+            )
+            .expect("the query result should deserialize to a list of MigrationRecord structs")
+        };
+        assert!(
+            query_history(deps.as_ref()).is_empty(),
+            "no migrations should be recorded after instantiation",
+        );
+        // Each migration is performed from an older version at a different block height, ensuring that both
+        // transitions are distinguishable in the history
+        for (from_version, block_height) in [("0.0.0", 100), ("0.0.1", 200)] {
+            set_version_info(
+                deps.as_mut().storage,
+                &VersionInfo {
+                    contract: CONTRACT_NAME.to_string(),
+                    version: from_version.to_string(),
+                },
+            )
+            .expect("version info change should succeed");
+            let mut env = mock_env();
+            env.block.height = block_height;
+            migrate(
+                deps.as_mut(),
+                env,
+                MigrateMsg {
+                    new_counter_value: None,
+                    increment_counter_fee: None,
+                    new_admin: None,
+                    effective_height: None,
+                },
+            )
+            .expect("the migration should succeed");
+        }
+        assert_eq!(
+            vec![
+                MigrationRecord {
+                    from_version: Some("0.0.0".to_string()),
+                    to_version: CONTRACT_VERSION.to_string(),
+                    block_height: 100,
+                },
+                MigrationRecord {
+                    from_version: Some("0.0.1".to_string()),
+                    to_version: CONTRACT_VERSION.to_string(),
+                    block_height: 200,
+                },
+            ],
+            query_history(deps.as_ref()),
+            "both migrations should be recorded in the order that they occurred",
+        );
+    }
+
     #[test]
     fn test_migration_with_no_optional_values() {
         let mut deps = mock_dependencies(&[]);
@@ -1392,6 +2621,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should succeed");
@@ -1413,6 +2643,8 @@ mod tests {
             MigrateMsg {
                 new_counter_value: None,
                 increment_counter_fee: None,
+                new_admin: None,
+                effective_height: None,
             },
         )
         .expect("a migration with no optional values should succeed");
@@ -1438,6 +2670,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_migration_with_effective_height_delays_counter_change() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(10),
+                increment_counter_fee: None,
+                authorized_addresses: None,
+            },
+        )
+        .expect("instantiation should succeed");
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfo {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("version info change should succeed");
+        let effective_height = mock_env().block.height + 100;
+        let response = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                new_counter_value: Some(150),
+                increment_counter_fee: None,
+                new_admin: None,
+                effective_height: Some(effective_height),
+            },
+        )
+        .expect("the migration should succeed");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "pending_counter_value" && attr.value == "150"),
+            "the pending_counter_value attribute should have the correct value",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "pending_effective_height"
+                    && attr.value == effective_height.to_string()),
+            "the pending_effective_height attribute should have the correct value",
+        );
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "modified_counter_value"),
+            "the counter should not be reported as modified when the change is delayed",
+        );
+        assert_eq!(
+            10,
+            state_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .contract_counter
+                .u128(),
+            "the counter should be unchanged until the pending change is applied",
+        );
+        let mut early_env = mock_env();
+        early_env.block.height = effective_height - 1;
+        let error = execute(
+            deps.as_mut(),
+            early_env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ApplyPendingChange {},
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::PendingChangeNotReady {
+                    effective_height: height,
+                    ..
+                } if height == effective_height
+            ),
+            "expected the pending change to be rejected before its effective height, but got error: {:?}",
+            error,
+        );
+        assert_eq!(
+            10,
+            state_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .contract_counter
+                .u128(),
+            "the counter should be unchanged after an early apply is rejected",
+        );
+        let mut ready_env = mock_env();
+        ready_env.block.height = effective_height;
+        let response = execute(
+            deps.as_mut(),
+            ready_env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ApplyPendingChange {},
+        )
+        .expect("the pending change should be applied at its effective height");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_apply_pending_change"),
+            "the action attribute should have the correct value",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "modified_counter_value" && attr.value == "150"),
+            "the modified_counter_value attribute should have the correct value",
+        );
+        assert_eq!(
+            150,
+            state_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .contract_counter
+                .u128(),
+            "the counter should be set to the pending value once the change is applied",
+        );
+        let error = execute(
+            deps.as_mut(),
+            ready_env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ApplyPendingChange {},
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::GenericError(_)),
+            "expected a second apply to be rejected because no change is pending, but got error: {:?}",
+            error,
+        );
+    }
+
     #[test]
     fn test_migration_failures() {
         let mut deps = mock_dependencies(&[]);
@@ -1449,6 +2822,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should succeed");
@@ -1460,6 +2834,8 @@ mod tests {
             MigrateMsg {
                 new_counter_value: None,
                 increment_counter_fee: None,
+                new_admin: None,
+                effective_height: None,
             },
         )
         .unwrap_err();
@@ -1492,6 +2868,8 @@ mod tests {
             MigrateMsg {
                 new_counter_value: None,
                 increment_counter_fee: None,
+                new_admin: None,
+                effective_height: None,
             },
         )
         .unwrap_err();
@@ -1521,6 +2899,7 @@ mod tests {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
                 increment_counter_fee: None,
+                authorized_addresses: None,
             },
         )
         .expect("instantiation should succeed");
@@ -15,6 +15,11 @@ pub enum ContractError {
     #[error("{0}")]
     GenericError(String),
 
+    /// Occurs when the contract_base_name provided during instantiation cannot be bound as a name
+    /// in the Provenance Blockchain name module.
+    #[error("Invalid contract base name [{name}]: {explanation}")]
+    InvalidBaseName { name: String, explanation: String },
+
     #[error("Invalid funds were provided: {explanation}")]
     InvalidFunds { explanation: String },
 
@@ -24,6 +29,17 @@ pub enum ContractError {
     #[error("Expected the name {name} to not exist, but it was already bound to address {owner_address}")]
     NameAlreadyExists { name: String, owner_address: String },
 
+    /// Occurs when the sender of a message is not allowed to use the route it is attempting to execute.
+    #[error("Unauthorized: {explanation}")]
+    Unauthorized { explanation: String },
+
+    /// Occurs when a pending counter change is applied before the block height it was scheduled for.
+    #[error("The pending change cannot be applied until block height {effective_height}, but the current height is {current_height}")]
+    PendingChangeNotReady {
+        effective_height: u64,
+        current_height: u64,
+    },
+
     // Ensure that the ContractError can be derived directly from a semver Error.
     // This will allow the ? operator to magically up-shift cosmwasm errors into ContractError.
     #[error("{0}")]
@@ -47,4 +63,88 @@ impl ContractError {
     pub fn generic_err<S: Into<String>>(msg: S) -> Self {
         Self::GenericError(msg.into())
     }
+
+    /// Returns a stable numeric code for this error's variant.  Off-chain applications can switch on
+    /// these codes rather than matching against error message text, which is free to change.  A code
+    /// is never reassigned once used, so any new variant should be given the next unused code.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::GenericError(_) => 1,
+            Self::InvalidBaseName { .. } => 2,
+            Self::InvalidFunds { .. } => 3,
+            Self::InvalidVersion { .. } => 4,
+            Self::NameAlreadyExists { .. } => 5,
+            Self::Unauthorized { .. } => 6,
+            Self::SemVerError(_) => 7,
+            Self::Std(_) => 8,
+            Self::PendingChangeNotReady { .. } => 9,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::StdError;
+
+    use super::ContractError;
+
+    #[test]
+    fn test_error_codes() {
+        let errors = vec![
+            (ContractError::generic_err("message"), 1),
+            (
+                ContractError::InvalidBaseName {
+                    name: "name".to_string(),
+                    explanation: "explanation".to_string(),
+                },
+                2,
+            ),
+            (
+                ContractError::InvalidFunds {
+                    explanation: "explanation".to_string(),
+                },
+                3,
+            ),
+            (
+                ContractError::InvalidVersion {
+                    explanation: "explanation".to_string(),
+                },
+                4,
+            ),
+            (
+                ContractError::NameAlreadyExists {
+                    name: "name".to_string(),
+                    owner_address: "owner".to_string(),
+                },
+                5,
+            ),
+            (
+                ContractError::Unauthorized {
+                    explanation: "explanation".to_string(),
+                },
+                6,
+            ),
+            (
+                ContractError::SemVerError("not a version".parse::<semver::Version>().unwrap_err()),
+                7,
+            ),
+            (ContractError::Std(StdError::generic_err("message")), 8),
+            (
+                ContractError::PendingChangeNotReady {
+                    effective_height: 2,
+                    current_height: 1,
+                },
+                9,
+            ),
+        ];
+        for (error, expected_code) in errors {
+            assert_eq!(
+                expected_code,
+                error.code(),
+                "expected the error {:?} to have code {}",
+                error,
+                expected_code,
+            );
+        }
+    }
 }
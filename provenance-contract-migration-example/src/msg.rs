@@ -1,3 +1,5 @@
+use cosmwasm_std::Coin;
+use provwasm_std::Attribute;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +20,11 @@ pub struct InitMsg {
     /// value will be zero.
     pub starting_counter: Option<u128>,
     pub increment_counter_fee: Option<FeeCollectionDetail>,
+    /// This value restricts which addresses can increment the counter.  If left blank, any address
+    /// will be able to increment it.  If provided, only the addresses in the list will be able to
+    /// do so, and the address that instantiates the contract will be able to add or remove
+    /// addresses from the list later.
+    pub authorized_addresses: Option<Vec<String>>,
 }
 
 /// The ExecuteMsg will generally be an enum to allow for multiple different types of contract
@@ -38,6 +45,11 @@ pub enum ExecuteMsg {
         /// The amount to add to the internal contract counter.  If left blank, a default value
         /// of one will be used.
         increment_amount: Option<u128>,
+        /// An optional client-supplied identifier for this increment, at most 64 characters long.  If
+        /// a request_id recently used by the same sender is provided again, such as when a client
+        /// retries after a timeout, the counter will not be incremented a second time, and any
+        /// provided funds will be returned to the sender.
+        request_id: Option<String>,
     },
     /// This execution route will append an attribute to the contract itself, using its reserved
     /// contract_base_name value.  For example, if the base name of the contract was "testcontract.pb"
@@ -52,6 +64,25 @@ pub enum ExecuteMsg {
         /// at name "{attribute_prefix}.{contract_base_name}" and will be plain text.
         attribute_text: String,
     },
+    /// This execution route adds an address to the list of addresses that are authorized to increment
+    /// the counter.  Only the contract's admin can use this route.  If the contract was instantiated
+    /// without an authorized_addresses list, using this route will create one, and the counter will
+    /// no longer be open to all addresses.
+    AddAuthorizedAddress {
+        /// The bech32 address of the account to allow to increment the counter.
+        address: String,
+    },
+    /// This execution route removes an address from the list of addresses that are authorized to
+    /// increment the counter.  Only the contract's admin can use this route.  Removing the final
+    /// address will leave an empty list, which prevents all addresses from incrementing the counter.
+    RemoveAuthorizedAddress {
+        /// The bech32 address of the account that should no longer be allowed to increment the counter.
+        address: String,
+    },
+    /// This execution route applies a counter change that a migration scheduled with an
+    /// effective_height.  Any address can use this route, but only once the current block height
+    /// is at or after the effective_height.  If no change is pending, the request will be rejected.
+    ApplyPendingChange {},
     /// This execution route will send the funds passed in to the contract to the target address.
     /// This example will illustrate using cosmwasm's provided functionality for Coin management.
     SendFunds {
@@ -80,10 +111,56 @@ pub enum QueryMsg {
     /// in internal storage.  The value of the counter is stored in State, so this route can also
     /// be used to fetch the current counter value.
     QueryState {},
+    /// This query will return only the current value of the contract's counter, serialized as a
+    /// Uint128.  Clients that frequently poll the counter can use this route to avoid receiving
+    /// the rest of the State value with every request.
+    QueryCounter {},
+    /// This query will return only the fee charged by the IncrementCounter route, serialized as an
+    /// optional FeeCollectionDetail.  A null response indicates that incrementing the counter is free.
+    /// Clients that display the current increment cost can use this route to avoid receiving the rest
+    /// of the State value.
+    QueryFeeConfig {},
+    /// This query will check whether the given funds would satisfy the fee charged by the IncrementCounter
+    /// route, using the same comparison that the route performs.  Clients can use this to confirm that their
+    /// funds are exactly right before incrementing the counter.  The response is a CheckIncrementFundsResponse.
+    CheckIncrementFunds {
+        /// The funds that would be provided to the IncrementCounter route.
+        funds: Vec<Coin>,
+    },
     /// This query will return the current contract name and version stored in the VersionInfo
     /// struct.  This is contained in the version_info.rs file, and is loaded from the VERSION_INFO
     /// const Item.
     QueryVersion {},
+    /// This query will return every version transition performed by the migrate entry_point, ordered
+    /// from oldest to newest, as a list of MigrationRecord structs.  Unlike the VersionInfo returned by
+    /// QueryVersion, which is overwritten on each migration, this history is only ever appended to.
+    QueryMigrationHistory {},
+    /// This query will return the contract_base_name stored in the contract's State, along with
+    /// every attribute currently attached to the contract's own address in the attribute module.
+    /// The response is a QueryContractInfoResponse.
+    QueryContractInfo {},
+}
+
+/// The response to the CheckIncrementFunds query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckIncrementFundsResponse {
+    /// Whether or not the funds would be accepted by the IncrementCounter route.
+    pub sufficient: bool,
+    /// Describes why the funds would be rejected.  This is only set when the funds are not sufficient.
+    pub explanation: Option<String>,
+}
+
+/// The response to the QueryContractInfo query, containing the name bound to the contract and the
+/// attributes attached to the contract's address.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryContractInfoResponse {
+    /// The base name bound to the contract during instantiation.
+    pub contract_base_name: String,
+    /// Every attribute currently attached to the contract's address, as returned by the attribute
+    /// module.
+    pub attributes: Vec<Attribute>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -91,4 +168,11 @@ pub enum QueryMsg {
 pub struct MigrateMsg {
     pub new_counter_value: Option<u128>,
     pub increment_counter_fee: Option<FeeCollectionDetail>,
+    /// Sets the address that is allowed to modify the authorized addresses list.  Contracts that
+    /// were instantiated before the admin value existed must use this to establish one.
+    pub new_admin: Option<String>,
+    /// Delays the new_counter_value until the given block height.  When provided, the counter is
+    /// left unchanged by the migration, and the new value is stored as a pending change that the
+    /// ApplyPendingChange route can apply at or after this height.  Requires a new_counter_value.
+    pub effective_height: Option<u64>,
 }
@@ -1,15 +1,26 @@
-use cosmwasm_std::{Storage, Uint128};
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
 use cosmwasm_storage::{singleton, singleton_read, ReadonlySingleton, Singleton};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::types::FeeCollectionDetail;
+use crate::types::{FeeCollectionDetail, LegacyFeeCollectionDetail};
 
 // Each value saved into cosmwasm standard storage must have a byte array as its key.
 // Cosmwasm Docs: https://docs.cosmwasm.com/dev-academy/develop-smart-contract/intro/
 // Their tutorial also goes over using cosmwasm storage plus's Item struct, which is
 // another simple way to store a persistent value in a smart contract.
 static STATE_KEY: &[u8] = b"contract_state";
+static RECENT_REQUEST_IDS_KEY: &[u8] = b"recent_request_ids";
+static PENDING_COUNTER_CHANGE_KEY: &[u8] = b"pending_counter_change";
+
+/// The maximum amount of request_ids provided to the IncrementCounter route that are remembered by the
+/// contract.  When a new request_id would exceed this amount, the oldest remembered request_id is dropped.
+/// Keeping this list bounded prevents clients from growing the contract's storage without limit.
+pub const MAX_RECENT_REQUEST_IDS: usize = 100;
+
+/// The maximum length of a request_id provided to the IncrementCounter route.  Each remembered request_id
+/// is stored in full, so limiting its length keeps the size of the remembered list bounded as well.
+pub const MAX_REQUEST_ID_LENGTH: usize = 64;
 
 /// The State struct contains all persistent data associated with the contract.
 /// A struct such as this should be used for maintaining values across various
@@ -25,6 +36,50 @@ pub struct State {
     /// internal contract storage.
     pub contract_counter: Uint128,
     pub increment_counter_fee: Option<FeeCollectionDetail>,
+    /// The address that is allowed to modify the authorized_addresses list.  This is set to the
+    /// instantiating address.  Contracts instantiated before this value existed will not have an
+    /// admin until one is provided in a MigrateMsg.
+    pub admin: Option<Addr>,
+    /// An optional list of addresses that are allowed to increment the counter.  When this value
+    /// is None, any address can increment the counter.  When it is set, only the addresses it
+    /// contains can do so.  Note that an empty list is still a set value, and will prevent all
+    /// addresses from incrementing the counter.
+    pub authorized_addresses: Option<Vec<Addr>>,
+}
+
+/// The State struct as stored by contract versions before the increment_counter_fee could be charged in
+/// multiple coins.  It is stored under the same key as the State, and is only used to convert an older
+/// stored value during a migration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LegacyState {
+    pub contract_base_name: String,
+    pub contract_counter: Uint128,
+    pub increment_counter_fee: Option<LegacyFeeCollectionDetail>,
+    pub admin: Option<Addr>,
+    pub authorized_addresses: Option<Vec<Addr>>,
+}
+impl From<LegacyState> for State {
+    fn from(legacy: LegacyState) -> Self {
+        State {
+            contract_base_name: legacy.contract_base_name,
+            contract_counter: legacy.contract_counter,
+            increment_counter_fee: legacy.increment_counter_fee.map(FeeCollectionDetail::from),
+            admin: legacy.admin,
+            authorized_addresses: legacy.authorized_addresses,
+        }
+    }
+}
+
+/// A counter value set by a migration that cannot be applied until the chain reaches a certain block
+/// height.  This allows a counter change to be announced ahead of the time that it takes effect.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingCounterChange {
+    /// The value that the counter will be set to when the change is applied.
+    pub new_counter_value: Uint128,
+    /// The block height at or after which the ApplyPendingChange route can apply the change.
+    pub effective_height: u64,
 }
 
 /// This function loads the state in a mutable manner, taking a mutable reference to the
@@ -39,3 +94,49 @@ pub fn state(storage: &mut dyn Storage) -> Singleton<State> {
 pub fn state_read(storage: &dyn Storage) -> ReadonlySingleton<State> {
     singleton_read(storage, STATE_KEY)
 }
+
+/// This function loads the state as it was stored before the increment_counter_fee could be charged in
+/// multiple coins.  Only the migrate entry_point should use this, in order to convert an older state.
+pub fn legacy_state_read(storage: &dyn Storage) -> ReadonlySingleton<LegacyState> {
+    singleton_read(storage, STATE_KEY)
+}
+
+/// Converts a state stored in the format used before the increment_counter_fee could be charged in multiple
+/// coins into the current format.  Returns true if a conversion occurred.  A state that is already in the
+/// current format, or a missing state, is left untouched.
+pub fn migrate_legacy_state(storage: &mut dyn Storage) -> StdResult<bool> {
+    // Only a stored value that fails to deserialize as the current State can be in the legacy format
+    if state_read(storage).may_load().is_ok() {
+        return Ok(false);
+    }
+    let legacy_state = legacy_state_read(storage).load()?;
+    state(storage).save(&legacy_state.into())?;
+    Ok(true)
+}
+
+/// This function loads the request_ids most recently provided to the IncrementCounter route in a mutable
+/// manner, ordered from oldest to newest.  These values are stored separately from the State, ensuring
+/// that they are never included in a QueryState response.
+/// Each request_id is stored alongside the sender that provided it, in the form "{sender}/{request_id}".
+pub fn recent_request_ids(storage: &mut dyn Storage) -> Singleton<Vec<String>> {
+    singleton(storage, RECENT_REQUEST_IDS_KEY)
+}
+
+/// This function loads the request_ids most recently provided to the IncrementCounter route in a
+/// read-only manner.
+pub fn recent_request_ids_read(storage: &dyn Storage) -> ReadonlySingleton<Vec<String>> {
+    singleton_read(storage, RECENT_REQUEST_IDS_KEY)
+}
+
+/// This function loads the counter change awaiting its effective height in a mutable manner.  This value
+/// is stored separately from the State, and is removed once the change is applied.
+pub fn pending_counter_change(storage: &mut dyn Storage) -> Singleton<PendingCounterChange> {
+    singleton(storage, PENDING_COUNTER_CHANGE_KEY)
+}
+
+/// This function loads the counter change awaiting its effective height in a read-only manner.
+pub fn pending_counter_change_read(
+    storage: &dyn Storage,
+) -> ReadonlySingleton<PendingCounterChange> {
+    singleton_read(storage, PENDING_COUNTER_CHANGE_KEY)
+}
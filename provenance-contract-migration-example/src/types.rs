@@ -7,34 +7,102 @@ use crate::error::ContractError;
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct FeeCollectionDetail {
     pub fee_collector_address: String,
-    pub fee_collection_amount: Coin,
+    /// Every coin that must be sent to pay the fee.  Each coin must be of a different denom, and all
+    /// of them must be provided exactly.
+    pub fee_collection_amounts: Vec<Coin>,
 }
 impl FeeCollectionDetail {
     pub fn get_fee_amount_msg(&self) -> String {
-        format!(
-            "{}{}",
-            self.fee_collection_amount.amount.u128(),
-            self.fee_collection_amount.denom
-        )
+        self.fee_collection_amounts
+            .iter()
+            .map(|coin| format!("{}{}", coin.amount.u128(), coin.denom))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /// Verifies that the provided funds are exactly the coins required by this fee detail.
+    pub fn check_funds(&self, funds: &[Coin]) -> Result<(), ContractError> {
+        // If the user sent a number of coin definitions other than the number of fee coins, then they either didn't provide
+        // every coin, or provided extra coin denominations that won't be detected by the contract.  Rejecting this prevents
+        // funds from being stuck in the contract.
+        // The second check to ensure that each fee coin is identical to a provided coin ensures two things:
+        // 1: The sender sent enough of every coin,
+        // 2: The sender did not send too much of any coin.  Sending too much coin would cause the overage amount to be held in the contract's bank balances,
+        // which would essentially "steal" those funds from the sender.
+        // Fee coins are validated to never repeat a denom, so matching each of them to a distinct provided coin is assured.
+        if funds.len() != self.fee_collection_amounts.len()
+            || !self
+                .fee_collection_amounts
+                .iter()
+                .all(|fee_coin| funds.contains(fee_coin))
+        {
+            return Err(ContractError::InvalidFunds {
+                explanation: format!(
+                    "the charge to increment the counter is [{}]. found funds: {:?}",
+                    self.get_fee_amount_msg(),
+                    // Map out the funds to a more readable manner, ensuring that they can be printed in a format like "50nhash" as opposed to
+                    // the fully-expanded debug explanation for a Coin
+                    funds
+                        .iter()
+                        .map(|coin| format!("{}{}", coin.amount.u128(), coin.denom))
+                        .collect::<Vec<String>>(),
+                ),
+            });
+        }
+        Ok(())
     }
 
     pub fn self_validate(&self, api: &dyn Api) -> Result<(), ContractError> {
         // Ensure that the provided address is in valid form
         api.addr_validate(&self.fee_collector_address)?;
-        if self.fee_collection_amount.amount.is_zero() {
+        if self.fee_collection_amounts.is_empty() {
             return Err(ContractError::generic_err(
-                "fee collection amount must be greater than zero",
+                "fee collection amounts must contain at least one coin",
             ));
         }
-        if self.fee_collection_amount.denom.is_empty() {
-            return Err(ContractError::generic_err(
-                "fee collection denom must be defined",
-            ));
+        for (index, fee_coin) in self.fee_collection_amounts.iter().enumerate() {
+            if fee_coin.amount.is_zero() {
+                return Err(ContractError::generic_err(
+                    "fee collection amount must be greater than zero",
+                ));
+            }
+            if fee_coin.denom.is_empty() {
+                return Err(ContractError::generic_err(
+                    "fee collection denom must be defined",
+                ));
+            }
+            // Funds can only ever contain a single coin of each denom, so a fee that repeats a denom
+            // could never be paid exactly
+            if self.fee_collection_amounts[..index]
+                .iter()
+                .any(|other_coin| other_coin.denom == fee_coin.denom)
+            {
+                return Err(ContractError::generic_err(format!(
+                    "fee collection denom [{}] must not be repeated",
+                    fee_coin.denom,
+                )));
+            }
         }
         Ok(())
     }
 }
 
+/// The FeeCollectionDetail as stored by contract versions before fees could be charged in multiple coins.
+/// This is only used by the migrate entry_point to convert an older stored fee to the current format.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyFeeCollectionDetail {
+    pub fee_collector_address: String,
+    pub fee_collection_amount: Coin,
+}
+impl From<LegacyFeeCollectionDetail> for FeeCollectionDetail {
+    fn from(legacy: LegacyFeeCollectionDetail) -> Self {
+        FeeCollectionDetail {
+            fee_collector_address: legacy.fee_collector_address,
+            fee_collection_amounts: vec![legacy.fee_collection_amount],
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,11 +115,20 @@ mod tests {
             "1500nhash",
             FeeCollectionDetail {
                 fee_collector_address: "address".to_string(),
-                fee_collection_amount: coin(1500, "nhash")
+                fee_collection_amounts: vec![coin(1500, "nhash")]
             }
             .get_fee_amount_msg(),
             "expected the correct amount to be printed when using the coin helper",
         );
+        assert_eq!(
+            "1500nhash,10usdf",
+            FeeCollectionDetail {
+                fee_collector_address: "address".to_string(),
+                fee_collection_amounts: vec![coin(1500, "nhash"), coin(10, "usdf")]
+            }
+            .get_fee_amount_msg(),
+            "expected each coin to be printed, separated by commas",
+        );
     }
 
     #[test]
@@ -59,7 +136,7 @@ mod tests {
         let deps = mock_dependencies(&[]);
         FeeCollectionDetail {
             fee_collector_address: "address".to_string(),
-            fee_collection_amount: coin(1, "coins"),
+            fee_collection_amounts: vec![coin(1, "coins")],
         }
         .self_validate(deps.as_ref().api)
         .expect("expected proper input to result in a passing validation");
@@ -71,7 +148,7 @@ mod tests {
         // Test bad address
         let error = FeeCollectionDetail {
             fee_collector_address: String::new(),
-            fee_collection_amount: coin(1, "bitcoin"),
+            fee_collection_amounts: vec![coin(1, "bitcoin")],
         }
         .self_validate(deps.as_ref().api)
         .unwrap_err();
@@ -82,10 +159,13 @@ mod tests {
         // Test zero coin amount
         let error = FeeCollectionDetail {
             fee_collector_address: "address".to_string(),
-            fee_collection_amount: Coin {
-                denom: "bitcoin".to_string(),
-                amount: Uint128::zero(),
-            },
+            fee_collection_amounts: vec![
+                coin(10, "nhash"),
+                Coin {
+                    denom: "bitcoin".to_string(),
+                    amount: Uint128::zero(),
+                },
+            ],
         }
         .self_validate(deps.as_ref().api)
         .unwrap_err();
@@ -104,10 +184,10 @@ mod tests {
         // Test empty denom
         let error = FeeCollectionDetail {
             fee_collector_address: "address".to_string(),
-            fee_collection_amount: Coin {
+            fee_collection_amounts: vec![Coin {
                 denom: String::new(),
                 amount: Uint128::new(10),
-            },
+            }],
         }
         .self_validate(deps.as_ref().api)
         .unwrap_err();
@@ -123,5 +203,36 @@ mod tests {
                 error
             ),
         };
+        // Test no coins
+        let error = FeeCollectionDetail {
+            fee_collector_address: "address".to_string(),
+            fee_collection_amounts: vec![],
+        }
+        .self_validate(deps.as_ref().api)
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::GenericError(..)),
+            "a GenericError should be emitted when no fee coins are supplied, but got: {:?}",
+            error,
+        );
+        // Test repeated denom
+        let error = FeeCollectionDetail {
+            fee_collector_address: "address".to_string(),
+            fee_collection_amounts: vec![coin(10, "nhash"), coin(20, "nhash")],
+        }
+        .self_validate(deps.as_ref().api)
+        .unwrap_err();
+        match error {
+            ContractError::GenericError(message) => {
+                assert_eq!(
+                    "fee collection denom [nhash] must not be repeated", message,
+                    "unexpected GenericError encountered when a repeated denom supplied",
+                );
+            }
+            _ => panic!(
+                "unexpected error encountered when a repeated denom supplied: {:?}",
+                error
+            ),
+        };
     }
 }
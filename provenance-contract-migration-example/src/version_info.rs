@@ -17,6 +17,11 @@ const VERSION_INFO_NAMESPACE: &str = "version_info";
 /// The benefit of this usage is that these Item structs can be created as consts, which can
 /// be appealing for numerous reasons. This syntax can be much clearer than the state() implementation, as well.
 const VERSION_INFO: Item<VersionInfo> = Item::new(VERSION_INFO_NAMESPACE);
+/// cw_storage_plus's Item requires a namespace to be used when creating it
+const MIGRATION_HISTORY_NAMESPACE: &str = "migration_history";
+/// Unlike VERSION_INFO, which is overwritten by each migration, this Item is only ever appended to.
+/// Migrations are rare, so the list is not bounded.
+const MIGRATION_HISTORY: Item<Vec<MigrationRecord>> = Item::new(MIGRATION_HISTORY_NAMESPACE);
 
 /// It is important when migrating to establish boundaries for when a migration is appropriate.
 /// One incredibly important feature is ensuring that a migration does not downgrade the version
@@ -53,6 +58,19 @@ impl VersionInfo {
     }
 }
 
+/// A single version transition performed by the migrate entry_point, stored in the MIGRATION_HISTORY
+/// const in the order that the migrations occurred.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrationRecord {
+    /// The version of the contract before the migration.  This will be None if the contract was
+    /// migrated from a version that did not yet store a VersionInfo.
+    pub from_version: Option<String>,
+    /// The version of the contract after the migration.
+    pub to_version: String,
+    /// The block height at which the migration occurred.
+    pub block_height: u64,
+}
+
 /// Leverages the contract's Storage from the DepsMut struct to establish a new VersionInfo
 /// struct, stored in the VERSION_INFO const. This will overwrite any existing value.
 pub fn set_version_info(
@@ -76,6 +94,23 @@ pub fn migrate_version_info(storage: &mut dyn Storage) -> Result<VersionInfo, Co
     Ok(version_info)
 }
 
+/// Appends the given MigrationRecord to the end of the MIGRATION_HISTORY Item, creating the list if
+/// no migrations have been recorded yet.
+pub fn record_migration(
+    storage: &mut dyn Storage,
+    migration_record: MigrationRecord,
+) -> Result<(), ContractError> {
+    let mut migration_history = get_migration_history(storage)?;
+    migration_history.push(migration_record);
+    Ok(MIGRATION_HISTORY.save(storage, &migration_history)?)
+}
+
+/// Leverages the contract's Storage from the Deps or DepsMut structs to fetch every MigrationRecord,
+/// ordered from oldest to newest.  If no migrations have occurred, an empty list will be returned.
+pub fn get_migration_history(storage: &dyn Storage) -> Result<Vec<MigrationRecord>, ContractError> {
+    Ok(MIGRATION_HISTORY.may_load(storage)?.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,22 @@
 use cosmwasm_std::{
-    entry_point, to_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, Uint128,
+    entry_point, from_slice, to_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps,
+    DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
 };
 use provwasm_std::{
-    add_attribute, bind_name, AttributeValueType, NameBinding, ProvenanceMsg, ProvenanceQuerier,
-    ProvenanceQuery,
+    add_attribute, bind_name, delete_attributes, update_attribute, AttributeValueType, NameBinding,
+    ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery,
 };
+use serde::de::IgnoredAny;
 
 use crate::{
     error::ContractError,
-    msg::{ExecuteMsg, InitMsg, QueryMsg},
+    msg::{
+        ContractAttribute, ExecuteMsg, InitMsg, ListAttributesResponse, MigrateMsg, NewAttribute,
+        QueryAttributeResponse, QueryContractInfoResponse, QueryMsg,
+    },
     state::{state, state_read, State},
+    types::FeeCollectionDetail,
+    version_info::{get_version_info, migrate_version_info, VersionInfo},
 };
 
 /// The instantiation entry_point is the first function that is ever executed in a smart contract, and
@@ -29,6 +35,13 @@ pub fn instantiate(
         info.funds,
         "funds should not be provided when instantiating the contract",
     )?;
+    // If a fee detail was provided, it should be validated to ensure that bad interactions do not occur downstream
+    if let Some(fee_detail) = &msg.add_attribute_fee {
+        fee_detail.self_validate(deps.api)?
+    }
+    // Verify that the base name is well-formed before it is stored and bound.  The name module would reject a
+    // malformed name anyway, but its error would be far less clear than the one produced here.
+    validate_contract_base_name(&msg.contract_base_name)?;
     // Create an instance of the contract's State, which holds the contract's base name and a counter for later.
     // The base name will be used to create attributes later, so it's very important that that value is recorded
     // in a place that can be located later.
@@ -40,6 +53,25 @@ pub fn instantiate(
         // purposes.  In this case, the wrapper used is Uint128, and has an implementation for Into<u128>, which
         // automatically allows this u128 value to be converted with a simple .into() call.
         contract_counter: msg.starting_counter.unwrap_or(0).into(),
+        // The instantiating address becomes the admin, which is the only address that can change the list
+        // of authorized addresses later
+        admin: info.sender,
+        // Each provided address is validated before it is stored.  This ensures that a typo in the list is
+        // caught right away, instead of silently locking out the intended address.
+        authorized_addresses: msg
+            .authorized_addresses
+            .map(|addresses| {
+                addresses
+                    .iter()
+                    .map(|address| deps.api.addr_validate(address))
+                    .collect::<StdResult<Vec<Addr>>>()
+            })
+            .transpose()?,
+        // Like the counter, the default increment is wrapped in a Uint128 for storage
+        default_increment: msg.default_increment.map(|increment| increment.into()),
+        // No attributes have been added yet
+        attribute_prefixes: vec![],
+        add_attribute_fee: msg.add_attribute_fee,
     };
     // Store the initial state in the contract's internal storage, which can be referenced during execution
     // and query routes later.
@@ -59,6 +91,9 @@ pub fn instantiate(
         // various sub-names.
         NameBinding::Restricted,
     )?;
+    // Before completing instantiation, the default contract name and version should be set in the version info
+    // struct and saved to internal storage.  This allows future migrations to verify that they are upgrades.
+    migrate_version_info(deps.storage)?;
     // After successful instantiation, a response must be returned containing the various messages and attributes
     // that will be included in the transaction that this instantiation creates.  Upon successful instantiation,
     // all messages included in the response will be executed and their actions will be completed.  In this case,
@@ -91,10 +126,58 @@ pub fn execute(
         ExecuteMsg::IncrementCounter { increment_amount } => {
             increment_counter(deps, info, increment_amount)
         }
+        ExecuteMsg::DecrementCounter { decrement_amount } => {
+            decrement_counter(deps, info, decrement_amount)
+        }
         ExecuteMsg::AddAttribute {
             attribute_prefix,
             attribute_text,
-        } => add_attribute_to_contract(deps, info, env, attribute_prefix, attribute_text),
+        } => add_attribute_to_contract(
+            deps,
+            info,
+            env,
+            attribute_prefix,
+            // Serialize the provided text as Binary.  Cosmwasm provides a set of to_binary and from_binary functions
+            // that allow any serializable value to easily be converted.  Serializing custom structs is easy, as well!
+            // Simply #derive(Serialize, Deserialize) using serde and these binary helper functions will automatically
+            // know how to convert them into cosmwasm's Binary struct.
+            to_binary(&attribute_text)?,
+            // The Provenance Blockchain requires that each attribute be tagged with its type.  Plain text attributes
+            // use AttributeValueType::String.
+            AttributeValueType::String,
+            false,
+        ),
+        ExecuteMsg::AddAttributes { attributes } => {
+            add_attributes_to_contract(deps, info, env, attributes)
+        }
+        ExecuteMsg::AppendAttribute {
+            attribute_prefix,
+            attribute_text,
+        } => add_attribute_to_contract(
+            deps,
+            info,
+            env,
+            attribute_prefix,
+            // Appended values are plain text, exactly like AddAttribute
+            to_binary(&attribute_text)?,
+            AttributeValueType::String,
+            true,
+        ),
+        ExecuteMsg::AddJsonAttribute {
+            attribute_prefix,
+            attribute_json,
+        } => add_json_attribute_to_contract(deps, info, env, attribute_prefix, attribute_json),
+        ExecuteMsg::UpdateAttribute {
+            attribute_prefix,
+            attribute_text,
+        } => update_attribute_on_contract(deps, info, env, attribute_prefix, attribute_text),
+        ExecuteMsg::DeleteAttribute { attribute_prefix } => {
+            delete_attribute_from_contract(deps, info, env, attribute_prefix)
+        }
+        ExecuteMsg::AddAuthorizedAddress { address } => add_authorized_address(deps, info, address),
+        ExecuteMsg::RemoveAuthorizedAddress { address } => {
+            remove_authorized_address(deps, info, address)
+        }
         ExecuteMsg::SendFunds { recipient_address } => send_funds(deps, info, recipient_address),
     }
 }
@@ -117,39 +200,196 @@ pub fn query(
     let contract_state = state_read(deps.storage).load()?;
     match msg {
         QueryMsg::QueryAttribute { attribute_prefix } => {
-            // Construct the expected attribute name from the prefix and the contract base name.  This mirrors
-            // the formatting used in the execute route: AddAttribute.
-            let target_attribute_name =
-                format_attribute_name(&attribute_prefix, &contract_state.contract_base_name);
-            // Provwasm provides a wrapper for the cosmwasm's QuerierWrapper, which is contained in deps.querier.
-            // This allows for Provenance Blockchain modules to be queried simply and easily.
-            let provenance_querier = ProvenanceQuerier::new(&deps.querier);
-            // This check is to ensure that the attribute being searched for exists.  The AddAttribute route
-            // ensures that only a single attribute for a single name can be added, so this check verifies that
-            // that state exists.
-            let attribute_wrapper = provenance_querier
-                .get_attributes(env.contract.address, Some(target_attribute_name))?;
-            if attribute_wrapper.attributes.len() != 1 {
+            let attributes =
+                query_contract_attributes(deps, env, &contract_state, &attribute_prefix)?;
+            // The value type is included alongside the value, allowing clients to determine how the value bytes
+            // should be decoded.  Every value is included as well, in case AppendAttribute added more than one.
+            Ok(to_binary(&QueryAttributeResponse {
+                value_type: attribute_value_type_name(&attributes[0].value_type).to_string(),
+                value: attributes[0].value.to_owned(),
+                values: attributes
+                    .into_iter()
+                    .map(|attribute| attribute.value)
+                    .collect(),
+            })?)
+        }
+        QueryMsg::QueryRawAttribute { attribute_prefix } => {
+            let mut attributes =
+                query_contract_attributes(deps, env, &contract_state, &attribute_prefix)?;
+            // The raw response can only represent a single value, so names with appended values must use the
+            // QueryAttribute route instead
+            if attributes.len() != 1 {
                 return Err(ContractError::generic_err(format!(
                     "expected only one attribute to exist at the specified name, but found {}",
-                    attribute_wrapper.attributes.len()
+                    attributes.len()
                 )));
             }
             // Note that this response does not use to_binary.  This is because the ProvenanceQuerier will
             // respond with the attribute value already wrapped in cosmwasm's Binary struct, so that step
             // can be skipped entirely.
-            Ok(attribute_wrapper
-                .attributes
-                .first()
-                .unwrap()
-                .value
-                .to_owned())
+            Ok(attributes.remove(0).value)
         }
         // The state has been pre-fetched before all query routes.  It derives Serialize and Deserialize, so
         // it is safe to use to_binary on it to use the entire value as a response and serialize it to a Binary
         // struct.
         QueryMsg::QueryState {} => Ok(to_binary(&contract_state)?),
+        // Only the counter is serialized, keeping the response as small as possible
+        QueryMsg::QueryCounter {} => Ok(to_binary(&contract_state.contract_counter)?),
+        // Clients can use this name to look up the attribute directly, without replicating the contract's naming
+        // convention
+        QueryMsg::PreviewAttributeName { attribute_prefix } => Ok(to_binary(
+            &format_attribute_name(&attribute_prefix, &contract_state.contract_base_name),
+        )?),
+        QueryMsg::ListAttributes {} => {
+            let provenance_querier = ProvenanceQuerier::new(&deps.querier);
+            // Each stored prefix is resolved against the attribute module, so the listing always reflects the
+            // values that currently exist on the contract
+            let attributes = contract_state
+                .attribute_prefixes
+                .iter()
+                .map(|attribute_prefix| {
+                    let attribute_name =
+                        format_attribute_name(attribute_prefix, &contract_state.contract_base_name);
+                    let attribute_wrapper = provenance_querier
+                        .get_attributes(env.contract.address.clone(), Some(&attribute_name))?;
+                    // Names grown by the AppendAttribute route hold a list of values, and every one of them is listed
+                    let values = attribute_wrapper
+                        .attributes
+                        .into_iter()
+                        .map(|attribute| attribute.value)
+                        .collect::<Vec<Binary>>();
+                    Ok(ContractAttribute {
+                        attribute_prefix: attribute_prefix.to_owned(),
+                        attribute_name,
+                        value: values.first().cloned(),
+                        values,
+                    })
+                })
+                .collect::<Result<Vec<ContractAttribute>, ContractError>>()?;
+            Ok(to_binary(&ListAttributesResponse { attributes })?)
+        }
+        QueryMsg::QueryContractInfo {} => {
+            // Omitting the name returns every attribute on the contract's address, regardless of the route
+            // that added it
+            let attribute_wrapper = ProvenanceQuerier::new(&deps.querier)
+                .get_attributes(env.contract.address, None::<String>)?;
+            Ok(to_binary(&QueryContractInfoResponse {
+                contract_base_name: contract_state.contract_base_name,
+                attributes: attribute_wrapper.attributes,
+            })?)
+        }
+    }
+}
+
+/// The migrate entry_point is invoked when an existing instance of the contract is moved to a new code version.
+/// It records the new version info, and can optionally overwrite the counter stored in the contract's state.
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut<ProvenanceQuery>,
+    _env: Env,
+    msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    // Ensure that the code being migrated to is a newer version than the one already stored.  Instances that were
+    // instantiated before version info existed will have none in storage, and can always be migrated.
+    if let Ok(version_info) = get_version_info(deps.storage) {
+        let stored_version = version_info.parse_sem_ver()?;
+        let current_version = VersionInfo::current_version().parse_sem_ver()?;
+        if stored_version >= current_version {
+            return Err(ContractError::InvalidVersion { explanation: format!("stored contract version {stored_version} is greater than or equal to the attempted migration version {current_version}. no migration necessary") });
+        }
+    }
+    // Establish the new version declared in the migrating contract codebase
+    let version_info = migrate_version_info(deps.storage)?;
+    let mut attributes: Vec<Attribute> = vec![];
+    if let Some(new_counter_value) = msg.new_counter_value {
+        let mut contract_state = state(deps.storage);
+        let mut state = contract_state.load()?;
+        attributes.push(Attribute::new(
+            "modified_counter_value",
+            format!("{new_counter_value}"),
+        ));
+        state.contract_counter = Uint128::new(new_counter_value);
+        contract_state.save(&state)?;
+    }
+    // Any messages added to a migration Response are ignored, so only attributes are emitted here
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("new_version", &version_info.version)
+        .add_attributes(attributes))
+}
+
+/// Fetches every attribute that the AddAttribute, AddJsonAttribute, or AppendAttribute routes created on the contract
+/// with the given prefix.  An error is returned if no attribute exists, so the result always contains at least one.
+fn query_contract_attributes(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    contract_state: &State,
+    attribute_prefix: &str,
+) -> Result<Vec<provwasm_std::Attribute>, ContractError> {
+    // Construct the expected attribute name from the prefix and the contract base name.  This mirrors
+    // the formatting used in the execute route: AddAttribute.
+    let target_attribute_name =
+        format_attribute_name(attribute_prefix, &contract_state.contract_base_name);
+    // Provwasm provides a wrapper for the cosmwasm's QuerierWrapper, which is contained in deps.querier.
+    // This allows for Provenance Blockchain modules to be queried simply and easily.
+    let provenance_querier = ProvenanceQuerier::new(&deps.querier);
+    // This check is to ensure that the attribute being searched for exists.  The AddAttribute route
+    // ensures that only a single attribute for a single name can be added, but the AppendAttribute route
+    // can add more, so any non-empty result is valid.
+    let attribute_wrapper =
+        provenance_querier.get_attributes(env.contract.address, Some(target_attribute_name))?;
+    if attribute_wrapper.attributes.is_empty() {
+        return Err(ContractError::generic_err(
+            "expected an attribute to exist at the specified name, but found none",
+        ));
+    }
+    Ok(attribute_wrapper.attributes)
+}
+
+/// Converts an attribute value type to the name the attribute module uses for it, like "string" or "json".
+fn attribute_value_type_name(value_type: &AttributeValueType) -> &'static str {
+    match value_type {
+        AttributeValueType::Uuid => "uuid",
+        AttributeValueType::Json => "json",
+        AttributeValueType::String => "string",
+        AttributeValueType::Bytes => "bytes",
+        AttributeValueType::Uri => "uri",
+        AttributeValueType::Int => "int",
+        AttributeValueType::Float => "float",
+        AttributeValueType::Proto => "proto",
+        AttributeValueType::Unspecified => "unspecified",
+    }
+}
+
+/// Ensures that the provided base name can be bound to the contract.  A valid base name is non-empty,
+/// and consists of one or more dot-separated segments that contain only lowercase alphanumeric
+/// characters, like "examples.pio".
+fn validate_contract_base_name(base_name: &str) -> Result<(), ContractError> {
+    let invalid_base_name = |explanation: &str| {
+        Err(ContractError::InvalidBaseName {
+            name: base_name.to_string(),
+            explanation: explanation.to_string(),
+        })
+    };
+    if base_name.is_empty() {
+        return invalid_base_name("the base name cannot be empty");
+    }
+    for segment in base_name.split('.') {
+        if segment.is_empty() {
+            return invalid_base_name(
+                "each dot-separated segment of the base name must be non-empty",
+            );
+        }
+        if !segment
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        {
+            return invalid_base_name(
+                "the base name can only contain lowercase alphanumeric characters and dots",
+            );
+        }
     }
+    Ok(())
 }
 
 /// A function for standardizing the format for sub-names of the base contract name.
@@ -184,6 +424,85 @@ fn check_funds_are_empty<S: Into<String>>(
     }
 }
 
+/// Verifies that the sender provided the fee for adding the given number of attributes, and builds the message that
+/// forwards it to the fee collector.  When the contract charges no fee, the sender must not provide any funds.
+fn collect_add_attribute_fee(
+    add_attribute_fee: &Option<FeeCollectionDetail>,
+    funds: Vec<Coin>,
+    attribute_count: u128,
+) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    let fee_detail = match add_attribute_fee {
+        Some(fee_detail) => fee_detail,
+        None => {
+            // Leverage the funds check to ensure that this free execution route does not receive funds at all
+            check_funds_are_empty(
+                funds,
+                "funds should not be provided when adding an attribute",
+            )?;
+            return Ok(vec![]);
+        }
+    };
+    let fee_amount = fee_detail
+        .fee_collection_amount
+        .amount
+        .checked_mul(Uint128::new(attribute_count))
+        .map_err(|e| ContractError::generic_err(e.to_string()))?;
+    let fee = Coin {
+        denom: fee_detail.fee_collection_amount.denom.clone(),
+        amount: fee_amount,
+    };
+    // The sender must provide exactly the fee amount.  Providing any other coin, or too much of the fee coin,
+    // would cause the extra funds to be held by the contract's address.
+    if funds.len() != 1 || funds[0] != fee {
+        let charge = if attribute_count == 1 {
+            "the charge to add an attribute".to_string()
+        } else {
+            format!("the charge to add {attribute_count} attributes")
+        };
+        return Err(ContractError::InvalidFunds {
+            explanation: format!(
+                "{} is [{}{}]. found funds: {:?}",
+                charge,
+                fee.amount.u128(),
+                fee.denom,
+                funds
+                    .iter()
+                    .map(|coin| format!("{}{}", coin.amount.u128(), coin.denom))
+                    .collect::<Vec<String>>(),
+            ),
+        });
+    }
+    // The provided funds are verified to exactly equal the fee, so they are passed directly to the fee collector
+    Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: fee_detail.fee_collector_address.clone(),
+        amount: vec![fee],
+    })])
+}
+
+/// Verifies that the sender is allowed to modify the counter.  Contracts that were instantiated without an
+/// authorized_addresses list are open to all senders, which retains the original behavior of the counter.
+fn check_sender_is_authorized(state: &State, sender: &Addr) -> Result<(), ContractError> {
+    match &state.authorized_addresses {
+        Some(addresses) if !addresses.contains(sender) => Err(ContractError::Unauthorized {
+            explanation: format!("address [{sender}] is not authorized to modify the counter"),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Verifies that the sender is the contract's admin.
+fn check_sender_is_admin(state: &State, sender: &Addr) -> Result<(), ContractError> {
+    if &state.admin != sender {
+        return Err(ContractError::Unauthorized {
+            explanation: format!(
+                "only the contract admin [{}] can modify the authorized addresses",
+                state.admin
+            ),
+        });
+    }
+    Ok(())
+}
+
 fn increment_counter(
     deps: DepsMut<ProvenanceQuery>,
     info: MessageInfo,
@@ -194,14 +513,31 @@ fn increment_counter(
         info.funds,
         "funds should not be provided when incrementing the counter",
     )?;
-    // If the increment amount provided in the message was present, use it.
-    // Otherwise, default to the standard increment amount of 1. This allows the user to
-    // completely omit the value from the request payload and still get an increment.
-    let amount_to_increment: Uint128 = increment_amount.unwrap_or(1).into();
     let mut state_storage = state(deps.storage);
     // Load the contract state in a mutable manner, allowing the internals to be modified in this execution route
     let mut contract_state = state_storage.load()?;
-    contract_state.contract_counter += amount_to_increment;
+    check_sender_is_authorized(&contract_state, &info.sender)?;
+    // If the increment amount provided in the message was present, use it.
+    // Otherwise, default to the increment amount configured during instantiation, or the standard increment
+    // amount of 1 if none was configured. This allows the user to completely omit the value from the request
+    // payload and still get an increment.
+    let amount_to_increment: Uint128 = match increment_amount {
+        Some(amount) => amount.into(),
+        None => contract_state
+            .default_increment
+            .unwrap_or_else(|| Uint128::new(1)),
+    };
+    // The previous value is captured before incrementing so that the response records the full transition
+    let previous_counter_value = contract_state.contract_counter;
+    // Just like decrementing, a plain addition past the maximum value would panic.  A large configured default
+    // increment makes this reachable, so checked_add is used to surface the overflow as a ContractError instead.
+    contract_state.contract_counter = contract_state
+        .contract_counter
+        .checked_add(amount_to_increment)
+        .map_err(|_| ContractError::CounterOverflow {
+            current_value: contract_state.contract_counter.u128(),
+            increment_amount: amount_to_increment.u128(),
+        })?;
     // After incrementing the counter, it must be saved to the contract's internal state. This will persist
     // the value, and subsequent increments will see the new value. This will also be available and evident in
     // the query routes.
@@ -211,22 +547,119 @@ fn increment_counter(
         .add_attribute(
             "new_counter_value",
             contract_state.contract_counter.to_string(),
-        ))
+        )
+        .add_attribute("previous_counter_value", previous_counter_value.to_string())
+        .add_attribute("sender", info.sender))
 }
 
-fn add_attribute_to_contract(
+fn decrement_counter(
     deps: DepsMut<ProvenanceQuery>,
     info: MessageInfo,
-    env: Env,
-    attribute_name: String,
-    attribute_text: String,
+    decrement_amount: Option<u128>,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     // Leverage the funds check to ensure that this free execution route does not receive funds at all
     check_funds_are_empty(
         info.funds,
-        "funds should not be provided when adding an attribute",
+        "funds should not be provided when decrementing the counter",
     )?;
-    let contract_state = state(deps.storage).load()?;
+    // Just like incrementing, omitting the decrement amount defaults to a decrement of 1
+    let amount_to_decrement: Uint128 = decrement_amount.unwrap_or(1).into();
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    check_sender_is_authorized(&contract_state, &info.sender)?;
+    // The counter is unsigned, so a plain subtraction below zero would panic.  Using checked_sub
+    // allows the underflow to be surfaced as a ContractError instead, which will cleanly reject
+    // the request.
+    contract_state.contract_counter = contract_state
+        .contract_counter
+        .checked_sub(amount_to_decrement)
+        .map_err(|_| ContractError::CounterUnderflow {
+            current_value: contract_state.contract_counter.u128(),
+            decrement_amount: amount_to_decrement.u128(),
+        })?;
+    state_storage.save(&contract_state)?;
+    Ok(Response::new()
+        .add_attribute("action", "execute_decrement_counter")
+        .add_attribute(
+            "new_counter_value",
+            contract_state.contract_counter.to_string(),
+        ))
+}
+
+fn add_authorized_address(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when adding an authorized address",
+    )?;
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    check_sender_is_admin(&contract_state, &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+    // If no list existed before, the counter was open to everyone.  Adding the first address creates the list,
+    // which restricts the counter to only that address from this point forward.
+    let mut authorized_addresses = contract_state.authorized_addresses.unwrap_or_default();
+    if authorized_addresses.contains(&address) {
+        return Err(ContractError::generic_err(format!(
+            "address [{address}] is already authorized"
+        )));
+    }
+    authorized_addresses.push(address.clone());
+    contract_state.authorized_addresses = Some(authorized_addresses);
+    state_storage.save(&contract_state)?;
+    Ok(Response::new()
+        .add_attribute("action", "execute_add_authorized_address")
+        .add_attribute("authorized_address", address))
+}
+
+fn remove_authorized_address(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when removing an authorized address",
+    )?;
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    check_sender_is_admin(&contract_state, &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+    let mut authorized_addresses = contract_state.authorized_addresses.unwrap_or_default();
+    if !authorized_addresses.contains(&address) {
+        return Err(ContractError::generic_err(format!(
+            "address [{address}] is not currently authorized"
+        )));
+    }
+    // Note: If this removes the final address, the list is left empty rather than being removed.  An empty list
+    // locks the counter, which is safer than silently re-opening it to every address.
+    authorized_addresses.retain(|authorized| authorized != &address);
+    contract_state.authorized_addresses = Some(authorized_addresses);
+    state_storage.save(&contract_state)?;
+    Ok(Response::new()
+        .add_attribute("action", "execute_remove_authorized_address")
+        .add_attribute("removed_address", address))
+}
+
+/// Adds an attribute to the contract at the name derived from the given prefix.  When append is false, the name must
+/// not already exist.  When append is true, an existing name bound to the contract receives the attribute as an
+/// additional value, allowing a single name to accumulate a list of values.
+fn add_attribute_to_contract(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    env: Env,
+    attribute_name: String,
+    attribute_value: Binary,
+    attribute_value_type: AttributeValueType,
+    append: bool,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    // Establish a vector of messages that will get appended to the response after all checks have been made
+    let messages = collect_add_attribute_fee(&contract_state.add_attribute_fee, info.funds, 1)?;
     let new_attribute_name =
         format_attribute_name(&attribute_name, &contract_state.contract_base_name);
     let provenance_querier = ProvenanceQuerier::new(&deps.querier);
@@ -234,25 +667,41 @@ fn add_attribute_to_contract(
     // searching for the name, that means that the name was correctly resolved, and is already set on the contract.
     // This check will prevent execution calls from adding duplicate attributes and/or names, which is allowed in the name module of the
     // Provenance blockchain but not the desired functionality for this contract.  For the purposes of this contract, only one attribute
-    // should exist per sub-name.
-    if let Ok(name_result) = provenance_querier.resolve_name(&new_attribute_name) {
-        return Err(ContractError::NameAlreadyExists {
-            name: name_result.name,
-            owner_address: name_result.address.to_string(),
-        });
-    }
+    // should exist per sub-name, unless the attribute is being appended.  Appending still requires that the name belong to the
+    // contract, because the contract cannot add attributes to a restricted name that it does not own.
+    let name_exists = match provenance_querier.resolve_name(&new_attribute_name) {
+        Ok(name_result) if append && name_result.address == env.contract.address => true,
+        Ok(name_result) => {
+            return Err(ContractError::NameAlreadyExists {
+                name: name_result.name,
+                owner_address: name_result.address.to_string(),
+            });
+        }
+        Err(_) => false,
+    };
     // Like in the instantiation function, construct another bind name message using the constructed attribute name.
-    let bind_name_msg = bind_name(
-        &new_attribute_name,
-        // Set the owner of the name as the contract, ensuring that only the contract's address can add
-        // attributes to this name
-        env.contract.address.clone(),
-        // Restrict this name to ensure that only one attribute can be added to it, and only by the contract
-        NameBinding::Restricted,
-    )?;
+    // An appended attribute reuses the existing name, so it is only bound when it does not yet exist.
+    let bind_name_msg = if name_exists {
+        None
+    } else {
+        Some(bind_name(
+            &new_attribute_name,
+            // Set the owner of the name as the contract, ensuring that only the contract's address can add
+            // attributes to this name
+            env.contract.address.clone(),
+            // Restrict this name to ensure that only one attribute can be added to it, and only by the contract
+            NameBinding::Restricted,
+        )?)
+    };
     // Finally, after creating the newly-desired name, craft an add_attribute message that will store an attribute
     // at the newly-created name.  Attributes can only be assigned to existing names, so this must occur after the
     // name is bound to the contract.
+    // Record the prefix so that the ListAttributes query can discover every attribute the contract has added.  An
+    // appended attribute's prefix may already be recorded, and should only be listed once.
+    if !contract_state.attribute_prefixes.contains(&attribute_name) {
+        contract_state.attribute_prefixes.push(attribute_name);
+    }
+    state_storage.save(&contract_state)?;
     let add_attribute_msg = add_attribute(
         // Bind the attribute to the contract itself.  In a normal use-case, the contract itself would not get
         // the attribute, because granting an attribute to the contract essentially has no value.  For the purposes
@@ -260,14 +709,9 @@ fn add_attribute_to_contract(
         env.contract.address,
         // Use the previously-bound name as the attribute's name
         &new_attribute_name,
-        // Serialize the provided text as Binary.  Cosmwasm provides a set of to_binary and from_binary functions
-        // that allow any serializable value to easily be converted.  Serializing custom structs is easy, as well!
-        // Simply #derive(Serialize, Deserialize) using serde and these binary helper functions will automatically
-        // know how to convert them into cosmwasm's Binary struct.
-        to_binary(&attribute_text)?,
-        // The Provenance Blockchain requires that each attribute be tagged with its type.  Custom structs would use
-        // type AttributeValueType::Json, but this simple example just uses a String.
-        AttributeValueType::String,
+        // The value has already been serialized to Binary by the caller, along with its matching type
+        attribute_value,
+        attribute_value_type,
     )?;
     Ok(Response::new()
         // IMPORTANT: The name binding message must be added to the response before the attribute message.
@@ -275,12 +719,202 @@ fn add_attribute_to_contract(
         // it is used to bind an attribute.  Attempting to swap this order will cause an error, because the
         // attribute module requires an associated existing name, which doesn't exist until the first name
         // bind is executed.
-        .add_message(bind_name_msg)
+        .add_messages(bind_name_msg)
         .add_message(add_attribute_msg)
-        .add_attribute("action", "execute_add_attribute")
+        // If no fee is required by the contract, this vector will be empty
+        .add_messages(messages)
+        .add_attribute(
+            "action",
+            if append {
+                "execute_append_attribute"
+            } else {
+                "execute_add_attribute"
+            },
+        )
         .add_attribute("new_attribute_name", new_attribute_name))
 }
 
+/// Adds a batch of plain text attributes to the contract.  Every derived name is checked before any messages are
+/// created, so a single name that already exists rejects the whole batch instead of leaving it partially applied.
+fn add_attributes_to_contract(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    env: Env,
+    attributes: Vec<NewAttribute>,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if attributes.is_empty() {
+        return Err(ContractError::generic_err(
+            "at least one attribute must be provided",
+        ));
+    }
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    let messages = collect_add_attribute_fee(
+        &contract_state.add_attribute_fee,
+        info.funds,
+        attributes.len() as u128,
+    )?;
+    let provenance_querier = ProvenanceQuerier::new(&deps.querier);
+    let mut new_attribute_names: Vec<String> = Vec::with_capacity(attributes.len());
+    for attribute in attributes.iter() {
+        let new_attribute_name =
+            format_attribute_name(&attribute.prefix, &contract_state.contract_base_name);
+        // Binding the same name twice in one batch would fail in the name module after the first bind succeeded
+        if new_attribute_names.contains(&new_attribute_name) {
+            return Err(ContractError::generic_err(format!(
+                "duplicate attribute prefix [{}] provided",
+                attribute.prefix
+            )));
+        }
+        // Just like AddAttribute, a name that resolves already exists and cannot be used for a new attribute
+        if let Ok(name_result) = provenance_querier.resolve_name(&new_attribute_name) {
+            return Err(ContractError::NameAlreadyExists {
+                name: name_result.name,
+                owner_address: name_result.address.to_string(),
+            });
+        }
+        new_attribute_names.push(new_attribute_name);
+    }
+    let mut response = Response::new();
+    for (attribute, new_attribute_name) in attributes.into_iter().zip(new_attribute_names.iter()) {
+        // Each name must be bound before its attribute is added, so the messages are emitted in bind, add pairs
+        response = response
+            .add_message(bind_name(
+                new_attribute_name,
+                env.contract.address.clone(),
+                NameBinding::Restricted,
+            )?)
+            .add_message(add_attribute(
+                env.contract.address.clone(),
+                new_attribute_name,
+                to_binary(&attribute.text)?,
+                AttributeValueType::String,
+            )?);
+        contract_state.attribute_prefixes.push(attribute.prefix);
+    }
+    state_storage.save(&contract_state)?;
+    Ok(response
+        // If no fee is required by the contract, this vector will be empty
+        .add_messages(messages)
+        .add_attribute("action", "execute_add_attributes")
+        .add_attribute("new_attribute_names", new_attribute_names.join(",")))
+}
+
+/// Adds an attribute with a JSON payload to the contract.  The payload is accepted as raw Binary, which allows
+/// any structure to be stored without the contract needing to know its shape ahead of time.  Because of this,
+/// the bytes must be checked to be well-formed JSON before they are handed to the attribute module.
+fn add_json_attribute_to_contract(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    env: Env,
+    attribute_name: String,
+    attribute_json: Binary,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Deserializing to serde's IgnoredAny walks the entire JSON payload without allocating any values for it,
+    // which is a cheap way to verify that it is structurally valid.  The bytes themselves are stored unchanged.
+    if let Err(e) = from_slice::<IgnoredAny>(attribute_json.as_slice()) {
+        return Err(ContractError::InvalidJson {
+            explanation: format!("attribute_json must be well-formed JSON: {e}"),
+        });
+    }
+    add_attribute_to_contract(
+        deps,
+        info,
+        env,
+        attribute_name,
+        attribute_json,
+        AttributeValueType::Json,
+        false,
+    )
+}
+
+fn update_attribute_on_contract(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    env: Env,
+    attribute_prefix: String,
+    attribute_text: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Leverage the funds check to ensure that this free execution route does not receive funds at all
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when updating an attribute",
+    )?;
+    let contract_state = state(deps.storage).load()?;
+    let attribute_name =
+        format_attribute_name(&attribute_prefix, &contract_state.contract_base_name);
+    let provenance_querier = ProvenanceQuerier::new(&deps.querier);
+    // The attribute module's update message requires the original value and type of the attribute being
+    // replaced, so the existing attribute must be fetched before the update can be built.  The AddAttribute
+    // route only ever creates one attribute per name, so anything other than a single result indicates that
+    // the attribute was never added (or that the invariant was somehow broken).
+    let attribute_wrapper =
+        provenance_querier.get_attributes(env.contract.address.clone(), Some(&attribute_name))?;
+    let existing_attribute = match attribute_wrapper.attributes.len() {
+        0 => return Err(ContractError::AttributeNotFound { attribute_name }),
+        1 => attribute_wrapper.attributes.first().unwrap().to_owned(),
+        count => {
+            return Err(ContractError::generic_err(format!(
+                "expected only one attribute to exist at the specified name, but found {count}",
+            )))
+        }
+    };
+    let update_attribute_msg = update_attribute(
+        env.contract.address,
+        &attribute_name,
+        // The original value and type must exactly match the existing attribute, or the attribute module
+        // will be unable to locate the attribute to replace
+        existing_attribute.value,
+        existing_attribute.value_type,
+        // Just like AddAttribute, the new value is plain text, serialized to Binary
+        to_binary(&attribute_text)?,
+        AttributeValueType::String,
+    )?;
+    Ok(Response::new()
+        .add_message(update_attribute_msg)
+        .add_attribute("action", "execute_update_attribute")
+        .add_attribute("updated_attribute_name", attribute_name))
+}
+
+fn delete_attribute_from_contract(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    env: Env,
+    attribute_prefix: String,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    // Leverage the funds check to ensure that this free execution route does not receive funds at all
+    check_funds_are_empty(
+        info.funds,
+        "funds should not be provided when deleting an attribute",
+    )?;
+    let mut state_storage = state(deps.storage);
+    let mut contract_state = state_storage.load()?;
+    let attribute_name =
+        format_attribute_name(&attribute_prefix, &contract_state.contract_base_name);
+    let provenance_querier = ProvenanceQuerier::new(&deps.querier);
+    // Verify that there is actually an attribute to delete.  Emitting a delete message for an attribute that
+    // does not exist would cause the transaction to fail in the attribute module anyway, but checking here
+    // produces a much clearer error.
+    let attribute_wrapper =
+        provenance_querier.get_attributes(env.contract.address.clone(), Some(&attribute_name))?;
+    if attribute_wrapper.attributes.is_empty() {
+        return Err(ContractError::AttributeNotFound { attribute_name });
+    }
+    // Note: Only the attribute is removed here.  The name bound by AddAttribute is intentionally left in place,
+    // so it will still resolve to the contract.  Because the name remains, AddAttribute cannot be used to
+    // re-create an attribute with the same prefix.
+    let delete_attributes_msg = delete_attributes(env.contract.address, &attribute_name)?;
+    // The attribute no longer exists, so it should no longer be included in the ListAttributes query
+    contract_state
+        .attribute_prefixes
+        .retain(|prefix| prefix != &attribute_prefix);
+    state_storage.save(&contract_state)?;
+    Ok(Response::new()
+        .add_message(delete_attributes_msg)
+        .add_attribute("action", "execute_delete_attribute")
+        .add_attribute("deleted_attribute_name", attribute_name))
+}
+
 /// Sends funds provided by the sender in the "amount" field to the specified recipient address.
 /// Note: This functionality can easily be accomplished simply by using Provenance's bank module,
 /// but this route is here to show how simple it is to send funds in a smart contract.  Using this
@@ -335,6 +969,8 @@ mod tests {
     use serde_json_wasm::to_string;
 
     use super::*;
+    use crate::types::FeeCollectionDetail;
+    use crate::version_info::{set_version_info, CONTRACT_NAME, CONTRACT_VERSION};
 
     // Testing all routes defined in a smart contract is incredibly important!  It can prevent unexpected bugs
     // during actual contract execution. While some aspects of contract execution are difficult to mock, having
@@ -359,6 +995,9 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: Some(150),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully when all arguments are as expected");
@@ -448,6 +1087,9 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should succeed when arguments are properly supplied, even without a starting_counter value");
@@ -473,6 +1115,9 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .unwrap_err();
@@ -483,6 +1128,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instantiation_base_name_validation() {
+        let mut deps = mock_dependencies(&[]);
+        for invalid_name in ["", "Test.pio", "test..pio", "test.pio.", "test-name.pio"] {
+            let error = instantiate(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                InitMsg {
+                    contract_base_name: invalid_name.to_string(),
+                    starting_counter: None,
+                    authorized_addresses: None,
+                    default_increment: None,
+                    add_attribute_fee: None,
+                },
+            )
+            .unwrap_err();
+            match error {
+                ContractError::InvalidBaseName { name, .. } => assert_eq!(
+                    invalid_name, name,
+                    "expected the invalid base name to be included in the error",
+                ),
+                _ => panic!(
+                    "expected base name [{}] to cause an InvalidBaseName ContractError, but got error: {:?}",
+                    invalid_name, error,
+                ),
+            }
+        }
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "my1.example.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should succeed with a properly-formed base name");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the contract state to be created by instantiation");
+        assert_eq!("my1.example.pio", state.contract_base_name);
+    }
+
     // This test showcases a round-trip through the contract.  It runs an execution route to
     // increment the contract's internal state's counter, and then runs a query to acquire the
     // value as Binary.
@@ -497,6 +1189,9 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: Some(1),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -514,9 +1209,9 @@ mod tests {
             "the increment counter entry_point should not generate messages"
         );
         assert_eq!(
-            2,
+            4,
             response.attributes.len(),
-            "expected two attributes to be generated when increment counter is executed",
+            "expected four attributes to be generated when increment counter is executed",
         );
         assert!(
             response
@@ -543,10 +1238,69 @@ mod tests {
             6, state.contract_counter.u128(),
             "expected the counter in the contract state to correctly equate to 6, the result of the initial value of 1 + the input value of 5",
         );
+        // The counter query should report the same value as the state query, without the surrounding struct
+        let counter_binary = query(deps.as_ref(), mock_env(), QueryMsg::QueryCounter {})
+            .expect("expected the counter query to respond with a binary");
+        assert_eq!(
+            "\"6\"",
+            String::from_utf8(counter_binary.to_vec()).unwrap(),
+            "expected the counter query to respond with only the serialized counter value",
+        );
+        assert_eq!(
+            state.contract_counter,
+            from_binary::<Uint128>(&counter_binary)
+                .expect("expected the resulting binary to deserialize to a Uint128 without issue"),
+            "expected the counter query to report the same value as the state query",
+        );
     }
 
     // This test showcases excluding an Option parameter from an execute functionality.  When executing a smart contract
     // that has been deployed to Provenance, the JSON payload sent to the contract at that point would omit the field entirely.
+    #[test]
+    fn test_increment_counter_emits_previous_value_and_sender() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(3),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: Some(2),
+            },
+        )
+        .expect("expected the increment counter response to complete successfully");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "new_counter_value" && attr.value == "5"),
+            "expected the new_counter_value attribute to include the incremented value",
+        );
+        assert!(
+            response.attributes.iter().any(|attr| attr.key == "previous_counter_value" && attr.value == "3"),
+            "expected the previous_counter_value attribute to include the value before the increment",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "sender" && attr.value == "example_sender"),
+            "expected the sender attribute to include the address that incremented the counter",
+        );
+    }
+
     #[test]
     fn test_increment_counter_without_increment_amount() {
         let mut deps = mock_dependencies(&[]);
@@ -557,6 +1311,9 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -579,7 +1336,7 @@ mod tests {
     }
 
     #[test]
-    fn test_increment_counter_failures() {
+    fn test_increment_counter_with_default_increment() {
         let mut deps = mock_dependencies(&[]);
         instantiate(
             deps.as_mut(),
@@ -588,30 +1345,1684 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: Some(10),
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully");
-        let error = execute(
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .expect("expected the increment counter response to complete successfully");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            10, state.contract_counter.u128(),
+            "expected the contract counter to be incremented by the configured default increment of 10",
+        );
+        // An explicitly provided amount should always take precedence over the default increment
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: Some(3),
+            },
+        )
+        .expect("expected the increment counter response to complete successfully");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            13,
+            state.contract_counter.u128(),
+            "expected the provided increment amount to be used instead of the default increment",
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_with_default_increment_overflow() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(u128::MAX - 5),
+                authorized_addresses: None,
+                default_increment: Some(10),
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::CounterOverflow {
+                    current_value,
+                    increment_amount: 10,
+                } if current_value == u128::MAX - 5,
+            ),
+            "expected an increment past the maximum value to cause a CounterOverflow ContractError, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            u128::MAX - 5,
+            state.contract_counter.u128(),
+            "expected the counter to be unchanged after an increment was rejected",
+        );
+    }
+
+    #[test]
+    fn test_increment_counter_failures() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(10, "fakecoin")]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected provided fakecoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_decrement_counter_with_explicit_amount() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(10),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::DecrementCounter {
+                decrement_amount: Some(4),
+            },
+        )
+        .expect("expected the decrement counter response to complete successfully");
+        assert!(
+            response.messages.is_empty(),
+            "the decrement counter entry_point should not generate messages"
+        );
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "expected two attributes to be generated when decrement counter is executed",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_decrement_counter"),
+            "expected the action attribute to use the correct value",
+        );
+        assert!(
+            response.attributes.iter().any(|attr| attr.key == "new_counter_value" && attr.value == "6"),
+            "expected the new_counter_value attribute to properly indicate that the counter was decremented by 4",
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            6,
+            state.contract_counter.u128(),
+            "expected the contract counter to be decremented to 6 from its initial value of 10",
+        );
+    }
+
+    #[test]
+    fn test_decrement_counter_without_decrement_amount() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(3),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::DecrementCounter {
+                decrement_amount: None,
+            },
+        )
+        .expect("expected the decrement counter response to complete successfully");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            2,
+            state.contract_counter.u128(),
+            "expected the contract counter to be decremented by the default value of 1",
+        );
+    }
+
+    #[test]
+    fn test_decrement_counter_failures() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(2),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let error = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("example_sender", &[coin(10, "fakecoin")]),
+            ExecuteMsg::DecrementCounter {
+                decrement_amount: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected provided fakecoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::DecrementCounter {
+                decrement_amount: Some(3),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::CounterUnderflow {
+                    current_value: 2,
+                    decrement_amount: 3,
+                },
+            ),
+            "expected a decrement below zero to cause a CounterUnderflow ContractError, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            2,
+            state.contract_counter.u128(),
+            "expected the contract counter to remain unchanged after a rejected decrement",
+        );
+    }
+
+    #[test]
+    fn test_authorized_addresses_restrict_counter_changes() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(5),
+                authorized_addresses: Some(vec!["operator".to_string()]),
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            "admin",
+            state.admin.as_str(),
+            "expected the instantiating address to be set as the admin",
+        );
+        assert_eq!(
+            Some(vec![Addr::unchecked("operator")]),
+            state.authorized_addresses,
+            "expected the authorized addresses to be stored in the state",
+        );
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .expect("an authorized address should be able to increment the counter");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[]),
+            ExecuteMsg::DecrementCounter {
+                decrement_amount: Some(2),
+            },
+        )
+        .expect("an authorized address should be able to decrement the counter");
+        for msg in [
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+            ExecuteMsg::DecrementCounter {
+                decrement_amount: None,
+            },
+        ] {
+            let error =
+                execute(deps.as_mut(), mock_env(), mock_info("stranger", &[]), msg).unwrap_err();
+            assert!(
+                matches!(error, ContractError::Unauthorized { .. }),
+                "expected an unauthorized sender to be rejected with an Unauthorized error, but got error: {:?}",
+                error,
+            );
+        }
+        // The admin is not implicitly authorized to modify the counter
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
             ExecuteMsg::IncrementCounter {
                 increment_amount: None,
             },
         )
         .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the admin to be rejected when not in the authorized list, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            4,
+            state.contract_counter.u128(),
+            "expected only the authorized address's changes to be applied to the counter",
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_authorized_addresses() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        // Without a list, anyone can increment the counter
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .expect("any address should be able to increment the counter when no list is set");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "stranger".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a non-admin to be rejected when adding an address, but got error: {:?}",
+            error,
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .expect("the admin should be able to add an authorized address");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_add_authorized_address"),
+            "expected the action attribute to have the proper value",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "authorized_address" && attr.value == "operator"),
+            "expected the authorized_address attribute to have the proper value",
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::GenericError(..)),
+            "expected adding a duplicate address to be rejected, but got error: {:?}",
+            error,
+        );
+        // Now that a list exists, only the operator can increment the counter
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unlisted address to be rejected after the list was created, but got error: {:?}",
+            error,
+        );
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .expect("the newly-authorized address should be able to increment the counter");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[]),
+            ExecuteMsg::RemoveAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a non-admin to be rejected when removing an address, but got error: {:?}",
+            error,
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .expect("the admin should be able to remove an authorized address");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action"
+                    && attr.value == "execute_remove_authorized_address"),
+            "expected the action attribute to have the proper value",
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveAuthorizedAddress {
+                address: "operator".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::GenericError(..)),
+            "expected removing an address that is not in the list to be rejected, but got error: {:?}",
+            error,
+        );
+        // Removing the final address leaves an empty list, which locks the counter
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("operator", &[]),
+            ExecuteMsg::IncrementCounter {
+                increment_amount: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the removed address to be rejected, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            Some(vec![]),
+            state.authorized_addresses,
+            "expected an empty list to remain after the final address is removed",
+        );
+        assert_eq!(
+            2,
+            state.contract_counter.u128(),
+            "expected only the two permitted increments to be applied to the counter",
+        );
+    }
+
+    // This test showcases how to use provwasm's MockQuerier (encapsulated within the response from mock_dependencies())
+    // to mock out responses from the Provenance Attribute module.  Although this test only uses the attribute mock functionality,
+    // there are also mocks for the other modules that provwasm covers (like the name module).
+    #[test]
+    fn test_add_attribute_and_query_flow() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .expect("expected the add attribute execution route to complete successfully");
+        // A bind name message, and an add attribute message should be included in the response
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "expected two messages to be included in the response"
+        );
+        response.messages.iter().for_each(|msg| match &msg.msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Name(NameMsgParams::BindName {
+                        name,
+                        address,
+                        restrict,
+                    }),
+                ..
+            }) => {
+                assert_eq!("example.test.pio", name, "the name bound should be the concatenation of the contract_base_name and the provided attribute_prefix");
+                assert_eq!(MOCK_CONTRACT_ADDR, address.to_string(), "the address used should be that of the contract itself to bind the name");
+                assert!(restrict, "expected the bound name to be restricted as was defined in the name bind message");
+            }
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                        address,
+                        name,
+                        value,
+                        value_type,
+                    }),
+                ..
+            }) => {
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR,
+                    address.to_string(),
+                    "expected the address the attribute is added to to be the contract's address",
+                );
+                assert_eq!(
+                    "example.test.pio",
+                    name,
+                    "expected the name the attribute is bound to to be the concatenation of the contract_base_name and the provided attribute_prefix",
+                );
+                assert_eq!(
+                    &AttributeValueType::String,
+                    value_type,
+                    "expected the used value type to be a String, as was defined in the add_attribute message",
+                );
+                let value_string = from_binary::<String>(value).expect("expected the value in the add_attribute message to deserialize to a string");
+                assert_eq!(
+                    "my amazing text",
+                    value_string,
+                    "expected the deserialized value string to be the value provided to the execute functionality in the attribute_text parameter",
+                );
+            }
+            msg => panic!(
+                "unexpected msg encountered after executing add_attribute: {:?}",
+                msg
+            ),
+        });
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "expected two attributes to be included in the add_attribute response",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_add_attribute"),
+            "expected the action attribute to have the proper value",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "new_attribute_name" && attr.value == "example.test.pio"),
+            "expected the new_attribute_name attribute to have the proper value",
+        );
+        // This is an example of using provwasm's mock_dependencies() to simulate an attribute's existence.
+        // When these tests run, the Response values are never consumed by the underlying blockchain code,
+        // so any messages emitted in the responses must be manually simulated in order for other processes
+        // to acknowledge their existence (like a query).  The previous test assertions proved that the attribute
+        // was requested and well-formed, so this mock can simulate its existence after the fact.  A more thoroughly
+        // intricate test framework would perhaps add an interceptor function around the execute call to automatically
+        // mock attributes in the querier when they are intercepted.
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            // This input accepts a slice of Tuples of (&str, &str, &str), which
+            // themselves map to: name, value, type
+            &[(
+                "example.test.pio",
+                // The input value, even with plain text, should be encoded using cosmwasm's to_string() serde encoder.
+                // This usage can be expanded further for complex scenarios that also utilize structs, as long as they
+                // derive Serialize and Deserialize.
+                &to_string("my amazing text")
+                    .expect("the attribute text should be properly serialized"),
+                "string",
+            )],
+        );
+        // With a successful mock established to equate to the derived values from the add_attribute message,
+        // a query to the QueryAttribute route should produce the desired output string.
+        let query_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryAttribute {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .expect("the query should execute successfully and find the mocked attribute");
+        let attribute_response = from_binary::<QueryAttributeResponse>(&query_binary)
+            .expect("the binary should deserialize to a QueryAttributeResponse successfully");
+        let attribute_value = from_binary::<String>(&attribute_response.value)
+            .expect("the attribute value should deserialize to a String successfully");
+        assert_eq!(
+            "my amazing text",
+            attribute_value,
+            "expected the query to correctly locate the attribute in the attribute module after mocks were created",
+        );
+        assert_eq!(
+            "string", attribute_response.value_type,
+            "expected the query to include the attribute's value type",
+        );
+    }
+
+    #[test]
+    fn test_append_attribute_and_query_flow() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let append = |deps: DepsMut<ProvenanceQuery>, attribute_text: &str| {
+            execute(
+                deps,
+                mock_env(),
+                mock_info("example_sender", &[]),
+                ExecuteMsg::AppendAttribute {
+                    attribute_prefix: "example".to_string(),
+                    attribute_text: attribute_text.to_string(),
+                },
+            )
+        };
+        // The name does not exist yet, so the first append should bind it before adding the attribute
+        let response = append(deps.as_mut(), "first value")
+            .expect("expected the first append to complete successfully");
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "expected a bind name message and an add attribute message when appending to a new name",
+        );
+        assert!(
+            matches!(
+                &response.messages[0].msg,
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params: ProvenanceMsgParams::Name(NameMsgParams::BindName { .. }),
+                    ..
+                })
+            ),
+            "expected the name to be bound first, but got: {:?}",
+            response.messages[0].msg,
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_append_attribute"),
+            "expected the action attribute to have the proper value",
+        );
+        // Simulate the name that the first append bound to the contract
+        deps.querier
+            .with_names(&[("example.test.pio", MOCK_CONTRACT_ADDR, true)]);
+        let response = append(deps.as_mut(), "second value")
+            .expect("expected appending to an existing name to complete successfully");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "expected only an add attribute message when appending to an existing name",
+        );
+        match &response.messages[0].msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                        name, value, ..
+                    }),
+                ..
+            }) => {
+                assert_eq!("example.test.pio", name);
+                assert_eq!("second value", from_binary::<String>(value).unwrap());
+            }
+            msg => panic!(
+                "unexpected msg encountered after appending an attribute: {:?}",
+                msg
+            ),
+        }
+        assert_eq!(
+            vec!["example".to_string()],
+            state_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .attribute_prefixes,
+            "expected the appended prefix to only be recorded once",
+        );
+        // The AddAttribute route still rejects names that already exist
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "third value".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameAlreadyExists { .. }),
+            "expected adding an attribute to an existing name to be rejected, but got error: {:?}",
+            error,
+        );
+        // Simulate both appended values existing in the attribute module
+        let first_value = to_string("first value").unwrap();
+        let second_value = to_string("second value").unwrap();
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[
+                ("example.test.pio", &first_value, "string"),
+                ("example.test.pio", &second_value, "string"),
+            ],
+        );
+        let attribute_response = from_binary::<QueryAttributeResponse>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryAttribute {
+                    attribute_prefix: "example".to_string(),
+                },
+            )
+            .expect("the query should find both appended values"),
+        )
+        .expect("the binary should deserialize to a QueryAttributeResponse successfully");
+        assert_eq!(
+            vec!["first value".to_string(), "second value".to_string()],
+            attribute_response
+                .values
+                .iter()
+                .map(|value| from_binary::<String>(value).unwrap())
+                .collect::<Vec<String>>(),
+            "expected the query to return every appended value",
+        );
+        assert_eq!(
+            "first value",
+            from_binary::<String>(&attribute_response.value).unwrap(),
+            "expected the value to be the first appended value",
+        );
+        // Listing the contract's attributes should also include every appended value
+        let list_response = from_binary::<ListAttributesResponse>(
+            &query(deps.as_ref(), mock_env(), QueryMsg::ListAttributes {})
+                .expect("the list attributes query should execute successfully"),
+        )
+        .expect("the binary should deserialize to a ListAttributesResponse successfully");
+        assert_eq!(
+            1,
+            list_response.attributes.len(),
+            "expected the appended name to be listed once",
+        );
+        assert_eq!(
+            vec!["first value".to_string(), "second value".to_string()],
+            list_response.attributes[0]
+                .values
+                .iter()
+                .map(|value| from_binary::<String>(value).unwrap())
+                .collect::<Vec<String>>(),
+            "expected the listed attribute to include every appended value",
+        );
+        // The raw query can only represent a single value
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryRawAttribute {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .expect_err("expected the raw query to reject a name with multiple values");
+    }
+
+    #[test]
+    fn test_preview_attribute_name() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let query_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PreviewAttributeName {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .expect("the preview attribute name query should execute successfully");
+        let previewed_name = from_binary::<String>(&query_binary)
+            .expect("the binary should deserialize to a String successfully");
+        assert_eq!(
+            "example.test.pio", previewed_name,
+            "expected the previewed name to be the concatenation of the provided attribute_prefix and the contract_base_name",
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .expect("expected the add attribute execution route to complete successfully");
+        let bound_name = response
+            .messages
+            .iter()
+            .find_map(|msg| match &msg.msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params: ProvenanceMsgParams::Name(NameMsgParams::BindName { name, .. }),
+                    ..
+                }) => Some(name.to_owned()),
+                _ => None,
+            })
+            .expect("expected a bind name message to be included in the add attribute response");
+        assert_eq!(
+            previewed_name, bound_name,
+            "expected the previewed name to match the name bound by the add attribute execution route",
+        );
+    }
+
+    #[test]
+    fn test_list_attributes() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        for (prefix, text) in [("first", "first text"), ("second", "second text")] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("example_sender", &[]),
+                ExecuteMsg::AddAttribute {
+                    attribute_prefix: prefix.to_string(),
+                    attribute_text: text.to_string(),
+                },
+            )
+            .expect("the add attribute route should execute successfully");
+        }
+        // Simulate the attribute module processing both AddAttribute messages
+        let first_value = to_string("first text").unwrap();
+        let second_value = to_string("second text").unwrap();
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[
+                ("first.test.pio", &first_value, "string"),
+                ("second.test.pio", &second_value, "string"),
+            ],
+        );
+        let query_binary = query(deps.as_ref(), mock_env(), QueryMsg::ListAttributes {})
+            .expect("the list attributes query should execute successfully");
+        let response = from_binary::<ListAttributesResponse>(&query_binary)
+            .expect("the binary should deserialize to a ListAttributesResponse successfully");
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "expected both added attributes to be listed",
+        );
+        for (attribute, (prefix, text)) in response
+            .attributes
+            .iter()
+            .zip([("first", "first text"), ("second", "second text")])
+        {
+            assert_eq!(prefix, attribute.attribute_prefix);
+            assert_eq!(format!("{prefix}.test.pio"), attribute.attribute_name);
+            let value = attribute
+                .value
+                .as_ref()
+                .expect("expected the attribute value to be resolved from the attribute module");
+            assert_eq!(
+                text,
+                from_binary::<String>(value).unwrap(),
+                "expected the listed value to match the value stored in the attribute module",
+            );
+        }
+        // Deleting an attribute should remove it from the listing
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::DeleteAttribute {
+                attribute_prefix: "first".to_string(),
+            },
+        )
+        .expect("the delete attribute route should execute successfully");
+        let response = from_binary::<ListAttributesResponse>(
+            &query(deps.as_ref(), mock_env(), QueryMsg::ListAttributes {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec!["second".to_string()],
+            response
+                .attributes
+                .into_iter()
+                .map(|attribute| attribute.attribute_prefix)
+                .collect::<Vec<String>>(),
+            "expected only the remaining attribute to be listed after a deletion",
+        );
+    }
+
+    #[test]
+    fn test_query_contract_info() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        // Simulate attributes that were attached to the contract's address, including one that was not added
+        // by the contract's own execution routes
+        let first_value = to_string("first text").unwrap();
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[
+                ("first.test.pio", &first_value, "string"),
+                ("external.attribute", "{\"key\":\"value\"}", "json"),
+            ],
+        );
+        let query_binary = query(deps.as_ref(), mock_env(), QueryMsg::QueryContractInfo {})
+            .expect("the contract info query should execute successfully");
+        let response = from_binary::<QueryContractInfoResponse>(&query_binary)
+            .expect("the binary should deserialize to a QueryContractInfoResponse successfully");
+        assert_eq!(
+            "test.pio", response.contract_base_name,
+            "expected the contract base name to be returned from the contract's state",
+        );
+        assert_eq!(
+            vec!["first.test.pio", "external.attribute"],
+            response
+                .attributes
+                .iter()
+                .map(|attribute| attribute.name.as_str())
+                .collect::<Vec<&str>>(),
+            "expected every attribute on the contract's address to be returned",
+        );
+        assert_eq!(
+            "first text",
+            from_binary::<String>(&response.attributes[0].value).unwrap(),
+            "expected the attribute values to be returned from the attribute module",
+        );
+        assert_eq!(
+            AttributeValueType::Json,
+            response.attributes[1].value_type,
+            "expected the attribute value types to be returned from the attribute module",
+        );
+    }
+
+    // This test is an example of using provwasm's MockQuerier to mock out a name module response
+    // in order to demonstrate a potential error that can be encountered during contract execution.
+    #[test]
+    fn test_add_attribute_failures() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        // Verify that including funds results in an error
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(15, "othercoin")]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected provided othercoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+        // Verify that an existing name results in an error.  This utilizes provwasm's MockQuerier
+        // to mock out responses from the name module.
+        // This function takes a Tuple of &str that equates to:
+        // 0: The fully-qualified name
+        // 1: The address that the name is bound to
+        // 2: Whether or not the name is restricted
+        deps.querier
+            .with_names(&[("example.test.pio", MOCK_CONTRACT_ADDR, true)]);
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NameAlreadyExists { .. }),
+            "expected an attempt to add an attribute with a name that already exists to be rejected with a NameAlreadyExists error, but got error: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_add_attributes_batch() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_with_add_attribute_fee(deps.as_mut());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(200, "nhash")]),
+            ExecuteMsg::AddAttributes {
+                attributes: vec![
+                    NewAttribute {
+                        prefix: "first".to_string(),
+                        text: "first text".to_string(),
+                    },
+                    NewAttribute {
+                        prefix: "second".to_string(),
+                        text: "second text".to_string(),
+                    },
+                ],
+            },
+        )
+        .expect("expected the add attributes execution route to complete successfully");
+        assert_eq!(
+            5,
+            response.messages.len(),
+            "expected a bind name and add attribute message for each attribute, followed by the fee message",
+        );
+        // Each name must be bound immediately before the attribute that uses it is added
+        for (index, (expected_name, expected_text)) in [
+            ("first.test.pio", "first text"),
+            ("second.test.pio", "second text"),
+        ]
+        .iter()
+        .enumerate()
+        {
+            match &response.messages[index * 2].msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Name(NameMsgParams::BindName {
+                            name,
+                            address,
+                            restrict,
+                        }),
+                    ..
+                }) => {
+                    assert_eq!(
+                        expected_name, name,
+                        "expected the names to be bound in the order they were provided"
+                    );
+                    assert_eq!(
+                        MOCK_CONTRACT_ADDR,
+                        address.to_string(),
+                        "the address used should be that of the contract itself to bind the name"
+                    );
+                    assert!(restrict, "expected the bound name to be restricted");
+                }
+                msg => panic!("expected a bind name message, but got: {:?}", msg),
+            }
+            match &response.messages[index * 2 + 1].msg {
+                CosmosMsg::Custom(ProvenanceMsg {
+                    params:
+                        ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
+                            name,
+                            value,
+                            ..
+                        }),
+                    ..
+                }) => {
+                    assert_eq!(
+                        expected_name, name,
+                        "expected the attribute to be added to the name bound before it"
+                    );
+                    assert_eq!(
+                        expected_text,
+                        &from_binary::<String>(value)
+                            .expect("expected the attribute value to deserialize to a string"),
+                        "expected the attribute value to be the provided text",
+                    );
+                }
+                msg => panic!("expected an add attribute message, but got: {:?}", msg),
+            }
+        }
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "fee-collector".to_string(),
+                amount: vec![coin(200, "nhash")],
+            }),
+            response.messages[4].msg,
+            "expected the fee for both attributes to be forwarded to the fee collector",
+        );
+        let state = state_read(deps.as_ref().storage).load().unwrap();
+        assert_eq!(
+            vec!["first".to_string(), "second".to_string()],
+            state.attribute_prefixes,
+            "expected both prefixes to be recorded in the contract state",
+        );
+    }
+
+    #[test]
+    fn test_add_attributes_rejects_batch_with_existing_name() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        deps.querier
+            .with_names(&[("second.test.pio", MOCK_CONTRACT_ADDR, true)]);
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttributes {
+                attributes: vec![
+                    NewAttribute {
+                        prefix: "first".to_string(),
+                        text: "first text".to_string(),
+                    },
+                    NewAttribute {
+                        prefix: "second".to_string(),
+                        text: "second text".to_string(),
+                    },
+                ],
+            },
+        )
+        .unwrap_err();
+        match error {
+            ContractError::NameAlreadyExists { name, .. } => assert_eq!(
+                "second.test.pio", name,
+                "expected the error to reference the name that already exists",
+            ),
+            error => panic!(
+                "expected a batch containing an existing name to be rejected with a NameAlreadyExists error, but got error: {:?}",
+                error,
+            ),
+        }
+        assert!(
+            state_read(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .attribute_prefixes
+                .is_empty(),
+            "expected no prefixes to be recorded when the batch is rejected",
+        );
+    }
+
+    // Instantiates the contract with a fee of 100nhash required to add an attribute, collected by fee-collector
+    fn instantiate_with_add_attribute_fee(deps: DepsMut<ProvenanceQuery>) {
+        instantiate(
+            deps,
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: Some(FeeCollectionDetail {
+                    fee_collector_address: "fee-collector".to_string(),
+                    fee_collection_amount: coin(100, "nhash"),
+                }),
+            },
+        )
+        .expect("instantiation should complete successfully");
+    }
+
+    #[test]
+    fn test_add_attribute_with_correct_fee() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_with_add_attribute_fee(deps.as_mut());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(100, "nhash")]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .expect("the add attribute route should succeed when the exact fee is provided");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "expected the bind name, add attribute, and fee messages to be included in the response",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "fee-collector".to_string(),
+                    amount: vec![coin(100, "nhash")],
+                })),
+            "expected the fee to be forwarded to the fee collector",
+        );
+    }
+
+    #[test]
+    fn test_add_attribute_without_required_fee() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_with_add_attribute_fee(deps.as_mut());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected omitting the required fee to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert!(
+            state.attribute_prefixes.is_empty(),
+            "no attribute should be recorded when the fee is not paid",
+        );
+    }
+
+    #[test]
+    fn test_add_attribute_with_fee_overpayment() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_with_add_attribute_fee(deps.as_mut());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(101, "nhash")]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected overpaying the fee to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(
+                "example_sender",
+                &[coin(100, "nhash"), coin(1, "otherdenom")],
+            ),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected extra coin denominations to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+    }
+
+    // This test walks through the full lifecycle of changing an attribute's value: it is added, mocked into
+    // existence, updated, and then queried to verify that the new text is returned.
+    #[test]
+    fn test_update_attribute_and_query_flow() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::AddAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .expect("expected the add attribute execution route to complete successfully");
+        let original_value =
+            to_string("my amazing text").expect("the attribute text should be properly serialized");
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[("example.test.pio", &original_value, "string")],
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::UpdateAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my even better text".to_string(),
+            },
+        )
+        .expect("expected the update attribute execution route to complete successfully");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "expected a single update attribute message to be included in the response",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::UpdateAttribute {
+                        address,
+                        name,
+                        original_value: msg_original_value,
+                        original_value_type,
+                        update_value,
+                        update_value_type,
+                    }),
+                ..
+            }) => {
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR,
+                    address.to_string(),
+                    "expected the attribute to be updated on the contract's address",
+                );
+                assert_eq!(
+                    "example.test.pio", name,
+                    "expected the updated attribute name to be derived from the prefix and the contract_base_name",
+                );
+                assert_eq!(
+                    original_value.as_bytes(),
+                    msg_original_value.as_slice(),
+                    "expected the original value to match the existing attribute's value",
+                );
+                assert_eq!(
+                    &AttributeValueType::String,
+                    original_value_type,
+                    "expected the original value type to match the existing attribute's type",
+                );
+                assert_eq!(
+                    "my even better text",
+                    from_binary::<String>(update_value)
+                        .expect("expected the update value to deserialize to a string"),
+                    "expected the update value to be the new attribute text",
+                );
+                assert_eq!(
+                    &AttributeValueType::String,
+                    update_value_type,
+                    "expected the update value type to be a String",
+                );
+            }
+            msg => panic!(
+                "unexpected msg encountered after executing update_attribute: {:?}",
+                msg
+            ),
+        }
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_update_attribute"),
+            "expected the action attribute to have the proper value",
+        );
+        // Simulate the attribute module processing the update message, and verify the query reflects it
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[(
+                "example.test.pio",
+                &to_string("my even better text")
+                    .expect("the attribute text should be properly serialized"),
+                "string",
+            )],
+        );
+        let query_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryRawAttribute {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .expect("the query should execute successfully and find the mocked attribute");
+        assert_eq!(
+            "my even better text",
+            from_binary::<String>(&query_binary)
+                .expect("the binary should deserialize to a String successfully"),
+            "expected the query to return the updated attribute text",
+        );
+    }
+
+    #[test]
+    fn test_update_attribute_failures() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(15, "othercoin")]),
+            ExecuteMsg::UpdateAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds { .. }),
+            "expected provided othercoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
+            error,
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::UpdateAttribute {
+                attribute_prefix: "example".to_string(),
+                attribute_text: "my amazing text".to_string(),
+            },
+        )
+        .unwrap_err();
+        match error {
+            ContractError::AttributeNotFound { attribute_name } => assert_eq!(
+                "example.test.pio", attribute_name,
+                "expected the missing attribute's name to be included in the error",
+            ),
+            e => panic!(
+                "expected an update for a missing attribute to cause an AttributeNotFound error, but got error: {:?}",
+                e
+            ),
+        }
+    }
+
+    #[test]
+    fn test_delete_attribute() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+        // Verify that attempting to delete an attribute that has never been added is rejected
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::DeleteAttribute {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::AttributeNotFound { .. }),
+            "expected a delete for a missing attribute to cause an AttributeNotFound error, but got error: {:?}",
+            error,
+        );
+        deps.querier.with_attributes(
+            MOCK_CONTRACT_ADDR,
+            &[(
+                "example.test.pio",
+                &to_string("my amazing text")
+                    .expect("the attribute text should be properly serialized"),
+                "string",
+            )],
+        );
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[coin(15, "othercoin")]),
+            ExecuteMsg::DeleteAttribute {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .unwrap_err();
         assert!(
             matches!(error, ContractError::InvalidFunds { .. }),
-            "expected provided fakecoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
+            "expected provided othercoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
             error,
         );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("example_sender", &[]),
+            ExecuteMsg::DeleteAttribute {
+                attribute_prefix: "example".to_string(),
+            },
+        )
+        .expect("expected the delete attribute execution route to complete successfully");
+        // Only the attribute should be deleted - no name messages should be emitted
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "expected a single delete attribute message to be included in the response",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteAttribute {
+                        address,
+                        name,
+                    }),
+                ..
+            }) => {
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR,
+                    address.to_string(),
+                    "expected the attribute to be deleted from the contract's address",
+                );
+                assert_eq!(
+                    "example.test.pio", name,
+                    "expected the deleted attribute name to be derived from the prefix and the contract_base_name",
+                );
+            }
+            msg => panic!(
+                "unexpected msg encountered after executing delete_attribute: {:?}",
+                msg
+            ),
+        }
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_delete_attribute"),
+            "expected the action attribute to have the proper value",
+        );
+        assert!(
+            response.attributes.iter().any(
+                |attr| attr.key == "deleted_attribute_name" && attr.value == "example.test.pio"
+            ),
+            "expected the deleted_attribute_name attribute to have the proper value",
+        );
     }
 
-    // This test showcases how to use provwasm's MockQuerier (encapsulated within the response from mock_dependencies())
-    // to mock out responses from the Provenance Attribute module.  Although this test only uses the attribute mock functionality,
-    // there are also mocks for the other modules that provwasm covers (like the name module).
     #[test]
-    fn test_add_attribute_and_query_flow() {
+    fn test_add_json_attribute_and_query_flow() {
         let mut deps = mock_dependencies(&[]);
         instantiate(
             deps.as_mut(),
@@ -620,139 +3031,99 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully");
+        let attribute_json = r#"{"color":"blue","sizes":[1,2,3],"nested":{"enabled":true}}"#;
         let response = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("example_sender", &[]),
-            ExecuteMsg::AddAttribute {
-                attribute_prefix: "example".to_string(),
-                attribute_text: "my amazing text".to_string(),
+            ExecuteMsg::AddJsonAttribute {
+                attribute_prefix: "json".to_string(),
+                attribute_json: Binary::from(attribute_json.as_bytes()),
             },
         )
-        .expect("expected the add attribute execution route to complete successfully");
-        // A bind name message, and an add attribute message should be included in the response
+        .expect("expected the add json attribute execution route to complete successfully");
         assert_eq!(
             2,
             response.messages.len(),
-            "expected two messages to be included in the response"
+            "expected a bind name and an add attribute message to be included in the response",
         );
-        response.messages.iter().for_each(|msg| match &msg.msg {
-            CosmosMsg::Custom(ProvenanceMsg {
-                params:
-                    ProvenanceMsgParams::Name(NameMsgParams::BindName {
-                        name,
-                        address,
-                        restrict,
-                    }),
-                ..
-            }) => {
-                assert_eq!("example.test.pio", name, "the name bound should be the concatenation of the contract_base_name and the provided attribute_prefix");
-                assert_eq!(MOCK_CONTRACT_ADDR, address.to_string(), "the address used should be that of the contract itself to bind the name");
-                assert!(restrict, "expected the bound name to be restricted as was defined in the name bind message");
-            }
+        match &response.messages[1].msg {
             CosmosMsg::Custom(ProvenanceMsg {
                 params:
                     ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute {
-                        address,
                         name,
                         value,
                         value_type,
+                        ..
                     }),
                 ..
             }) => {
                 assert_eq!(
-                    MOCK_CONTRACT_ADDR,
-                    address.to_string(),
-                    "expected the address the attribute is added to to be the contract's address",
-                );
-                assert_eq!(
-                    "example.test.pio",
-                    name,
-                    "expected the name the attribute is bound to to be the concatenation of the contract_base_name and the provided attribute_prefix",
+                    "json.test.pio", name,
+                    "expected the attribute name to be derived from the prefix and the contract_base_name",
                 );
                 assert_eq!(
-                    &AttributeValueType::String,
+                    &AttributeValueType::Json,
                     value_type,
-                    "expected the used value type to be a String, as was defined in the add_attribute message",
+                    "expected the attribute to be tagged as json",
                 );
-                let value_string = from_binary::<String>(value).expect("expected the value in the add_attribute message to deserialize to a string");
                 assert_eq!(
-                    "my amazing text",
-                    value_string,
-                    "expected the deserialized value string to be the value provided to the execute functionality in the attribute_text parameter",
+                    attribute_json.as_bytes(),
+                    value.as_slice(),
+                    "expected the attribute value to be the provided json bytes, unchanged",
                 );
             }
             msg => panic!(
-                "unexpected msg encountered after executing add_attribute: {:?}",
+                "unexpected msg encountered after executing add_json_attribute: {:?}",
                 msg
             ),
-        });
-        assert_eq!(
-            2,
-            response.attributes.len(),
-            "expected two attributes to be included in the add_attribute response",
-        );
-        assert!(
-            response
-                .attributes
-                .iter()
-                .any(|attr| attr.key == "action" && attr.value == "execute_add_attribute"),
-            "expected the action attribute to have the proper value",
-        );
-        assert!(
-            response
-                .attributes
-                .iter()
-                .any(|attr| attr.key == "new_attribute_name" && attr.value == "example.test.pio"),
-            "expected the new_attribute_name attribute to have the proper value",
-        );
-        // This is an example of using provwasm's mock_dependencies() to simulate an attribute's existence.
-        // When these tests run, the Response values are never consumed by the underlying blockchain code,
-        // so any messages emitted in the responses must be manually simulated in order for other processes
-        // to acknowledge their existence (like a query).  The previous test assertions proved that the attribute
-        // was requested and well-formed, so this mock can simulate its existence after the fact.  A more thoroughly
-        // intricate test framework would perhaps add an interceptor function around the execute call to automatically
-        // mock attributes in the querier when they are intercepted.
+        }
         deps.querier.with_attributes(
             MOCK_CONTRACT_ADDR,
-            // This input accepts a slice of Tuples of (&str, &str, &str), which
-            // themselves map to: name, value, type
-            &[(
-                "example.test.pio",
-                // The input value, even with plain text, should be encoded using cosmwasm's to_string() serde encoder.
-                // This usage can be expanded further for complex scenarios that also utilize structs, as long as they
-                // derive Serialize and Deserialize.
-                &to_string("my amazing text")
-                    .expect("the attribute text should be properly serialized"),
-                "string",
-            )],
+            &[("json.test.pio", attribute_json, "json")],
         );
-        // With a successful mock established to equate to the derived values from the add_attribute message,
-        // a query to the QueryAttribute route should produce the desired output string.
         let query_binary = query(
             deps.as_ref(),
             mock_env(),
             QueryMsg::QueryAttribute {
-                attribute_prefix: "example".to_string(),
+                attribute_prefix: "json".to_string(),
             },
         )
         .expect("the query should execute successfully and find the mocked attribute");
-        let attribute_value = from_binary::<String>(&query_binary)
-            .expect("the binary should deserialize to a String successfully");
+        let attribute_response = from_binary::<QueryAttributeResponse>(&query_binary)
+            .expect("the binary should deserialize to a QueryAttributeResponse successfully");
         assert_eq!(
-            "my amazing text",
-            attribute_value,
-            "expected the query to correctly locate the attribute in the attribute module after mocks were created",
+            attribute_json.as_bytes(),
+            attribute_response.value.as_slice(),
+            "expected the query to return the stored json byte-for-byte",
+        );
+        assert_eq!(
+            "json", attribute_response.value_type,
+            "expected the query to identify the attribute as json",
+        );
+        let raw_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryRawAttribute {
+                attribute_prefix: "json".to_string(),
+            },
+        )
+        .expect("the raw query should execute successfully and find the mocked attribute");
+        assert_eq!(
+            attribute_json.as_bytes(),
+            raw_binary.as_slice(),
+            "expected the raw query to return the stored json byte-for-byte",
         );
     }
 
-    // This test is an example of using provwasm's MockQuerier to mock out a name module response
-    // in order to demonstrate a potential error that can be encountered during contract execution.
     #[test]
-    fn test_add_attribute_failures() {
+    fn test_add_json_attribute_failures() {
         let mut deps = mock_dependencies(&[]);
         instantiate(
             deps.as_mut(),
@@ -761,17 +3132,42 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully");
-        // Verify that including funds results in an error
+        for malformed_json in [
+            r#"{"color":"blue""#,
+            "not json",
+            r#"{"color":"blue"} trailing"#,
+            "",
+        ] {
+            let error = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("example_sender", &[]),
+                ExecuteMsg::AddJsonAttribute {
+                    attribute_prefix: "json".to_string(),
+                    attribute_json: Binary::from(malformed_json.as_bytes()),
+                },
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidJson { .. }),
+                "expected malformed json [{}] to cause an InvalidJson ContractError, but got error: {:?}",
+                malformed_json,
+                error,
+            );
+        }
         let error = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("example_sender", &[coin(15, "othercoin")]),
-            ExecuteMsg::AddAttribute {
-                attribute_prefix: "example".to_string(),
-                attribute_text: "my amazing text".to_string(),
+            ExecuteMsg::AddJsonAttribute {
+                attribute_prefix: "json".to_string(),
+                attribute_json: Binary::from(r#"{"color":"blue"}"#.as_bytes()),
             },
         )
         .unwrap_err();
@@ -780,29 +3176,6 @@ mod tests {
             "expected provided othercoin funds to cause an InvalidFunds ContractError, but got error: {:?}",
             error,
         );
-        // Verify that an existing name results in an error.  This utilizes provwasm's MockQuerier
-        // to mock out responses from the name module.
-        // This function takes a Tuple of &str that equates to:
-        // 0: The fully-qualified name
-        // 1: The address that the name is bound to
-        // 2: Whether or not the name is restricted
-        deps.querier
-            .with_names(&[("example.test.pio", MOCK_CONTRACT_ADDR, true)]);
-        let error = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("example_sender", &[]),
-            ExecuteMsg::AddAttribute {
-                attribute_prefix: "example".to_string(),
-                attribute_text: "my amazing text".to_string(),
-            },
-        )
-        .unwrap_err();
-        assert!(
-            matches!(error, ContractError::NameAlreadyExists { .. }),
-            "expected an attempt to add an attribute with a name that already exists to be rejected with a NameAlreadyExists error, but got error: {:?}",
-            error,
-        );
     }
 
     #[test]
@@ -815,6 +3188,9 @@ mod tests {
             InitMsg {
                 contract_base_name: "test.pio".to_string(),
                 starting_counter: None,
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
             },
         )
         .expect("instantiation should complete successfully");
@@ -879,4 +3255,126 @@ mod tests {
             "expected the recipient_address attribute to include the proper value",
         );
     }
+
+    // Instantiation stores the current contract version, so these tests downgrade the stored version to simulate
+    // a deployed instance running older code
+    fn instantiate_with_older_version(mut deps: DepsMut<ProvenanceQuery>) {
+        instantiate_for_migration(deps.branch());
+        set_version_info(
+            deps.storage,
+            &VersionInfo {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting version info should succeed");
+    }
+
+    fn instantiate_for_migration(deps: DepsMut<ProvenanceQuery>) {
+        instantiate(
+            deps,
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                contract_base_name: "test.pio".to_string(),
+                starting_counter: Some(10),
+                authorized_addresses: None,
+                default_increment: None,
+                add_attribute_fee: None,
+            },
+        )
+        .expect("instantiation should complete successfully");
+    }
+
+    #[test]
+    fn test_migrate_with_new_counter_value() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_with_older_version(deps.as_mut());
+        let response = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                new_counter_value: Some(0),
+            },
+        )
+        .expect("migration should execute successfully");
+        assert!(
+            response.messages.is_empty(),
+            "a migration response should never contain messages"
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "new_version" && attr.value == CONTRACT_VERSION),
+            "the new_version attribute should have the correct value",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "modified_counter_value" && attr.value == "0"),
+            "the modified_counter_value attribute should have the correct value",
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            0,
+            state.contract_counter.u128(),
+            "the counter should be reset to the value provided in the migration",
+        );
+        let version_info = get_version_info(deps.as_ref().storage)
+            .expect("version info should be available after a migration");
+        assert_eq!(
+            CONTRACT_VERSION, version_info.version,
+            "the migration should store the new contract version",
+        );
+    }
+
+    #[test]
+    fn test_migrate_without_new_counter_value() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_with_older_version(deps.as_mut());
+        let response = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                new_counter_value: None,
+            },
+        )
+        .expect("migration should execute successfully");
+        assert_eq!(
+            2,
+            response.attributes.len(),
+            "only the action and new_version attributes should be added when no optional values are provided",
+        );
+        let state = state_read(deps.as_ref().storage)
+            .load()
+            .expect("expected the state to load correctly");
+        assert_eq!(
+            10,
+            state.contract_counter.u128(),
+            "the counter should be unchanged when no new value is provided",
+        );
+    }
+
+    #[test]
+    fn test_migrate_to_same_version_fails() {
+        let mut deps = mock_dependencies(&[]);
+        instantiate_for_migration(deps.as_mut());
+        let error = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                new_counter_value: Some(0),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidVersion { .. }),
+            "expected a migration to the already-stored version to be rejected, but got error: {:?}",
+            error,
+        );
+    }
 }
@@ -15,12 +15,55 @@ pub enum ContractError {
     #[error("{0}")]
     GenericError(String),
 
+    /// Occurs when a decrement would cause the contract's counter to drop below zero.  The counter
+    /// is unsigned, so rather than allowing it to wrap around or panic, the request is rejected.
+    #[error("Cannot decrement counter value {current_value} by {decrement_amount}: the counter cannot go below zero")]
+    CounterUnderflow {
+        current_value: u128,
+        decrement_amount: u128,
+    },
+
+    /// Occurs when an increment would cause the contract's counter to exceed the maximum value of a
+    /// u128.  Like an underflow, the request is rejected rather than allowing the counter to panic.
+    #[error("Cannot increment counter value {current_value} by {increment_amount}: the counter cannot exceed the maximum u128 value")]
+    CounterOverflow {
+        current_value: u128,
+        increment_amount: u128,
+    },
+
+    /// Occurs when a route expects an attribute to already exist on the contract at a given name,
+    /// but none has been added yet.
+    #[error("No attribute was found on the contract with name {attribute_name}")]
+    AttributeNotFound { attribute_name: String },
+
+    /// Occurs when the contract_base_name provided during instantiation cannot be bound as a name
+    /// in the Provenance Blockchain name module.
+    #[error("Invalid contract base name [{name}]: {explanation}")]
+    InvalidBaseName { name: String, explanation: String },
+
     #[error("Invalid funds were provided: {explanation}")]
     InvalidFunds { explanation: String },
 
+    /// Occurs when a migration would not move the contract to a newer version.
+    #[error("Invalid contract version: {explanation}")]
+    InvalidVersion { explanation: String },
+
+    /// Occurs when a payload that is expected to be JSON cannot be parsed as such.
+    #[error("Invalid JSON was provided: {explanation}")]
+    InvalidJson { explanation: String },
+
     #[error("Expected the name {name} to not exist, but it was already bound to address {owner_address}")]
     NameAlreadyExists { name: String, owner_address: String },
 
+    /// Occurs when the sender of a message is not allowed to use the route it is attempting to execute.
+    #[error("Unauthorized: {explanation}")]
+    Unauthorized { explanation: String },
+
+    // Ensure that the ContractError can be derived directly from a semver Error.
+    // This will allow the ? operator to magically up-shift semver errors into ContractError.
+    #[error("{0}")]
+    SemVerError(#[from] semver::Error),
+
     // Ensure that the ContractError can be derived directly from a cosmwasm_std StdError.
     // This will allow the ? operator to magically up-shift cosmwasm errors into ContractError.
     #[error("{0}")]
@@ -39,4 +82,110 @@ impl ContractError {
     pub fn generic_err<S: Into<String>>(msg: S) -> Self {
         Self::GenericError(msg.into())
     }
+
+    /// Returns a stable numeric code for this error's variant.  Off-chain applications can switch on
+    /// these codes rather than matching against error message text, which is free to change.  A code
+    /// is never reassigned once used, so any new variant should be given the next unused code.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::GenericError(_) => 1,
+            Self::CounterUnderflow { .. } => 2,
+            Self::AttributeNotFound { .. } => 3,
+            Self::InvalidBaseName { .. } => 4,
+            Self::InvalidFunds { .. } => 5,
+            Self::InvalidVersion { .. } => 6,
+            Self::InvalidJson { .. } => 7,
+            Self::NameAlreadyExists { .. } => 8,
+            Self::Unauthorized { .. } => 9,
+            Self::SemVerError(_) => 10,
+            Self::Std(_) => 11,
+            Self::CounterOverflow { .. } => 12,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::StdError;
+
+    use super::ContractError;
+
+    #[test]
+    fn test_error_codes() {
+        let errors = vec![
+            (ContractError::generic_err("message"), 1),
+            (
+                ContractError::CounterUnderflow {
+                    current_value: 0,
+                    decrement_amount: 1,
+                },
+                2,
+            ),
+            (
+                ContractError::AttributeNotFound {
+                    attribute_name: "name".to_string(),
+                },
+                3,
+            ),
+            (
+                ContractError::InvalidBaseName {
+                    name: "name".to_string(),
+                    explanation: "explanation".to_string(),
+                },
+                4,
+            ),
+            (
+                ContractError::InvalidFunds {
+                    explanation: "explanation".to_string(),
+                },
+                5,
+            ),
+            (
+                ContractError::InvalidVersion {
+                    explanation: "explanation".to_string(),
+                },
+                6,
+            ),
+            (
+                ContractError::InvalidJson {
+                    explanation: "explanation".to_string(),
+                },
+                7,
+            ),
+            (
+                ContractError::NameAlreadyExists {
+                    name: "name".to_string(),
+                    owner_address: "owner".to_string(),
+                },
+                8,
+            ),
+            (
+                ContractError::Unauthorized {
+                    explanation: "explanation".to_string(),
+                },
+                9,
+            ),
+            (
+                ContractError::SemVerError("not a version".parse::<semver::Version>().unwrap_err()),
+                10,
+            ),
+            (ContractError::Std(StdError::generic_err("message")), 11),
+            (
+                ContractError::CounterOverflow {
+                    current_value: u128::MAX,
+                    increment_amount: 1,
+                },
+                12,
+            ),
+        ];
+        for (error, expected_code) in errors {
+            assert_eq!(
+                expected_code,
+                error.code(),
+                "expected the error {:?} to have code {}",
+                error,
+                expected_code,
+            );
+        }
+    }
 }
@@ -3,3 +3,5 @@ pub mod contract;
 pub mod error;
 pub mod msg;
 pub mod state;
+pub mod types;
+pub mod version_info;
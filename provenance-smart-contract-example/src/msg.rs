@@ -1,6 +1,10 @@
+use cosmwasm_std::Binary;
+use provwasm_std::Attribute;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::types::FeeCollectionDetail;
+
 /// The InitMsg is used once in the smart contract lifecycle. When the instantiate execution
 /// route is invoked, this message is expected as input.  Clearly defining all requirements
 /// for the initial state of the smart contract is key to a well-made and useful contract.
@@ -15,6 +19,17 @@ pub struct InitMsg {
     /// it an optional input parameter during instantiation.  If left blank, the initial
     /// value will be zero.
     pub starting_counter: Option<u128>,
+    /// This value restricts which addresses can modify the counter.  If left blank, any address
+    /// will be able to increment or decrement it.  If provided, only the addresses in the list
+    /// will be able to do so, and the address that instantiates the contract will be able to
+    /// add or remove addresses from the list later.
+    pub authorized_addresses: Option<Vec<String>>,
+    /// This value will be used as the amount to increment the counter by when an IncrementCounter
+    /// request does not specify one.  If left blank, the counter will be incremented by one.
+    pub default_increment: Option<u128>,
+    /// This value establishes a fee that must be paid each time an attribute is added to the
+    /// contract.  If left blank, adding attributes will be free.
+    pub add_attribute_fee: Option<FeeCollectionDetail>,
 }
 
 /// The ExecuteMsg will generally be an enum to allow for multiple different types of contract
@@ -32,10 +47,19 @@ pub enum ExecuteMsg {
     /// negatives are included.  This could be changed simply by changing the type to signed,
     /// if one were so inclined to do so.
     IncrementCounter {
-        /// The amount to add to the internal contract counter.  If left blank, a default value
-        /// of one will be used.
+        /// The amount to add to the internal contract counter.  If left blank, the default_increment
+        /// value provided during instantiation will be used, or one if none was provided.
         increment_amount: Option<u128>,
     },
+    /// This execution route decrements the internal counter created during instantiation and
+    /// stored in the contract state.  It mirrors IncrementCounter, but because the counter is
+    /// stored as an unsigned value, the subtraction is checked.  Any request that would cause
+    /// the counter to drop below zero will be rejected with an error instead of wrapping around.
+    DecrementCounter {
+        /// The amount to subtract from the internal contract counter.  If left blank, a default
+        /// value of one will be used.
+        decrement_amount: Option<u128>,
+    },
     /// This execution route will append an attribute to the contract itself, using its reserved
     /// contract_base_name value.  For example, if the base name of the contract was "testcontract.pb"
     /// and the attribute_name value used in this route was "new", the newly-created attribute would be
@@ -49,6 +73,75 @@ pub enum ExecuteMsg {
         /// at name "{attribute_prefix}.{contract_base_name}" and will be plain text.
         attribute_text: String,
     },
+    /// This execution route works just like AddAttribute, but adds several attributes in a single
+    /// request.  Every name "{prefix}.{contract_base_name}" is checked before any messages are
+    /// created, and if any of them already exists, the entire batch will be rejected.  When the
+    /// contract charges a fee to add an attribute, the fee is charged once for each attribute.
+    AddAttributes {
+        /// The attributes to add, in the order that their names will be bound.  Each prefix must be
+        /// unique within the batch.
+        attributes: Vec<NewAttribute>,
+    },
+    /// This execution route works just like AddAttribute, but allows a name to accumulate a list of
+    /// values.  If the name "{attribute_prefix}.{contract_base_name}" has not been bound, it will be
+    /// bound to the contract.  Otherwise, the text is added as an additional attribute value under
+    /// the existing name, rather than rejecting the request.
+    AppendAttribute {
+        /// The sub-name of contract_base_name to be used when appending the attribute.  This behaves
+        /// identically to the attribute_prefix in AddAttribute.
+        attribute_prefix: String,
+        /// The text to append as an attribute body.  Like AddAttribute, this will be plain text.
+        attribute_text: String,
+    },
+    /// This execution route works just like AddAttribute, but stores a JSON payload instead of plain
+    /// text.  The attribute will be tagged with the Json type in the attribute module, allowing
+    /// structured data to be stored on the contract.  The payload must be well-formed JSON, or the
+    /// request will be rejected.
+    AddJsonAttribute {
+        /// The sub-name of contract_base_name to be used when creating the attribute.  This behaves
+        /// identically to the attribute_prefix in AddAttribute.
+        attribute_prefix: String,
+        /// The JSON to use as the attribute body, encoded as base64 in the request payload.  These
+        /// bytes are stored as-is, so the QueryAttribute route will return them unchanged.
+        attribute_json: Binary,
+    },
+    /// This execution route will replace the text of an attribute previously created by the
+    /// AddAttribute route.  The existing attribute is located by its name alone and swapped out
+    /// for the new value.  If no attribute has been added at the derived name, or if multiple values
+    /// have been added to it by the AppendAttribute route, the request will be rejected.
+    UpdateAttribute {
+        /// The sub-name of contract_base_name that was used when the attribute was created.  The
+        /// attribute to update will be located at "{attribute_prefix}.{contract_base_name}".
+        attribute_prefix: String,
+        /// The new text to use as the attribute body.  This will completely replace the previous
+        /// value, and will be stored as plain text.
+        attribute_text: String,
+    },
+    /// This execution route will remove an attribute previously created by the AddAttribute route.
+    /// Only the attribute is deleted; the name that was bound for it remains owned by the contract
+    /// and can still be resolved.  If no attribute has been added at the derived name, the request
+    /// will be rejected.
+    DeleteAttribute {
+        /// The sub-name of contract_base_name that was used when the attribute was created.  The
+        /// attribute to delete will be located at "{attribute_prefix}.{contract_base_name}".
+        attribute_prefix: String,
+    },
+    /// This execution route adds an address to the list of addresses that are authorized to modify
+    /// the counter.  Only the address that instantiated the contract can use this route.  If the
+    /// contract was instantiated without an authorized_addresses list, using this route will create
+    /// one, and the counter will no longer be open to all addresses.
+    AddAuthorizedAddress {
+        /// The bech32 address of the account to allow to modify the counter.
+        address: String,
+    },
+    /// This execution route removes an address from the list of addresses that are authorized to
+    /// modify the counter.  Only the address that instantiated the contract can use this route.
+    /// Removing the final address will leave an empty list, which prevents all addresses from
+    /// modifying the counter.
+    RemoveAuthorizedAddress {
+        /// The bech32 address of the account that should no longer be allowed to modify the counter.
+        address: String,
+    },
     /// This execution route will send the funds passed in to the contract to the target address.
     /// This example will illustrate using cosmwasm's provided functionality for Coin management.
     SendFunds {
@@ -58,6 +151,17 @@ pub enum ExecuteMsg {
     },
 }
 
+/// A single attribute to be added by the AddAttributes execution route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NewAttribute {
+    /// The sub-name of contract_base_name to be used when creating the attribute, exactly like the
+    /// attribute_prefix value of the AddAttribute route.
+    pub prefix: String,
+    /// The text to use as the attribute body, stored as plain text.
+    pub text: String,
+}
+
 /// The QueryMsg will generally be an enum to allow for multiple different types of queries.
 /// The entry_point for queries allows a read-only Deps<ProvenanceQuery>, so mutation of values
 /// is not expected for these routes.
@@ -65,16 +169,108 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     /// This query will attempt to find an attribute with a name that starts with the given
-    /// prefix, and ends with the contract_base_name.  This mirrors the AddAttribute execution
-    /// route, and is designed to lookup existing attributes created by it. The AddAttribute
-    /// route creates String attributes, so the expected response value upon finding an attribute
-    /// will be the String value contained within the attribute.
+    /// prefix, and ends with the contract_base_name.  This mirrors the AddAttribute and
+    /// AddJsonAttribute execution routes, and is designed to lookup existing attributes created
+    /// by them.  The response is a QueryAttributeResponse, which includes the attribute's value
+    /// type so that String and Json values can be told apart.
     QueryAttribute {
         /// The prefix to an existing attribute, always ending in contract_base_name.
         attribute_prefix: String,
     },
+    /// This query behaves exactly like QueryAttribute, but responds with only the attribute's raw
+    /// value bytes.  This was the response of QueryAttribute before value types were included, and
+    /// is kept for callers that expect it.
+    QueryRawAttribute {
+        /// The prefix to an existing attribute, always ending in contract_base_name.
+        attribute_prefix: String,
+    },
     /// This query will return the current values of the contract's State value, which is held
     /// in internal storage.  The value of the counter is stored in State, so this route can also
     /// be used to fetch the current counter value.
     QueryState {},
+    /// This query will return only the current value of the contract's counter, serialized as a
+    /// Uint128.  Clients that frequently poll the counter can use this route to avoid receiving
+    /// the rest of the State value with every request.
+    QueryCounter {},
+    /// This query will return every attribute that has been added to the contract by the
+    /// AddAttribute, AddJsonAttribute, and AppendAttribute execution routes, along with each
+    /// attribute's current values in the attribute module.  The response is a
+    /// ListAttributesResponse.
+    ListAttributes {},
+    /// This query will return the fully-qualified name that the AddAttribute and AddJsonAttribute
+    /// execution routes would bind for the given prefix, serialized as a String.  No lookup is
+    /// performed, so the name is returned whether or not an attribute already exists for it.
+    PreviewAttributeName {
+        /// The prefix to combine with the contract_base_name.
+        attribute_prefix: String,
+    },
+    /// This query will return the contract_base_name stored in the contract's State, along with
+    /// every attribute currently attached to the contract's own address in the attribute module.
+    /// Unlike ListAttributes, this includes attributes that were not added through the contract's
+    /// execution routes.  The response is a QueryContractInfoResponse.
+    QueryContractInfo {},
+}
+
+/// The response to the QueryAttribute query, containing an attribute's value and the type it was
+/// stored with.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryAttributeResponse {
+    /// The attribute's value as stored in the attribute module.  When multiple values have been
+    /// added by the AppendAttribute route, this is the first of them.
+    pub value: Binary,
+    /// The attribute module's name for the value's type, like "string" or "json".
+    pub value_type: String,
+    /// Every value stored at the attribute's name, in the order returned by the attribute module.
+    /// This will only contain more than one value if the AppendAttribute route has been used.
+    pub values: Vec<Binary>,
+}
+
+/// The response to the ListAttributes query, containing one entry for each attribute that has
+/// been added to the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ListAttributesResponse {
+    pub attributes: Vec<ContractAttribute>,
+}
+
+/// A single attribute added to the contract, combining the prefix it was created with and the
+/// value currently stored for it in the attribute module.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractAttribute {
+    /// The prefix provided when the attribute was added.
+    pub attribute_prefix: String,
+    /// The full attribute name, which is the prefix combined with the contract_base_name.
+    pub attribute_name: String,
+    /// The attribute's value as stored in the attribute module.  This will be empty if the
+    /// attribute module has no value for the name.  When multiple values have been added by the
+    /// AppendAttribute route, this is the first of them.
+    pub value: Option<Binary>,
+    /// Every value stored at the attribute's name, in the order returned by the attribute module.
+    /// This will only contain more than one value if the AppendAttribute route has been used.
+    pub values: Vec<Binary>,
+}
+
+/// The response to the QueryContractInfo query, containing the name bound to the contract and the
+/// attributes attached to the contract's address.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryContractInfoResponse {
+    /// The base name bound to the contract during instantiation.
+    pub contract_base_name: String,
+    /// Every attribute currently attached to the contract's address, as returned by the attribute
+    /// module.
+    pub attributes: Vec<Attribute>,
+}
+
+/// The MigrateMsg is used when an existing instance of the contract is upgraded to a new code
+/// version.  Each value is optional, so a migration can simply move the contract to the new
+/// version without changing anything else in its state.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrateMsg {
+    /// If provided, the contract's counter will be overwritten with this value during the
+    /// migration.  This allows the counter of a deployed instance to be reset or adjusted.
+    pub new_counter_value: Option<u128>,
 }
@@ -1,8 +1,10 @@
-use cosmwasm_std::{Storage, Uint128};
+use cosmwasm_std::{Addr, Storage, Uint128};
 use cosmwasm_storage::{singleton, singleton_read, ReadonlySingleton, Singleton};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::types::FeeCollectionDetail;
+
 // Each value saved into cosmwasm standard storage must have a byte array as its key.
 // Cosmwasm Docs: https://docs.cosmwasm.com/dev-academy/develop-smart-contract/intro/
 // Their tutorial also goes over using cosmwasm storage plus's Item struct, which is
@@ -22,6 +24,26 @@ pub struct State {
     /// IncrementCounter execution route, and will be used to demonstrate mutating the
     /// internal contract storage.
     pub contract_counter: Uint128,
+    /// The address that instantiated the contract.  Only this address is allowed to modify the
+    /// authorized_addresses list.
+    pub admin: Addr,
+    /// An optional list of addresses that are allowed to modify the counter.  When this value is
+    /// None, any address can modify the counter.  When it is set, only the addresses it contains
+    /// can do so.  Note that an empty list is still a set value, and will prevent all addresses
+    /// from modifying the counter.
+    pub authorized_addresses: Option<Vec<Addr>>,
+    /// The amount that the IncrementCounter execution route adds to the counter when its request
+    /// omits an increment_amount.  When this value is None, a default of one is used.
+    pub default_increment: Option<Uint128>,
+    /// The prefixes of every attribute that has been added to the contract by the AddAttribute and
+    /// AddJsonAttribute execution routes.  A prefix is removed when its attribute is deleted.
+    /// Contracts instantiated before this value existed will start with an empty list.
+    #[serde(default)]
+    pub attribute_prefixes: Vec<String>,
+    /// An optional fee that must be paid to add an attribute to the contract.  When set, the
+    /// AddAttribute and AddJsonAttribute execution routes require the exact fee amount to be sent,
+    /// and forward it to the fee collector.
+    pub add_attribute_fee: Option<FeeCollectionDetail>,
 }
 
 /// This function loads the state in a mutable manner, taking a mutable reference to the